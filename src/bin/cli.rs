@@ -0,0 +1,341 @@
+// hardware-diagnostic - Ferramenta de diagnóstico de hardware
+// Copyright (C) 2025  Seu Nome
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Módulo `cli` - Argumentos de linha de comando
+//! 
+//! Definidos com `clap`: aceita `--opcao=valor`, flags curtas combinadas
+//! (`-sf`) e opções em qualquer ordem. Argumentos inválidos são reportados
+//! no stderr com código de saída 2.
+//! 
+//! Sem subcomando, a CLI exibe o resumo (ou a saída escolhida pelas opções);
+//! os subcomandos `cpu`, `ram`, `disk` e `score` consultam só um componente.
+
+use clap::{ArgAction, Args, Parser, Subcommand};
+use hardware_diagnostic::{Language, OutputFormat, PerformanceCategory, ScoreProfile, ScoreWeights};
+use std::net::IpAddr;
+use std::path::PathBuf;
+
+/// Exemplos exibidos ao final de `--help`
+const EXAMPLES: &str = "\
+Exemplos:
+  hardware-diagnostic
+  hardware-diagnostic cpu --json
+  hardware-diagnostic disk --no-color
+  hardware-diagnostic --full --ascii
+  hardware-diagnostic score --weights cpu=0.5,ram=0.3,disk=0.2 --threshold 6 --exit-code 3
+  hardware-diagnostic report
+  hardware-diagnostic --save
+  hardware-diagnostic -fs --output relatorios/diagnostico.txt
+  hardware-diagnostic --save=relatorios/pc-01.txt
+  hardware-diagnostic --json --pretty
+  hardware-diagnostic --save --json
+  hardware-diagnostic --format markdown --save
+  hardware-diagnostic --score-only
+  hardware-diagnostic --csv > frota.csv
+  hardware-diagnostic --csv-no-header >> frota.csv
+  hardware-diagnostic --markdown > diagnostico.md
+  hardware-diagnostic --prometheus > metrics.prom
+  hardware-diagnostic --metrics-server 9101 --metrics-interval 60 --verbose
+  hardware-diagnostic --metrics-server 9101 --metrics-bind 0.0.0.0
+  hardware-diagnostic --html relatorio.html
+  hardware-diagnostic --snapshot antes.json
+  hardware-diagnostic --compare antes.json
+  hardware-diagnostic --benchmark
+  hardware-diagnostic --benchmark 30
+  hardware-diagnostic --watch 5
+  hardware-diagnostic --profile server --config limites.toml
+  hardware-diagnostic --dump-config > diagnostic.toml
+  hardware-diagnostic --config diagnostic.toml
+  hardware-diagnostic --alert-config alertas.toml --alert-log alertas.log
+  hardware-diagnostic --config diagnostic.toml --alert-config
+  hardware-diagnostic --reference optiplex-7090.json
+  hardware-diagnostic --lang en
+  hardware-diagnostic --history historico.jsonl
+  hardware-diagnostic --threshold 6.5
+  hardware-diagnostic --threshold-category BomEstado --exit-code 3";
+
+/// Ferramenta de diagnóstico de hardware com sistema de pontuação
+#[derive(Debug, Parser)]
+#[command(name = "hardware-diagnostic", disable_help_flag = true, after_help = EXAMPLES)]
+pub struct Cli {
+    /// Consulta apenas um componente, ou o relatório completo
+    #[command(subcommand)]
+    pub command: Option<Command>,
+    
+    /// Salva o relatório completo em arquivo (padrão diagnostico_<timestamp>.txt,
+    /// ou com a extensão do formato de --format); o caminho vem após `=`
+    /// (`--save=ARQ`) ou em --output, para que `-sf` continue sendo duas flags
+    #[arg(short, long, value_name = "ARQ", num_args = 0..=1, require_equals = true)]
+    pub save: Option<Option<PathBuf>>,
+    
+    /// Exibe o relatório completo
+    #[arg(short, long)]
+    pub full: bool,
+    
+    /// Caminho do arquivo gravado por --save ou --html
+    #[arg(short, long, value_name = "ARQ")]
+    pub output: Option<PathBuf>,
+    
+    /// Emite o diagnóstico completo (ou o do subcomando) em JSON
+    #[arg(short, long, global = true)]
+    pub json: bool,
+    
+    /// Formata o JSON dos subcomandos com indentação (use com --json); o
+    /// relatório completo em JSON é sempre indentado
+    #[arg(long, global = true, requires = "json")]
+    pub pretty: bool,
+    
+    /// Emite apenas a pontuação geral (com --json, um objeto mínimo); o código
+    /// de saída indica a categoria: 0 bom estado, 1 precaução, 2 manutenção, 3 descarte
+    #[arg(long)]
+    pub score_only: bool,
+    
+    /// Formato da saída: text, json, csv, html, markdown ou prometheus; com
+    /// --save, grava o arquivo nesse formato
+    #[arg(long, value_name = "FORMATO", value_parser = parse_format,
+        conflicts_with_all = ["json", "csv", "csv_no_header", "markdown", "prometheus", "html"])]
+    pub format: Option<OutputFormat>,
+    
+    /// Emite o resumo da máquina em CSV, com cabeçalho
+    #[arg(long)]
+    pub csv: bool,
+    
+    /// Emite apenas a linha CSV, sem cabeçalho
+    #[arg(long)]
+    pub csv_no_header: bool,
+    
+    /// Emite o relatório em Markdown
+    #[arg(short, long)]
+    pub markdown: bool,
+    
+    /// Emite as métricas no formato de texto do Prometheus
+    #[arg(long)]
+    pub prometheus: bool,
+    
+    /// Salva o relatório HTML (o mesmo que --format html --save; padrão
+    /// diagnostico_<timestamp>.html)
+    #[arg(long, value_name = "ARQ", num_args = 0..=1)]
+    pub html: Option<Option<PathBuf>>,
+    
+    /// Salva o diagnóstico completo em JSON, para --compare
+    #[arg(long, value_name = "ARQ")]
+    pub snapshot: Option<PathBuf>,
+    
+    /// Compara uma fotografia salva com um diagnóstico novo
+    #[arg(long, value_name = "ARQ")]
+    pub compare: Option<PathBuf>,
+    
+    /// Mede CPU, memória e disco com carga real e pontua a máquina pelo
+    /// desempenho medido; sem N, o benchmark rápido (~3 s), com N, até N segundos
+    #[arg(long, value_name = "N", num_args = 0..=1, value_parser = clap::value_parser!(u64).range(1..))]
+    pub benchmark: Option<Option<u64>>,
+    
+    /// Serve as métricas do Prometheus em http://127.0.0.1:PORTA/metrics
+    /// até o processo ser encerrado
+    #[arg(long, value_name = "PORTA")]
+    pub metrics_server: Option<u16>,
+    
+    /// Endereço de escuta de --metrics-server; o padrão aceita só conexões
+    /// locais, use 0.0.0.0 para expor as métricas na rede
+    #[arg(long, value_name = "ENDERECO", default_value = "127.0.0.1", requires = "metrics_server")]
+    pub metrics_bind: IpAddr,
+    
+    /// Intervalo de atualização das métricas de --metrics-server
+    #[arg(long, value_name = "N", default_value_t = 30, requires = "metrics_server",
+        value_parser = clap::value_parser!(u64).range(1..))]
+    pub metrics_interval: u64,
+    
+    /// Registra cada raspagem de --metrics-server no stderr
+    #[arg(long)]
+    pub verbose: bool,
+    
+    /// Repete o diagnóstico a cada N segundos (Ctrl-C para sair)
+    #[arg(short, long, value_name = "N", value_parser = clap::value_parser!(u64).range(1..))]
+    pub watch: Option<u64>,
+    
+    /// Carrega limiares, pesos e alertas de um arquivo TOML ou JSON
+    #[arg(long, value_name = "ARQ")]
+    pub config: Option<PathBuf>,
+    
+    /// Pesos predefinidos: workstation, server ou laptop (inclui a bateria);
+    /// --config é aplicado sobre o perfil e ajusta só o que o arquivo define
+    #[arg(long, value_name = "PERFIL", value_parser = parse_profile)]
+    pub profile: Option<ScoreProfile>,
+    
+    /// Verifica alertas; sai com 2 (crítico), 1 (aviso) ou 0. Sem arquivo,
+    /// usa a tabela [alerts] de --config (ou os limiares padrão)
+    #[arg(long, value_name = "ARQ", num_args = 0..=1)]
+    pub alert_config: Option<Option<PathBuf>>,
+    
+    /// Acrescenta os alertas a um log (use com --alert-config)
+    #[arg(long, value_name = "ARQ", requires = "alert_config")]
+    pub alert_log: Option<PathBuf>,
+    
+    /// Compara a máquina com a especificação do modelo (JSON ou TOML);
+    /// sai com 1 se faltar RAM, núcleos ou discos, ou se um disco mudou de tipo
+    #[arg(long, value_name = "ARQ")]
+    pub reference: Option<PathBuf>,
+    
+    /// Exibe a configuração atual em TOML
+    #[arg(long)]
+    pub dump_config: bool,
+    
+    /// Acrescenta a pontuação a um histórico JSONL e exibe a tendência das
+    /// últimas 30 execuções
+    #[arg(long, value_name = "ARQ")]
+    pub history: Option<PathBuf>,
+    
+    /// Sai com erro se a pontuação ficar abaixo de N (0 a 10)
+    #[arg(long, value_name = "N", value_parser = parse_score, conflicts_with = "threshold_category")]
+    pub threshold: Option<f64>,
+    
+    /// Mínimo pela categoria: BomEstado, Precaucao, Manutencao ou Descarte
+    #[arg(long, value_name = "CAT", value_parser = parse_category)]
+    pub threshold_category: Option<PerformanceCategory>,
+    
+    /// Código de saída usado por --threshold, também no subcomando score
+    #[arg(long, global = true, value_name = "N", default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..))]
+    pub exit_code: u8,
+    
+    /// Desativa as cores ANSI na saída (o mesmo que NO_COLOR=1)
+    #[arg(long, global = true)]
+    pub no_color: bool,
+    
+    /// Saída em ASCII puro: barras com # e -, sem emojis nem setas, para
+    /// consoles seriais e terminais sem Unicode
+    #[arg(long, global = true)]
+    pub ascii: bool,
+    
+    /// Idioma da saída: pt (padrão) ou en
+    #[arg(long, value_name = "IDIOMA", value_parser = parse_language)]
+    pub lang: Option<Language>,
+    
+    /// Mostra esta ajuda
+    #[arg(short, long, global = true, action = ArgAction::Help)]
+    pub help: Option<bool>,
+}
+
+/// Subcomandos: cada um coleta apenas o que exibe
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Apenas as informações da CPU
+    Cpu,
+    /// Apenas as informações de memória
+    Ram,
+    /// Apenas as informações dos discos
+    Disk,
+    /// Apenas a pontuação de desempenho
+    Score(ScoreArgs),
+    /// Relatório completo com a decisão recomendada
+    Report,
+}
+
+/// Opções do subcomando `score`
+#[derive(Debug, Args)]
+pub struct ScoreArgs {
+    /// Pesos gerais da pontuação, somando 1.0 (ex: cpu=0.5,ram=0.3,disk=0.2)
+    #[arg(long, value_name = "PESOS", value_parser = parse_weights)]
+    pub weights: Option<ScoreWeights>,
+    
+    /// Sai com erro (código de --exit-code) se a pontuação ficar abaixo de N (0 a 10)
+    #[arg(long, value_name = "N", value_parser = parse_score)]
+    pub threshold: Option<f64>,
+}
+
+/// Converte o código de `--lang` (pt ou en)
+fn parse_language(code: &str) -> Result<Language, String> {
+    Language::from_code(code).ok_or_else(|| format!("idioma desconhecido '{}' (use pt ou en)", code))
+}
+
+/// Converte o nome de `--format`
+fn parse_format(name: &str) -> Result<OutputFormat, String> {
+    OutputFormat::from_name(name)
+        .ok_or_else(|| format!("formato desconhecido '{}' (use text, json, csv, html, markdown ou prometheus)", name))
+}
+
+/// Converte o nome de `--profile` (workstation, server ou laptop)
+fn parse_profile(name: &str) -> Result<ScoreProfile, String> {
+    ScoreProfile::from_name(name)
+        .ok_or_else(|| format!("perfil desconhecido '{}' (use workstation, server ou laptop)", name))
+}
+
+/// Converte a pontuação de `--threshold`, que deve estar entre 0 e 10
+fn parse_score(value: &str) -> Result<f64, String> {
+    match value.parse::<f64>() {
+        Ok(score) if (0.0..=10.0).contains(&score) => Ok(score),
+        _ => Err(format!("'{}' não é uma pontuação de 0 a 10", value)),
+    }
+}
+
+/// Converte a categoria de `--threshold-category`, com ou sem acentos
+fn parse_category(name: &str) -> Result<PerformanceCategory, String> {
+    let normalized = name.to_lowercase().replace('ç', "c").replace('ã', "a");
+    
+    PerformanceCategory::all()
+        .iter()
+        .find(|category| category.to_ascii_name().to_lowercase() == normalized)
+        .cloned()
+        .ok_or_else(|| format!(
+            "categoria desconhecida '{}' (use BomEstado, Precaucao, Manutencao ou Descarte)",
+            name
+        ))
+}
+
+/// Converte os pesos de `--weights` no formato `cpu=0.5,ram=0.3,disk=0.2`
+/// 
+/// Os três componentes são obrigatórios, em qualquer ordem.
+fn parse_weights(value: &str) -> Result<ScoreWeights, String> {
+    let (mut cpu, mut ram, mut disk) = (None, None, None);
+    
+    for pair in value.split(',') {
+        let (name, weight) = pair
+            .split_once('=')
+            .ok_or_else(|| format!("'{}' não está no formato componente=peso", pair))?;
+        let weight: f64 = weight
+            .trim()
+            .parse()
+            .map_err(|_| format!("peso inválido '{}' para {}", weight, name))?;
+        
+        match name.trim().to_ascii_lowercase().as_str() {
+            "cpu" => cpu = Some(weight),
+            "ram" => ram = Some(weight),
+            "disk" => disk = Some(weight),
+            other => return Err(format!("componente desconhecido '{}' (use cpu, ram e disk)", other)),
+        }
+    }
+    
+    match (cpu, ram, disk) {
+        (Some(cpu), Some(ram), Some(disk)) => ScoreWeights::new(cpu, ram, disk).map_err(|e| e.to_string()),
+        _ => Err("informe os pesos de cpu, ram e disk".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_short_flags_combined_with_save() {
+        // `-sf` são duas flags, não `--save f`
+        let cli = Cli::try_parse_from(["hardware-diagnostic", "-sf"]).unwrap();
+        assert_eq!(cli.save, Some(None));
+        assert!(cli.full);
+        
+        let cli = Cli::try_parse_from(["hardware-diagnostic", "--save=relatorio.txt"]).unwrap();
+        assert_eq!(cli.save, Some(Some(PathBuf::from("relatorio.txt"))));
+        assert!(!cli.full);
+    }
+}
@@ -18,77 +18,734 @@
 //! 
 //! Aplicação de linha de comando para diagnóstico de hardware.
 
-use hardware_diagnostic::engine::utils;
-use hardware_diagnostic::{calculate_performance_score, display_performance_score, PerformanceCategory};
-use std::env;
+/// `println!` com o texto passado por `utils::render_text`, para que as
+/// linhas de status também respeitem o `--ascii`
+macro_rules! println_text {
+    ($($arg:tt)*) => {
+        println!("{}", hardware_diagnostic::engine::utils::render_text(&format!($($arg)*)))
+    };
+}
+
+/// `eprintln!` com o texto passado por `utils::render_text`, como [`println_text`]
+macro_rules! eprintln_text {
+    ($($arg:tt)*) => {
+        eprintln!("{}", hardware_diagnostic::engine::utils::render_text(&format!($($arg)*)))
+    };
+}
+
+mod cli;
+mod metrics_server;
+
+use hardware_diagnostic::engine::memory::memory_layout;
+use hardware_diagnostic::engine::{utils, DEFAULT_CPU_SAMPLE_INTERVAL};
+use hardware_diagnostic::{
+    calculate_performance_score_with_config, cpu_info, disk_info, display_performance_score_with_config, ram_info, render,
+    run_benchmark, run_quick_benchmark, AlertConfig,
+    AlertManager, AlertSeverity, DiagnosticConfig, DiagnosticReport, Language, OutputFormat, PerformanceCategory, PerformanceScore,
+    ReferenceProfile, ReportEnvelope, ScoreProfile, ScoringConfig, SystemContext, TimestampedScore,
+};
+use clap::Parser;
+use cli::{Cli, Command, ScoreArgs};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Execuções mais recentes do `--history` consideradas na tendência
+#[cfg_attr(not(feature = "serde"), allow(dead_code))]
+const HISTORY_TREND_SNAPSHOTS: usize = 30;
+
+/// Passo de espera do `--watch` entre verificações do Ctrl-C
+const WATCH_POLL_INTERVAL: Duration = Duration::from_millis(100);
 
 fn main() {
-    let args: Vec<String> = env::args().collect();
+    // Argumentos inválidos: mensagem no stderr e código de saída 2
+    let cli = Cli::parse();
+    
+    // Sem cores (`--no-color`, `NO_COLOR` ou `TERM=dumb`): precisa valer
+    // antes de qualquer saída
+    utils::set_ansi_enabled(!cli.no_color && utils::AnsiSupport::detect().is_enabled());
+    if cli.ascii {
+        utils::set_bar_style(utils::BarStyle::Ascii);
+        utils::set_ascii_output(true);
+    }
+    
+    // Perfil de uso: pesos gerais e da bateria de partida, que `--config` pode ajustar
+    let mut diagnostic = DiagnosticConfig::default();
+    if let Some(profile) = cli.profile {
+        profile.apply(&mut diagnostic.scoring);
+    }
+    
+    // Limiares, pesos e alertas de `--config <arquivo>`, sobre o perfil
+    if let Some(path) = &cli.config {
+        diagnostic = read_config_file(path, &diagnostic);
+    }
+    
+    // Idioma: `--lang` prevalece sobre o definido no arquivo de configuração
+    if let Some(language) = cli.lang {
+        diagnostic.scoring.language = language;
+    }
+    
+    if cli.profile == Some(ScoreProfile::Laptop)
+        && diagnostic.scoring.battery_weight > 0.0
+        && hardware_diagnostic::battery_info().is_none()
+    {
+        eprintln_text!("⚠️  Perfil laptop: nenhuma bateria detectada, a pontuação não inclui a bateria");
+    }
+    
+    // Pontuação com os limiares explícitos do arquivo já aplicados
+    let scoring = diagnostic.scoring_config();
+    let config = &scoring;
+    
+    // Subcomandos: apenas o componente pedido é coletado
+    if let Some(command) = &cli.command {
+        match command {
+            Command::Cpu => present_cpu(cli.json, cli.pretty),
+            Command::Ram => present_ram(cli.json, cli.pretty),
+            Command::Disk => present_disk(cli.json, cli.pretty),
+            Command::Score(args) => present_score(args, cli.json, cli.exit_code, config),
+            Command::Report if cli.json => present_format(OutputFormat::Json, &cli, config),
+            Command::Report => present_report(&cli, config, true),
+        }
+        return;
+    }
+    
+    // Exibe a configuração efetiva em TOML, pronta para ser editada
+    if cli.dump_config {
+        dump_config(&diagnostic);
+        return;
+    }
+    
+    // Apenas a pontuação, para scripts; o código de saída indica a categoria
+    if cli.score_only {
+        print_score_only(cli.json, config);
+    }
+    
+    // Formato escolhido por `--format`; `--json`, `--csv`, `--markdown`,
+    // `--prometheus` e `--html` são atalhos
+    let format = if let Some(format) = cli.format {
+        Some(format)
+    } else if cli.json {
+        Some(OutputFormat::Json)
+    } else if cli.csv || cli.csv_no_header {
+        Some(OutputFormat::Csv)
+    } else if cli.markdown {
+        Some(OutputFormat::Markdown)
+    } else if cli.prometheus {
+        Some(OutputFormat::Prometheus)
+    } else if cli.html.is_some() {
+        Some(OutputFormat::Html)
+    } else {
+        None
+    };
+    if let Some(format) = format {
+        present_format(format, &cli, config);
+        return;
+    }
+    
+    // Fotografia em JSON para comparar depois com `--compare`
+    if let Some(path) = &cli.snapshot {
+        save_snapshot(path, config);
+        return;
+    }
+    
+    // Antes e depois: compara uma fotografia salva com um diagnóstico novo
+    if let Some(path) = &cli.compare {
+        compare_snapshot(path, config);
+        return;
+    }
+    
+    // Benchmark opcional: carga real de CPU, memória e disco, limitada no tempo
+    if let Some(seconds) = cli.benchmark {
+        print_benchmark(seconds.map(Duration::from_secs), config);
+        return;
+    }
+    
+    // Alertas por limiar: código de saída 2 (crítico), 1 (aviso) ou 0 (sem alertas)
+    if let Some(path) = &cli.alert_config {
+        let alert_config = match path {
+            Some(path) => read_alert_config(path),
+            None => diagnostic.alert_thresholds.clone(),
+        };
+        std::process::exit(check_alerts(&alert_config, config, cli.alert_log.as_deref()));
+    }
+    
+    // Integridade do equipamento: desvios em relação à especificação do modelo
+    if let Some(path) = &cli.reference {
+        let profile = read_reference_profile(path);
+        std::process::exit(check_reference(&profile, config));
+    }
+    
+    // Exportador do Prometheus: serve /metrics até o processo ser encerrado
+    if let Some(port) = cli.metrics_server {
+        let interval = Duration::from_secs(cli.metrics_interval);
+        if let Err(e) = metrics_server::serve((cli.metrics_bind, port).into(), interval, config, cli.verbose) {
+            eprintln_text!("❌ Erro ao abrir {}:{}: {}", cli.metrics_bind, port, e);
+            std::process::exit(1);
+        }
+        return;
+    }
+    
+    // Modo de monitoramento contínuo: repete o diagnóstico até Ctrl-C
+    if let Some(seconds) = cli.watch {
+        watch(Duration::from_secs(seconds), config);
+        return;
+    }
+    
+    present_report(&cli, config, cli.full);
+}
+
+/// Exibe o resumo com a decisão recomendada e executa `--history`, `--save`
+/// e `--threshold`; com `full`, inclui todas as seções do relatório
+fn present_report(cli: &Cli, config: &ScoringConfig, full: bool) {
+    let language = config.language;
+    
+    // Pontuação mínima exigida (CI/CD); a categoria vira pontuação pelos limites da configuração
+    let threshold = cli
+        .threshold
+        .or_else(|| cli.threshold_category.as_ref().map(|category| config.categories.min_score(category)));
     
     println!("{}", "=".repeat(60));
-    println!("           🖥️  DIAGNÓSTICO DE HARDWARE - WINDOWS           ");
+    println!("           {} - {}           ", utils::render_text(CliText::Title.text(language)), utils::platform_name().to_uppercase());
     println!("{}", "=".repeat(60));
     
-    // Pontuação de desempenho
-    let performance_score = calculate_performance_score();
-    println!("\n{}", display_performance_score(&performance_score));
+    // Com `--save`, o arquivo traz a mesma captura exibida, sem coletar de novo
+    let mut snapshot = (full || cli.save.is_some()).then(|| DiagnosticReport::capture_with_config(config));
+    
+    // Pontuação de desempenho; com `full`, precedida de todas as seções do relatório.
+    // Com `--history`, a pontuação é registrada antes, para a tendência incluí-la
+    let performance_score = match snapshot.as_mut() {
+        Some(snapshot) if full => {
+            snapshot.include_processes();
+            if let Some(path) = &cli.history {
+                snapshot.include_trend(&append_history(path, &snapshot.score));
+            }
+            println!("\n{}", utils::format_complete_report(snapshot));
+            snapshot.score.clone()
+        }
+        captured => {
+            let score = captured.map_or_else(|| score_with(config), |snapshot| snapshot.score.clone());
+            let recent = cli.history.as_ref().map(|path| append_history(path, &score)).unwrap_or_default();
+            println!("\n{}", display_performance_score_with_config(&score, utils::colors_enabled(), config));
+            if let Some(fit) = utils::fit_score_trend(&recent) {
+                println!("{}", utils::render_text(&fit.summary(language)));
+            }
+            score
+        }
+    };
     
     // Decisão recomendada
     println!("{}", "=".repeat(60));
-    println!("           🎯 DECISÃO RECOMENDADA           ");
+    println!("           {}           ", utils::render_text(CliText::Decision.text(language)));
     println!("{}", "=".repeat(60));
     
-    match performance_score.category {
-        PerformanceCategory::Descarte => {
-            println!("🚨 AÇÃO RECOMENDADA: DESCARTE/UPGRADE COMPLETO");
-            println!("• Pontuação: {:.1}/10", performance_score.overall_score);
+    println!("{}", utils::render_text(CliText::Action(&performance_score.category).text(language)));
+    println!("{}", utils::render_text(&format!("• {}: {:.1}/10", CliText::Score.text(language), performance_score.overall_score)));
+    
+    // Salvamento de relatório: `--save <arquivo>`, `--output <arquivo>` ou o nome padrão
+    if let (Some(path), Some(snapshot)) = (&cli.save, &snapshot) {
+        let path = path
+            .clone()
+            .or_else(|| cli.output.clone())
+            .unwrap_or_else(utils::default_report_path);
+        
+        match utils::write_complete_report_to(&path, snapshot) {
+            Ok(()) => {
+                let absolute = std::fs::canonicalize(&path).unwrap_or(path);
+                println_text!("📄 Relatório salvo: {}", absolute.display());
+            }
+            Err(e) => eprintln_text!("❌ Erro ao salvar: {}", e),
         }
-        PerformanceCategory::Manutencao => {
-            println!("⚠️ AÇÃO RECOMENDADA: MANUTENÇÃO URGENTE");
-            println!("• Pontuação: {:.1}/10", performance_score.overall_score);
+    }
+    
+    println!("\n{}", "=".repeat(60));
+    
+    // Falha do job de CI quando a máquina não atinge o mínimo exigido
+    if let Some(minimum) = threshold {
+        if performance_score.overall_score < minimum {
+            eprintln_text!(
+                "❌ Pontuação {:.1} abaixo do mínimo exigido ({:.1})",
+                performance_score.overall_score, minimum
+            );
+            std::process::exit(cli.exit_code as i32);
         }
-        PerformanceCategory::Precaução => {
-            println!("🔶 AÇÃO RECOMENDADA: USO COM PRECAUÇÃO");
-            println!("• Pontuação: {:.1}/10", performance_score.overall_score);
+    }
+}
+
+/// Emite o relatório no formato indicado ou, com `--save` ou `--html`, grava-o
+/// em `diagnostico_<timestamp>.<extensão do formato>` (ou no caminho informado)
+/// 
+/// Com `--csv-no-header`, emite apenas a linha de dados do CSV.
+fn present_format(format: OutputFormat, cli: &Cli, config: &ScoringConfig) {
+    // Saídas para arquivos e outras ferramentas: sem cores ANSI
+    utils::disable_ansi();
+    let report = DiagnosticReport::capture_with_config(config);
+    let output = match format {
+        OutputFormat::Csv if cli.csv_no_header => Ok(format!("{}\n", report.to_csv_row())),
+        _ => render(format, &report),
+    };
+    let output = match output {
+        Ok(output) => output,
+        Err(e) => {
+            eprintln_text!("❌ {}", e);
+            std::process::exit(1);
         }
-        PerformanceCategory::BomEstado => {
-            println!("✅ AÇÃO RECOMENDADA: USO NORMAL");
-            println!("• Pontuação: {:.1}/10", performance_score.overall_score);
+    };
+    
+    // O HTML é sempre gravado em arquivo; os demais formatos, só com `--save`
+    let Some(path) = cli.save.as_ref().or(cli.html.as_ref().filter(|_| format == OutputFormat::Html)) else {
+        print!("{}", output);
+        return;
+    };
+    let path = path
+        .clone()
+        .or_else(|| cli.output.clone())
+        .unwrap_or_else(|| utils::default_report_path().with_extension(format.extension()));
+    
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        if let Err(e) = std::fs::create_dir_all(parent) {
+            eprintln_text!("❌ Erro ao salvar: {}", e);
+            std::process::exit(1);
         }
     }
+    if let Err(e) = std::fs::write(&path, output) {
+        eprintln_text!("❌ Erro ao salvar: {}", e);
+        std::process::exit(1);
+    }
+    let absolute = std::fs::canonicalize(&path).unwrap_or(path);
+    println_text!("📄 Relatório salvo: {}", absolute.display());
+}
+
+/// Exibe apenas as informações da CPU
+fn present_cpu(json: bool, pretty: bool) {
+    let cpu = cpu_info();
+    if json {
+        print_component_json(&cpu, pretty);
+    } else {
+        print!("{}", utils::format_cpu_section(&cpu));
+    }
+}
+
+/// Exibe apenas as informações de memória, com os pentes instalados
+fn present_ram(json: bool, pretty: bool) {
+    let ram = ram_info();
+    if json {
+        print_component_json(&ram, pretty);
+    } else {
+        print!("{}", utils::format_ram_section(&ram, &memory_layout()));
+    }
+}
+
+/// Exibe apenas as informações dos discos
+fn present_disk(json: bool, pretty: bool) {
+    let disks = disk_info();
+    if json {
+        print_component_json(&disks, pretty);
+    } else {
+        print!("{}", utils::format_disk_section(&disks));
+    }
+}
+
+/// Exibe apenas a pontuação, com os pesos de `--weights`, e sai com
+/// `exit_code` se ela ficar abaixo de `--threshold`
+fn present_score(args: &ScoreArgs, json: bool, exit_code: u8, config: &ScoringConfig) {
+    let mut config = config.clone();
+    if let Some(weights) = args.weights {
+        config.weights = weights;
+    }
     
-    // Salvamento de relatório
-    if args.len() > 1 && (args[1] == "--save" || args[1] == "-s") {
-        let timestamp = std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_secs();
-        
-        let filename = format!("diagnostico_{}.txt", timestamp);
-        let full_report = utils::generate_complete_report();
+    let score = score_with(&config);
+    if json {
+        print_component_json(&score, false);
+    } else {
+        println!("{}", display_performance_score_with_config(&score, utils::colors_enabled(), &config));
+    }
+    
+    if let Some(minimum) = args.threshold {
+        if score.overall_score < minimum {
+            eprintln_text!("❌ Pontuação {:.1} abaixo do mínimo exigido ({:.1})", score.overall_score, minimum);
+            std::process::exit(exit_code as i32);
+        }
+    }
+}
+
+/// Textos próprios da CLI, fora da pontuação exibida pela biblioteca
+enum CliText<'a> {
+    Title,
+    Decision,
+    Score,
+    Action(&'a PerformanceCategory),
+}
+
+impl CliText<'_> {
+    /// Retorna o texto no idioma indicado
+    fn text(&self, language: Language) -> &'static str {
+        match language {
+            Language::PtBr => match self {
+                CliText::Title => "🖥️  DIAGNÓSTICO DE HARDWARE",
+                CliText::Decision => "🎯 DECISÃO RECOMENDADA",
+                CliText::Score => "Pontuação",
+                CliText::Action(PerformanceCategory::Descarte) => "🚨 AÇÃO RECOMENDADA: DESCARTE/UPGRADE COMPLETO",
+                CliText::Action(PerformanceCategory::Manutencao) => "⚠️ AÇÃO RECOMENDADA: MANUTENÇÃO URGENTE",
+                CliText::Action(PerformanceCategory::Precaução) => "🔶 AÇÃO RECOMENDADA: USO COM PRECAUÇÃO",
+                CliText::Action(PerformanceCategory::BomEstado) => "✅ AÇÃO RECOMENDADA: USO NORMAL",
+            },
+            Language::EnUs => match self {
+                CliText::Title => "🖥️  HARDWARE DIAGNOSTIC",
+                CliText::Decision => "🎯 RECOMMENDED DECISION",
+                CliText::Score => "Score",
+                CliText::Action(PerformanceCategory::Descarte) => "🚨 RECOMMENDED ACTION: DISCARD/FULL UPGRADE",
+                CliText::Action(PerformanceCategory::Manutencao) => "⚠️ RECOMMENDED ACTION: URGENT MAINTENANCE",
+                CliText::Action(PerformanceCategory::Precaução) => "🔶 RECOMMENDED ACTION: USE WITH CAUTION",
+                CliText::Action(PerformanceCategory::BomEstado) => "✅ RECOMMENDED ACTION: NORMAL USE",
+            },
+        }
+    }
+}
+
+/// Calcula a pontuação com a configuração já validada por [`read_config_file`]
+fn score_with(config: &ScoringConfig) -> PerformanceScore {
+    match calculate_performance_score_with_config(config) {
+        Ok(score) => score,
+        Err(e) => {
+            eprintln_text!("❌ Erro na configuração: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Lê e valida o arquivo de configuração sobre `base`, encerrando em caso de erro
+#[cfg(feature = "serde")]
+fn read_config_file(path: &Path, base: &DiagnosticConfig) -> DiagnosticConfig {
+    match DiagnosticConfig::from_toml_file_over(path, base) {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln_text!("❌ Erro na configuração: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Sem a feature `serde` não há leitura de arquivos de configuração
+#[cfg(not(feature = "serde"))]
+fn read_config_file(_path: &Path, _base: &DiagnosticConfig) -> DiagnosticConfig {
+    eprintln_text!("❌ Arquivos de configuração indisponíveis: recompile com `--features serde`");
+    std::process::exit(1);
+}
+
+/// Lê e valida os limiares de alerta, encerrando em caso de erro
+#[cfg(feature = "serde")]
+fn read_alert_config(path: &Path) -> AlertConfig {
+    match AlertConfig::from_file(path) {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln_text!("❌ Erro na configuração de alertas: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Sem a feature `serde` não há leitura de arquivos de configuração
+#[cfg(not(feature = "serde"))]
+fn read_alert_config(_path: &Path) -> AlertConfig {
+    eprintln_text!("❌ Arquivos de configuração indisponíveis: recompile com `--features serde`");
+    std::process::exit(1);
+}
+
+/// Lê a especificação de referência, encerrando em caso de erro
+#[cfg(feature = "serde")]
+fn read_reference_profile(path: &Path) -> ReferenceProfile {
+    match ReferenceProfile::from_file(path) {
+        Ok(profile) => profile,
+        Err(e) => {
+            eprintln_text!("❌ Erro no perfil de referência: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Sem a feature `serde` não há leitura de perfis de referência
+#[cfg(not(feature = "serde"))]
+fn read_reference_profile(_path: &Path) -> ReferenceProfile {
+    eprintln_text!("❌ Perfis de referência indisponíveis: recompile com `--features serde`");
+    std::process::exit(1);
+}
+
+/// Imprime os desvios em relação à referência e retorna o código de saída:
+/// 1 com algum desvio, 0 quando a máquina confere
+fn check_reference(profile: &ReferenceProfile, config: &ScoringConfig) -> i32 {
+    let deviations = DiagnosticReport::capture_with_config(config).check_against(profile);
+    let model = if profile.model.is_empty() { "a referência" } else { profile.model.as_str() };
+    
+    if deviations.is_empty() {
+        println_text!("✅ Hardware confere com {}", model);
+        return 0;
+    }
+    println_text!("⚠️  {} desvio(s) em relação a {}:", deviations.len(), model);
+    for deviation in &deviations {
+        println_text!("  • {}", deviation);
+    }
+    1
+}
+
+/// Verifica os alertas, imprime-os e retorna o código de saída correspondente
+fn check_alerts(alert_config: &AlertConfig, config: &ScoringConfig, log: Option<&Path>) -> i32 {
+    let report = DiagnosticReport::capture_with_config(config);
+    let alerts = AlertManager::check(&report, alert_config);
+    
+    if alerts.is_empty() {
+        println_text!("✅ Nenhum alerta");
+    }
+    for alert in &alerts {
+        println_text!("{}", alert);
+    }
+    
+    if let Some(path) = log {
+        if let Err(e) = AlertManager::write_alerts(&alerts, path) {
+            eprintln_text!("❌ Erro ao gravar alertas: {}", e);
+        }
+    }
+    
+    match AlertManager::highest_severity(&alerts) {
+        Some(AlertSeverity::Critical) => 2,
+        Some(AlertSeverity::Warning) => 1,
+        Some(AlertSeverity::Info) | None => 0,
+    }
+}
+
+/// Acrescenta a pontuação ao histórico JSONL indicado por `--history` e
+/// retorna as últimas [`HISTORY_TREND_SNAPSHOTS`] execuções, para a tendência
+#[cfg(feature = "serde")]
+fn append_history(path: &Path, score: &PerformanceScore) -> Vec<TimestampedScore> {
+    match utils::append_snapshot(path, score) {
+        Ok(()) => println_text!("📈 Pontuação registrada em {}", path.display()),
+        Err(e) => {
+            eprintln_text!("❌ Erro ao gravar histórico: {}", e);
+            return Vec::new();
+        }
+    }
+    
+    let mut snapshots = utils::load_snapshots(path);
+    snapshots.drain(..snapshots.len().saturating_sub(HISTORY_TREND_SNAPSHOTS));
+    snapshots
+}
+
+/// Sem a feature `serde` não há como serializar o histórico
+#[cfg(not(feature = "serde"))]
+fn append_history(_path: &Path, _score: &PerformanceScore) -> Vec<TimestampedScore> {
+    eprintln_text!("❌ Histórico indisponível: recompile com `--features serde`");
+    Vec::new()
+}
+
+/// Imprime a configuração em TOML, no formato aceito por `--config`
+#[cfg(feature = "serde")]
+fn dump_config(config: &DiagnosticConfig) {
+    match config.to_toml() {
+        Ok(toml) => print!("{}", toml),
+        Err(e) => {
+            eprintln_text!("❌ Erro ao gerar a configuração: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Sem a feature `serde` não há como serializar a configuração
+#[cfg(not(feature = "serde"))]
+fn dump_config(_config: &DiagnosticConfig) {
+    eprintln_text!("❌ Exportação da configuração indisponível: recompile com `--features serde`");
+    std::process::exit(1);
+}
+
+/// Imprime só a pontuação geral e encerra com o código da categoria
+/// 
+/// O JSON é montado à mão para não depender da feature `serde`; a categoria
+/// usa os mesmos nomes ASCII da serialização.
+fn print_score_only(json: bool, config: &ScoringConfig) -> ! {
+    let score = score_with(config);
+    let code = match score.category {
+        PerformanceCategory::BomEstado => 0,
+        PerformanceCategory::Precaução => 1,
+        PerformanceCategory::Manutencao => 2,
+        PerformanceCategory::Descarte => 3,
+    };
+    
+    if json {
+        // Mesmo envelope das demais saídas JSON, com a pontuação em `data`
+        let envelope = ReportEnvelope::new(());
+        println!(
+            "{{\"schema_version\":{},\"generated_at\":{},\"crate_version\":\"{}\",\"data\":{{\"overall_score\":{:.1},\"category\":\"{}\"}}}}",
+            envelope.schema_version,
+            envelope.generated_at,
+            envelope.crate_version,
+            score.overall_score,
+            score.category.to_ascii_name()
+        );
+    } else {
+        println!("{:.1}", score.overall_score);
+    }
+    std::process::exit(code);
+}
+
+/// Grava o relatório completo em JSON, no formato lido por `--compare`
+#[cfg(feature = "serde")]
+fn save_snapshot(path: &Path, config: &ScoringConfig) {
+    let report = DiagnosticReport::capture_with_config(config);
+    
+    if let Err(e) = std::fs::write(path, report.to_json()) {
+        eprintln_text!("❌ Erro ao salvar: {}", e);
+        std::process::exit(1);
+    }
+    println_text!("📸 Fotografia salva: {}", path.display());
+}
+
+/// Sem a feature `serde` não há como serializar o relatório
+#[cfg(not(feature = "serde"))]
+fn save_snapshot(_path: &Path, _config: &ScoringConfig) {
+    eprintln_text!("❌ Fotografias indisponíveis: recompile com `--features serde`");
+    std::process::exit(1);
+}
+
+/// Carrega a fotografia, executa um diagnóstico novo e imprime a comparação
+#[cfg(feature = "serde")]
+fn compare_snapshot(path: &Path, config: &ScoringConfig) {
+    let before = match DiagnosticReport::load_json(path) {
+        Ok(report) => report,
+        Err(e) => {
+            eprintln_text!("❌ Erro ao carregar fotografia: {}", e);
+            std::process::exit(1);
+        }
+    };
+    let after = DiagnosticReport::capture_with_config(config);
+    
+    print!("{}", before.diff(&after).to_text_colored(utils::colors_enabled()));
+}
+
+/// Sem a feature `serde` não há como ler a fotografia
+#[cfg(not(feature = "serde"))]
+fn compare_snapshot(_path: &Path, _config: &ScoringConfig) {
+    eprintln_text!("❌ Fotografias indisponíveis: recompile com `--features serde`");
+    std::process::exit(1);
+}
+
+/// Executa o benchmark e imprime as taxas medidas e a pontuação derivada delas
+/// 
+/// Sem duração, usa o benchmark rápido (~3 s). A pontuação usa os pesos, os
+/// limites de categoria e o idioma da configuração.
+fn print_benchmark(max_duration: Option<Duration>, config: &ScoringConfig) {
+    let result = match max_duration {
+        Some(max_duration) => {
+            println_text!("⏱️  Executando benchmark (até {}s)...", max_duration.as_secs());
+            run_benchmark(max_duration)
+        }
+        None => {
+            println_text!("⏱️  Executando benchmark rápido...");
+            run_quick_benchmark()
+        }
+    };
+    
+    match result {
+        Ok(result) => {
+            println_text!("• CPU:             {:.0} MFLOPS (um núcleo)", result.cpu_mflops);
+            println_text!("• CPU (hash):      {:.0} Mhash/s (um núcleo)", result.cpu_mhash_per_sec);
+            println_text!("• Memória (cópia): {:.1} GB/s", result.memory_bandwidth_gb_per_sec);
+            println_text!("• Disco (escrita): {:.1} MB/s", result.disk_write_mb_per_sec);
+            println_text!("• Disco (leitura): {:.1} MB/s", result.disk_seq_read_mb_per_sec);
+            println_text!("• Duração:         {:.1}s", result.elapsed.as_secs_f64());
+            println!(
+                "\n{}",
+                display_performance_score_with_config(
+                    &result.to_performance_score_with_config(config),
+                    utils::colors_enabled(),
+                    config
+                )
+            );
+        }
+        Err(e) => {
+            eprintln_text!("❌ Erro no benchmark: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Repete o diagnóstico completo a cada `interval`, limpando a tela, até Ctrl-C
+/// 
+/// Um único `SystemContext` é reaproveitado entre as iterações.
+fn watch(interval: Duration, config: &ScoringConfig) {
+    if interval < Duration::from_secs(2) {
+        eprintln_text!("⚠️ Intervalos abaixo de 2s deixam pouco tempo para a amostragem da CPU");
+    }
+    
+    let stop = Arc::new(AtomicBool::new(false));
+    install_stop_handler(&stop);
+    
+    // Intervalos curtos também encurtam a amostragem para não atrasar os ciclos
+    let mut ctx = SystemContext::with_sample_interval(interval.min(DEFAULT_CPU_SAMPLE_INTERVAL));
+    
+    while !stop.load(Ordering::SeqCst) {
+        let cycle_start = Instant::now();
+        ctx.refresh_all();
+        let report = DiagnosticReport::from_context(&ctx, config);
         
-        if let Err(e) = std::fs::write(&filename, full_report) {
-            eprintln!("❌ Erro ao salvar: {}", e);
+        if utils::colors_enabled() {
+            // Limpa a tela e move o cursor para o topo
+            print!("\x1B[2J\x1B[H");
         } else {
-            println!("📄 Relatório salvo: {}", filename);
+            // Sem ANSI (`--no-color`, `NO_COLOR` ou saída redirecionada), só separa as atualizações
+            println!("\n{}", "-".repeat(60));
+        }
+        println_text!(
+            "🔄 Última atualização: {} | a cada {}s | Ctrl-C para sair",
+            utils::format_iso8601(report.captured_at),
+            interval.as_secs()
+        );
+        println!("{}", "=".repeat(60));
+        println!("\n{}", report.to_text());
+        
+        // Espera em passos curtos para responder logo ao Ctrl-C
+        while !stop.load(Ordering::SeqCst) && cycle_start.elapsed() < interval {
+            std::thread::sleep(WATCH_POLL_INTERVAL.min(interval.saturating_sub(cycle_start.elapsed())));
         }
     }
     
-    // Ajuda
-    if args.len() > 1 && (args[1] == "--help" || args[1] == "-h") {
-        println!("\n{}", "=".repeat(60));
-        println!("           📖 AJUDA           ");
-        println!("{}", "=".repeat(60));
-        println!("\nUso: hardware-diagnostic [OPÇÃO]");
-        println!("\nOpções:");
-        println!("  --save, -s    Salva relatório em arquivo");
-        println!("  --full, -f    Exibe relatório completo");
-        println!("  --help, -h    Mostra esta ajuda");
-        println!("\nExemplos:");
-        println!("  hardware-diagnostic");
-        println!("  hardware-diagnostic --save");
-        println!("  hardware-diagnostic --help");
+    println_text!("\n⏹️  Monitoramento encerrado");
+}
+
+/// Marca `stop` quando o usuário pressiona Ctrl-C
+#[cfg(feature = "ctrlc")]
+fn install_stop_handler(stop: &Arc<AtomicBool>) {
+    let handler_stop = Arc::clone(stop);
+    if let Err(e) = ctrlc::set_handler(move || handler_stop.store(true, Ordering::SeqCst)) {
+        eprintln_text!("⚠️ Não foi possível tratar Ctrl-C: {}", e);
     }
+}
+
+/// Sem a feature `ctrlc` o sinal encerra o processo sem a mensagem final
+#[cfg(not(feature = "ctrlc"))]
+fn install_stop_handler(_stop: &Arc<AtomicBool>) {}
+
+/// Imprime um componente isolado (saída dos subcomandos) em JSON, dentro
+/// do envelope versionado
+#[cfg(feature = "serde")]
+fn print_component_json<T: serde::Serialize>(value: &T, pretty: bool) {
+    let envelope = ReportEnvelope::new(value);
+    let output = if pretty {
+        serde_json::to_string_pretty(&envelope)
+    } else {
+        serde_json::to_string(&envelope)
+    };
     
-    println!("\n{}", "=".repeat(60));
-}
\ No newline at end of file
+    match output {
+        Ok(json) => println!("{}", json),
+        Err(e) => {
+            eprintln_text!("❌ Erro ao gerar JSON: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Sem a feature `serde` não há suporte a JSON
+#[cfg(not(feature = "serde"))]
+fn print_component_json<T>(_value: &T, _pretty: bool) {
+    eprintln_text!("❌ Suporte a JSON indisponível: recompile com `--features serde`");
+    std::process::exit(1);
+}
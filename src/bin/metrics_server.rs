@@ -0,0 +1,105 @@
+// hardware-diagnostic - Ferramenta de diagnóstico de hardware
+// Copyright (C) 2025  Seu Nome
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Módulo `metrics_server` - Endpoint `/metrics` para o Prometheus
+//! 
+//! Servidor HTTP mínimo sobre `std::net`: uma thread recoleta o diagnóstico
+//! a cada intervalo e as raspagens recebem o último resultado, sem esperar
+//! pela amostragem da CPU e dos discos. As métricas são as de
+//! [`utils::to_prometheus`].
+
+use hardware_diagnostic::engine::utils;
+use hardware_diagnostic::{DiagnosticReport, ScoringConfig, SystemContext};
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, SystemTime};
+
+/// Tempo máximo de espera pela linha de requisição de um cliente
+const READ_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Serve `/metrics` no endereço indicado até o processo ser encerrado
+/// 
+/// A CLI escuta em `127.0.0.1` salvo com `--metrics-bind`. A primeira coleta é feita antes de aceitar conexões, para que nenhuma
+/// raspagem receba um corpo vazio.
+/// 
+/// # Erros
+/// Retorna o erro de E/S se o endereço não puder ser aberto.
+pub fn serve(address: SocketAddr, interval: Duration, config: &ScoringConfig, verbose: bool) -> io::Result<()> {
+    let listener = TcpListener::bind(address)?;
+    
+    let mut ctx = SystemContext::new();
+    let metrics = Arc::new(RwLock::new(collect(&mut ctx, config)));
+    
+    // Recoleta em segundo plano, reaproveitando o mesmo contexto
+    let latest = Arc::clone(&metrics);
+    let config = config.clone();
+    std::thread::spawn(move || loop {
+        std::thread::sleep(interval);
+        let refreshed = collect(&mut ctx, &config);
+        *latest.write().unwrap_or_else(|e| e.into_inner()) = refreshed;
+    });
+    
+    eprintln_text!(
+        "📡 Métricas em http://{}/metrics, atualizadas a cada {}s",
+        address,
+        interval.as_secs()
+    );
+    
+    for stream in listener.incoming() {
+        let result = stream.and_then(|stream| respond(stream, &metrics, verbose));
+        if let Err(e) = result {
+            eprintln_text!("⚠️ Falha ao atender raspagem: {}", e);
+        }
+    }
+    
+    Ok(())
+}
+
+/// Atualiza o contexto e gera as métricas no formato do Prometheus
+fn collect(ctx: &mut SystemContext, config: &ScoringConfig) -> String {
+    ctx.refresh_all();
+    let report = DiagnosticReport::from_context(ctx, config);
+    utils::to_prometheus(&report.score, &report.cpu, &report.ram, &report.disks)
+}
+
+/// Lê a linha de requisição e responde com as métricas ou 404
+fn respond(mut stream: TcpStream, metrics: &RwLock<String>, verbose: bool) -> io::Result<()> {
+    stream.set_read_timeout(Some(READ_TIMEOUT))?;
+    let mut request_line = String::new();
+    BufReader::new(&stream).read_line(&mut request_line)?;
+    
+    let mut parts = request_line.split_whitespace();
+    let (method, path) = (parts.next().unwrap_or(""), parts.next().unwrap_or(""));
+    let (status, body) = match (method, path) {
+        ("GET", "/metrics") => ("200 OK", metrics.read().unwrap_or_else(|e| e.into_inner()).clone()),
+        _ => ("404 Not Found", "Use GET /metrics\n".to_string()),
+    };
+    
+    if verbose {
+        let peer = stream.peer_addr().map(|addr| addr.to_string()).unwrap_or_default();
+        eprintln!("{} {} {} {} -> {}", utils::format_iso8601(SystemTime::now()), peer, method, path, status);
+    }
+    
+    write!(
+        stream,
+        "HTTP/1.1 {}\r\nContent-Type: text/plain; version=0.0.4; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    )?;
+    stream.flush()
+}
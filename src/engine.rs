@@ -17,12 +17,116 @@
 //! Módulo `engine` - Coleta e estrutura informações do sistema
 //! 
 //! Este módulo fornece funcionalidades para coletar informações de hardware
-//! como CPU, RAM e discos de armazenamento no Windows usando a crate `sysinfo`.
+//! como CPU, RAM e discos de armazenamento no Windows e no Linux usando a
+//! crate `sysinfo`. Onde o comportamento difere entre as plataformas (ex:
+//! tipo de mídia dos discos, sensores), há implementações por `target_os`.
+
+use sysinfo::{System, Disks, Networks, NetworkData, Components};
+use std::{io, fs, fmt};
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
+use std::time::{Duration, Instant};
+use log::{debug, info, warn};
+
+use battery::BatteryInfo;
+use smart::{DiskHealth, SmartInfo, SmartStatus};
+use locale::Message;
+use memory::MemoryLayout;
+use processes::ProcessInfo;
+
+pub use crate::error::DiagnosticError;
+
+/// Variantes assíncronas das funções de coleta (feature `async`)
+#[cfg(feature = "async")]
+pub mod async_api;
+
+/// Medição real de desempenho da CPU e do disco (opcional)
+pub mod benchmark;
+
+/// Versão, data e Secure Boot do firmware (BIOS/UEFI)
+pub mod bios;
+
+/// Alertas por limiar para monitoramento automatizado
+pub mod alerts;
+
+/// Coleta de informações da bateria
+pub mod battery;
+
+/// Monitores conectados e suas resoluções
+pub mod display;
+
+/// Rotação das ventoinhas
+pub mod fans;
+
+/// Histórico de pontuações ao longo do tempo
+pub mod history;
+
+/// Idiomas das saídas em texto
+pub mod locale;
+
+/// Pentes de memória instalados (dados apenas com a feature `memory`)
+pub mod memory;
+
+/// Identificação da plataforma (Windows, Linux ou macOS)
+pub mod platform;
+
+/// Processos que mais consomem CPU e memória
+pub mod processes;
+
+/// Detecção de máquinas virtuais e contêineres
+pub mod virtualization;
+
+/// Relatório completo capturado de uma só vez
+pub mod report;
+
+/// Comparação com a especificação de referência de cada modelo
+pub mod reference;
+
+/// Atributos SMART dos discos (dados apenas com a feature `smart`)
+pub mod smart;
+
+pub use report::{collect_snapshot, render, DiagnosticReport, OutputFormat, ReportEnvelope, SystemSnapshot};
+pub use locale::Language;
+pub use virtualization::{detect_virtualization, VirtKind};
+
+/// Coleta de informações de GPU (feature `gpu`)
+#[cfg(feature = "gpu")]
+pub mod gpu;
+
+/// Intervalo padrão entre as duas leituras usadas para medir o uso da CPU
+pub const DEFAULT_CPU_SAMPLE_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Uso da CPU (%) acima do qual a recomendação cita os maiores consumidores
+pub(crate) const HIGH_CPU_USAGE_PERCENT: f32 = 80.0;
+
+/// Dias ligados a partir dos quais a pontuação sugere reiniciar a máquina
+const LONG_UPTIME_DAYS: u64 = 30;
+
+/// Uso da RAM (%) acima do qual a falta de SWAP passa a ser um risco
+const NO_SWAP_RAM_USAGE_PERCENT: f64 = 85.0;
+
+/// Temperatura da CPU (°C) acima da qual há alerta crítico e suspeita de throttling térmico
+const CPU_CRITICAL_TEMPERATURE_CELSIUS: f32 = 90.0;
+
+/// Limite de desempenho da CPU (%) abaixo do qual o throttling é considerado ativo
+/// 
+/// Planos de economia de energia, bateria e estados ociosos reportam pouco
+/// menos de 100% sem que a máquina esteja de fato limitada.
+#[cfg_attr(not(all(target_os = "windows", feature = "wmi")), allow(dead_code))]
+const THROTTLING_PERFORMANCE_LIMIT_PERCENT: u32 = 90;
+
+/// Pontuação mínima padrão da categoria Manutenção (abaixo dela, Descarte)
+pub const MANUTENCAO_MIN_SCORE: f64 = 3.0;
+
+/// Pontuação mínima padrão da categoria Precaução
+pub const PRECAUCAO_MIN_SCORE: f64 = 5.0;
+
+/// Pontuação mínima padrão da categoria Bom Estado
+pub const BOM_ESTADO_MIN_SCORE: f64 = 7.0;
 
-use sysinfo::{System, Disks};
-use std::{io, fs};
 /// Representa as informações coletadas da CPU do sistema
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CpuInfo {
     /// Número total de CPUs/cores lógicos detectados
     pub number_cpus: usize,
@@ -34,10 +138,81 @@ pub struct CpuInfo {
     pub name: String,
     /// Número de núcleos físicos (se disponível)
     pub physical_cores: Option<usize>,
+    /// Percentual de uso de cada núcleo lógico (0.0 a 100.0)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub per_core_usage: Vec<f32>,
+    /// Frequência de cada núcleo lógico em MHz
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub per_core_frequency: Vec<u64>,
+    /// Indica se a CPU está quente e operando abaixo da frequência base
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub thermal_throttling_detected: bool,
+    /// Indica se o sistema reporta a CPU limitada neste momento (contador
+    /// `% Performance Limit` do WMI no Windows); `None` quando não informa
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub throttling: Option<bool>,
+    /// Estado das mitigações de vulnerabilidades de execução especulativa
+    /// (Spectre, Meltdown...); `None` quando o sistema não informa
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub vulnerabilities: Option<Vec<VulnerabilityStatus>>,
+}
+
+impl Default for CpuInfo {
+    fn default() -> Self {
+        CpuInfo {
+            number_cpus: 0,
+            cpu_usage: 0.0,
+            frequency: 0,
+            name: "Desconhecido".to_string(),
+            physical_cores: None,
+            per_core_usage: Vec::new(),
+            per_core_frequency: Vec::new(),
+            thermal_throttling_detected: false,
+            throttling: None,
+            vulnerabilities: None,
+        }
+    }
+}
+
+impl CpuInfo {
+    /// Vulnerabilidades da CPU sem mitigação ativa (vazio se desconhecido)
+    pub fn unmitigated_vulnerabilities(&self) -> Vec<&VulnerabilityStatus> {
+        self.vulnerabilities
+            .iter()
+            .flatten()
+            .filter(|status| !status.mitigated)
+            .collect()
+    }
+    
+    /// Retorna o maior uso entre os núcleos (0.0 se não houver dados por núcleo)
+    pub fn max_core_usage(&self) -> f32 {
+        self.per_core_usage.iter().copied().fold(0.0, f32::max)
+    }
+    
+    /// Retorna o menor uso entre os núcleos (0.0 se não houver dados por núcleo)
+    pub fn min_core_usage(&self) -> f32 {
+        self.per_core_usage
+            .iter()
+            .copied()
+            .reduce(f32::min)
+            .unwrap_or(0.0)
+    }
+}
+
+/// Resumo em uma linha: `Intel Core i7-12700K | 20 cores (12 physical) | 3.60 GHz | 45.2% usage`
+impl fmt::Display for CpuInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} | {} cores", self.name, self.number_cpus)?;
+        if let Some(physical) = self.physical_cores {
+            write!(f, " ({} physical)", physical)?;
+        }
+        write!(f, " | {:.2} GHz | {:.1}% usage", self.frequency as f64 / 1000.0, self.cpu_usage)
+    }
 }
 
 /// Representa as informações coletadas da memória RAM
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RamInfo {
     /// Memória RAM total em bytes
     pub total_ram: u64,
@@ -53,10 +228,127 @@ pub struct RamInfo {
     pub ram_usage_percent: f64,
     /// Percentual de uso do SWAP (0.0 a 100.0)
     pub swap_usage_percent: f64,
+    /// Tipo da memória (ex: "DDR4"), se conhecido
+    /// 
+    /// Assim como `speed_mhz` e os slots, vem dos pentes instalados
+    /// ([`memory::MemoryLayout`]) e é `None` fora do Windows ou sem a
+    /// feature `memory`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub memory_type: Option<String>,
+    /// Velocidade efetiva em MHz (a do pente mais lento), se conhecida
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub speed_mhz: Option<u32>,
+    /// Slots de memória ocupados, se conhecidos
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub slots_used: Option<usize>,
+    /// Total de slots da placa-mãe, se conhecido
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub slots_total: Option<usize>,
+}
+
+impl RamInfo {
+    /// Indica se todos os slots estão ocupados (upgrade exige trocar pentes)
+    /// 
+    /// `false` quando os slots não são conhecidos.
+    pub fn all_slots_used(&self) -> bool {
+        matches!((self.slots_used, self.slots_total), (Some(used), Some(total)) if used >= total)
+    }
+    
+    /// Pressão de memória com os limites padrão ([`ScoringThresholds::default`])
+    /// 
+    /// Combina o uso da RAM e do SWAP: um SWAP em uso acima de 10% indica
+    /// que o sistema já está paginando, e eleva a pressão a pelo menos
+    /// [`MemoryPressure::Moderate`] mesmo com a RAM folgada.
+    /// 
+    /// # Exemplo
+    /// ```
+    /// use hardware_diagnostic::{MemoryPressure, RamInfo};
+    /// 
+    /// let ram = RamInfo { ram_usage_percent: 40.0, total_swap: 1 << 30, swap_usage_percent: 15.0, ..RamInfo::default() };
+    /// assert_eq!(ram.pressure_level(), MemoryPressure::Moderate);
+    /// ```
+    pub fn pressure_level(&self) -> MemoryPressure {
+        self.pressure_level_with(&ScoringThresholds::default())
+    }
+    
+    /// Pressão de memória com os limites de uso da RAM e do SWAP indicados
+    /// 
+    /// É o maior entre os níveis da RAM e do SWAP; sem SWAP configurado,
+    /// apenas a RAM conta.
+    pub fn pressure_level_with(&self, thresholds: &ScoringThresholds) -> MemoryPressure {
+        let swap = self.swap_pressure(thresholds).unwrap_or(MemoryPressure::Low);
+        self.ram_pressure(thresholds).max(swap)
+    }
+    
+    /// Nível de pressão considerando só o uso da RAM
+    fn ram_pressure(&self, thresholds: &ScoringThresholds) -> MemoryPressure {
+        MemoryPressure::from_usage(self.ram_usage_percent, thresholds.ram_usage)
+    }
+    
+    /// Nível de pressão considerando só o uso do SWAP (`None` sem SWAP)
+    fn swap_pressure(&self, thresholds: &ScoringThresholds) -> Option<MemoryPressure> {
+        (self.total_swap > 0).then(|| MemoryPressure::from_usage(self.swap_usage_percent, thresholds.swap_usage))
+    }
+}
+
+/// Pressão de memória, gerada por [`RamInfo::pressure_level`]
+/// 
+/// Os níveis seguem as faixas de uso excelente, boa e regular de
+/// [`ScoringThresholds`] e são ordenados do menor para o maior.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MemoryPressure {
+    /// Memória folgada
+    Low,
+    /// Uso considerável, ou SWAP já em uso
+    Moderate,
+    /// Pouca margem antes de faltar memória
+    High,
+    /// Memória esgotada ou paginação intensa
+    Critical,
+}
+
+impl MemoryPressure {
+    /// Nível de um percentual de uso nas faixas `[excelente, boa, regular]`
+    /// 
+    /// Os limites são exclusivos: o percentual igual a um limite já pertence
+    /// à faixa seguinte.
+    fn from_usage(percent: f64, bands: [f64; 3]) -> Self {
+        if percent < bands[0] {
+            MemoryPressure::Low
+        } else if percent < bands[1] {
+            MemoryPressure::Moderate
+        } else if percent < bands[2] {
+            MemoryPressure::High
+        } else {
+            MemoryPressure::Critical
+        }
+    }
+    
+    /// Pontuação do nível nos fatores de uso de `calculate_ram_score`
+    fn score(self) -> f64 {
+        match self {
+            MemoryPressure::Low => 10.0,     // Excelente
+            MemoryPressure::Moderate => 7.0, // Bom
+            MemoryPressure::High => 4.0,     // Regular
+            MemoryPressure::Critical => 1.0, // Crítico
+        }
+    }
+}
+
+/// Resumo em uma linha: `16.0 GiB / 32.0 GiB (50.0% used) | SWAP: 0.5 GiB / 8.0 GiB`
+impl fmt::Display for RamInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let gib = utils::bytes_to_gib_f64;
+        write!(f, "{:.1} GiB / {:.1} GiB ({:.1}% used) | SWAP: {:.1} GiB / {:.1} GiB",
+            gib(self.used_ram), gib(self.total_ram), self.ram_usage_percent,
+            gib(self.used_swap), gib(self.total_swap))
+    }
 }
 
 /// Representa informações de um disco individual
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DiskInfo {
     /// Nome do dispositivo (ex: "C:")
     pub name: String,
@@ -72,53 +364,703 @@ pub struct DiskInfo {
     pub usage_percent: f64,
     /// Sistema de arquivos (ex: "NTFS")
     pub file_system: String,
-    /// Tipo de disco
-    pub disk_type: String,
+    /// Tipo de disco normalizado, independente da plataforma
+    pub kind: DiskKind,
+    /// Bytes lidos por segundo (0 se não disponível na plataforma)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub read_bytes_per_sec: u64,
+    /// Bytes escritos por segundo (0 se não disponível na plataforma)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub write_bytes_per_sec: u64,
+    /// Operações de leitura por segundo (0 se não disponível na plataforma)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub read_iops: u64,
+    /// Operações de escrita por segundo (0 se não disponível na plataforma)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub write_iops: u64,
+    /// Percentual do tempo em que o disco esteve ocupado com E/S
+    /// (0.0 a 100.0; 0 se não disponível na plataforma)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub io_utilization_percent: f32,
+    /// Saúde do disco físico segundo o SMART (`None` se inacessível ou
+    /// sem a feature `smart`)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub health: Option<DiskHealth>,
+    /// Indica mídia removível (pendrive, cartão, imagem montada)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub removable: bool,
+    /// Indica volume montado somente para leitura (ex: imagem ISO)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub read_only: bool,
+}
+
+impl DiskInfo {
+    /// Estado de saúde do disco ([`SmartStatus::Unknown`] sem dados SMART)
+    pub fn health_status(&self) -> SmartStatus {
+        self.health.as_ref().map_or(SmartStatus::Unknown, |health| health.health_status)
+    }
+    
+    /// Indica uma unidade de estado sólido, incluindo as NVMe
+    pub fn is_ssd(&self) -> bool {
+        self.kind == DiskKind::Ssd
+    }
+    
+    /// Indica um SSD NVMe, reconhecido pelo nome do dispositivo (ex:
+    /// "/dev/nvme0n1p2")
+    /// 
+    /// Exige [`DiskKind::Ssd`] e o nome do kernel (`nvme` seguido do número
+    /// do controlador), então um volume rotulado "nvme-backup" não conta.
+    /// Apenas o Linux expõe o barramento no nome; nas demais plataformas
+    /// retorna `false` mesmo para discos NVMe.
+    pub fn is_nvme(&self) -> bool {
+        let device = self.name.rsplit(['/', '\\']).next().unwrap_or_default();
+        self.is_ssd()
+            && device
+                .strip_prefix("nvme")
+                .is_some_and(|controller| controller.starts_with(|c: char| c.is_ascii_digit()))
+    }
+}
+
+/// Resumo em uma linha: `C: [NTFS/SSD] 256.0 GiB / 512.0 GiB (50.0% used)`
+impl fmt::Display for DiskInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let gib = utils::bytes_to_gib_f64;
+        write!(f, "{} [{}/{}] {:.1} GiB / {:.1} GiB ({:.1}% used)",
+            self.name, self.file_system, self.kind,
+            gib(self.used_space), gib(self.total_space), self.usage_percent)
+    }
+}
+
+/// Critérios para ignorar discos na coleta e na pontuação
+/// 
+/// Imagens ISO e discos em RAM costumam aparecer quase 100% ocupados e
+/// derrubariam a pontuação dos discos. O filtro padrão não exclui nenhum disco.
+/// 
+/// # Exemplo
+/// ```
+/// use hardware_diagnostic::{disk_info_filtered, DiskFilter};
+/// 
+/// let filter = DiskFilter {
+///     exclude_removable: true,
+///     exclude_read_only: true,
+///     min_total_space: 1024 * 1024 * 1024,
+/// };
+/// for disk in disk_info_filtered(&filter) {
+///     println!("{}: {:.1}% usado", disk.mount_point, disk.usage_percent);
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct DiskFilter {
+    /// Ignora mídias removíveis
+    pub exclude_removable: bool,
+    /// Ignora volumes somente leitura
+    pub exclude_read_only: bool,
+    /// Ignora discos com capacidade total menor que este valor, em bytes
+    pub min_total_space: u64,
+}
+
+impl DiskFilter {
+    /// Indica se o disco passa pelo filtro
+    pub fn matches(&self, disk: &DiskInfo) -> bool {
+        let excluded = (self.exclude_removable && disk.removable)
+            || (self.exclude_read_only && disk.read_only);
+        !excluded && disk.total_space >= self.min_total_space
+    }
+    
+    /// Mantém apenas os discos que passam pelo filtro
+    pub fn apply(&self, disks: &[DiskInfo]) -> Vec<DiskInfo> {
+        disks.iter().filter(|disk| self.matches(disk)).cloned().collect()
+    }
+}
+
+/// Contadores acumulados de E/S de um dispositivo
+#[derive(Debug, Clone, Copy, Default)]
+struct DiskIoCounters {
+    read_ops: u64,
+    write_ops: u64,
+    read_bytes: u64,
+    write_bytes: u64,
+    busy_ms: u64,
+}
+
+/// Taxas de E/S de um dispositivo calculadas entre duas leituras
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct DiskIoRates {
+    read_bytes_per_sec: u64,
+    write_bytes_per_sec: u64,
+    read_iops: u64,
+    write_iops: u64,
+    io_utilization_percent: f32,
+}
+
+/// Leitura dos contadores de E/S de todos os dispositivos em um instante
+struct DiskIoSample {
+    taken_at: Instant,
+    counters: HashMap<String, DiskIoCounters>,
+}
+
+impl DiskIoSample {
+    fn now() -> Self {
+        DiskIoSample {
+            taken_at: Instant::now(),
+            counters: read_disk_io_counters(),
+        }
+    }
+    
+    /// Indica se a plataforma expôs contadores de E/S nesta leitura
+    fn has_counters(&self) -> bool {
+        !self.counters.is_empty()
+    }
+    
+    /// Aguarda até `interval` após esta leitura, para que a próxima meça as taxas
+    /// 
+    /// Sem contadores de E/S na plataforma (leitura vazia, ex: Windows e
+    /// macOS) não há taxa a medir, então não espera.
+    fn wait_for_next(&self, interval: Duration) {
+        if self.has_counters() {
+            std::thread::sleep(interval.saturating_sub(self.taken_at.elapsed()));
+        }
+    }
+    
+    /// Calcula as taxas de E/S entre uma leitura anterior e esta
+    fn rates_since(&self, previous: &DiskIoSample) -> HashMap<String, DiskIoRates> {
+        let elapsed = self.taken_at.duration_since(previous.taken_at).as_secs_f64();
+        if elapsed <= 0.0 {
+            return HashMap::new();
+        }
+        
+        let per_sec = |delta: u64| (delta as f64 / elapsed) as u64;
+        
+        self.counters
+            .iter()
+            .filter_map(|(device, current)| {
+                let before = previous.counters.get(device)?;
+                let busy_ms = current.busy_ms.saturating_sub(before.busy_ms) as f64;
+                Some((
+                    device.clone(),
+                    DiskIoRates {
+                        read_bytes_per_sec: per_sec(current.read_bytes.saturating_sub(before.read_bytes)),
+                        write_bytes_per_sec: per_sec(current.write_bytes.saturating_sub(before.write_bytes)),
+                        read_iops: per_sec(current.read_ops.saturating_sub(before.read_ops)),
+                        write_iops: per_sec(current.write_ops.saturating_sub(before.write_ops)),
+                        io_utilization_percent: (busy_ms / (elapsed * 1000.0) * 100.0).clamp(0.0, 100.0) as f32,
+                    },
+                ))
+            })
+            .collect()
+    }
+}
+
+/// Sistemas de arquivos que só podem ser montados para leitura
+const READ_ONLY_FILE_SYSTEMS: [&str; 6] = ["iso9660", "udf", "cdfs", "squashfs", "erofs", "cramfs"];
+
+/// Lê os pontos de montagem somente leitura
+/// 
+/// No Linux usa as opções de `/proc/mounts`. Nas demais plataformas o
+/// conjunto retornado é vazio e apenas o sistema de arquivos é considerado.
+fn read_only_mount_points() -> HashSet<String> {
+    #[cfg(target_os = "linux")]
+    {
+        let content = fs::read_to_string("/proc/mounts").unwrap_or_default();
+        content
+            .lines()
+            .filter_map(|line| {
+                let fields: Vec<&str> = line.split_whitespace().collect();
+                let options = fields.get(3)?;
+                options
+                    .split(',')
+                    .any(|option| option == "ro")
+                    // Espaços no caminho aparecem como \040 em /proc/mounts
+                    .then(|| fields[1].replace("\\040", " "))
+            })
+            .collect()
+    }
+    
+    #[cfg(not(target_os = "linux"))]
+    HashSet::new()
+}
+
+/// Lê os contadores de E/S por dispositivo
+/// 
+/// No Linux usa `/proc/diskstats`. Nas demais plataformas o `sysinfo` não
+/// expõe esses dados e o mapa retornado é vazio.
+fn read_disk_io_counters() -> HashMap<String, DiskIoCounters> {
+    #[cfg(target_os = "linux")]
+    {
+        // Setores de /proc/diskstats têm sempre 512 bytes
+        const SECTOR_SIZE: u64 = 512;
+        
+        let content = fs::read_to_string("/proc/diskstats").unwrap_or_default();
+        content
+            .lines()
+            .filter_map(|line| {
+                let fields: Vec<&str> = line.split_whitespace().collect();
+                if fields.len() < 13 {
+                    return None;
+                }
+                let field = |i: usize| fields[i].parse::<u64>().unwrap_or(0);
+                Some((
+                    fields[2].to_string(),
+                    DiskIoCounters {
+                        read_ops: field(3),
+                        read_bytes: field(5) * SECTOR_SIZE,
+                        write_ops: field(7),
+                        write_bytes: field(9) * SECTOR_SIZE,
+                        busy_ms: field(12),
+                    },
+                ))
+            })
+            .collect()
+    }
+    
+    #[cfg(not(target_os = "linux"))]
+    HashMap::new()
+}
+
+/// Representa informações de uma interface de rede
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NetworkInfo {
+    /// Nome da interface (ex: "Ethernet", "eth0")
+    pub name: String,
+    /// Endereço MAC (ex: "00:1a:2b:3c:4d:5e")
+    pub mac_address: String,
+    /// Endereços IPv4 e IPv6 atribuídos à interface
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub ip_addresses: Vec<String>,
+    /// Total de bytes recebidos desde a inicialização
+    pub total_received_bytes: u64,
+    /// Total de bytes transmitidos desde a inicialização
+    pub total_transmitted_bytes: u64,
+    /// Indica se o link da interface está ativo
+    pub is_up: bool,
+}
+
+/// Representa informações do sistema operacional
+/// 
+/// Inclui o tempo ligado e o instante da inicialização; veja [`system_info`].
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OsInfo {
+    /// Nome do sistema operacional (ex: "Windows", "Ubuntu")
+    pub os_name: String,
+    /// Versão do sistema operacional (ex: "11 (22631)")
+    pub os_version: String,
+    /// Versão do kernel
+    pub kernel_version: String,
+    /// Nome da máquina na rede
+    pub hostname: String,
+    /// Tempo desde a inicialização, em segundos
+    pub uptime_seconds: u64,
+    /// Instante da inicialização, em segundos desde a época Unix
+    pub boot_time: u64,
+    /// Plataforma da coleta: "windows", "linux" ou "macos"
+    /// (veja [`platform::current_os`])
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub platform: String,
+    /// VM ou contêiner em que a coleta foi feita, `None` em máquinas físicas
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub virtualization: Option<VirtKind>,
+}
+
+/// Estado de uma vulnerabilidade de execução especulativa da CPU
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VulnerabilityStatus {
+    /// Nome da vulnerabilidade (ex: "spectre_v2", "meltdown")
+    pub name: String,
+    /// `true` se a CPU não é afetada ou a mitigação está ativa
+    pub mitigated: bool,
+    /// Estado informado pelo sistema (ex: "Mitigation: PTI", "Vulnerable")
+    pub description: String,
+}
+
+/// Representa a leitura de um sensor de temperatura
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TemperatureReading {
+    /// Rótulo do sensor (ex: "coretemp Package id 0")
+    pub label: String,
+    /// Temperatura atual em graus Celsius
+    pub temperature_celsius: f32,
+    /// Temperatura crítica informada pelo sensor, se disponível
+    pub critical_threshold: Option<f32>,
+    /// Componente monitorado pelo sensor
+    pub component: ThermalComponent,
+}
+
+/// Componente de hardware associado a um sensor de temperatura
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ThermalComponent {
+    /// Processador
+    Cpu,
+    /// Disco de armazenamento
+    Disk,
+    /// Placa de vídeo
+    Gpu,
+    /// Placa-mãe e demais sensores
+    Motherboard,
+}
+
+impl ThermalComponent {
+    /// Classifica um sensor a partir do seu rótulo
+    fn from_label(label: &str) -> Self {
+        let label = label.to_lowercase();
+        let matches = |keys: &[&str]| keys.iter().any(|key| label.contains(key));
+        
+        if matches(&["gpu", "nouveau", "radeon", "nvidia"]) {
+            ThermalComponent::Gpu
+        } else if matches(&["cpu", "coretemp", "k10temp", "package", "core", "tctl", "tdie"]) {
+            ThermalComponent::Cpu
+        } else if matches(&["nvme", "drivetemp", "disk", "ssd", "hdd"]) {
+            ThermalComponent::Disk
+        } else {
+            ThermalComponent::Motherboard
+        }
+    }
+}
+
+/// Tipo de mídia de um disco, normalizado a partir de `sysinfo::DiskKind`
+/// 
+/// NVMe não é um tipo à parte: um SSD NVMe é [`DiskKind::Ssd`], e
+/// [`DiskInfo::is_nvme`] o reconhece pelo nome do dispositivo. Só o Linux
+/// expõe o barramento nesse nome; no Windows e no macOS um NVMe aparece
+/// apenas como SSD.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DiskKind {
+    /// Unidade de estado sólido (SSD/NVMe)
+    Ssd,
+    /// Disco rígido mecânico
+    Hdd,
+    /// Tipo não identificado pelo sistema
+    #[default]
+    Unknown,
+}
+
+/// Rótulo igual em todas as plataformas: `SSD`, `HDD` ou `Unknown`
+impl fmt::Display for DiskKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            DiskKind::Ssd => "SSD",
+            DiskKind::Hdd => "HDD",
+            DiskKind::Unknown => "Unknown",
+        })
+    }
+}
+
+impl From<sysinfo::DiskKind> for DiskKind {
+    fn from(kind: sysinfo::DiskKind) -> Self {
+        match kind {
+            sysinfo::DiskKind::SSD => DiskKind::Ssd,
+            sysinfo::DiskKind::HDD => DiskKind::Hdd,
+            sysinfo::DiskKind::Unknown(_) => DiskKind::Unknown,
+        }
+    }
 }
 
 /// Representa a pontuação de desempenho da máquina
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PerformanceScore {
     /// Pontuação geral (0.0 a 10.0)
     pub overall_score: f64,
-    /// Pontuação da CPU (0.0 a 10.0)
-    pub cpu_score: f64,
-    /// Pontuação da RAM (0.0 a 10.0)
-    pub ram_score: f64,
-    /// Pontuação dos discos (0.0 a 10.0)
-    pub disk_score: f64,
+    /// Pontuação da CPU (0.0 a 10.0), `None` se a CPU não foi avaliada
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub cpu_score: Option<f64>,
+    /// Pontuação da RAM (0.0 a 10.0), `None` se a RAM não foi avaliada
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub ram_score: Option<f64>,
+    /// Pontuação dos discos (0.0 a 10.0), `None` se os discos não foram avaliados
+    /// 
+    /// É a média de `per_disk_scores`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub disk_score: Option<f64>,
+    /// Pontuação de cada disco avaliado (nome, 0.0 a 10.0)
+    /// 
+    /// Permite identificar um disco problemático que a média esconderia.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub per_disk_scores: Vec<(String, f64)>,
+    /// Pontuação da GPU (0.0 a 10.0), presente apenas com a feature `gpu`
+    /// e quando há adaptadores detectados
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub gpu_score: Option<f64>,
+    /// Pontuação da bateria (0.0 a 10.0), presente apenas em máquinas com bateria
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub battery_score: Option<f64>,
     /// Categoria de desempenho
     pub category: PerformanceCategory,
     /// Recomendações específicas
     pub recommendations: Vec<String>,
 }
 
+impl PerformanceScore {
+    /// Compara esta pontuação com uma anterior (ex: antes de uma manutenção)
+    /// 
+    /// Componentes ausentes em qualquer uma das pontuações ficam como `None`
+    /// no resultado.
+    /// 
+    /// # Exemplo
+    /// ```no_run
+    /// use hardware_diagnostic::{calculate_performance_score, display_diff};
+    /// 
+    /// let before = calculate_performance_score();
+    /// // ... manutenção ...
+    /// let after = calculate_performance_score();
+    /// println!("{}", display_diff(&after.compare(&before)));
+    /// ```
+    pub fn compare(&self, previous: &PerformanceScore) -> ScoreDiff {
+        let delta = |previous: Option<f64>, current: Option<f64>| {
+            Some(ScoreDelta { previous: previous?, current: current? })
+        };
+        
+        // A ordem das faixas é a mesma para quaisquer limites válidos
+        let order = CategoryBoundaries::default();
+        let previous_rank = order.min_score(&previous.category);
+        let current_rank = order.min_score(&self.category);
+        
+        ScoreDiff {
+            overall: ScoreDelta { previous: previous.overall_score, current: self.overall_score },
+            cpu: delta(previous.cpu_score, self.cpu_score),
+            ram: delta(previous.ram_score, self.ram_score),
+            disk: delta(previous.disk_score, self.disk_score),
+            gpu: delta(previous.gpu_score, self.gpu_score),
+            battery: delta(previous.battery_score, self.battery_score),
+            previous_category: previous.category.clone(),
+            current_category: self.category.clone(),
+            category_improved: current_rank > previous_rank,
+            category_degraded: current_rank < previous_rank,
+        }
+    }
+}
+
+/// Resumo em uma linha: `8.3/10 — BomEstado`
+impl fmt::Display for PerformanceScore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:.1}/10 — {}", self.overall_score, self.category.to_ascii_name())
+    }
+}
+
+/// Valor de uma pontuação antes e depois
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScoreDelta {
+    /// Pontuação anterior (0.0 a 10.0)
+    pub previous: f64,
+    /// Pontuação atual (0.0 a 10.0)
+    pub current: f64,
+}
+
+impl ScoreDelta {
+    /// Variação da pontuação (positiva quando melhorou)
+    pub fn delta(&self) -> f64 {
+        self.current - self.previous
+    }
+    
+    /// Exibe a variação com seta de tendência (ex: "▲ +1.2", "▼ -0.8")
+    /// 
+    /// Melhorias aparecem em verde e pioras em vermelho, exceto após
+    /// [`utils::disable_ansi`]. Variações menores que 0.05 ponto (invisíveis
+    /// com uma casa decimal) são exibidas como estáveis (→).
+    pub fn to_text(&self) -> String {
+        self.to_text_colored(true)
+    }
+    
+    /// Exibe a variação com seta de tendência, com ou sem cores ANSI
+    pub fn to_text_colored(&self, use_color: bool) -> String {
+        let change = self.delta();
+        let (arrow, color) = if change >= 0.05 {
+            ("▲", "\x1b[32m")
+        } else if change <= -0.05 {
+            ("▼", "\x1b[31m")
+        } else {
+            return format!("→ {:+.1}", 0.0);
+        };
+        let text = format!("{} {:+.1}", arrow, change);
+        if use_color && utils::ansi_enabled() {
+            format!("{}{}{}", color, text, PerformanceCategory::reset_color())
+        } else {
+            text
+        }
+    }
+}
+
+/// Comparação entre duas pontuações, gerada por [`PerformanceScore::compare`]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScoreDiff {
+    /// Variação da pontuação geral
+    pub overall: ScoreDelta,
+    /// Variação da CPU, `None` se ausente em alguma das pontuações
+    pub cpu: Option<ScoreDelta>,
+    /// Variação da RAM, `None` se ausente em alguma das pontuações
+    pub ram: Option<ScoreDelta>,
+    /// Variação dos discos, `None` se ausente em alguma das pontuações
+    pub disk: Option<ScoreDelta>,
+    /// Variação da GPU, `None` se ausente em alguma das pontuações
+    pub gpu: Option<ScoreDelta>,
+    /// Variação da bateria, `None` se ausente em alguma das pontuações
+    pub battery: Option<ScoreDelta>,
+    /// Categoria da pontuação anterior
+    pub previous_category: PerformanceCategory,
+    /// Categoria da pontuação atual
+    pub current_category: PerformanceCategory,
+    /// Indica se a categoria passou para uma faixa melhor
+    pub category_improved: bool,
+    /// Indica se a categoria passou para uma faixa pior
+    pub category_degraded: bool,
+}
+
+impl ScoreDiff {
+    /// Indica se a categoria de desempenho mudou
+    pub fn category_changed(&self) -> bool {
+        self.previous_category != self.current_category
+    }
+    
+    /// Resume as variações em uma linha com setas (ex: "Geral: ▲ +1.2 | CPU: ▼ -0.8")
+    /// 
+    /// Melhorias aparecem em verde e pioras em vermelho, exceto após
+    /// [`utils::disable_ansi`]. Variações menores que 0.05 são exibidas como
+    /// estáveis (→); componentes ausentes em alguma das pontuações são omitidos.
+    pub fn to_text(&self) -> String {
+        self.to_text_localized(Language::default())
+    }
+    
+    /// Resume as variações em uma linha, com os rótulos no idioma indicado
+    pub fn to_text_localized(&self, language: Language) -> String {
+        let parts: Vec<String> = [
+            (Message::OverallLabel.text(language), Some(self.overall)),
+            ("CPU".to_string(), self.cpu),
+            ("RAM".to_string(), self.ram),
+            (Message::DisksLabel.text(language), self.disk),
+        ]
+        .into_iter()
+        .filter_map(|(label, delta)| Some(format!("{}: {}", label, delta?.to_text())))
+        .collect();
+        utils::render_text(&parts.join(" | "))
+    }
+}
+
+/// Pontuação registrada com o instante da execução
+/// 
+/// Cada linha do arquivo gravado por [`utils::append_snapshot`] é um valor
+/// deste tipo em JSON. É o único formato de histórico em disco, lido também
+/// por [`ScoreHistory::load`](history::ScoreHistory::load).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimestampedScore {
+    /// Instante da execução
+    pub timestamp: std::time::SystemTime,
+    /// Pontuação calculada
+    pub score: PerformanceScore,
+}
+
 /// Categorias de desempenho da máquina
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PerformanceCategory {
-    /// 1-2 pontos: Descarte ou upgrade completo necessário
+    /// Abaixo de [`MANUTENCAO_MIN_SCORE`]: Descarte ou upgrade completo necessário
     Descarte,
-    /// 3-4 pontos: Manutenção urgente necessária
+    /// A partir de [`MANUTENCAO_MIN_SCORE`]: Manutenção urgente necessária
     Manutencao,
-    /// 5-6 pontos: Uso com precaução/monitoramento
+    /// A partir de [`PRECAUCAO_MIN_SCORE`]: Uso com precaução/monitoramento
+    // Nome ASCII na serialização para compatibilidade com outras linguagens
+    #[cfg_attr(feature = "serde", serde(rename = "Precaucao"))]
     Precaução,
-    /// 7+ pontos: Máquina em bom estado de uso
+    /// A partir de [`BOM_ESTADO_MIN_SCORE`]: Máquina em bom estado de uso
     BomEstado,
 }
 
 impl PerformanceCategory {
-    /// Retorna a descrição da categoria
-    pub fn description(&self) -> &str {
+    /// Determina a categoria de uma pontuação com os limites padrão
+    /// 
+    /// Abaixo de 3.0 é Descarte, abaixo de 5.0 é Manutenção, abaixo de 7.0 é
+    /// Precaução e, a partir de 7.0, Bom Estado. Para limites personalizados,
+    /// use [`CategoryBoundaries::category_for`] com [`ScoringConfig::categories`].
+    /// 
+    /// # Exemplo
+    /// ```
+    /// use hardware_diagnostic::PerformanceCategory;
+    /// 
+    /// assert_eq!(PerformanceCategory::from_score(4.99), PerformanceCategory::Manutencao);
+    /// assert_eq!(PerformanceCategory::from_score(7.0), PerformanceCategory::BomEstado);
+    /// ```
+    pub fn from_score(score: f64) -> Self {
+        CategoryBoundaries::default().category_for(score)
+    }
+    
+    /// Faixa de pontuação da categoria com os limites padrão
+    /// 
+    /// O início é inclusivo e o fim, exclusivo. A faixa de Bom Estado termina
+    /// em `f64::INFINITY`, para que a pontuação máxima (10.0) pertença a ela.
+    pub fn score_range(&self) -> Range<f64> {
+        CategoryBoundaries::default().range(self)
+    }
+    
+    /// Nome ASCII da categoria, o mesmo usado na serialização
+    /// 
+    /// Para CSV, métricas e scripts: `Precaução` vira `"Precaucao"`. Para
+    /// exibição, use [`PerformanceCategory::description`], que mantém os acentos.
+    /// 
+    /// # Exemplo
+    /// ```
+    /// use hardware_diagnostic::PerformanceCategory;
+    /// 
+    /// assert_eq!(PerformanceCategory::Precaução.to_ascii_name(), "Precaucao");
+    /// assert_eq!(PerformanceCategory::BomEstado.to_ascii_name(), "BomEstado");
+    /// ```
+    pub fn to_ascii_name(&self) -> &'static str {
         match self {
-            PerformanceCategory::Descarte => "DESCARTE - Upgrade completo necessário",
-            PerformanceCategory::Manutencao => "MANUTENÇÃO URGENTE - Requer ações corretivas",
-            PerformanceCategory::Precaução => "USO COM PRECAUÇÃO - Monitorar constantemente",
-            PerformanceCategory::BomEstado => "BOM ESTADO - Adequado para uso normal",
+            PerformanceCategory::Descarte => "Descarte",
+            PerformanceCategory::Manutencao => "Manutencao",
+            PerformanceCategory::Precaução => "Precaucao",
+            PerformanceCategory::BomEstado => "BomEstado",
+        }
+    }
+    
+    /// Todas as categorias, da pior para a melhor
+    pub fn all() -> &'static [PerformanceCategory] {
+        &[
+            PerformanceCategory::Descarte,
+            PerformanceCategory::Manutencao,
+            PerformanceCategory::Precaução,
+            PerformanceCategory::BomEstado,
+        ]
+    }
+    
+    /// Retorna a descrição da categoria em português
+    pub fn description(&self) -> &str {
+        self.description_in(Language::default())
+    }
+    
+    /// Retorna a descrição da categoria no idioma indicado
+    pub fn description_in(&self, language: Language) -> &'static str {
+        match language {
+            Language::PtBr => match self {
+                PerformanceCategory::Descarte => "DESCARTE - Upgrade completo necessário",
+                PerformanceCategory::Manutencao => "MANUTENÇÃO URGENTE - Requer ações corretivas",
+                PerformanceCategory::Precaução => "USO COM PRECAUÇÃO - Monitorar constantemente",
+                PerformanceCategory::BomEstado => "BOM ESTADO - Adequado para uso normal",
+            },
+            Language::EnUs => match self {
+                PerformanceCategory::Descarte => "DISCARD - Full upgrade required",
+                PerformanceCategory::Manutencao => "URGENT MAINTENANCE - Corrective action required",
+                PerformanceCategory::Precaução => "USE WITH CAUTION - Monitor constantly",
+                PerformanceCategory::BomEstado => "GOOD CONDITION - Fit for normal use",
+            },
         }
     }
     
     /// Retorna a cor ANSI para exibição (opcional)
+    /// 
+    /// Vazia quando as cores estão desligadas (veja [`utils::ansi_enabled`]).
     pub fn color_code(&self) -> &str {
+        if !utils::ansi_enabled() {
+            return "";
+        }
         match self {
             PerformanceCategory::Descarte => "\x1b[31m", // Vermelho
             PerformanceCategory::Manutencao => "\x1b[33m", // Amarelo
@@ -128,708 +1070,5965 @@ impl PerformanceCategory {
     }
     
     /// Retorna o código de reset ANSI
+    /// 
+    /// Vazio quando as cores estão desligadas (veja [`utils::ansi_enabled`]).
     pub fn reset_color() -> &'static str {
-        "\x1b[0m"
+        if utils::ansi_enabled() {
+            "\x1b[0m"
+        } else {
+            ""
+        }
     }
 }
 
-/// Coleta informações detalhadas da CPU
+/// Contexto compartilhado de coleta, dono de uma única instância de `sysinfo`
 /// 
-/// # Retorno
-/// Retorna uma instância de `CpuInfo` com:
-/// - Número de CPUs/cores lógicos
-/// - Percentual de uso atual
-/// - Frequência em MHz
-/// - Nome do modelo
-/// - Contagem de núcleos físicos
+/// Evita criar um `System` novo para cada consulta: atualize o contexto uma
+/// vez com [`refresh_all`](SystemContext::refresh_all) e leia CPU, RAM e discos
+/// a partir do mesmo estado. Reutilizar o contexto entre atualizações também
+/// dispensa a espera de amostragem da CPU quando o intervalo já passou.
 /// 
 /// # Exemplo
 /// ```
-/// let cpu_info = cpu_info();
-/// println!("CPU: {}", cpu_info.name);
-/// println!("Uso: {:.1}%", cpu_info.cpu_usage);
+/// use hardware_diagnostic::SystemContext;
+/// 
+/// let mut ctx = SystemContext::new();
+/// ctx.refresh_all();
+/// 
+/// let cpu = ctx.cpu_info_from();
+/// let ram = ctx.ram_info_from();
+/// println!("CPU: {} | RAM usada: {:.1}%", cpu.name, ram.ram_usage_percent);
 /// ```
-pub fn cpu_info() -> CpuInfo {
-    // Cria uma nova instância do System
-    let mut sys = System::new();
-    
-    // Atualiza apenas as informações da CPU
-    sys.refresh_cpu();
-    
-    // Aguarda um breve período para medição precisa do uso
-    std::thread::sleep(std::time::Duration::from_millis(500));
-    sys.refresh_cpu();
+pub struct SystemContext {
+    sys: System,
+    disks: Disks,
+    sample_interval: Duration,
+    last_cpu_refresh: Option<Instant>,
+    cpu_throttling: Option<bool>,
+    temperatures: Vec<TemperatureReading>,
+    last_process_refresh: Option<Instant>,
+    last_disk_io: Option<DiskIoSample>,
+    disk_io_rates: HashMap<String, DiskIoRates>,
+    smart: HashMap<String, SmartInfo>,
+    smart_queried: bool,
+    bios: Option<bios::BiosInfo>,
+    bios_queried: bool,
+}
+
+impl SystemContext {
+    /// Cria um contexto vazio com o intervalo de amostragem padrão
+    pub fn new() -> Self {
+        Self::with_sample_interval(DEFAULT_CPU_SAMPLE_INTERVAL)
+    }
     
-    // Obtém informações dos CPUs
-    let cpus = sys.cpus();
+    /// Cria um contexto vazio com um intervalo de amostragem da CPU personalizado
+    pub fn with_sample_interval(sample_interval: Duration) -> Self {
+        SystemContext {
+            sys: System::new(),
+            disks: Disks::new(),
+            sample_interval,
+            last_cpu_refresh: None,
+            cpu_throttling: None,
+            temperatures: Vec::new(),
+            last_process_refresh: None,
+            last_disk_io: None,
+            disk_io_rates: HashMap::new(),
+            smart: HashMap::new(),
+            smart_queried: false,
+            bios: None,
+            bios_queried: false,
+        }
+    }
     
-    // Calcula uso médio de todos os cores
-    let total_usage: f32 = cpus.iter().map(|cpu| cpu.cpu_usage()).sum();
-    let avg_usage = if !cpus.is_empty() {
-        total_usage / cpus.len() as f32
-    } else {
-        0.0
-    };
+    /// Atualiza CPU, memória e discos (e consulta o BIOS na primeira vez)
+    /// 
+    /// Com o uso da CPU acima de 80%, também amostra os processos, para que
+    /// a recomendação de [`SystemContext::performance_score`] cite os maiores
+    /// consumidores.
+    pub fn refresh_all(&mut self) {
+        // A primeira leitura de E/S aproveita a espera de amostragem da CPU
+        if self.last_disk_io.is_none() {
+            self.last_disk_io = Some(DiskIoSample::now());
+        }
+        self.refresh_cpu();
+        self.refresh_memory();
+        self.refresh_disks();
+        self.refresh_bios();
+        self.refresh_processes_if_busy(&self.cpu_info_from());
+    }
     
-    // Obtém informações do primeiro CPU para nome e frequência
-    let cpu_name = if let Some(first_cpu) = cpus.first() {
-        first_cpu.brand().to_string()
-    } else {
-        "Desconhecido".to_string()
-    };
+    /// Consulta o BIOS/UEFI, uma vez por contexto
+    /// 
+    /// O firmware não muda durante a execução: chamadas seguintes mantêm o
+    /// resultado da primeira consulta.
+    pub fn refresh_bios(&mut self) {
+        if !self.bios_queried {
+            self.bios = bios::bios_info().ok();
+            self.bios_queried = true;
+        }
+    }
     
-    let cpu_frequency = if let Some(first_cpu) = cpus.first() {
-        first_cpu.frequency()
-    } else {
-        0
-    };
+    /// Atualiza as informações da CPU
+    /// 
+    /// O uso da CPU exige duas leituras separadas pelo intervalo de amostragem.
+    /// Só aguarda o tempo que ainda falta desde a última atualização.
+    pub fn refresh_cpu(&mut self) {
+        match self.last_cpu_refresh {
+            Some(last) => {
+                std::thread::sleep(self.sample_interval.saturating_sub(last.elapsed()));
+            }
+            None => {
+                self.sys.refresh_cpu();
+                std::thread::sleep(self.sample_interval);
+            }
+        }
+        self.sys.refresh_cpu();
+        self.last_cpu_refresh = Some(Instant::now());
+        // Consultados uma vez por atualização, não a cada leitura
+        self.cpu_throttling = cpu_throttling();
+        self.temperatures = temperature_info();
+    }
     
-    CpuInfo {
-        number_cpus: cpus.len(),
-        cpu_usage: avg_usage,
-        frequency: cpu_frequency,
-        name: cpu_name,
-        physical_cores: sys.physical_core_count(),
+    /// Atualiza a lista de processos
+    /// 
+    /// Como no uso total da CPU, o uso por processo exige duas leituras
+    /// separadas pelo intervalo de amostragem. [`SystemContext::refresh_all`]
+    /// só a chama com a CPU sobrecarregada, pois percorrer os processos é custoso.
+    pub fn refresh_processes(&mut self) {
+        match self.last_process_refresh {
+            Some(last) => {
+                std::thread::sleep(self.sample_interval.saturating_sub(last.elapsed()));
+            }
+            None => {
+                self.sys.refresh_processes();
+                std::thread::sleep(self.sample_interval);
+            }
+        }
+        self.sys.refresh_processes();
+        self.last_process_refresh = Some(Instant::now());
     }
-}
-
-/// Coleta informações detalhadas da memória RAM e SWAP
-/// 
-/// # Retorno
-/// Retorna uma instância de `RamInfo` com:
-/// - Totais e usos de RAM e SWAP em bytes
-/// - Percentuais de uso calculados
-/// 
-/// # Exemplo
-/// ```
-/// let ram_info = ram_info();
-/// println!("RAM: {:.1} GB / {:.1} GB", 
-///     bytes_to_gb(ram_info.used_ram),
-///     bytes_to_gb(ram_info.total_ram)
-/// );
-/// ```
-pub fn ram_info() -> RamInfo {
-    let mut sys = System::new();
     
-    // Atualiza informações de memória
-    sys.refresh_memory();
+    /// Amostra os processos quando `cpu` indica uso acima de 80%
+    /// 
+    /// É o que permite à recomendação de CPU alta nomear os responsáveis;
+    /// com uso normal, não há espera.
+    fn refresh_processes_if_busy(&mut self, cpu: &CpuInfo) {
+        if cpu.cpu_usage > HIGH_CPU_USAGE_PERCENT {
+            self.refresh_processes();
+        }
+    }
     
-    let total_ram = sys.total_memory();
-    let used_ram = sys.used_memory();
-    let free_ram = sys.free_memory();
-    let total_swap = sys.total_swap();
-    let used_swap = sys.used_swap();
+    /// Atualiza as informações de memória RAM e SWAP
+    pub fn refresh_memory(&mut self) {
+        self.sys.refresh_memory();
+    }
     
-    // Calcula percentuais de uso
-    let ram_usage_percent = if total_ram > 0 {
-        (used_ram as f64 / total_ram as f64) * 100.0
-    } else {
-        0.0
-    };
+    /// Atualiza a lista de discos e as estatísticas de E/S
+    /// 
+    /// Assim como o uso da CPU, as taxas de E/S exigem duas leituras
+    /// separadas pelo intervalo de amostragem; em plataformas sem
+    /// contadores de E/S não há espera.
+    pub fn refresh_disks(&mut self) {
+        self.disks.refresh_list();
+        
+        let previous = self.last_disk_io.take().unwrap_or_else(DiskIoSample::now);
+        previous.wait_for_next(self.sample_interval);
+        let current = DiskIoSample::now();
+        self.disk_io_rates = current.rates_since(&previous);
+        self.last_disk_io = Some(current);
+        
+        // O SMART muda devagar e a consulta pode acordar os discos: uma vez por contexto
+        if !self.smart_queried {
+            self.smart = smart::query_smart();
+            self.smart_queried = true;
+        }
+    }
     
-    let swap_usage_percent = if total_swap > 0 {
-        (used_swap as f64 / total_swap as f64) * 100.0
-    } else {
-        0.0
-    };
+    /// Lê as informações da CPU do último refresh
+    pub fn cpu_info_from(&self) -> CpuInfo {
+        read_cpu(&self.sys, self.cpu_throttling, &self.temperatures).unwrap_or_default()
+    }
     
-    RamInfo {
-        total_ram,
-        used_ram,
-        free_ram,
-        total_swap,
-        used_swap,
-        ram_usage_percent,
-        swap_usage_percent,
+    /// Lê as informações da CPU do último refresh, propagando falhas
+    fn cpu_info_checked(&self) -> Result<CpuInfo, DiagnosticError> {
+        read_cpu(&self.sys, self.cpu_throttling, &self.temperatures)
+    }
+    
+    /// Lê as informações de memória do último refresh
+    pub fn ram_info_from(&self) -> RamInfo {
+        read_ram(&self.sys).unwrap_or_default()
+    }
+    
+    /// Lê as informações de discos do último refresh
+    pub fn disk_info_from(&self) -> Vec<DiskInfo> {
+        read_disks(&self.disks, &self.disk_io_rates, &self.smart).unwrap_or_default()
+    }
+    
+    /// Informações do BIOS/UEFI de [`SystemContext::refresh_bios`]
+    /// 
+    /// `None` se o firmware não pôde ser consultado ou ainda não foi.
+    pub fn bios_info_from(&self) -> Option<bios::BiosInfo> {
+        self.bios.clone()
+    }
+    
+    /// Estado do Secure Boot de [`SystemContext::refresh_bios`]
+    fn secure_boot_from(&self) -> Option<bool> {
+        self.bios.as_ref().and_then(|bios| bios.secure_boot_enabled)
+    }
+    
+    /// Lê os processos do último [`SystemContext::refresh_processes`]
+    /// 
+    /// Vazio se os processos nunca foram atualizados neste contexto.
+    pub fn processes_from(&self) -> Vec<ProcessInfo> {
+        processes::read_processes(&self.sys)
+    }
+    
+    /// Calcula a pontuação de desempenho a partir do último refresh
+    pub fn performance_score(&self) -> PerformanceScore {
+        self.performance_score_with_config(&ScoringConfig::default())
+    }
+    
+    /// Calcula a pontuação a partir do último refresh usando pesos personalizados
+    /// 
+    /// A configuração não é validada aqui; use [`ScoringConfig::validate`] antes.
+    pub fn performance_score_with_config(&self, config: &ScoringConfig) -> PerformanceScore {
+        self.score_with_cpu(&self.cpu_info_from(), config)
+    }
+    
+    /// Pontua o último refresh com a CPU informada
+    /// 
+    /// Os processos citados na recomendação de CPU alta são os de
+    /// [`SystemContext::refresh_processes_if_busy`].
+    fn score_with_cpu(&self, cpu: &CpuInfo, config: &ScoringConfig) -> PerformanceScore {
+        score_from(
+            Some(cpu),
+            Some(&self.ram_info_from()),
+            Some(&self.disk_info_from()),
+            collect_gpu_score(),
+            battery::battery_info().as_ref(),
+            &self.temperatures,
+            &processes::top_by_cpu(self.processes_from(), processes::RECOMMENDATION_TOP_PROCESSES),
+            Some(System::uptime()),
+            detect_virtualization(),
+            self.secure_boot_from(),
+            &DiagnosticConfig::from(config.clone()),
+        )
     }
 }
 
-/// Coleta informações de todos os discos do sistema
-/// 
-/// # Retorno
-/// Retorna um vetor contendo `DiskInfo` para cada disco encontrado
-/// 
-/// # Exemplo
-/// ```
-/// let disks = disk_info();
-/// for disk in disks {
-///     println!("Disco {}: {:.1} GB livre", 
-///         disk.name, 
-///         bytes_to_gb(disk.available_space)
-///     );
-/// }
-/// ```
-pub fn disk_info() -> Vec<DiskInfo> {
-    // Cria uma lista atualizada de discos
-    let disks = Disks::new_with_refreshed_list();
-    let mut disk_info_list = Vec::new();
-    
-    for disk in &disks {
-        let total_space = disk.total_space();
-        let available_space = disk.available_space();
-        let used_space = total_space - available_space;
-        let usage_percent = if total_space > 0 {
-            (used_space as f64 / total_space as f64) * 100.0
-        } else {
-            0.0
-        };
-        
-        // Converte &OsStr para String usando to_string_lossy
-        let file_system = disk.file_system()
-            .to_string_lossy()
-            .to_string();
-        
-        disk_info_list.push(DiskInfo {
-            name: disk.name().to_string_lossy().to_string(),
-            mount_point: disk.mount_point().to_string_lossy().to_string(),
-            total_space,
-            available_space,
-            used_space,
-            usage_percent,
-            file_system,
-            disk_type: format!("{:?}", disk.kind()),
-        });
+impl Default for SystemContext {
+    fn default() -> Self {
+        Self::new()
     }
-    
-    disk_info_list
 }
 
-/// Calcula a pontuação de desempenho da máquina
+/// Pesos de CPU, RAM e discos na pontuação geral
 /// 
-/// # Retorno
-/// Retorna uma instância de `PerformanceScore` com:
-/// - Pontuações individuais e geral
-/// - Categoria de desempenho
-/// - Recomendações específicas
+/// Os três pesos devem ser não negativos e somar 1.0; o construtor
+/// [`ScoreWeights::new`] garante isso. O valor padrão reproduz os pesos
+/// históricos: CPU 40%, RAM 30% e discos 30%.
 /// 
 /// # Exemplo
 /// ```
-/// let score = calculate_performance_score();
-/// println!("Pontuação: {:.1}/10 - {}", score.overall_score, score.category);
+/// use hardware_diagnostic::ScoreWeights;
+/// 
+/// // Servidor: RAM e discos pesam mais que a CPU
+/// let weights = ScoreWeights::new(0.2, 0.4, 0.4).unwrap();
+/// assert_eq!(weights.ram(), 0.4);
+/// 
+/// assert!(ScoreWeights::new(0.5, 0.5, 0.5).is_err());
 /// ```
-pub fn calculate_performance_score() -> PerformanceScore {
-    let cpu_info = cpu_info();
-    let ram_info = ram_info();
-    let disks_info = disk_info();
-    
-    // 1. PONTUAÇÃO DA CPU (0-10)
-    let cpu_score = calculate_cpu_score(&cpu_info);
-    
-    // 2. PONTUAÇÃO DA RAM (0-10)
-    let ram_score = calculate_ram_score(&ram_info);
-    
-    // 3. PONTUAÇÃO DOS DISCOS (0-10)
-    let disk_score = calculate_disk_score(&disks_info);
-    
-    // 4. PONTUAÇÃO GERAL (média ponderada)
-    let overall_score = cpu_score * 0.4 + ram_score * 0.3 + disk_score * 0.3;
-    
-    // 5. DETERMINAR CATEGORIA
-    let category = determine_category(overall_score);
-    
-    // 6. GERAR RECOMENDAÇÕES
-    let recommendations = generate_recommendations(&cpu_info, &ram_info, &disks_info, overall_score);
-    
-    PerformanceScore {
-        overall_score,
-        cpu_score,
-        ram_score,
-        disk_score,
-        category,
-        recommendations,
-    }
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScoreWeights {
+    cpu: f64,
+    ram: f64,
+    disk: f64,
 }
 
-/// Calcula a pontuação da CPU baseada em múltiplos fatores
-fn calculate_cpu_score(cpu_info: &CpuInfo) -> f64 {
-    let score: f64; // Declare sem valor inicial
-    
-    // Fator 1: Número de núcleos
-    let cores_score = match cpu_info.number_cpus {
-        0..=1 => 2.0,  // Muito baixo
-        2 => 4.0,      // Baixo
-        3..=4 => 6.0,  // Médio
-        5..=8 => 8.0,  // Bom
-        _ => 10.0,     // Excelente
-    };
+impl ScoreWeights {
+    /// Cria um conjunto de pesos validado
+    /// 
+    /// # Erros
+    /// Retorna `DiagnosticError::InvalidConfig` se algum peso for negativo
+    /// ou se a soma for diferente de 1.0.
+    pub fn new(cpu: f64, ram: f64, disk: f64) -> Result<Self, DiagnosticError> {
+        let weights = ScoreWeights { cpu, ram, disk };
+        weights.validate()?;
+        Ok(weights)
+    }
     
-    // Fator 2: Uso atual da CPU (quanto menor o uso, melhor)
-    let usage_score = if cpu_info.cpu_usage < 30.0 {
-        10.0 // Excelente (baixo uso)
-    } else if cpu_info.cpu_usage < 60.0 {
-        7.0  // Bom
-    } else if cpu_info.cpu_usage < 85.0 {
-        4.0  // Regular
-    } else {
-        1.0  // Crítico
-    };
+    /// Peso da CPU na pontuação geral
+    pub fn cpu(&self) -> f64 {
+        self.cpu
+    }
     
-    // Fator 3: Frequência da CPU (quanto maior, melhor)
-    let freq_score = if cpu_info.frequency < 2000 {
-        3.0  // Muito baixa
-    } else if cpu_info.frequency < 3000 {
-        6.0  // Baixa
-    } else if cpu_info.frequency < 4000 {
-        8.0  // Boa
-    } else {
-        10.0 // Excelente
-    };
+    /// Peso da RAM na pontuação geral
+    pub fn ram(&self) -> f64 {
+        self.ram
+    }
     
-    // Média dos fatores com pesos
-    score = cores_score * 0.4 + usage_score * 0.4 + freq_score * 0.2;
+    /// Peso dos discos na pontuação geral
+    pub fn disk(&self) -> f64 {
+        self.disk
+    }
     
-    // Garante entre 0 e 10
-    if score < 0.0 {
-        0.0
-    } else if score > 10.0 {
-        10.0
-    } else {
-        score
+    /// Verifica se os pesos são não negativos e somam 1.0
+    /// 
+    /// Útil para pesos desserializados, que não passam pelo construtor.
+    pub fn validate(&self) -> Result<(), DiagnosticError> {
+        validate_weight_group("geral", &[self.cpu, self.ram, self.disk])
     }
 }
 
-/// Calcula a pontuação da RAM
-fn calculate_ram_score(ram_info: &RamInfo) -> f64 {
-    let score: f64;
-    
-    // Fator 1: Uso da RAM (quanto menor, melhor)
-    let ram_usage_score = if ram_info.ram_usage_percent < 60.0 {
-        10.0 // Excelente
-    } else if ram_info.ram_usage_percent < 75.0 {
-        7.0  // Bom
-    } else if ram_info.ram_usage_percent < 90.0 {
-        4.0  // Regular
-    } else {
-        1.0  // Crítico
-    };
-    
-    // Fator 2: Uso do SWAP (quanto menor, melhor)
-    let swap_score = if ram_info.total_swap == 0 {
-        8.0 // Sem SWAP configurado (neutro)
-    } else if ram_info.swap_usage_percent < 10.0 {
-        10.0 // Excelente
-    } else if ram_info.swap_usage_percent < 30.0 {
-        7.0  // Bom
-    } else if ram_info.swap_usage_percent < 50.0 {
-        4.0  // Regular
-    } else {
-        1.0  // Crítico (muito uso de SWAP)
-    };
-    
-    // Fator 3: Quantidade total de RAM
-    let total_ram_gb = ram_info.total_ram as f64 / 1_073_741_824.0;
-    let capacity_score = if total_ram_gb < 4.0 {
-        3.0  // Muito baixa
-    } else if total_ram_gb < 8.0 {
-        6.0  // Baixa
-    } else if total_ram_gb < 16.0 {
-        8.0  // Boa
-    } else {
-        10.0 // Excelente
-    };
-    
-    score = ram_usage_score * 0.5 + swap_score * 0.3 + capacity_score * 0.2;
-    
-    // Garante entre 0 e 10
-    if score < 0.0 {
-        0.0
-    } else if score > 10.0 {
-        10.0
-    } else {
-        score
+impl Default for ScoreWeights {
+    fn default() -> Self {
+        ScoreWeights {
+            cpu: 0.4,
+            ram: 0.3,
+            disk: 0.3,
+        }
     }
 }
 
-/// Calcula a pontuação dos discos
-fn calculate_disk_score(disks: &[DiskInfo]) -> f64 {
-    if disks.is_empty() {
-        return 5.0; // Pontuação neutra se não houver discos
+impl ScoreWeights {
+    /// Pesos de estação de trabalho: CPU 50%, RAM 30% e discos 20%
+    pub fn workstation() -> Self {
+        ScoreWeights { cpu: 0.5, ram: 0.3, disk: 0.2 }
     }
     
-    let mut total_score = 0.0;
-    let mut count = 0;
-    
-    for disk in disks {
-        let disk_score: f64;
-        
-        // Fator 1: Uso do disco (quanto menor, melhor)
-        let usage_score = if disk.usage_percent < 70.0 {
-            10.0 // Excelente
-        } else if disk.usage_percent < 85.0 {
-            7.0  // Bom
-        } else if disk.usage_percent < 95.0 {
-            4.0  // Regular
-        } else {
-            1.0  // Crítico
-        };
-        
-        // Fator 2: Tipo de disco
-        let type_score = if disk.disk_type.contains("SSD") || disk.disk_type.contains("NVMe") {
-            10.0 // SSD (rápido)
-        } else if disk.disk_type.contains("HDD") {
-            6.0  // HDD (lento)
-        } else {
-            8.0  // Outro/desconhecido
-        };
-        
-        // Fator 3: Espaço livre
-        let free_gb = disk.available_space as f64 / 1_000_000_000.0;
-        let free_space_score = if free_gb > 100.0 {
-            10.0 // Excelente
-        } else if free_gb > 50.0 {
-            8.0  // Bom
-        } else if free_gb > 20.0 {
-            6.0  // Regular
-        } else if free_gb > 10.0 {
-            4.0  // Baixo
-        } else {
-            1.0  // Crítico
-        };
-        
-        disk_score = usage_score * 0.5 + type_score * 0.3 + free_space_score * 0.2;
-        
-        // Garante entre 0 e 10
-        let clamped_score = if disk_score < 0.0 {
-            0.0
-        } else if disk_score > 10.0 {
-            10.0
-        } else {
-            disk_score
-        };
-        
-        total_score += clamped_score;
-        count += 1;
+    /// Pesos de servidor: CPU 25%, RAM 40% e discos 35%
+    pub fn server() -> Self {
+        ScoreWeights { cpu: 0.25, ram: 0.4, disk: 0.35 }
     }
     
-    if count > 0 {
-        total_score / count as f64
-    } else {
-        5.0
+    /// Pesos de notebook: CPU 35%, RAM 35% e discos 30%
+    /// 
+    /// A bateria entra à parte, pelo peso de [`ScoreProfile::battery_weight`].
+    pub fn laptop() -> Self {
+        ScoreWeights { cpu: 0.35, ram: 0.35, disk: 0.3 }
     }
 }
 
-/// Determina a categoria baseada na pontuação geral
-fn determine_category(score: f64) -> PerformanceCategory {
-    match score {
-        s if s < 3.0 => PerformanceCategory::Descarte,     // 0-2.9: Descarte
-        s if s < 5.0 => PerformanceCategory::Manutencao,   // 3-4.9: Manutenção
-        s if s < 7.0 => PerformanceCategory::Precaução,    // 5-6.9: Precaução
-        _ => PerformanceCategory::BomEstado,               // 7+: Bom estado
-    }
+/// Perfis de uso com pesos predefinidos
+/// 
+/// Um perfil substitui apenas os pesos gerais e o peso da bateria de uma
+/// [`ScoringConfig`]; limiares, categorias e pesos dos subfatores continuam
+/// personalizáveis (ex: via arquivo de configuração).
+/// 
+/// # Exemplo
+/// ```
+/// use hardware_diagnostic::{ScoreProfile, ScoringConfig};
+/// 
+/// let mut config = ScoringConfig::default();
+/// ScoreProfile::from_name("server").unwrap().apply(&mut config);
+/// assert_eq!(config.weights.ram(), 0.4);
+/// assert_eq!(config.battery_weight, 0.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ScoreProfile {
+    /// Estação de trabalho: prioriza a CPU
+    Workstation,
+    /// Servidor: prioriza RAM e discos
+    Server,
+    /// Notebook: inclui a saúde da bateria com peso maior
+    Laptop,
 }
 
-/// Gera recomendações baseadas no estado da máquina
-fn generate_recommendations(
-    cpu_info: &CpuInfo,
-    ram_info: &RamInfo,
-    disks: &[DiskInfo],
-    overall_score: f64,
-) -> Vec<String> {
-    let mut recommendations = Vec::new();
-    
-    // Recomendações baseadas na pontuação geral
-    if overall_score < 3.0 {
-        recommendations.push("🛑 CONSIDERE DESCARTE: A máquina está em estado crítico".to_string());
-        recommendations.push("💡 Sugestão: Upgrade completo ou substituição do equipamento".to_string());
-    } else if overall_score < 5.0 {
-        recommendations.push("⚠️ MANUTENÇÃO URGENTE: A máquina requer intervenção imediata".to_string());
-    } else if overall_score < 7.0 {
-        recommendations.push("🔶 USO COM PRECAUÇÃO: Monitore o desempenho regularmente".to_string());
-    } else {
-        recommendations.push("✅ BOM ESTADO: A máquina está adequada para uso normal".to_string());
+impl ScoreProfile {
+    /// Interpreta o nome de um perfil ("workstation", "server" ou "laptop")
+    /// 
+    /// A comparação ignora maiúsculas.
+    pub fn from_name(name: &str) -> Option<ScoreProfile> {
+        match name.to_ascii_lowercase().as_str() {
+            "workstation" => Some(ScoreProfile::Workstation),
+            "server" => Some(ScoreProfile::Server),
+            "laptop" => Some(ScoreProfile::Laptop),
+            _ => None,
+        }
     }
     
-    // Recomendações específicas para CPU
-    if cpu_info.cpu_usage > 80.0 {
-        recommendations.push("🔴 CPU: Uso muito alto. Verifique processos desnecessários".to_string());
+    /// Pesos gerais de CPU, RAM e discos do perfil
+    pub fn weights(&self) -> ScoreWeights {
+        match self {
+            ScoreProfile::Workstation => ScoreWeights::workstation(),
+            ScoreProfile::Server => ScoreWeights::server(),
+            ScoreProfile::Laptop => ScoreWeights::laptop(),
+        }
     }
-    if cpu_info.number_cpus < 2 {
-        recommendations.push("🟡 CPU: Apenas 1 núcleo detectado. Limitação para multitarefa".to_string());
+    
+    /// Peso adicional da bateria (zero fora do perfil de notebook)
+    pub fn battery_weight(&self) -> f64 {
+        match self {
+            ScoreProfile::Laptop => 0.3,
+            ScoreProfile::Workstation | ScoreProfile::Server => 0.0,
+        }
     }
     
-    // Recomendações específicas para RAM
-    if ram_info.ram_usage_percent > 85.0 {
-        recommendations.push("🔴 RAM: Uso acima de 85%. Considere adicionar mais memória".to_string());
+    /// Aplica os pesos do perfil à configuração
+    pub fn apply(&self, config: &mut ScoringConfig) {
+        config.weights = self.weights();
+        config.battery_weight = self.battery_weight();
     }
-    if ram_info.total_ram < 4 * 1024 * 1024 * 1024 { // Menos de 4GB
-        recommendations.push("🟡 RAM: Memória insuficiente para sistemas modernos".to_string());
+}
+
+/// Verifica se um grupo de pesos é finito, não negativo e soma 1.0
+/// 
+/// NaN escaparia das comparações (`NaN < 0.0` e `|NaN - 1.0| > ε` são
+/// falsos), por isso é rejeitado antes.
+fn validate_weight_group(name: &str, weights: &[f64]) -> Result<(), DiagnosticError> {
+    if weights.iter().any(|w| !w.is_finite()) {
+        return Err(DiagnosticError::InvalidConfig(
+            format!("pesos do grupo {} devem ser números finitos", name),
+        ));
     }
-    if ram_info.swap_usage_percent > 50.0 {
-        recommendations.push("🔴 SWAP: Uso excessivo de memória virtual. Otimize a RAM".to_string());
+    if weights.iter().any(|w| *w < 0.0) {
+        return Err(DiagnosticError::InvalidConfig(
+            format!("pesos do grupo {} não podem ser negativos", name),
+        ));
     }
     
-    // Recomendações específicas para discos
-    for disk in disks {
-        if disk.usage_percent > 90.0 {
-            recommendations.push(format!("🔴 DISCO {}: Capacidade quase esgotada ({:.1}%)", 
-                disk.name, disk.usage_percent));
+    let sum: f64 = weights.iter().sum();
+    if (sum - 1.0).abs() > 1e-6 {
+        return Err(DiagnosticError::InvalidConfig(
+            format!("pesos do grupo {} somam {:.3}, esperado 1.0", name, sum),
+        ));
+    }
+    
+    Ok(())
+}
+
+/// Limiares das faixas de pontuação de cada subfator
+/// 
+/// Limiares de uso (`*_usage`) são percentuais em ordem crescente: abaixo do
+/// primeiro a faixa é excelente, abaixo do segundo boa, abaixo do terceiro
+/// regular e, a partir dele, crítica. Limiares de capacidade (frequência,
+/// RAM total, espaço livre) também são crescentes, mas quanto maior o valor
+/// medido, melhor a faixa.
+/// 
+/// Os valores padrão reproduzem os limiares históricos do diagnóstico.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct ScoringThresholds {
+    /// Núcleos lógicos que delimitam as faixas muito baixa, baixa, média e boa
+    pub cpu_cores: [usize; 4],
+    /// Uso da CPU (%) para as faixas excelente, boa e regular
+    pub cpu_usage: [f32; 3],
+    /// Uso de um único núcleo (%) a partir do qual ele é considerado saturado
+    pub cpu_saturated_core: f32,
+    /// Frequência (MHz) que delimita as faixas muito baixa, baixa e boa
+    pub cpu_frequency_mhz: [u64; 3],
+    /// Uso da RAM (%) para as faixas excelente, boa e regular
+    pub ram_usage: [f64; 3],
+    /// Uso do SWAP (%) para as faixas excelente, boa e regular
+    pub swap_usage: [f64; 3],
+    /// RAM total (GiB) que delimita as faixas muito baixa, baixa e boa
+    pub ram_capacity_gib: [f64; 3],
+    /// Uso do disco (%) para as faixas excelente, boa e regular
+    pub disk_usage: [f64; 3],
+    /// Espaço livre (GiB) que delimita as faixas crítica, baixa, regular e boa
+    pub disk_free_space_gib: [f64; 4],
+    /// Utilização de E/S (%) a partir da qual um HDD é considerado saturado
+    pub hdd_io_saturation: f32,
+    /// Pontuação máxima da CPU enquanto o sistema reporta throttling ativo
+    /// ([`CpuInfo::throttling`]); de 0.0 a 10.0
+    pub throttling_cpu_max_score: f64,
+    /// RAM total (GiB) abaixo da qual se recomenda ampliar a memória
+    pub low_ram_gib: f64,
+    /// Uso do disco (%) acima do qual se recomenda liberar espaço
+    pub disk_almost_full_percent: f64,
+    /// Espaço livre (GiB) abaixo do qual o disco recebe alerta de pouco espaço
+    pub low_disk_free_gib: f64,
+    /// Saúde da bateria (%) abaixo da qual se recomenda substituí-la
+    pub battery_health_percent: f32,
+}
+
+impl Default for ScoringThresholds {
+    fn default() -> Self {
+        ScoringThresholds {
+            cpu_cores: [1, 2, 4, 8],
+            cpu_usage: [30.0, 60.0, 85.0],
+            cpu_saturated_core: 95.0,
+            cpu_frequency_mhz: [2000, 3000, 4000],
+            ram_usage: [60.0, 75.0, 90.0],
+            swap_usage: [10.0, 30.0, 50.0],
+            ram_capacity_gib: [4.0, 8.0, 16.0],
+            disk_usage: [70.0, 85.0, 95.0],
+            disk_free_space_gib: [10.0, 20.0, 50.0, 100.0],
+            hdd_io_saturation: 80.0,
+            throttling_cpu_max_score: 3.0,
+            low_ram_gib: 4.0,
+            disk_almost_full_percent: 90.0,
+            low_disk_free_gib: 10.0,
+            battery_health_percent: 60.0,
+        }
+    }
+}
+
+impl ScoringThresholds {
+    /// Verifica se cada grupo de limiares está em ordem crescente, se os
+    /// limiares percentuais estão entre 0 e 100 e se o teto da CPU com
+    /// throttling é uma pontuação válida
+    /// 
+    /// # Erros
+    /// Retorna `DiagnosticError::InvalidConfig` indicando o limiar inválido.
+    pub fn validate(&self) -> Result<(), DiagnosticError> {
+        let cores: Vec<f64> = self.cpu_cores.iter().map(|c| *c as f64).collect();
+        let frequency: Vec<f64> = self.cpu_frequency_mhz.iter().map(|f| *f as f64).collect();
+        let cpu_usage = self.cpu_usage.map(f64::from);
+        
+        validate_breakpoints("cpu_cores", &cores)?;
+        validate_breakpoints("cpu_usage", &cpu_usage)?;
+        validate_breakpoints("cpu_frequency_mhz", &frequency)?;
+        validate_breakpoints("ram_usage", &self.ram_usage)?;
+        validate_breakpoints("swap_usage", &self.swap_usage)?;
+        validate_breakpoints("ram_capacity_gib", &self.ram_capacity_gib)?;
+        validate_breakpoints("disk_usage", &self.disk_usage)?;
+        validate_breakpoints("disk_free_space_gib", &self.disk_free_space_gib)?;
+        if !(0.0..=10.0).contains(&self.throttling_cpu_max_score) {
+            return Err(DiagnosticError::InvalidConfig(
+                "throttling_cpu_max_score deve estar entre 0.0 e 10.0".to_string(),
+            ));
+        }
+        let percentages = [
+            ("cpu_saturated_core", f64::from(self.cpu_saturated_core)),
+            ("hdd_io_saturation", f64::from(self.hdd_io_saturation)),
+            ("disk_almost_full_percent", self.disk_almost_full_percent),
+            ("battery_health_percent", f64::from(self.battery_health_percent)),
+        ];
+        for (name, value) in percentages {
+            if !(0.0..=100.0).contains(&value) {
+                return Err(DiagnosticError::InvalidConfig(format!("{} deve estar entre 0 e 100", name)));
+            }
+        }
+        for (name, value) in [("low_ram_gib", self.low_ram_gib), ("low_disk_free_gib", self.low_disk_free_gib)] {
+            if !(0.0..).contains(&value) {
+                return Err(DiagnosticError::InvalidConfig(format!("{} não pode ser negativo", name)));
+            }
+        }
+        
+        Ok(())
+    }
+}
+
+/// Pontuações gerais que separam as categorias de desempenho
+/// 
+/// Abaixo de `manutencao` a máquina é classificada como
+/// [`PerformanceCategory::Descarte`]; a partir de `bom_estado`, como
+/// [`PerformanceCategory::BomEstado`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct CategoryBoundaries {
+    /// Pontuação mínima da categoria Manutenção
+    pub manutencao: f64,
+    /// Pontuação mínima da categoria Precaução
+    pub precaucao: f64,
+    /// Pontuação mínima da categoria Bom Estado
+    pub bom_estado: f64,
+}
+
+impl Default for CategoryBoundaries {
+    fn default() -> Self {
+        CategoryBoundaries {
+            manutencao: MANUTENCAO_MIN_SCORE,
+            precaucao: PRECAUCAO_MIN_SCORE,
+            bom_estado: BOM_ESTADO_MIN_SCORE,
+        }
+    }
+}
+
+impl CategoryBoundaries {
+    /// Verifica se os limites estão em ordem crescente entre 0 e 10
+    /// 
+    /// # Erros
+    /// Retorna `DiagnosticError::InvalidConfig` se a ordem não for respeitada.
+    pub fn validate(&self) -> Result<(), DiagnosticError> {
+        let boundaries = [self.manutencao, self.precaucao, self.bom_estado];
+        validate_breakpoints("categorias", &boundaries)?;
+        
+        if boundaries.iter().any(|b| !(0.0..=10.0).contains(b)) {
+            return Err(DiagnosticError::InvalidConfig(
+                "limites das categorias devem estar entre 0 e 10".to_string(),
+            ));
+        }
+        
+        Ok(())
+    }
+    
+    /// Retorna a pontuação mínima para a máquina ficar na categoria indicada
+    /// 
+    /// # Exemplo
+    /// ```
+    /// use hardware_diagnostic::{CategoryBoundaries, PerformanceCategory};
+    /// 
+    /// let boundaries = CategoryBoundaries::default();
+    /// assert_eq!(boundaries.min_score(&PerformanceCategory::BomEstado), 7.0);
+    /// assert_eq!(boundaries.min_score(&PerformanceCategory::Descarte), 0.0);
+    /// ```
+    pub fn min_score(&self, category: &PerformanceCategory) -> f64 {
+        match category {
+            PerformanceCategory::Descarte => 0.0,
+            PerformanceCategory::Manutencao => self.manutencao,
+            PerformanceCategory::Precaução => self.precaucao,
+            PerformanceCategory::BomEstado => self.bom_estado,
+        }
+    }
+    
+    /// Faixa de pontuação da categoria: início inclusivo, fim exclusivo
+    /// 
+    /// A faixa de Bom Estado termina em `f64::INFINITY`.
+    pub fn range(&self, category: &PerformanceCategory) -> Range<f64> {
+        let end = match category {
+            PerformanceCategory::Descarte => self.manutencao,
+            PerformanceCategory::Manutencao => self.precaucao,
+            PerformanceCategory::Precaução => self.bom_estado,
+            PerformanceCategory::BomEstado => f64::INFINITY,
+        };
+        self.min_score(category)..end
+    }
+    
+    /// Categoria em que uma pontuação cai com estes limites
+    /// 
+    /// # Exemplo
+    /// ```
+    /// use hardware_diagnostic::{CategoryBoundaries, PerformanceCategory};
+    /// 
+    /// let strict = CategoryBoundaries { bom_estado: 8.0, ..CategoryBoundaries::default() };
+    /// assert_eq!(strict.category_for(7.5), PerformanceCategory::Precaução);
+    /// ```
+    pub fn category_for(&self, score: f64) -> PerformanceCategory {
+        determine_category(score, self)
+    }
+    
+    /// Faixa da categoria para a legenda (ex: "3-4.9", "7-10")
+    fn legend_range(&self, category: &PerformanceCategory) -> String {
+        let bound = |value: f64| {
+            if value.fract() == 0.0 {
+                format!("{:.0}", value)
+            } else {
+                format!("{:.1}", value)
+            }
+        };
+        let range = self.range(category);
+        let end = if range.end.is_finite() { range.end - 0.1 } else { 10.0 };
+        format!("{}-{}", bound(range.start), bound(end))
+    }
+}
+
+/// Verifica se um grupo de limiares é estritamente crescente
+fn validate_breakpoints(name: &str, values: &[f64]) -> Result<(), DiagnosticError> {
+    if !values.windows(2).all(|pair| pair[0] < pair[1]) {
+        return Err(DiagnosticError::InvalidConfig(
+            format!("limiares de {} devem estar em ordem crescente", name),
+        ));
+    }
+    
+    Ok(())
+}
+
+/// Pesos e limiares usados no cálculo da pontuação de desempenho
+/// 
+/// Os pesos gerais ([`ScoreWeights`]) e cada grupo de pesos de subfatores
+/// devem somar 1.0. Com a feature `serde`, a configuração pode ser carregada
+/// de um arquivo via [`ScoringConfig::from_file`]; campos ausentes no arquivo
+/// mantêm os valores padrão.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct ScoringConfig {
+    /// Pesos de CPU, RAM e discos na pontuação geral
+    pub weights: ScoreWeights,
+    /// Peso adicional da GPU, aplicado apenas quando há pontuação de GPU
+    /// 
+    /// Não entra na soma dos pesos gerais: a média é renormalizada
+    /// dividindo por `1.0 + gpu_weight`.
+    pub gpu_weight: f64,
+    /// Peso adicional da bateria, aplicado apenas quando há bateria
+    /// 
+    /// Assim como `gpu_weight`, não entra na soma dos pesos gerais.
+    pub battery_weight: f64,
+    /// Peso do número de núcleos na pontuação da CPU
+    pub cpu_cores_weight: f64,
+    /// Peso do uso atual na pontuação da CPU
+    pub cpu_usage_weight: f64,
+    /// Peso da frequência na pontuação da CPU
+    pub cpu_frequency_weight: f64,
+    /// Peso do uso da RAM na pontuação da RAM
+    pub ram_usage_weight: f64,
+    /// Peso do uso do SWAP na pontuação da RAM
+    pub ram_swap_weight: f64,
+    /// Peso da capacidade total na pontuação da RAM
+    pub ram_capacity_weight: f64,
+    /// Peso do uso do disco na pontuação dos discos
+    pub disk_usage_weight: f64,
+    /// Peso do tipo de disco na pontuação dos discos
+    pub disk_type_weight: f64,
+    /// Peso do espaço livre na pontuação dos discos
+    pub disk_free_space_weight: f64,
+    /// Limiares das faixas de cada subfator
+    pub thresholds: ScoringThresholds,
+    /// Limites entre as categorias de desempenho
+    pub categories: CategoryBoundaries,
+    /// Idioma das recomendações geradas
+    pub language: Language,
+    /// Discos ignorados na pontuação e nas recomendações
+    pub disk_filter: DiskFilter,
+    /// Ativa o piso de subsistema crítico (veja `critical_floor`)
+    pub critical_floor_enabled: bool,
+    /// Piso de subsistema crítico
+    /// 
+    /// Se a pontuação de qualquer subsistema avaliado (CPU, RAM, cada disco,
+    /// GPU ou bateria) ficar abaixo deste valor, a categoria fica limitada a
+    /// Manutenção, mesmo que a média ponderada indique Precaução ou Bom
+    /// Estado. Assim, um disco do sistema cheio e falhando não é mascarado
+    /// por CPU e RAM perfeitas. A pontuação geral não é alterada.
+    pub critical_floor: f64,
+}
+
+impl Default for ScoringConfig {
+    fn default() -> Self {
+        ScoringConfig {
+            weights: ScoreWeights::default(),
+            gpu_weight: 0.2,
+            battery_weight: 0.2,
+            cpu_cores_weight: 0.4,
+            cpu_usage_weight: 0.4,
+            cpu_frequency_weight: 0.2,
+            ram_usage_weight: 0.5,
+            ram_swap_weight: 0.3,
+            ram_capacity_weight: 0.2,
+            disk_usage_weight: 0.5,
+            disk_type_weight: 0.3,
+            disk_free_space_weight: 0.2,
+            thresholds: ScoringThresholds::default(),
+            categories: CategoryBoundaries::default(),
+            language: Language::default(),
+            disk_filter: DiskFilter::default(),
+            critical_floor_enabled: true,
+            critical_floor: 2.0,
+        }
+    }
+}
+
+impl ScoringConfig {
+    /// Carrega a configuração de um arquivo TOML ou JSON
+    /// 
+    /// O formato é escolhido pela extensão: `.json` é lido como JSON e
+    /// qualquer outra extensão como TOML. Campos ausentes mantêm os valores
+    /// padrão, e a configuração resultante é validada.
+    /// 
+    /// # Erros
+    /// - `DiagnosticError::Io` se o arquivo não puder ser lido
+    /// - `DiagnosticError::InvalidConfig` se o conteúdo for inválido
+    /// 
+    /// # Exemplo
+    /// ```no_run
+    /// use hardware_diagnostic::ScoringConfig;
+    /// 
+    /// let config = ScoringConfig::from_file("diagnostic.toml").unwrap();
+    /// println!("RAM: {:?}", config.thresholds.ram_usage);
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn from_file(path: impl AsRef<std::path::Path>) -> Result<Self, DiagnosticError> {
+        let config: ScoringConfig = read_config_file(path.as_ref())?;
+        config.validate()?;
+        Ok(config)
+    }
+    
+    /// Serializa a configuração em TOML, no formato aceito por [`ScoringConfig::from_file`]
+    #[cfg(feature = "serde")]
+    pub fn to_toml(&self) -> Result<String, DiagnosticError> {
+        toml::to_string(self).map_err(|e| DiagnosticError::InvalidConfig(e.to_string()))
+    }
+    
+    /// Verifica se cada grupo de pesos é não negativo e soma 1.0 e se os
+    /// limiares estão em ordem crescente
+    /// 
+    /// # Erros
+    /// Retorna `DiagnosticError::InvalidConfig` indicando o grupo inválido.
+    pub fn validate(&self) -> Result<(), DiagnosticError> {
+        self.weights.validate()?;
+        
+        let groups = [
+            ("CPU", [self.cpu_cores_weight, self.cpu_usage_weight, self.cpu_frequency_weight]),
+            ("RAM", [self.ram_usage_weight, self.ram_swap_weight, self.ram_capacity_weight]),
+            ("discos", [self.disk_usage_weight, self.disk_type_weight, self.disk_free_space_weight]),
+        ];
+        
+        if [self.gpu_weight, self.battery_weight].iter().any(|w| !w.is_finite() || *w < 0.0) {
+            return Err(DiagnosticError::InvalidConfig(
+                "pesos da GPU e da bateria devem ser números finitos não negativos".to_string(),
+            ));
         }
-        if disk.disk_type.contains("HDD") && overall_score < 7.0 {
-            recommendations.push(format!("🟡 DISCO {}: HDD pode estar limitando performance", 
-                disk.name));
+        
+        for (name, weights) in groups {
+            validate_weight_group(name, &weights)?;
         }
-        if disk.available_space as f64 / 1_000_000_000.0 < 10.0 {
-            recommendations.push(format!("🔴 DISCO {}: Menos de 10GB livres", disk.name));
+        
+        if !(0.0..=10.0).contains(&self.critical_floor) {
+            return Err(DiagnosticError::InvalidConfig(
+                "piso de subsistema crítico deve estar entre 0 e 10".to_string(),
+            ));
         }
+        
+        self.thresholds.validate()?;
+        self.categories.validate()
+    }
+}
+
+/// Configuração completa do diagnóstico: pontuação e alertas em um só arquivo
+/// 
+/// Os campos de [`ScoringConfig`] ficam no nível principal do arquivo, de
+/// modo que configurações antigas continuam válidas; os limiares de alerta
+/// ficam na tabela `[alerts]`. Campos ausentes mantêm os valores padrão, que
+/// reproduzem os limiares históricos do diagnóstico.
+/// 
+/// Os limiares mais ajustados também têm campos próprios
+/// (`cpu_usage_excellent_threshold`, `cpu_cores_high`,
+/// `ram_usage_excellent_threshold` e `disk_free_excellent_gb`). Quando
+/// definidos, substituem o valor correspondente de `[thresholds]`; veja
+/// [`DiagnosticConfig::thresholds`].
+/// 
+/// ```toml
+/// cpu_usage_excellent_threshold = 25.0
+/// disk_free_excellent_gb = 200.0
+/// 
+/// [weights]
+/// cpu = 0.5
+/// ram = 0.3
+/// disk = 0.2
+/// 
+/// [thresholds]
+/// ram_usage = [50.0, 65.0, 80.0]
+/// 
+/// [alerts]
+/// min_score = 6.0
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct DiagnosticConfig {
+    /// Uso da CPU (%) abaixo do qual a faixa é excelente
+    /// 
+    /// Substitui o primeiro valor de `thresholds.cpu_usage`.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub cpu_usage_excellent_threshold: Option<f32>,
+    /// Núcleos lógicos acima dos quais a CPU recebe a nota máxima
+    /// 
+    /// Substitui o último valor de `thresholds.cpu_cores`.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub cpu_cores_high: Option<usize>,
+    /// Uso da RAM (%) abaixo do qual a faixa é excelente
+    /// 
+    /// Substitui o primeiro valor de `thresholds.ram_usage`.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub ram_usage_excellent_threshold: Option<f64>,
+    /// Espaço livre, em GiB, a partir do qual a faixa do disco é a melhor
+    /// 
+    /// Substitui o último valor de `thresholds.disk_free_space_gib`.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub disk_free_excellent_gb: Option<f64>,
+    /// Pesos, limiares e categorias da pontuação
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub scoring: ScoringConfig,
+    /// Limiares usados por [`AlertManager::check`](alerts::AlertManager::check)
+    #[cfg_attr(feature = "serde", serde(rename = "alerts"))]
+    pub alert_thresholds: alerts::AlertConfig,
+}
+
+impl From<ScoringConfig> for DiagnosticConfig {
+    /// Configuração com a pontuação indicada e os alertas padrão
+    fn from(scoring: ScoringConfig) -> Self {
+        DiagnosticConfig { scoring, ..DiagnosticConfig::default() }
+    }
+}
+
+impl DiagnosticConfig {
+    /// Carrega a configuração de um arquivo TOML
+    /// 
+    /// Arquivos com extensão `.json` são lidos como JSON, como em
+    /// [`ScoringConfig::from_file`]. A configuração resultante é validada.
+    /// 
+    /// # Erros
+    /// - `DiagnosticError::Io` se o arquivo não puder ser lido
+    /// - `DiagnosticError::InvalidConfig` se o conteúdo for inválido
+    /// 
+    /// # Exemplo
+    /// ```no_run
+    /// use hardware_diagnostic::DiagnosticConfig;
+    /// use std::path::Path;
+    /// 
+    /// let config = DiagnosticConfig::from_toml_file(Path::new("diagnostic.toml")).unwrap();
+    /// println!("Pontuação mínima: {}", config.alert_thresholds.min_score);
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn from_toml_file(path: &std::path::Path) -> Result<Self, DiagnosticError> {
+        let config: DiagnosticConfig = read_config_file(path)?;
+        config.validate()?;
+        Ok(config)
+    }
+    
+    /// Carrega o arquivo sobre `base`: campos ausentes no arquivo mantêm os valores de `base`
+    /// 
+    /// Permite partir de um [`ScoreProfile`] e ajustar só parte dele no
+    /// arquivo (ex: os limiares, mantendo os pesos do perfil). A configuração
+    /// resultante é validada.
+    /// 
+    /// # Erros
+    /// Os mesmos de [`DiagnosticConfig::from_toml_file`].
+    /// 
+    /// # Exemplo
+    /// ```no_run
+    /// use hardware_diagnostic::{DiagnosticConfig, ScoreProfile};
+    /// use std::path::Path;
+    /// 
+    /// let mut base = DiagnosticConfig::default();
+    /// ScoreProfile::Server.apply(&mut base.scoring);
+    /// let config = DiagnosticConfig::from_toml_file_over(Path::new("limites.toml"), &base).unwrap();
+    /// println!("Peso da RAM: {}", config.scoring.weights.ram());
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn from_toml_file_over(path: &std::path::Path, base: &DiagnosticConfig) -> Result<Self, DiagnosticError> {
+        let config: DiagnosticConfig = read_config_file_over(path, base)?;
+        config.validate()?;
+        Ok(config)
+    }
+    
+    /// Serializa a configuração em TOML, no formato aceito por [`DiagnosticConfig::from_toml_file`]
+    #[cfg(feature = "serde")]
+    pub fn to_toml(&self) -> Result<String, DiagnosticError> {
+        toml::to_string(self).map_err(|e| DiagnosticError::InvalidConfig(e.to_string()))
     }
     
-    // Recomendação final baseada na categoria
-    match determine_category(overall_score) {
-        PerformanceCategory::Descarte => {
-            recommendations.push("📋 Ação recomendada: Substituir equipamento".to_string());
+    /// Limiares efetivos da pontuação: os de `scoring.thresholds`, com os
+    /// campos explícitos aplicados por cima
+    /// 
+    /// # Exemplo
+    /// ```
+    /// use hardware_diagnostic::DiagnosticConfig;
+    /// 
+    /// let config = DiagnosticConfig {
+    ///     cpu_usage_excellent_threshold: Some(20.0),
+    ///     ..DiagnosticConfig::default()
+    /// };
+    /// assert_eq!(config.thresholds().cpu_usage, [20.0, 60.0, 85.0]);
+    /// ```
+    pub fn thresholds(&self) -> ScoringThresholds {
+        let mut thresholds = self.scoring.thresholds.clone();
+        if let Some(usage) = self.cpu_usage_excellent_threshold {
+            thresholds.cpu_usage[0] = usage;
         }
-        PerformanceCategory::Manutencao => {
-            recommendations.push("📋 Ação recomendada: Manutenção técnica urgente".to_string());
+        if let Some(cores) = self.cpu_cores_high {
+            thresholds.cpu_cores[3] = cores;
         }
-        PerformanceCategory::Precaução => {
-            recommendations.push("📋 Ação recomendada: Monitoramento contínuo".to_string());
+        if let Some(usage) = self.ram_usage_excellent_threshold {
+            thresholds.ram_usage[0] = usage;
         }
-        PerformanceCategory::BomEstado => {
-            recommendations.push("📋 Ação recomendada: Manutenção preventiva regular".to_string());
+        if let Some(free) = self.disk_free_excellent_gb {
+            thresholds.disk_free_space_gib[3] = free;
         }
+        thresholds
     }
     
-    recommendations
+    /// Configuração de pontuação com os [limiares efetivos](DiagnosticConfig::thresholds)
+    /// 
+    /// É a configuração a passar às funções que recebem um [`ScoringConfig`],
+    /// como [`calculate_performance_score_with_config`].
+    pub fn scoring_config(&self) -> ScoringConfig {
+        ScoringConfig {
+            thresholds: self.thresholds(),
+            ..self.scoring.clone()
+        }
+    }
+    
+    /// Valida a pontuação, já com os limiares efetivos, e os limiares de alerta
+    /// 
+    /// # Erros
+    /// Retorna `DiagnosticError::InvalidConfig` indicando o valor inválido.
+    pub fn validate(&self) -> Result<(), DiagnosticError> {
+        self.scoring_config().validate()?;
+        self.alert_thresholds.validate()
+    }
 }
 
-/// Exibe a pontuação de forma formatada
-pub fn display_performance_score(score: &PerformanceScore) -> String {
-    let mut output = String::new();
+/// Lê um arquivo de configuração TOML ou JSON, escolhido pela extensão
+/// 
+/// `.json` é lido como JSON e qualquer outra extensão como TOML. Não valida
+/// o conteúdo; cada tipo de configuração faz a própria validação.
+#[cfg(feature = "serde")]
+pub(crate) fn read_config_file<T: serde::de::DeserializeOwned>(path: &std::path::Path) -> Result<T, DiagnosticError> {
+    let contents = fs::read_to_string(path).map_err(|e| DiagnosticError::Io {
+        path: path.display().to_string(),
+        source: e.to_string(),
+    })?;
     
-    output.push_str(&format!("{}\n", "=".repeat(60)));
-    output.push_str("           📊 PONTUAÇÃO DE DESEMPENHO DA MÁQUINA           \n");
-    output.push_str(&format!("{}\n\n", "=".repeat(60)));
+    let is_json = path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("json"));
+    if is_json {
+        serde_json::from_str(&contents).map_err(|e| DiagnosticError::InvalidConfig(e.to_string()))
+    } else {
+        toml::from_str(&contents).map_err(|e| DiagnosticError::InvalidConfig(e.to_string()))
+    }
+}
+
+/// Lê um arquivo de configuração sobre `base`, como [`read_config_file`]
+/// 
+/// `base` e o arquivo são combinados campo a campo (tabelas aninhadas
+/// inclusive), prevalecendo o arquivo.
+#[cfg(feature = "serde")]
+fn read_config_file_over<T>(path: &std::path::Path, base: &T) -> Result<T, DiagnosticError>
+where
+    T: serde::Serialize + serde::de::DeserializeOwned,
+{
+    fn merge(base: &mut serde_json::Value, overrides: serde_json::Value) {
+        match (base, overrides) {
+            (serde_json::Value::Object(base), serde_json::Value::Object(overrides)) => {
+                for (key, value) in overrides {
+                    merge(base.entry(key).or_insert(serde_json::Value::Null), value);
+                }
+            }
+            (base, overrides) => *base = overrides,
+        }
+    }
     
-    // Barra de pontuação visual
-    let bar_width = 40;
-    let filled = ((score.overall_score / 10.0) * bar_width as f64).round() as usize;
-    let empty = bar_width - filled;
+    let invalid = |e: serde_json::Error| DiagnosticError::InvalidConfig(e.to_string());
+    let mut merged = serde_json::to_value(base).map_err(invalid)?;
+    merge(&mut merged, read_config_file(path)?);
+    serde_json::from_value(merged).map_err(invalid)
+}
+
+/// Coleta informações detalhadas da CPU
+/// 
+/// Em caso de falha na consulta, retorna `CpuInfo::default()`.
+/// Use [`cpu_info_checked`] para tratar o erro explicitamente.
+/// 
+/// # Retorno
+/// Retorna uma instância de `CpuInfo` com:
+/// - Número de CPUs/cores lógicos
+/// - Percentual de uso atual
+/// - Frequência em MHz
+/// - Nome do modelo
+/// - Contagem de núcleos físicos
+/// 
+/// # Exemplo
+/// ```
+/// use hardware_diagnostic::cpu_info;
+/// 
+/// let cpu_info = cpu_info();
+/// println!("CPU: {}", cpu_info.name);
+/// println!("Uso: {:.1}%", cpu_info.cpu_usage);
+/// ```
+pub fn cpu_info() -> CpuInfo {
+    cpu_info_with_interval(DEFAULT_CPU_SAMPLE_INTERVAL)
+}
+
+/// Coleta informações da CPU com um intervalo de amostragem personalizado
+/// 
+/// O uso da CPU é calculado a partir de duas leituras separadas por
+/// `sample_interval`. Intervalos menores respondem mais rápido, intervalos
+/// maiores produzem leituras mais estáveis.
+/// 
+/// **Atenção:** intervalos abaixo de ~100ms produzem percentuais de uso
+/// pouco confiáveis no Windows.
+/// 
+/// # Argumentos
+/// * `sample_interval` - Tempo de espera entre as duas leituras
+/// 
+/// # Exemplo
+/// ```
+/// use std::time::Duration;
+/// use hardware_diagnostic::engine::cpu_info_with_interval;
+/// 
+/// let cpu = cpu_info_with_interval(Duration::from_millis(200));
+/// println!("Uso: {:.1}%", cpu.cpu_usage);
+/// ```
+pub fn cpu_info_with_interval(sample_interval: Duration) -> CpuInfo {
+    sample_cpu(sample_interval).unwrap_or_default()
+}
+
+/// Coleta informações detalhadas da CPU, propagando falhas
+/// 
+/// # Erros
+/// Retorna `DiagnosticError::CpuQueryFailed` se nenhuma CPU for detectada.
+/// 
+/// # Exemplo
+/// ```
+/// use hardware_diagnostic::cpu_info_checked;
+/// 
+/// match cpu_info_checked() {
+///     Ok(cpu) => println!("CPU: {}", cpu.name),
+///     Err(e) => eprintln!("Erro: {}", e),
+/// }
+/// ```
+pub fn cpu_info_checked() -> Result<CpuInfo, DiagnosticError> {
+    sample_cpu(DEFAULT_CPU_SAMPLE_INTERVAL)
+}
+
+/// Lê as informações da CPU de um `System` mantido por quem chama
+/// 
+/// Não cria outro `System`, não aguarda a amostragem nem consulta sensores:
+/// o uso da CPU é o calculado pelo `sysinfo` entre as duas últimas chamadas
+/// de `refresh_cpu`, e fica zerado se houve apenas uma. O throttling
+/// térmico é estimado a partir de `temperatures` (ex: uma leitura de
+/// [`temperature_info`] feita no ritmo de quem chama; vazio, não é
+/// detectado). O throttling informado pelo sistema fica `None`; o
+/// [`SystemContext`] o consulta a cada `refresh_cpu`. Em caso de falha,
+/// retorna os valores padrão.
+/// 
+/// # Exemplo
+/// ```
+/// use hardware_diagnostic::{cpu_info_from, temperature_info};
+/// use hardware_diagnostic::sysinfo::System;
+/// 
+/// let mut sys = System::new();
+/// sys.refresh_cpu();
+/// let temperatures = temperature_info();
+/// // ... o programa atualiza `sys` no seu próprio ritmo ...
+/// sys.refresh_cpu();
+/// println!("Uso: {:.1}%", cpu_info_from(&sys, &temperatures).cpu_usage);
+/// ```
+pub fn cpu_info_from(sys: &System, temperatures: &[TemperatureReading]) -> CpuInfo {
+    read_cpu(sys, None, temperatures).unwrap_or_default()
+}
+
+/// Lê as informações da CPU aguardando `sample_interval` entre as leituras
+fn sample_cpu(sample_interval: Duration) -> Result<CpuInfo, DiagnosticError> {
+    debug!("coletando informações da CPU (amostragem de {:?})", sample_interval);
+    let mut ctx = SystemContext::with_sample_interval(sample_interval);
+    ctx.refresh_cpu();
+    ctx.cpu_info_checked()
+}
+
+/// Extrai as informações da CPU de um `System` já atualizado
+/// 
+/// `temperatures` é a leitura dos sensores feita por quem chama, reaproveitada
+/// também na pontuação.
+fn read_cpu(sys: &System, throttling: Option<bool>, temperatures: &[TemperatureReading]) -> Result<CpuInfo, DiagnosticError> {
+    // Obtém informações dos CPUs
+    let cpus = sys.cpus();
     
-    output.push_str(&format!("PONTUAÇÃO GERAL: {:.1}/10.0\n", score.overall_score));
-    output.push_str(&format!("[{}{}]\n\n", "█".repeat(filled), "░".repeat(empty)));
+    // Sem CPUs não há como calcular uso, nome ou frequência
+    let first_cpu = match cpus.first() {
+        Some(cpu) => cpu,
+        None => {
+            warn!("sysinfo não retornou nenhuma CPU; usando valores padrão");
+            return Err(DiagnosticError::CpuQueryFailed(
+                "nenhuma CPU detectada".to_string(),
+            ))
+        }
+    };
     
-    // Categoria com cor (opcional)
-    output.push_str(&format!("CATEGORIA: {}{}{}\n\n", 
-        score.category.color_code(),
-        score.category.description(),
-        PerformanceCategory::reset_color()
-    ));
+    // Uso e frequência individuais de cada core
+    let per_core_usage: Vec<f32> = cpus.iter().map(|cpu| cpu.cpu_usage()).collect();
+    let per_core_frequency: Vec<u64> = cpus.iter().map(|cpu| cpu.frequency()).collect();
+    debug!(
+        "sysinfo: {} CPUs ({:?}), físicos {:?}, uso {:?}, frequência {:?} MHz",
+        cpus.len(),
+        first_cpu.brand(),
+        sys.physical_core_count(),
+        per_core_usage,
+        per_core_frequency
+    );
     
-    // Pontuações detalhadas
-    output.push_str("PONTUAÇÕES DETALHADAS:\n");
-    output.push_str(&format!("  • CPU:      {:.1}/10.0\n", score.cpu_score));
-    output.push_str(&format!("  • RAM:      {:.1}/10.0\n", score.ram_score));
-    output.push_str(&format!("  • Discos:   {:.1}/10.0\n\n", score.disk_score));
+    // Calcula uso médio de todos os cores
+    let total_usage: f32 = per_core_usage.iter().sum();
+    let avg_usage = total_usage / cpus.len() as f32;
     
-    // Legenda das categorias
-    output.push_str("LEGENDA DAS CATEGORIAS:\n");
-    output.push_str("  1-2  → DESCARTE/UPGRADE COMPLETO\n");
-    output.push_str("  3-4  → MANUTENÇÃO URGENTE\n");
-    output.push_str("  5-6  → USO COM PRECAUÇÃO\n");
-    output.push_str("  7-10 → BOM ESTADO DE USO\n\n");
+    // Frequência média, comparada à base para detectar throttling
+    let avg_frequency = per_core_frequency.iter().sum::<u64>() / cpus.len() as u64;
+    let thermal_throttling_detected =
+        detect_thermal_throttling(temperatures, avg_frequency, base_cpu_frequency());
     
-    // Recomendações
-    if !score.recommendations.is_empty() {
-        output.push_str("RECOMENDAÇÕES:\n");
-        for (i, rec) in score.recommendations.iter().enumerate() {
-            output.push_str(&format!("  {}. {}\n", i + 1, rec));
+    Ok(CpuInfo {
+        number_cpus: cpus.len(),
+        cpu_usage: avg_usage,
+        frequency: first_cpu.frequency(),
+        name: first_cpu.brand().to_string(),
+        physical_cores: sys.physical_core_count(),
+        per_core_usage,
+        per_core_frequency,
+        thermal_throttling_detected,
+        throttling,
+        vulnerabilities: cpu_vulnerabilities(),
+    })
+}
+
+/// Estado das mitigações de vulnerabilidades da CPU
+/// 
+/// No Linux lê `/sys/devices/system/cpu/vulnerabilities`; no Windows,
+/// `Get-SpeculationControlSettings` via PowerShell (módulo
+/// SpeculationControl). O resultado não muda durante a execução e é
+/// consultado uma só vez. Retorna `None` quando a informação não está
+/// disponível.
+fn cpu_vulnerabilities() -> Option<Vec<VulnerabilityStatus>> {
+    static VULNERABILITIES: std::sync::OnceLock<Option<Vec<VulnerabilityStatus>>> = std::sync::OnceLock::new();
+    
+    VULNERABILITIES
+        .get_or_init(|| {
+            #[cfg(target_os = "linux")]
+            return read_sysfs_vulnerabilities(std::path::Path::new("/sys/devices/system/cpu/vulnerabilities"));
+            
+            #[cfg(target_os = "windows")]
+            return std::process::Command::new("powershell")
+                .args(["-NoProfile", "-Command", "Get-SpeculationControlSettings -Quiet | Format-List"])
+                .output()
+                .ok()
+                .and_then(|output| parse_speculation_control(&String::from_utf8_lossy(&output.stdout)));
+            
+            #[cfg(not(any(target_os = "linux", target_os = "windows")))]
+            None
+        })
+        .clone()
+}
+
+/// Lê um arquivo por vulnerabilidade do diretório `vulnerabilities` do sysfs
+/// 
+/// Conteúdos como "Not affected" ou "Mitigation: PTI" contam como
+/// mitigados; os que começam com "Vulnerable", não.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn read_sysfs_vulnerabilities(dir: &std::path::Path) -> Option<Vec<VulnerabilityStatus>> {
+    let mut vulnerabilities: Vec<VulnerabilityStatus> = fs::read_dir(dir)
+        .ok()?
+        .flatten()
+        .filter_map(|entry| {
+            let description = fs::read_to_string(entry.path()).ok()?.trim().to_string();
+            Some(VulnerabilityStatus {
+                name: entry.file_name().to_string_lossy().to_string(),
+                mitigated: !description.starts_with("Vulnerable"),
+                description,
+            })
+        })
+        .collect();
+    vulnerabilities.sort_by(|a, b| a.name.cmp(&b.name));
+    
+    (!vulnerabilities.is_empty()).then_some(vulnerabilities)
+}
+
+/// Interpreta a saída `Format-List` de `Get-SpeculationControlSettings`
+/// 
+/// Cada vulnerabilidade é considerada mitigada quando o hardware não é
+/// afetado ou o suporte do Windows está ativo. Retorna `None` se a saída não
+/// contém nenhum campo conhecido (ex: módulo não instalado).
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+fn parse_speculation_control(output: &str) -> Option<Vec<VulnerabilityStatus>> {
+    let fields: HashMap<&str, bool> = output
+        .lines()
+        .filter_map(|line| line.split_once(':'))
+        .filter_map(|(key, value)| match value.trim() {
+            "True" => Some((key.trim(), true)),
+            "False" => Some((key.trim(), false)),
+            _ => None,
+        })
+        .collect();
+    
+    // (nome, campo que indica se a CPU é afetada, campo da mitigação ativa);
+    // toda CPU é afetada pelo Spectre v2, que depende apenas da mitigação
+    let checks = [
+        ("spectre_v2", None, "BTIWindowsSupportEnabled"),
+        ("meltdown", Some("KVAShadowRequired"), "KVAShadowWindowsSupportEnabled"),
+        ("l1tf", Some("L1TFRequired"), "L1TFWindowsSupportEnabled"),
+        ("mds", Some("MDSHardwareVulnerable"), "MDSWindowsSupportEnabled"),
+    ];
+    
+    let vulnerabilities: Vec<VulnerabilityStatus> = checks
+        .iter()
+        .filter_map(|(name, affected_key, enabled_key)| {
+            let enabled = fields.get(enabled_key).copied();
+            let affected = match affected_key {
+                Some(key) => *fields.get(key)?,
+                None if enabled.is_some() => true,
+                None => return None,
+            };
+            let enabled = enabled.unwrap_or(false);
+            let (mitigated, description) = match (affected, enabled) {
+                (false, _) => (true, "Not affected"),
+                (true, true) => (true, "Mitigation: Windows support enabled"),
+                (true, false) => (false, "Vulnerable"),
+            };
+            Some(VulnerabilityStatus {
+                name: name.to_string(),
+                mitigated,
+                description: description.to_string(),
+            })
+        })
+        .collect();
+    
+    (!vulnerabilities.is_empty()).then_some(vulnerabilities)
+}
+
+/// Frequência base da CPU em MHz, quando exposta pelo sistema
+/// 
+/// No Linux usa `cpufreq/base_frequency`, exposto pelo driver
+/// `intel_pstate`. `cpuinfo_max_freq` não serve: é a frequência de turbo, e
+/// operar abaixo dela é o normal. Sem a base (ex: `acpi-cpufreq`) e nas
+/// demais plataformas retorna `None`.
+fn base_cpu_frequency() -> Option<u64> {
+    #[cfg(target_os = "linux")]
+    {
+        fs::read_to_string("/sys/devices/system/cpu/cpu0/cpufreq/base_frequency")
+            .ok()
+            .and_then(|value| value.trim().parse::<u64>().ok())
+            .map(|khz| khz / 1000)
+    }
+    
+    #[cfg(not(target_os = "linux"))]
+    None
+}
+
+/// Detecta throttling térmico a partir da temperatura e da frequência
+/// 
+/// A CPU é considerada em throttling quando algum sensor de CPU está
+/// acima de 90°C (ou a menos de 5°C do limite crítico, se este for menor) e
+/// a frequência média está abaixo de 90% da frequência base. Sem frequência
+/// base, vale só a temperatura.
+fn detect_thermal_throttling(
+    temperatures: &[TemperatureReading],
+    avg_frequency: u64,
+    base_frequency: Option<u64>,
+) -> bool {
+    let overheating = temperatures
+        .iter()
+        .filter(|reading| reading.component == ThermalComponent::Cpu)
+        .any(|reading| {
+            let limit = reading
+                .critical_threshold
+                .map_or(CPU_CRITICAL_TEMPERATURE_CELSIUS, |critical| (critical - 5.0).min(CPU_CRITICAL_TEMPERATURE_CELSIUS));
+            reading.temperature_celsius > limit
+        });
+    
+    match base_frequency {
+        Some(base) if base > 0 => overheating && (avg_frequency as f64) < base as f64 * 0.9,
+        _ => overheating,
+    }
+}
+
+/// Consulta se o sistema está limitando o desempenho da CPU agora
+/// 
+/// No Windows (feature `wmi`) lê o contador `% Performance Limit` do
+/// `Win32_PerfFormattedData_Counters_ProcessorInformation`: abaixo de 90%,
+/// a CPU está sendo limitada por temperatura, energia ou firmware. A
+/// conexão WMI é aberta uma vez por thread e reaproveitada. Nas demais
+/// plataformas retorna `None`.
+#[cfg(all(target_os = "windows", feature = "wmi"))]
+fn cpu_throttling() -> Option<bool> {
+    use serde::Deserialize;
+    use wmi::{COMLibrary, WMIConnection};
+    
+    #[derive(Deserialize)]
+    #[serde(rename = "Win32_PerfFormattedData_Counters_ProcessorInformation")]
+    #[serde(rename_all = "PascalCase")]
+    struct ProcessorInformation {
+        name: String,
+        percent_performance_limit: Option<u32>,
+    }
+    
+    thread_local! {
+        // Abrir o COM e a conexão a cada leitura pesaria nos ciclos do --watch
+        static CONNECTION: Option<WMIConnection> = match COMLibrary::new().and_then(WMIConnection::new) {
+            Ok(connection) => Some(connection),
+            Err(e) => {
+                log::error!("conexão WMI falhou ({}); estado de throttling indisponível", e);
+                None
+            }
+        };
+    }
+    
+    let counters: Vec<ProcessorInformation> = CONNECTION.with(|connection| match connection.as_ref()?.query() {
+        Ok(counters) => Some(counters),
+        Err(e) => {
+            log::error!("consulta WMI de ProcessorInformation falhou: {}", e);
+            None
         }
+    })?;
+    
+    // A instância "_Total" resume todos os processadores
+    let limit = counters
+        .iter()
+        .find(|counter| counter.name == "_Total")?
+        .percent_performance_limit?;
+    debug!("WMI: limite de desempenho da CPU {}%", limit);
+    Some(performance_limit_throttling(limit))
+}
+
+/// Indica throttling a partir do `% Performance Limit` do Windows
+#[cfg_attr(not(all(target_os = "windows", feature = "wmi")), allow(dead_code))]
+fn performance_limit_throttling(limit_percent: u32) -> bool {
+    limit_percent < THROTTLING_PERFORMANCE_LIMIT_PERCENT
+}
+
+/// Sem WMI, o sistema não informa o estado de throttling
+#[cfg(not(all(target_os = "windows", feature = "wmi")))]
+fn cpu_throttling() -> Option<bool> {
+    None
+}
+
+/// Coleta informações detalhadas da memória RAM e SWAP
+/// 
+/// Em caso de falha na consulta, retorna `RamInfo::default()`.
+/// Use [`ram_info_checked`] para tratar o erro explicitamente.
+/// 
+/// # Retorno
+/// Retorna uma instância de `RamInfo` com:
+/// - Totais e usos de RAM e SWAP em bytes
+/// - Percentuais de uso calculados
+/// 
+/// # Exemplo
+/// ```
+/// use hardware_diagnostic::ram_info;
+/// use hardware_diagnostic::engine::utils::bytes_to_gib;
+/// 
+/// let ram_info = ram_info();
+/// println!("RAM: {} GiB / {} GiB", 
+///     bytes_to_gib(ram_info.used_ram),
+///     bytes_to_gib(ram_info.total_ram)
+/// );
+/// ```
+pub fn ram_info() -> RamInfo {
+    ram_info_checked().unwrap_or_default()
+}
+
+/// Coleta informações da memória RAM e SWAP, propagando falhas
+/// 
+/// # Erros
+/// Retorna `DiagnosticError::MemoryQueryFailed` se a memória total reportada for zero.
+pub fn ram_info_checked() -> Result<RamInfo, DiagnosticError> {
+    debug!("coletando informações de memória");
+    let mut ctx = SystemContext::new();
+    ctx.refresh_memory();
+    read_ram(&ctx.sys)
+}
+
+/// Lê as informações de memória de um `System` mantido por quem chama
+/// 
+/// Não cria outro `System`; os valores são os da última chamada de
+/// `refresh_memory`. Em caso de falha, retorna os valores padrão.
+pub fn ram_info_from(sys: &System) -> RamInfo {
+    read_ram(sys).unwrap_or_default()
+}
+
+/// Extrai as informações de memória de um `System` já atualizado
+fn read_ram(sys: &System) -> Result<RamInfo, DiagnosticError> {
+    let total_ram = sys.total_memory();
+    let used_ram = sys.used_memory();
+    let free_ram = sys.free_memory();
+    let total_swap = sys.total_swap();
+    let used_swap = sys.used_swap();
+    debug!(
+        "sysinfo: RAM total {} B, usada {} B, livre {} B; SWAP total {} B, usado {} B",
+        total_ram, used_ram, free_ram, total_swap, used_swap
+    );
+    
+    // Uma máquina sem RAM indica que a consulta não retornou dados
+    if total_ram == 0 {
+        warn!("sysinfo reportou memória total zero; usando valores padrão");
+        return Err(DiagnosticError::MemoryQueryFailed(
+            "memória total reportada como zero".to_string(),
+        ));
+    }
+    
+    // Calcula percentuais de uso
+    let ram_usage_percent = (used_ram as f64 / total_ram as f64) * 100.0;
+    
+    let swap_usage_percent = if total_swap > 0 {
+        (used_swap as f64 / total_swap as f64) * 100.0
+    } else {
+        0.0
+    };
+    
+    let layout = memory::cached_memory_layout();
+    Ok(RamInfo {
+        total_ram,
+        used_ram,
+        free_ram,
+        total_swap,
+        used_swap,
+        ram_usage_percent,
+        swap_usage_percent,
+        memory_type: layout.memory_type().map(str::to_string),
+        speed_mhz: layout.speed_mhz(),
+        slots_used: (!layout.modules.is_empty()).then_some(layout.modules.len()),
+        slots_total: layout.total_slots,
+    })
+}
+
+/// Coleta informações de todos os discos do sistema
+/// 
+/// Em caso de falha na consulta, retorna um vetor vazio.
+/// Use [`disk_info_checked`] para tratar o erro explicitamente.
+/// 
+/// # Retorno
+/// Retorna um vetor contendo `DiskInfo` para cada disco encontrado
+/// 
+/// # Exemplo
+/// ```
+/// use hardware_diagnostic::disk_info;
+/// use hardware_diagnostic::engine::utils::bytes_to_gib;
+/// 
+/// let disks = disk_info();
+/// for disk in disks {
+///     println!("Disco {}: {} GiB livre", 
+///         disk.name, 
+///         bytes_to_gib(disk.available_space)
+///     );
+/// }
+/// ```
+pub fn disk_info() -> Vec<DiskInfo> {
+    disk_info_checked().unwrap_or_default()
+}
+
+/// Coleta informações dos discos que passam pelo filtro
+/// 
+/// Útil para ignorar imagens montadas, discos em RAM e mídias removíveis.
+/// Em caso de falha na consulta, retorna uma lista vazia.
+pub fn disk_info_filtered(filter: &DiskFilter) -> Vec<DiskInfo> {
+    filter.apply(&disk_info())
+}
+
+/// Coleta informações de todos os discos do sistema, propagando falhas
+/// 
+/// # Erros
+/// Retorna `DiagnosticError::DiskQueryFailed` se nenhum disco for encontrado.
+pub fn disk_info_checked() -> Result<Vec<DiskInfo>, DiagnosticError> {
+    debug!("coletando informações dos discos");
+    let mut ctx = SystemContext::new();
+    ctx.refresh_disks();
+    read_disks(&ctx.disks, &ctx.disk_io_rates, &ctx.smart)
+}
+
+/// Lê as informações dos discos de uma lista mantida por quem chama
+/// 
+/// Não aguarda a amostragem de E/S nem consulta o SMART: as taxas de E/S
+/// ficam zeradas e `smart` fica `None`. Para esses dados, use um
+/// [`SystemContext`]. Em caso de falha, retorna uma lista vazia.
+/// 
+/// # Exemplo
+/// ```
+/// use hardware_diagnostic::disk_info_from;
+/// use hardware_diagnostic::sysinfo::Disks;
+/// 
+/// let disks = Disks::new_with_refreshed_list();
+/// for disk in disk_info_from(&disks) {
+///     println!("{}: {:.1}% usado", disk.mount_point, disk.usage_percent);
+/// }
+/// ```
+pub fn disk_info_from(disks: &Disks) -> Vec<DiskInfo> {
+    read_disks(disks, &HashMap::new(), &HashMap::new()).unwrap_or_default()
+}
+
+/// Extrai as informações de discos de uma lista já atualizada
+/// 
+/// As taxas de E/S são associadas pelo nome do dispositivo no kernel (ex:
+/// "sda1" para "/dev/sda1", "dm-0" para "/dev/mapper/raiz"); discos sem
+/// estatísticas ficam com E/S zerada.
+/// Os dados SMART são associados pelo volume (ex: "C:" para "C:\").
+fn read_disks(
+    disks: &Disks,
+    io_rates: &HashMap<String, DiskIoRates>,
+    smart: &HashMap<String, SmartInfo>,
+) -> Result<Vec<DiskInfo>, DiagnosticError> {
+    if disks.list().is_empty() {
+        warn!("sysinfo não retornou nenhum disco; usando lista vazia");
+        return Err(DiagnosticError::DiskQueryFailed {
+            mount_point: String::new(),
+            source: "nenhum disco encontrado".to_string(),
+        });
+    }
+    
+    let read_only_mounts = read_only_mount_points();
+    Ok(disks
+        .iter()
+        .map(|disk| read_disk(disk, io_rates, smart, &read_only_mounts))
+        .collect())
+}
+
+/// Coleta as informações de um único disco pelo ponto de montagem
+/// 
+/// Apenas a lista de montagens é consultada para localizar o disco; as
+/// estatísticas de E/S e os dados SMART são lidos somente se ele existir,
+/// e o SMART é consultado apenas para esse disco.
+/// No Windows a unidade pode ser informada com ou sem a barra final
+/// (`"C:"` ou `"C:\\"`), sem diferenciar maiúsculas.
+/// 
+/// # Retorno
+/// `None` se nenhum disco estiver montado em `mount_point`.
+/// 
+/// # Exemplo
+/// ```
+/// use hardware_diagnostic::disk_info_for;
+/// 
+/// if let Some(disk) = disk_info_for("/") {
+///     println!("Disco do sistema: {:.1}% usado", disk.usage_percent);
+/// }
+/// ```
+pub fn disk_info_for(mount_point: &str) -> Option<DiskInfo> {
+    let disks = Disks::new_with_refreshed_list();
+    let disk = disks
+        .iter()
+        .find(|disk| same_mount_point(&disk.mount_point().to_string_lossy(), mount_point))?;
+    
+    let previous = DiskIoSample::now();
+    previous.wait_for_next(DEFAULT_CPU_SAMPLE_INTERVAL);
+    let io_rates = DiskIoSample::now().rates_since(&previous);
+    
+    // SMART só do disco pedido: consultar os demais pode acordá-los
+    let name = disk.name().to_string_lossy();
+    let device = name.rsplit('/').next().unwrap_or_default();
+    let mount = disk.mount_point().to_string_lossy();
+    let smart = smart::query_smart_for(mount.trim_end_matches('\\'), device);
+    
+    Some(read_disk(disk, &io_rates, &smart, &read_only_mount_points()))
+}
+
+/// Compara pontos de montagem ignorando a barra final
+/// 
+/// No Windows a comparação também ignora maiúsculas (`"c:"` é `"C:\\"`).
+fn same_mount_point(a: &str, b: &str) -> bool {
+    fn trim(mount: &str) -> &str {
+        let trimmed = mount.trim_end_matches(['/', '\\']);
+        // A raiz "/" não pode virar uma string vazia
+        if trimmed.is_empty() { mount.get(..1).unwrap_or_default() } else { trimmed }
+    }
+    
+    if cfg!(target_os = "windows") {
+        trim(a).eq_ignore_ascii_case(trim(b))
+    } else {
+        trim(a) == trim(b)
+    }
+}
+
+/// Extrai as informações de um disco da lista do `sysinfo`
+fn read_disk(
+    disk: &sysinfo::Disk,
+    io_rates: &HashMap<String, DiskIoRates>,
+    smart: &HashMap<String, SmartInfo>,
+    read_only_mounts: &HashSet<String>,
+) -> DiskInfo {
+    let total_space = disk.total_space();
+    let available_space = disk.available_space();
+    debug!(
+        "sysinfo: disco {:?} em {:?}, {:?}, total {} B, livre {} B, {:?}",
+        disk.name(),
+        disk.mount_point(),
+        disk.kind(),
+        total_space,
+        available_space,
+        disk.file_system()
+    );
+    let (used_space, usage_percent) = disk_usage(total_space, available_space);
+    
+    // Converte &OsStr para String usando to_string_lossy
+    let file_system = disk.file_system()
+        .to_string_lossy()
+        .to_string();
+    
+    let name = disk.name().to_string_lossy().to_string();
+    let device = name.rsplit('/').next().unwrap_or_default();
+    let io = io_rates.get(&kernel_device_name(&name)).copied().unwrap_or_default();
+    let kind = detect_disk_kind(disk.kind(), &name);
+    let mount_point = disk.mount_point().to_string_lossy().to_string();
+    let volume = mount_point.trim_end_matches('\\');
+    let read_only = read_only_mounts.contains(&mount_point)
+        || READ_ONLY_FILE_SYSTEMS.contains(&file_system.to_lowercase().as_str());
+    let health = smart::smart_for(smart, volume, device).cloned().map(DiskHealth::from);
+    
+    DiskInfo {
+        name,
+        health,
+        mount_point,
+        total_space,
+        available_space,
+        used_space,
+        usage_percent,
+        file_system,
+        kind,
+        read_bytes_per_sec: io.read_bytes_per_sec,
+        write_bytes_per_sec: io.write_bytes_per_sec,
+        read_iops: io.read_iops,
+        write_iops: io.write_iops,
+        io_utilization_percent: io.io_utilization_percent,
+        removable: disk.is_removable(),
+        read_only,
+    }
+}
+
+/// Nome do dispositivo no kernel, como aparece em `/proc/diskstats` e no sysfs
+/// 
+/// No Linux, segue links como `/dev/mapper/raiz`, que aponta para
+/// `/dev/dm-0`; sem o link (ou fora do Linux), usa a última parte do nome.
+fn kernel_device_name(name: &str) -> String {
+    #[cfg(target_os = "linux")]
+    if let Some(device) = fs::canonicalize(name).ok().and_then(|path| path.file_name().map(|n| n.to_os_string())) {
+        return device.to_string_lossy().to_string();
+    }
+    name.rsplit('/').next().unwrap_or_default().to_string()
+}
+
+/// Normaliza o tipo de mídia informado pelo `sysinfo`
+/// 
+/// No Linux, quando o `sysinfo` não identifica o tipo (volumes LVM/device
+/// mapper, alguns discos virtuais), consulta o sysfs diretamente. No macOS,
+/// consulta o `diskutil` com o nome do volume (ex: `/dev/disk0s1`).
+#[cfg_attr(not(any(target_os = "linux", target_os = "macos")), allow(unused_variables))]
+fn detect_disk_kind(kind: sysinfo::DiskKind, name: &str) -> DiskKind {
+    match DiskKind::from(kind) {
+        #[cfg(target_os = "linux")]
+        DiskKind::Unknown => sysfs_disk_kind(std::path::Path::new("/sys/class/block"), &kernel_device_name(name)),
+        #[cfg(target_os = "macos")]
+        DiskKind::Unknown => match std::process::Command::new("diskutil").args(["info", name]).output() {
+            Ok(output) => diskutil_disk_kind(&String::from_utf8_lossy(&output.stdout)),
+            Err(_) => DiskKind::Unknown,
+        },
+        known => known,
+    }
+}
+
+/// Lê o tipo de mídia de `queue/rotational` do dispositivo em `class_block`
+/// 
+/// Partições não têm `queue/`; o valor vem do disco, um nível acima.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn sysfs_disk_kind(class_block: &std::path::Path, device: &str) -> DiskKind {
+    if device.is_empty() {
+        return DiskKind::Unknown;
+    }
+    let Ok(entry) = fs::canonicalize(class_block.join(device)) else {
+        return DiskKind::Unknown;
+    };
+    
+    let rotational = [Some(entry.as_path()), entry.parent()]
+        .into_iter()
+        .flatten()
+        .find_map(|dir| fs::read_to_string(dir.join("queue").join("rotational")).ok());
+    match rotational.as_deref().map(str::trim) {
+        Some("0") => DiskKind::Ssd,
+        Some("1") => DiskKind::Hdd,
+        _ => DiskKind::Unknown,
+    }
+}
+
+/// Lê o tipo de mídia da linha `Solid State:` de `diskutil info`
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+fn diskutil_disk_kind(output: &str) -> DiskKind {
+    let solid_state = output
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("Solid State:"))
+        .map(str::trim);
+    match solid_state {
+        Some("Yes") => DiskKind::Ssd,
+        Some("No") => DiskKind::Hdd,
+        _ => DiskKind::Unknown,
+    }
+}
+
+/// Calcula o espaço usado e o percentual de uso de um disco
+/// 
+/// Sistemas de arquivos de rede ou virtuais podem informar espaço livre
+/// maior que o total; nesse caso o uso é 0, sem underflow.
+fn disk_usage(total_space: u64, available_space: u64) -> (u64, f64) {
+    if available_space > total_space {
+        warn!(
+            "espaço livre ({} B) maior que o total ({} B); considerando uso 0",
+            available_space, total_space
+        );
+    }
+    let used_space = total_space.saturating_sub(available_space);
+    let usage_percent = if total_space > 0 {
+        (used_space as f64 / total_space as f64) * 100.0
+    } else {
+        0.0
+    };
+    
+    (used_space, usage_percent.clamp(0.0, 100.0))
+}
+
+/// Coleta informações de todas as interfaces de rede
+/// 
+/// Interfaces sem tráfego desde a inicialização são retornadas normalmente,
+/// com contadores zerados.
+/// 
+/// # Retorno
+/// Retorna um vetor com um `NetworkInfo` por interface, ordenado por nome
+/// 
+/// # Exemplo
+/// ```
+/// use hardware_diagnostic::network_info;
+/// 
+/// for net in network_info() {
+///     println!("{} ({}): {}", net.name, net.mac_address,
+///         if net.is_up { "ativa" } else { "inativa" });
+/// }
+/// ```
+pub fn network_info() -> Vec<NetworkInfo> {
+    debug!("coletando interfaces de rede");
+    let networks = Networks::new_with_refreshed_list();
+    
+    // O sysinfo não expõe endereços IP; a falha nessa consulta não é fatal
+    let addresses = local_ip_address::list_afinet_netifas().unwrap_or_default();
+    
+    let mut network_list: Vec<NetworkInfo> = networks
+        .iter()
+        .map(|(name, data)| NetworkInfo {
+            name: name.clone(),
+            mac_address: data.mac_address().to_string(),
+            ip_addresses: addresses
+                .iter()
+                .filter(|(iface, _)| iface == name)
+                .map(|(_, ip)| ip.to_string())
+                .collect(),
+            total_received_bytes: data.total_received(),
+            total_transmitted_bytes: data.total_transmitted(),
+            is_up: link_is_up(name, data),
+        })
+        .collect();
+    
+    // A lista do sysinfo não tem ordem definida
+    network_list.sort_by(|a, b| a.name.cmp(&b.name));
+    network_list
+}
+
+/// Coleta informações do sistema operacional
+/// 
+/// Campos que o sistema não informa ficam como "Desconhecido".
+/// 
+/// # Exemplo
+/// ```
+/// use hardware_diagnostic::os_info;
+/// use hardware_diagnostic::engine::utils::format_uptime;
+/// 
+/// let os = os_info();
+/// println!("{} {} ({}) - ligado há {}",
+///     os.os_name, os.os_version, os.hostname, format_uptime(os.uptime_seconds));
+/// ```
+pub fn os_info() -> OsInfo {
+    debug!("coletando informações do sistema operacional");
+    let unknown = || "Desconhecido".to_string();
+    
+    OsInfo {
+        os_name: System::name().unwrap_or_else(unknown),
+        os_version: System::os_version().unwrap_or_else(unknown),
+        kernel_version: System::kernel_version().unwrap_or_else(unknown),
+        hostname: System::host_name().unwrap_or_else(unknown),
+        uptime_seconds: System::uptime(),
+        boot_time: System::boot_time(),
+        platform: platform::current_os().to_string(),
+        virtualization: detect_virtualization(),
+    }
+}
+
+/// Informações do sistema: nome, versão, kernel, hostname e tempo ligado
+/// 
+/// Mesmo tipo de [`OsInfo`], com o nome usado pelo relatório.
+pub type SystemInfo = OsInfo;
+
+/// Coleta nome e versão do sistema, hostname, tempo ligado e inicialização
+/// 
+/// Equivalente a [`os_info()`]; o tempo ligado acima de 30 dias gera uma
+/// recomendação de reinicialização na pontuação.
+/// 
+/// # Exemplo
+/// ```
+/// use hardware_diagnostic::system_info;
+/// 
+/// let info = system_info();
+/// println!("{} ligado há {} s (boot em {})", info.hostname, info.uptime_seconds, info.boot_time);
+/// ```
+pub fn system_info() -> SystemInfo {
+    os_info()
+}
+
+/// Coleta as leituras de todos os sensores de temperatura disponíveis
+/// 
+/// # Retorno
+/// Retorna um vetor de `TemperatureReading`, vazio quando o sistema não
+/// expõe sensores (comum em máquinas virtuais e em alguns Windows).
+/// 
+/// # Exemplo
+/// ```
+/// use hardware_diagnostic::temperature_info;
+/// 
+/// for reading in temperature_info() {
+///     println!("{}: {:.1}°C", reading.label, reading.temperature_celsius);
+/// }
+/// ```
+pub fn temperature_info() -> Vec<TemperatureReading> {
+    debug!("coletando sensores de temperatura");
+    let components = Components::new_with_refreshed_list();
+    
+    components
+        .iter()
+        .filter(|component| !component.temperature().is_nan())
+        .map(|component| TemperatureReading {
+            label: component.label().to_string(),
+            temperature_celsius: component.temperature(),
+            critical_threshold: component.critical(),
+            component: ThermalComponent::from_label(component.label()),
+        })
+        .collect()
+}
+
+/// Verifica se há conectividade de rede básica
+/// 
+/// Considera conectada a máquina com pelo menos uma interface ativa
+/// que possua um endereço IP fora do loopback.
+pub fn has_network_connectivity(networks: &[NetworkInfo]) -> bool {
+    networks.iter().any(|net| {
+        net.is_up
+            && net.ip_addresses.iter().any(|ip| {
+                ip.parse::<std::net::IpAddr>()
+                    .map(|addr| !addr.is_loopback())
+                    .unwrap_or(false)
+            })
+    })
+}
+
+/// Determina se o link de uma interface está ativo
+/// 
+/// No Linux usa `/sys/class/net/<nome>/operstate`. Nas demais plataformas,
+/// onde o estado do link não é exposto, considera ativa a interface com tráfego.
+fn link_is_up(name: &str, data: &NetworkData) -> bool {
+    #[cfg(target_os = "linux")]
+    if let Ok(state) = fs::read_to_string(format!("/sys/class/net/{}/operstate", name)) {
+        // A interface de loopback reporta "unknown" mesmo estando ativa
+        let state = state.trim();
+        return state == "up" || (state == "unknown" && name == "lo");
+    }
+    
+    #[cfg(not(target_os = "linux"))]
+    let _ = name;
+    
+    data.total_received() > 0 || data.total_transmitted() > 0
+}
+
+/// Calcula a pontuação de desempenho da máquina
+/// 
+/// # Retorno
+/// Retorna uma instância de `PerformanceScore` com:
+/// - Pontuações individuais e geral
+/// - Categoria de desempenho
+/// - Recomendações específicas
+/// 
+/// # Exemplo
+/// ```
+/// use hardware_diagnostic::calculate_performance_score;
+/// 
+/// let score = calculate_performance_score();
+/// println!("Pontuação: {:.1}/10 - {:?}", score.overall_score, score.category);
+/// ```
+pub fn calculate_performance_score() -> PerformanceScore {
+    calculate_performance_score_with_weights(ScoreWeights::default())
+}
+
+/// Calcula a pontuação de desempenho com pesos gerais personalizados
+/// 
+/// Os pesos dos subfatores permanecem os padrões de [`ScoringConfig`].
+/// 
+/// # Exemplo
+/// ```
+/// use hardware_diagnostic::{calculate_performance_score_with_weights, ScoreWeights};
+/// 
+/// // Estação de trabalho: CPU pesa mais
+/// let weights = ScoreWeights::new(0.6, 0.2, 0.2).unwrap();
+/// let score = calculate_performance_score_with_weights(weights);
+/// println!("Pontuação: {:.1}/10", score.overall_score);
+/// ```
+pub fn calculate_performance_score_with_weights(weights: ScoreWeights) -> PerformanceScore {
+    let config = ScoringConfig {
+        weights,
+        ..ScoringConfig::default()
+    };
+    
+    score_current_system(&config)
+}
+
+/// Calcula a pontuação de desempenho usando pesos personalizados
+/// 
+/// # Erros
+/// Retorna `DiagnosticError::InvalidConfig` se algum grupo de pesos
+/// da configuração não somar 1.0.
+/// 
+/// # Exemplo
+/// ```
+/// use hardware_diagnostic::engine::{calculate_performance_score_with_config, ScoreWeights, ScoringConfig};
+/// 
+/// // Servidor de armazenamento: discos pesam mais e o espaço livre é decisivo
+/// let config = ScoringConfig {
+///     weights: ScoreWeights::new(0.2, 0.2, 0.6).unwrap(),
+///     disk_usage_weight: 0.3,
+///     disk_free_space_weight: 0.4,
+///     ..ScoringConfig::default()
+/// };
+/// 
+/// let score = calculate_performance_score_with_config(&config).unwrap();
+/// println!("Pontuação: {:.1}/10", score.overall_score);
+/// ```
+pub fn calculate_performance_score_with_config(config: &ScoringConfig) -> Result<PerformanceScore, DiagnosticError> {
+    config.validate()?;
+    
+    Ok(score_current_system(config))
+}
+
+/// Coleta todo o sistema e pontua com `config`, registrando o resultado no nível info
+/// 
+/// Os cálculos internos (ex: `SystemContext::performance_score_with_config`
+/// no modo monitor) registram só no nível debug, para não repetir a linha a
+/// cada amostra.
+fn score_current_system(config: &ScoringConfig) -> PerformanceScore {
+    let mut ctx = SystemContext::new();
+    ctx.refresh_all();
+    let score = ctx.performance_score_with_config(config);
+    info!("pontuação geral {:.2} ({})", score.overall_score, score.category.to_ascii_name());
+    score
+}
+
+/// Construtor de pontuação que avalia apenas os componentes escolhidos
+/// 
+/// Útil para evitar custos desnecessários: sem `include_cpu()` não há a
+/// espera de amostragem da CPU. Componentes não incluídos ficam com
+/// pontuação `None` e fora da média ponderada.
+/// 
+/// # Exemplo
+/// ```
+/// use hardware_diagnostic::PerformanceScoreBuilder;
+/// 
+/// // Apenas a RAM, sem amostrar a CPU
+/// let score = PerformanceScoreBuilder::new().include_ram().build().unwrap();
+/// assert!(score.ram_score.is_some());
+/// assert!(score.cpu_score.is_none());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PerformanceScoreBuilder {
+    include_cpu: bool,
+    include_ram: bool,
+    include_disks: bool,
+    config: ScoringConfig,
+}
+
+impl PerformanceScoreBuilder {
+    /// Cria um construtor sem componentes incluídos e com pesos padrão
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Inclui a CPU (envolve a espera de amostragem do uso)
+    pub fn include_cpu(mut self) -> Self {
+        self.include_cpu = true;
+        self
+    }
+    
+    /// Inclui a memória RAM e SWAP
+    pub fn include_ram(mut self) -> Self {
+        self.include_ram = true;
+        self
+    }
+    
+    /// Inclui os discos (envolve a espera de amostragem de E/S)
+    pub fn include_disks(mut self) -> Self {
+        self.include_disks = true;
+        self
+    }
+    
+    /// Define os pesos gerais de CPU, RAM e discos
+    /// 
+    /// Os pesos dos componentes excluídos são ignorados na média.
+    pub fn with_weights(mut self, weights: ScoreWeights) -> Self {
+        self.config.weights = weights;
+        self
+    }
+    
+    /// Coleta os componentes incluídos e calcula a pontuação
+    /// 
+    /// # Erros
+    /// - `DiagnosticError::InvalidConfig` se nenhum componente foi incluído
+    ///   ou se os pesos forem inválidos
+    /// - Erros de consulta dos componentes incluídos (ex: `CpuQueryFailed`)
+    pub fn build(self) -> Result<PerformanceScore, DiagnosticError> {
+        DiagnosticBuilder {
+            with_cpu: self.include_cpu,
+            with_ram: self.include_ram,
+            with_disks: self.include_disks,
+            config: self.config,
+        }
+        .collect()
+        .map(|diagnostic| diagnostic.score)
+    }
+}
+
+/// Dados coletados por [`DiagnosticBuilder::collect`]
+/// 
+/// Subsistemas não solicitados ficam `None`, assim como as respectivas
+/// pontuações em `score`.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CollectedDiagnostic {
+    /// Informações da CPU, se solicitada
+    pub cpu: Option<CpuInfo>,
+    /// Informações de memória, se solicitada
+    pub ram: Option<RamInfo>,
+    /// Informações dos discos, se solicitados
+    pub disks: Option<Vec<DiskInfo>>,
+    /// Pontuação calculada apenas com os subsistemas coletados
+    pub score: PerformanceScore,
+}
+
+/// Construtor de diagnóstico que coleta apenas os subsistemas escolhidos
+/// 
+/// Como [`PerformanceScoreBuilder`], mas devolve também os dados coletados.
+/// Sem `with_cpu()` não há a espera de amostragem da CPU; subsistemas
+/// ignorados ficam como não avaliados (`None`) e fora da média ponderada.
+/// 
+/// # Exemplo
+/// ```
+/// use hardware_diagnostic::DiagnosticBuilder;
+/// 
+/// // Apenas a saúde dos discos, sem amostrar a CPU
+/// let diagnostic = DiagnosticBuilder::new().with_disks().collect().unwrap();
+/// assert!(diagnostic.cpu.is_none());
+/// assert!(diagnostic.score.cpu_score.is_none());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct DiagnosticBuilder {
+    with_cpu: bool,
+    with_ram: bool,
+    with_disks: bool,
+    config: ScoringConfig,
+}
+
+impl DiagnosticBuilder {
+    /// Cria um construtor sem subsistemas e com a configuração padrão
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Coleta a CPU (envolve a espera de amostragem do uso)
+    pub fn with_cpu(mut self) -> Self {
+        self.with_cpu = true;
+        self
+    }
+    
+    /// Coleta a memória RAM e SWAP
+    pub fn with_ram(mut self) -> Self {
+        self.with_ram = true;
+        self
+    }
+    
+    /// Coleta os discos (envolve a espera de amostragem de E/S)
+    pub fn with_disks(mut self) -> Self {
+        self.with_disks = true;
+        self
+    }
+    
+    /// Define a configuração de pontuação (pesos, limiares e categorias)
+    pub fn with_config(mut self, config: ScoringConfig) -> Self {
+        self.config = config;
+        self
+    }
+    
+    /// Coleta os subsistemas solicitados e calcula a pontuação
+    /// 
+    /// # Erros
+    /// - `DiagnosticError::InvalidConfig` se nenhum subsistema foi solicitado
+    ///   ou se a configuração for inválida
+    /// - Erros de consulta dos subsistemas solicitados (ex: `CpuQueryFailed`)
+    pub fn collect(self) -> Result<CollectedDiagnostic, DiagnosticError> {
+        if !(self.with_cpu || self.with_ram || self.with_disks) {
+            return Err(DiagnosticError::InvalidConfig(
+                "nenhum componente incluído na pontuação".to_string(),
+            ));
+        }
+        self.config.validate()?;
+        
+        let mut ctx = SystemContext::new();
+        
+        let cpu = if self.with_cpu {
+            ctx.refresh_cpu();
+            Some(ctx.cpu_info_checked()?)
+        } else {
+            None
+        };
+        let ram = if self.with_ram {
+            ctx.refresh_memory();
+            Some(read_ram(&ctx.sys)?)
+        } else {
+            None
+        };
+        let disks = if self.with_disks {
+            ctx.refresh_disks();
+            Some(read_disks(&ctx.disks, &ctx.disk_io_rates, &ctx.smart)?)
+        } else {
+            None
+        };
+        
+        ctx.refresh_bios();
+        
+        // Uso alto da CPU: amostra os processos para nomear o responsável
+        if let Some(cpu) = &cpu {
+            ctx.refresh_processes_if_busy(cpu);
+        }
+        let top_processes = processes::top_by_cpu(ctx.processes_from(), processes::RECOMMENDATION_TOP_PROCESSES);
+        
+        let score = score_from(
+            cpu.as_ref(),
+            ram.as_ref(),
+            disks.as_deref(),
+            None,
+            None,
+            &ctx.temperatures,
+            &top_processes,
+            Some(System::uptime()),
+            detect_virtualization(),
+            ctx.secure_boot_from(),
+            &DiagnosticConfig::from(self.config.clone()),
+        );
+        Ok(CollectedDiagnostic { cpu, ram, disks, score })
+    }
+}
+
+/// Monitora continuamente a pontuação de desempenho
+/// 
+/// Reamostra o sistema a cada `interval` e chama `callback` com a nova
+/// pontuação. Um único [`SystemContext`] é reutilizado entre as leituras,
+/// então a amostragem da CPU aproveita o próprio intervalo de monitoramento
+/// em vez de somar uma espera extra a cada ciclo.
+/// 
+/// Esta função não retorna; encerre o processo (ex: Ctrl-C) para parar.
+/// 
+/// # Exemplo
+/// ```no_run
+/// use hardware_diagnostic::monitor;
+/// use std::time::Duration;
+/// 
+/// monitor(Duration::from_secs(2), |score| {
+///     println!("Pontuação: {:.1}/10", score.overall_score);
+/// });
+/// ```
+pub fn monitor(interval: Duration, mut callback: impl FnMut(&PerformanceScore)) -> ! {
+    // Intervalos curtos também encurtam a amostragem para não atrasar os ciclos
+    let mut ctx = SystemContext::with_sample_interval(interval.min(DEFAULT_CPU_SAMPLE_INTERVAL));
+    
+    loop {
+        let cycle_start = Instant::now();
+        
+        ctx.refresh_all();
+        callback(&ctx.performance_score());
+        
+        // Desconta o tempo gasto na coleta para manter o ritmo
+        std::thread::sleep(interval.saturating_sub(cycle_start.elapsed()));
+    }
+}
+
+/// Coleta a pontuação da GPU quando a feature `gpu` está habilitada
+fn collect_gpu_score() -> Option<f64> {
+    #[cfg(feature = "gpu")]
+    {
+        gpu::calculate_gpu_score(&gpu::gpu_info())
+    }
+    #[cfg(not(feature = "gpu"))]
+    {
+        None
+    }
+}
+
+/// Calcula a pontuação a partir de informações já coletadas
+/// 
+/// Componentes ausentes (`None`) ficam sem pontuação e fora da média.
+/// `top_processes` são os maiores consumidores de CPU, citados na
+/// recomendação de uso alto (vazio quando não foram amostrados).
+#[allow(clippy::too_many_arguments)] // uma entrada por fonte de dados coletada
+fn score_from(
+    cpu_info: Option<&CpuInfo>,
+    ram_info: Option<&RamInfo>,
+    disks_info: Option<&[DiskInfo]>,
+    gpu_score: Option<f64>,
+    battery_info: Option<&BatteryInfo>,
+    temperatures: &[TemperatureReading],
+    top_processes: &[ProcessInfo],
+    uptime_seconds: Option<u64>,
+    virtualization: Option<VirtKind>,
+    secure_boot_enabled: Option<bool>,
+    config: &DiagnosticConfig,
+) -> PerformanceScore {
+    // Pesos, categorias e idioma, com os limiares efetivos da configuração
+    let scoring = config.scoring_config();
+    
+    // 1. PONTUAÇÃO DA CPU (0-10)
+    let cpu_score = cpu_info.map(|cpu| calculate_cpu_score(cpu, config));
+    
+    // 2. PONTUAÇÃO DA RAM (0-10)
+    let ram_score = ram_info.map(|ram| calculate_ram_score(ram, config));
+    
+    // 3. PONTUAÇÃO DOS DISCOS (0-10), apenas dos discos que passam pelo filtro;
+    // um filtro que descarta todos deixa os discos sem avaliação
+    let filtered_disks = disks_info.map(|disks| scoring.disk_filter.apply(disks));
+    let no_disk_selected = disks_info.is_some_and(|disks| !disks.is_empty())
+        && filtered_disks.as_ref().is_some_and(Vec::is_empty);
+    let disks_info = if no_disk_selected { None } else { filtered_disks.as_deref() };
+    let disk_score = disks_info.map(|disks| calculate_disk_score(disks, config));
+    let per_disk_scores = disks_info
+        .map(|disks| calculate_per_disk_scores(disks, config))
+        .unwrap_or_default();
+    
+    // 4. PONTUAÇÃO GERAL (média ponderada dos componentes avaliados)
+    let battery_score = battery_info.map(battery::calculate_battery_score);
+    let weighted = [
+        (cpu_score, scoring.weights.cpu()),
+        (ram_score, scoring.weights.ram()),
+        (disk_score, scoring.weights.disk()),
+        (gpu_score, scoring.gpu_weight),
+        (battery_score, scoring.battery_weight),
+    ];
+    let present: Vec<(f64, f64)> = weighted
+        .iter()
+        .filter_map(|(score, weight)| score.map(|s| (s, *weight)))
+        .collect();
+    let total_weight: f64 = present.iter().map(|(_, weight)| weight).sum();
+    let overall_score = if total_weight > 0.0 {
+        present.iter().map(|(score, weight)| score * weight).sum::<f64>() / total_weight
+    } else if !present.is_empty() {
+        // Componentes avaliados com peso zero: média simples
+        present.iter().map(|(score, _)| score).sum::<f64>() / present.len() as f64
+    } else {
+        0.0
+    };
+    
+    // 5. DETERMINAR CATEGORIA, limitada a Manutenção se um subsistema estiver abaixo do piso
+    let mut category = determine_category(overall_score, &scoring.categories);
+    let critical = [
+        ("CPU".to_string(), cpu_score),
+        ("RAM".to_string(), ram_score),
+        ("GPU".to_string(), gpu_score),
+        (Message::BatteryLabel.text(scoring.language), battery_score),
+    ]
+    .into_iter()
+    .chain(per_disk_scores.iter().map(|(name, score)| (name.clone(), Some(*score))))
+    .filter_map(|(name, score)| score.map(|s| (name, s)))
+    .filter(|(_, score)| scoring.critical_floor_enabled && *score < scoring.critical_floor)
+    .min_by(|a, b| a.1.total_cmp(&b.1));
+    if critical.is_some() && matches!(category, PerformanceCategory::Precaução | PerformanceCategory::BomEstado) {
+        category = PerformanceCategory::Manutencao;
+    }
+    // Disco com falha prevista pelo SMART: descarte, qualquer que seja o espaço livre
+    if disks_info.is_some_and(|disks| disks.iter().any(|disk| disk.health_status() == SmartStatus::Failed)) {
+        category = PerformanceCategory::Descarte;
+    }
+    debug!(
+        "pontuação: CPU {:?}, RAM {:?}, discos {:?}, GPU {:?}, bateria {:?}, geral {:.2} ({})",
+        cpu_score, ram_score, disk_score, gpu_score, battery_score, overall_score, category.to_ascii_name()
+    );
+    
+    // 6. GERAR RECOMENDAÇÕES
+    let mut recommendations = generate_recommendations(
+        cpu_info,
+        ram_info,
+        disks_info.unwrap_or_default(),
+        battery_info,
+        temperatures,
+        top_processes,
+        uptime_seconds,
+        virtualization,
+        secure_boot_enabled,
+        &category,
+        &scoring,
+    );
+    if let Some((name, score)) = critical {
+        let message = Message::CriticalSubsystem(&name, score, scoring.critical_floor).text(scoring.language);
+        recommendations.insert(1, message);
+    }
+    if no_disk_selected {
+        recommendations.insert(1, Message::NoDiskSelected.text(scoring.language));
+    }
+    
+    PerformanceScore {
+        overall_score,
+        cpu_score,
+        ram_score,
+        disk_score,
+        per_disk_scores,
+        gpu_score,
+        battery_score,
+        category,
+        recommendations,
+    }
+}
+
+/// Calcula a pontuação da CPU baseada em múltiplos fatores
+pub(crate) fn calculate_cpu_score(cpu_info: &CpuInfo, config: &DiagnosticConfig) -> f64 {
+    let thresholds = &config.thresholds();
+    
+    // Fator 1: Número de núcleos
+    let cores = thresholds.cpu_cores;
+    let cores_score = if cpu_info.number_cpus <= cores[0] {
+        2.0  // Muito baixo
+    } else if cpu_info.number_cpus <= cores[1] {
+        4.0  // Baixo
+    } else if cpu_info.number_cpus <= cores[2] {
+        6.0  // Médio
+    } else if cpu_info.number_cpus <= cores[3] {
+        8.0  // Bom
+    } else {
+        10.0 // Excelente
+    };
+    
+    // Fator 2: Uso atual da CPU (quanto menor o uso, melhor)
+    let usage = thresholds.cpu_usage;
+    let usage_score = if cpu_info.cpu_usage < usage[0] {
+        10.0 // Excelente (baixo uso)
+    } else if cpu_info.cpu_usage < usage[1] {
+        7.0  // Bom
+    } else if cpu_info.cpu_usage < usage[2] {
+        4.0  // Regular
+    } else {
+        1.0  // Crítico
+    };
+    
+    // Um núcleo saturado com média baixa indica gargalo single-thread
+    let usage_score = if cpu_info.max_core_usage() > thresholds.cpu_saturated_core {
+        f64::min(usage_score, 4.0)
+    } else {
+        usage_score
+    };
+    
+    // Fator 3: Frequência da CPU (quanto maior, melhor)
+    let frequency = thresholds.cpu_frequency_mhz;
+    let freq_score = if cpu_info.frequency < frequency[0] {
+        3.0  // Muito baixa
+    } else if cpu_info.frequency < frequency[1] {
+        6.0  // Baixa
+    } else if cpu_info.frequency < frequency[2] {
+        8.0  // Boa
+    } else {
+        10.0 // Excelente
+    };
+    
+    // Média dos fatores com pesos
+    let score: f64 = cores_score * config.scoring.cpu_cores_weight
+        + usage_score * config.scoring.cpu_usage_weight
+        + freq_score * config.scoring.cpu_frequency_weight;
+    
+    // Throttling reportado pelo sistema explica a lentidão mesmo com
+    // temperatura moderada: a CPU não entrega o desempenho nominal
+    let score = if cpu_info.throttling == Some(true) {
+        f64::min(score, thresholds.throttling_cpu_max_score)
+    } else {
+        score
+    };
+    
+    // Garante entre 0 e 10
+    score.clamp(0.0, 10.0)
+}
+
+/// Calcula a pontuação da RAM
+pub(crate) fn calculate_ram_score(ram_info: &RamInfo, config: &DiagnosticConfig) -> f64 {
+    let thresholds = &config.thresholds();
+    
+    // Fator 1: Uso da RAM (quanto menor, melhor)
+    let ram_usage_score = ram_info.ram_pressure(thresholds).score();
+    
+    // Fator 2: Uso do SWAP (quanto menor, melhor)
+    let swap_score = match ram_info.swap_pressure(thresholds) {
+        Some(pressure) => pressure.score(),
+        // Sem SWAP com a RAM quase cheia: sem margem antes de faltar memória
+        None if ram_info.ram_usage_percent > NO_SWAP_RAM_USAGE_PERCENT => 2.0,
+        None => 8.0, // Sem SWAP configurado (neutro)
+    };
+    
+    // Fator 3: Quantidade total de RAM
+    let total_ram_gb = utils::bytes_to_gib_f64(ram_info.total_ram);
+    let capacity = thresholds.ram_capacity_gib;
+    let capacity_score = if total_ram_gb < capacity[0] {
+        3.0  // Muito baixa
+    } else if total_ram_gb < capacity[1] {
+        6.0  // Baixa
+    } else if total_ram_gb < capacity[2] {
+        8.0  // Boa
+    } else {
+        10.0 // Excelente
+    };
+    
+    let score: f64 = ram_usage_score * config.scoring.ram_usage_weight
+        + swap_score * config.scoring.ram_swap_weight
+        + capacity_score * config.scoring.ram_capacity_weight;
+    
+    // Garante entre 0 e 10
+    score.clamp(0.0, 10.0)
+}
+
+/// Calcula a pontuação dos discos (média das pontuações individuais)
+pub(crate) fn calculate_disk_score(disks: &[DiskInfo], config: &DiagnosticConfig) -> f64 {
+    if disks.is_empty() {
+        return 5.0; // Pontuação neutra se não houver discos
+    }
+    
+    let total_score: f64 = disks
+        .iter()
+        .map(|disk| calculate_single_disk_score(disk, config))
+        .sum();
+    total_score / disks.len() as f64
+}
+
+/// Calcula a pontuação de cada disco, identificada pelo nome
+fn calculate_per_disk_scores(disks: &[DiskInfo], config: &DiagnosticConfig) -> Vec<(String, f64)> {
+    disks
+        .iter()
+        .map(|disk| (disk.name.clone(), calculate_single_disk_score(disk, config)))
+        .collect()
+}
+
+/// Calcula a pontuação de um único disco
+fn calculate_single_disk_score(disk: &DiskInfo, config: &DiagnosticConfig) -> f64 {
+    let thresholds = &config.thresholds();
+    let usage = thresholds.disk_usage;
+    let free_space = thresholds.disk_free_space_gib;
+    
+    // Fator 1: Uso do disco (quanto menor, melhor)
+    let usage_score = if disk.usage_percent < usage[0] {
+        10.0 // Excelente
+    } else if disk.usage_percent < usage[1] {
+        7.0  // Bom
+    } else if disk.usage_percent < usage[2] {
+        4.0  // Regular
+    } else {
+        1.0  // Crítico
+    };
+    
+    // Fator 2: Tipo de disco
+    let type_score = match disk.kind {
+        DiskKind::Ssd => 10.0,     // SSD (rápido)
+        DiskKind::Hdd => 6.0,      // HDD (lento)
+        DiskKind::Unknown => 8.0,  // Outro/desconhecido
+    };
+    
+    // Fator 3: Espaço livre
+    let free_gb = utils::bytes_to_gib_f64(disk.available_space);
+    let free_space_score = if free_gb > free_space[3] {
+        10.0 // Excelente
+    } else if free_gb > free_space[2] {
+        8.0  // Bom
+    } else if free_gb > free_space[1] {
+        6.0  // Regular
+    } else if free_gb > free_space[0] {
+        4.0  // Baixo
+    } else {
+        1.0  // Crítico
+    };
+    
+    let mut disk_score: f64 = usage_score * config.scoring.disk_usage_weight
+        + type_score * config.scoring.disk_type_weight
+        + free_space_score * config.scoring.disk_free_space_weight;
+    
+    // HDD saturado de E/S é um gargalo sério mesmo com espaço sobrando
+    if disk.kind == DiskKind::Hdd && disk.io_utilization_percent > thresholds.hdd_io_saturation {
+        disk_score *= 0.6;
+    }
+    
+    // Falha prevista pelo SMART prevalece sobre qualquer outro fator
+    if disk.health_status() == SmartStatus::Failed {
+        disk_score = 1.0;
+    }
+    
+    // Garante entre 0 e 10
+    disk_score.clamp(0.0, 10.0)
+}
+
+/// Determina a categoria baseada na pontuação geral
+fn determine_category(score: f64, boundaries: &CategoryBoundaries) -> PerformanceCategory {
+    match score {
+        s if s < boundaries.manutencao => PerformanceCategory::Descarte,  // padrão 0-2.9
+        s if s < boundaries.precaucao => PerformanceCategory::Manutencao, // padrão 3-4.9
+        s if s < boundaries.bom_estado => PerformanceCategory::Precaução, // padrão 5-6.9
+        _ => PerformanceCategory::BomEstado,                              // padrão 7+
+    }
+}
+
+/// Gera recomendações baseadas no estado da máquina, com os limites e o
+/// idioma da configuração
+#[allow(clippy::too_many_arguments)] // uma entrada por fonte de dados coletada
+fn generate_recommendations(
+    cpu_info: Option<&CpuInfo>,
+    ram_info: Option<&RamInfo>,
+    disks: &[DiskInfo],
+    battery_info: Option<&BatteryInfo>,
+    temperatures: &[TemperatureReading],
+    top_processes: &[ProcessInfo],
+    uptime_seconds: Option<u64>,
+    virtualization: Option<VirtKind>,
+    secure_boot_enabled: Option<bool>,
+    category: &PerformanceCategory,
+    config: &ScoringConfig,
+) -> Vec<String> {
+    let mut recommendations = Vec::new();
+    let mut push = |message: Message| recommendations.push(message.text(config.language));
+    
+    // Recomendações baseadas na categoria geral; em VMs e contêineres os
+    // recursos são os alocados, e descartar o hardware não faz sentido
+    push(Message::CategorySummary(category));
+    match virtualization {
+        Some(kind) => push(Message::VirtualEnvironment(kind)),
+        None if *category == PerformanceCategory::Descarte => push(Message::DiscardSuggestion),
+        None => {}
+    }
+    
+    // Recomendações específicas para CPU
+    if let Some(cpu_info) = cpu_info {
+        if cpu_info.cpu_usage > HIGH_CPU_USAGE_PERCENT {
+            push(Message::HighCpuUsage(top_processes));
+        }
+        if cpu_info.number_cpus < 2 {
+            push(Message::SingleCore);
+        }
+        if cpu_info.throttling == Some(true) {
+            push(Message::ActiveThrottling);
+        } else if cpu_info.thermal_throttling_detected {
+            push(Message::ThermalThrottling);
+        }
+        let unmitigated: Vec<&str> = cpu_info
+            .unmitigated_vulnerabilities()
+            .iter()
+            .map(|status| status.name.as_str())
+            .collect();
+        if !unmitigated.is_empty() {
+            push(Message::CpuVulnerable(unmitigated.join(", ")));
+        }
+    }
+    let max_cpu_temperature = temperatures
+        .iter()
+        .filter(|reading| reading.component == ThermalComponent::Cpu)
+        .map(|reading| reading.temperature_celsius)
+        .fold(f32::NAN, f32::max);
+    if max_cpu_temperature > CPU_CRITICAL_TEMPERATURE_CELSIUS {
+        push(Message::CpuTemperatureCritical(max_cpu_temperature));
+    }
+    
+    // Recomendações específicas para RAM
+    if let Some(ram_info) = ram_info {
+        // Uso da RAM e do SWAP pelos mesmos limites da pontuação
+        let pressure = ram_info.pressure_level_with(&config.thresholds);
+        if pressure >= MemoryPressure::Moderate {
+            push(Message::MemoryPressure(pressure, ram_info.ram_usage_percent, ram_info.swap_usage_percent));
+        }
+        if utils::bytes_to_gib_f64(ram_info.total_ram) < config.thresholds.low_ram_gib {
+            push(Message::LowRam);
+        }
+        if ram_info.total_swap == 0 && ram_info.ram_usage_percent > NO_SWAP_RAM_USAGE_PERCENT {
+            push(Message::NoSwapHighRam);
+        }
+        if ram_info.all_slots_used() {
+            push(Message::AllSlotsUsed);
+        }
+        if ram_info.memory_type.as_deref() == Some("DDR3") {
+            push(Message::Ddr3EndOfLife);
+        }
+    }
+    
+    // Recomendações específicas para discos
+    for disk in disks {
+        if disk.usage_percent > config.thresholds.disk_almost_full_percent {
+            push(Message::DiskAlmostFull(&disk.name, disk.usage_percent));
+        }
+        if disk.kind == DiskKind::Hdd && *category != PerformanceCategory::BomEstado {
+            push(Message::HddBottleneck(&disk.name));
+        }
+        // Disco ocupado com E/S quase o tempo todo explica a pontuação baixa;
+        // o mesmo limite que penaliza a pontuação do HDD
+        if disk.io_utilization_percent > config.thresholds.hdd_io_saturation && *category != PerformanceCategory::BomEstado {
+            push(Message::IoSaturated(&disk.name, disk.io_utilization_percent));
+        }
+        if utils::bytes_to_gib_f64(disk.available_space) < config.thresholds.low_disk_free_gib {
+            push(Message::LowDiskSpace(&disk.name, config.thresholds.low_disk_free_gib));
+        }
+        if let Some(health) = &disk.health {
+            let unstable = health.pending_sectors.unwrap_or(0) + health.uncorrectable_errors.unwrap_or(0);
+            if health.health_status == SmartStatus::Failed {
+                push(Message::SmartFailure(&disk.name));
+            } else if unstable > 0 {
+                push(Message::UnstableSectors(&disk.name, unstable));
+            } else if let Some(sectors) = health.reallocated_sectors.filter(|s| *s > 0) {
+                push(Message::ReallocatedSectors(&disk.name, sectors));
+            }
+        }
+    }
+    
+    // Recomendações específicas para bateria
+    if let Some(health) = battery_info.and_then(|b| b.health_percent) {
+        if health < config.thresholds.battery_health_percent {
+            push(Message::BatteryDegraded(health));
+        }
+    }
+    
+    // Máquina ligada há muito tempo acumula atualizações pendentes
+    let uptime_days = uptime_seconds.unwrap_or(0) / 86_400;
+    if uptime_days > LONG_UPTIME_DAYS {
+        push(Message::LongUptime(uptime_days));
+    }
+    
+    // Firmware UEFI com Secure Boot desligado aceita bootloaders não assinados
+    if secure_boot_enabled == Some(false) {
+        push(Message::SecureBootDisabled);
+    }
+    
+    // Recomendação final baseada na categoria; em VMs e contêineres não há
+    // equipamento a substituir, e sim recursos alocados a rever
+    if virtualization.is_some() && *category == PerformanceCategory::Descarte {
+        push(Message::ReviewVirtualResources);
+    } else {
+        push(Message::RecommendedAction(category));
+    }
+    
+    recommendations
+}
+
+/// Exibe a pontuação de forma formatada
+/// 
+/// Usa cores ANSI apenas quando a saída padrão é um terminal e as cores não
+/// foram desligadas (veja [`utils::colors_enabled`]). Use
+/// [`display_performance_score_colored`] para forçar o comportamento.
+pub fn display_performance_score(score: &PerformanceScore) -> String {
+    display_performance_score_colored(score, utils::colors_enabled())
+}
+
+/// Exibe a pontuação de forma formatada, com ou sem cores ANSI
+/// 
+/// # Argumentos
+/// * `score` - Pontuação a ser exibida
+/// * `use_color` - Se `true`, destaca a categoria com códigos de cor ANSI
+///   (sem efeito quando [`utils::ansi_enabled`] é falso)
+pub fn display_performance_score_colored(score: &PerformanceScore, use_color: bool) -> String {
+    display_performance_score_localized(score, use_color, Language::default())
+}
+
+/// Exibe a pontuação de forma formatada no idioma indicado
+/// 
+/// As recomendações são exibidas como estão em `score`; para traduzi-las,
+/// calcule a pontuação com [`ScoringConfig::language`] no mesmo idioma.
+/// 
+/// # Argumentos
+/// * `score` - Pontuação a ser exibida
+/// * `use_color` - Se `true`, destaca a categoria com códigos de cor ANSI
+///   (sem efeito quando [`utils::ansi_enabled`] é falso)
+/// * `language` - Idioma dos títulos e da descrição da categoria
+/// 
+/// # Exemplo
+/// ```
+/// use hardware_diagnostic::{calculate_performance_score_with_config, Language, ScoringConfig};
+/// use hardware_diagnostic::engine::display_performance_score_localized;
+/// 
+/// let config = ScoringConfig { language: Language::EnUs, ..ScoringConfig::default() };
+/// let score = calculate_performance_score_with_config(&config).unwrap();
+/// println!("{}", display_performance_score_localized(&score, false, Language::EnUs));
+/// ```
+pub fn display_performance_score_localized(score: &PerformanceScore, use_color: bool, language: Language) -> String {
+    display_performance_score_with_boundaries(score, use_color, language, &CategoryBoundaries::default())
+}
+
+/// Exibe a pontuação no idioma e com a legenda das categorias de `config`
+/// 
+/// Use com a mesma configuração que calculou `score`, para que a legenda
+/// mostre as faixas que decidiram a categoria.
+/// 
+/// # Exemplo
+/// ```
+/// use hardware_diagnostic::{calculate_performance_score_with_config, ScoringConfig};
+/// use hardware_diagnostic::engine::display_performance_score_with_config;
+/// 
+/// let mut config = ScoringConfig::default();
+/// config.categories.bom_estado = 8.0;
+/// let score = calculate_performance_score_with_config(&config).unwrap();
+/// println!("{}", display_performance_score_with_config(&score, false, &config));
+/// ```
+pub fn display_performance_score_with_config(score: &PerformanceScore, use_color: bool, config: &ScoringConfig) -> String {
+    display_performance_score_with_boundaries(score, use_color, config.language, &config.categories)
+}
+
+/// Monta a exibição da pontuação com a legenda das faixas de `boundaries`
+fn display_performance_score_with_boundaries(
+    score: &PerformanceScore,
+    use_color: bool,
+    language: Language,
+    boundaries: &CategoryBoundaries,
+) -> String {
+    let text = |message: Message| message.text(language);
+    let mut output = String::new();
+    
+    output.push_str(&format!("{}\n", "=".repeat(60)));
+    output.push_str(&format!("           {}           \n", text(Message::ScoreTitle)));
+    output.push_str(&format!("{}\n\n", "=".repeat(60)));
+    
+    // Barra de pontuação visual
+    output.push_str(&format!("{}\n", text(Message::OverallScore(score.overall_score))));
+    output.push_str(&format!("{}\n\n", utils::format_score_bar(score.overall_score, 10.0, 40)));
+    
+    // Categoria com cor (opcional)
+    let description = score.category.description_in(language);
+    if use_color {
+        output.push_str(&format!("{}: {}{}{}\n\n", 
+            text(Message::CategoryLabel),
+            score.category.color_code(),
+            description,
+            PerformanceCategory::reset_color()
+        ));
+    } else {
+        output.push_str(&format!("{}: {}\n\n", text(Message::CategoryLabel), description));
+    }
+    
+    // Pontuações detalhadas
+    output.push_str(&format!("{}:\n", text(Message::DetailedScores)));
+    let sub_scores = [
+        ("CPU".to_string(), score.cpu_score),
+        ("RAM".to_string(), score.ram_score),
+        (text(Message::DisksLabel), score.disk_score),
+        ("GPU".to_string(), score.gpu_score),
+        (text(Message::BatteryLabel), score.battery_score),
+    ];
+    for (label, sub_score) in sub_scores {
+        if let Some(sub_score) = sub_score {
+            output.push_str(&format!("  • {:<10}{:.1}/10.0\n", format!("{}:", label), sub_score));
+        }
+    }
+    output.push('\n');
+    
+    // Legenda das categorias
+    output.push_str(&format!("{}:\n", text(Message::LegendTitle)));
+    for category in [
+        PerformanceCategory::Descarte,
+        PerformanceCategory::Manutencao,
+        PerformanceCategory::Precaução,
+        PerformanceCategory::BomEstado,
+    ] {
+        let range = boundaries.legend_range(&category);
+        output.push_str(&format!("  {:<6}→ {}\n", range, text(Message::Legend(&category))));
+    }
+    output.push('\n');
+    
+    // Recomendações
+    if !score.recommendations.is_empty() {
+        output.push_str(&format!("{}:\n", text(Message::RecommendationsTitle)));
+        for (i, rec) in score.recommendations.iter().enumerate() {
+            output.push_str(&format!("  {}. {}\n", i + 1, rec));
+        }
+    }
+    
+    utils::render_text(&output)
+}
+
+/// Exibe a comparação entre duas pontuações com setas de tendência
+/// 
+/// Variações menores que 0.05 ponto (invisíveis com uma casa decimal) são
+/// exibidas como estáveis (→).
+pub fn display_diff(diff: &ScoreDiff) -> String {
+    display_diff_colored(diff, false)
+}
+
+/// Exibe a comparação entre duas pontuações, com ou sem cores ANSI
+/// 
+/// Com cores, melhorias aparecem em verde e pioras em vermelho. Após
+/// [`utils::disable_ansi`], `use_color` é ignorado.
+pub fn display_diff_colored(diff: &ScoreDiff, use_color: bool) -> String {
+    display_diff_localized(diff, use_color, Language::default())
+}
+
+/// Exibe a comparação entre duas pontuações no idioma indicado
+/// 
+/// Cada linha usa a mesma seta de [`ScoreDelta::to_text`].
+pub fn display_diff_localized(diff: &ScoreDiff, use_color: bool, language: Language) -> String {
+    let text = |message: Message| message.text(language);
+    let mut output = String::new();
+    
+    output.push_str(&format!("{}\n", "=".repeat(60)));
+    output.push_str(&format!("           {}           \n", text(Message::DiffTitle)));
+    output.push_str(&format!("{}\n\n", "=".repeat(60)));
+    
+    let rows = [
+        (text(Message::OverallLabel), Some(diff.overall)),
+        ("CPU".to_string(), diff.cpu),
+        ("RAM".to_string(), diff.ram),
+        (text(Message::DisksLabel), diff.disk),
+        ("GPU".to_string(), diff.gpu),
+        (text(Message::BatteryLabel), diff.battery),
+    ];
+    for (label, delta) in rows {
+        if let Some(delta) = delta {
+            output.push_str(&format!(
+                "  • {:<10}{:.1} → {:.1}  {}\n",
+                format!("{}:", label),
+                delta.previous,
+                delta.current,
+                delta.to_text_colored(use_color)
+            ));
+        }
+    }
+    output.push('\n');
+    
+    if diff.category_changed() {
+        output.push_str(&format!(
+            "{}: {} → {}\n",
+            text(Message::CategoryLabel),
+            diff.previous_category.description_in(language),
+            diff.current_category.description_in(language)
+        ));
+    } else {
+        output.push_str(&format!(
+            "{}: {} {}\n",
+            text(Message::CategoryLabel),
+            diff.current_category.description_in(language),
+            text(Message::CategoryUnchanged)
+        ));
+    }
+    
+    utils::render_text(&output)
+}
+
+/// Funções utilitárias para formatação de dados
+pub mod utils {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::RwLock;
+    
+    /// Chave global de cores ANSI, alterada por [`set_ansi_enabled`]
+    static ANSI_ENABLED: AtomicBool = AtomicBool::new(true);
+    
+    /// Suporte a cores ANSI indicado pelo ambiente
+    /// 
+    /// Segue a convenção de <https://no-color.org>: `NO_COLOR` com qualquer
+    /// valor não vazio desliga as cores. Terminais que se declaram `dumb`
+    /// também não as interpretam.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum AnsiSupport {
+        /// Cores permitidas
+        Enabled,
+        /// Desligadas pela variável `NO_COLOR`
+        NoColor,
+        /// Terminal sem suporte (`TERM=dumb`)
+        DumbTerminal,
+    }
+    
+    impl AnsiSupport {
+        /// Lê `NO_COLOR` e `TERM` do ambiente do processo
+        pub fn detect() -> AnsiSupport {
+            AnsiSupport::from_env(
+                std::env::var_os("NO_COLOR").as_deref(),
+                std::env::var_os("TERM").as_deref(),
+            )
+        }
+        
+        /// Decide a partir dos valores de `NO_COLOR` e `TERM`
+        /// 
+        /// # Exemplo
+        /// ```
+        /// use hardware_diagnostic::engine::utils::AnsiSupport;
+        /// use std::ffi::OsStr;
+        /// 
+        /// assert_eq!(AnsiSupport::from_env(Some(OsStr::new("1")), None), AnsiSupport::NoColor);
+        /// assert_eq!(AnsiSupport::from_env(Some(OsStr::new("")), None), AnsiSupport::Enabled);
+        /// assert_eq!(AnsiSupport::from_env(None, Some(OsStr::new("dumb"))), AnsiSupport::DumbTerminal);
+        /// ```
+        pub fn from_env(no_color: Option<&std::ffi::OsStr>, term: Option<&std::ffi::OsStr>) -> AnsiSupport {
+            if no_color.is_some_and(|v| !v.is_empty()) {
+                AnsiSupport::NoColor
+            } else if term.is_some_and(|t| t == "dumb") {
+                AnsiSupport::DumbTerminal
+            } else {
+                AnsiSupport::Enabled
+            }
+        }
+        
+        /// Indica se as cores são permitidas
+        pub fn is_enabled(self) -> bool {
+            self == AnsiSupport::Enabled
+        }
+    }
+    
+    /// Estilo global das barras, alterado por [`set_bar_style`]
+    static BAR_STYLE: RwLock<BarStyle> = RwLock::new(BarStyle::Unicode);
+    
+    /// Chave global da saída em ASCII puro, ligada por [`set_ascii_output`]
+    static ASCII_OUTPUT: AtomicBool = AtomicBool::new(false);
+    
+    /// Caracteres usados nas barras de progresso
+    /// 
+    /// [`BarStyle::Ascii`] serve para terminais que não exibem caracteres de
+    /// bloco (ex: console legado do Windows com página de código 850).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+    pub enum BarStyle {
+        /// Blocos Unicode: `█` preenchido e `░` vazio
+        #[default]
+        Unicode,
+        /// Apenas ASCII: `#` preenchido e `-` vazio
+        Ascii,
+        /// Caracteres escolhidos pelo usuário
+        Custom {
+            /// Caractere da parte preenchida
+            fill: char,
+            /// Caractere da parte vazia
+            empty: char,
+        },
+    }
+    
+    impl BarStyle {
+        /// Caractere da parte preenchida
+        pub fn fill(self) -> char {
+            match self {
+                BarStyle::Unicode => '█',
+                BarStyle::Ascii => '#',
+                BarStyle::Custom { fill, .. } => fill,
+            }
+        }
+        
+        /// Caractere da parte vazia
+        pub fn empty(self) -> char {
+            match self {
+                BarStyle::Unicode => '░',
+                BarStyle::Ascii => '-',
+                BarStyle::Custom { empty, .. } => empty,
+            }
+        }
+    }
+    
+    /// Define o estilo das barras de todas as saídas da biblioteca
+    /// 
+    /// Usado pela opção `--ascii` da CLI. Vale para o restante do processo.
+    pub fn set_bar_style(style: BarStyle) {
+        // Um valor `Copy` não fica inconsistente se outra thread entrar em pânico
+        *BAR_STYLE.write().unwrap_or_else(|e| e.into_inner()) = style;
+    }
+    
+    /// Estilo atual das barras (padrão [`BarStyle::Unicode`])
+    pub fn bar_style() -> BarStyle {
+        *BAR_STYLE.read().unwrap_or_else(|e| e.into_inner())
+    }
+    
+    /// Liga ou desliga a saída em ASCII puro, para consoles seriais e o
+    /// `cmd` legado do Windows
+    /// 
+    /// Com a saída ASCII ligada, a exibição da pontuação, as recomendações e
+    /// os relatórios em texto passam por [`to_ascii`]. Só a exibição muda: as
+    /// pontuações e as recomendações guardadas em `PerformanceScore` (e
+    /// serializadas em JSON) continuam com o texto original. Markdown e HTML não são afetados. Usado pela opção
+    /// `--ascii` da CLI; vale para o restante do processo.
+    pub fn set_ascii_output(enabled: bool) {
+        ASCII_OUTPUT.store(enabled, Ordering::Relaxed);
+    }
+    
+    /// Indica se a saída em ASCII puro está ligada
+    pub fn ascii_output() -> bool {
+        ASCII_OUTPUT.load(Ordering::Relaxed)
+    }
+    
+    /// Aplica [`to_ascii`] se a saída ASCII estiver ligada
+    pub fn render_text(text: &str) -> String {
+        if ascii_output() {
+            to_ascii(text)
+        } else {
+            text.to_string()
+        }
+    }
+    
+    /// Substitui emojis, setas, blocos e bordas por equivalentes ASCII
+    /// 
+    /// Letras acentuadas perdem o acento ("ç" vira "c") e as demais letras
+    /// fora do ASCII viram "?". Emojis sem equivalente são removidos junto
+    /// com o espaço que os segue.
+    /// 
+    /// # Exemplo
+    /// ```
+    /// use hardware_diagnostic::engine::utils::to_ascii;
+    /// 
+    /// assert_eq!(to_ascii("🔴 CPU: 95°C → ▲ 2.0"), "[!!] CPU: 95C -> ^ 2.0");
+    /// assert_eq!(to_ascii("📊 PONTUAÇÃO ███░░"), "PONTUACAO ###--");
+    /// ```
+    pub fn to_ascii(text: &str) -> String {
+        let mut output = String::with_capacity(text.len());
+        let mut after_removed = false;
+        
+        for c in text.chars() {
+            // Espaços que separavam um emoji removido do texto
+            if after_removed && (c == ' ' || c == '\u{FE0F}') {
+                continue;
+            }
+            after_removed = false;
+            
+            if c.is_ascii() {
+                output.push(c);
+                continue;
+            }
+            if let Some(base) = without_accent(c) {
+                output.push(base);
+                continue;
+            }
+            
+            let replacement = match c {
+                '🔴' | '🚨' => "[!!]",
+                '🛑' | '❌' => "[X]",
+                '🟡' | '🔶' | '⚠' => "[!]",
+                '✅' => "[OK]",
+                'ℹ' => "[i]",
+                '💡' | '•' => "*",
+                '→' | '↳' => "->",
+                '↑' | '▲' => "^",
+                '↓' | '▼' => "v",
+                '≤' => "<=",
+                '≥' => ">=",
+                '≈' => "~",
+                '—' | '–' | '·' => "-",
+                '█' => "#",
+                '▓' => "=",
+                '░' => "-",
+                '▁' => "_",
+                '▂' => ".",
+                '▃' => ":",
+                '▄' => "-",
+                '▅' => "=",
+                '▆' => "+",
+                '▇' => "*",
+                '─' | '━' | '═' => "-",
+                '│' | '┃' | '║' => "|",
+                '\u{2500}'..='\u{257F}' => "+",
+                // Seletor de variação de emoji e grau ("95°C" vira "95C")
+                '\u{FE0F}' | '°' => "",
+                c if c.is_alphabetic() => "?",
+                _ => {
+                    after_removed = true;
+                    continue;
+                }
+            };
+            output.push_str(replacement);
+        }
+        
+        output
+    }
+    
+    /// Letra sem o acento, para as letras acentuadas do português e do espanhol
+    fn without_accent(c: char) -> Option<char> {
+        let base = match c {
+            'á' | 'à' | 'â' | 'ã' | 'ä' => 'a',
+            'Á' | 'À' | 'Â' | 'Ã' | 'Ä' => 'A',
+            'é' | 'è' | 'ê' | 'ë' => 'e',
+            'É' | 'È' | 'Ê' | 'Ë' => 'E',
+            'í' | 'ì' | 'î' | 'ï' => 'i',
+            'Í' | 'Ì' | 'Î' | 'Ï' => 'I',
+            'ó' | 'ò' | 'ô' | 'õ' | 'ö' => 'o',
+            'Ó' | 'Ò' | 'Ô' | 'Õ' | 'Ö' => 'O',
+            'ú' | 'ù' | 'û' | 'ü' => 'u',
+            'Ú' | 'Ù' | 'Û' | 'Ü' => 'U',
+            'ç' => 'c',
+            'Ç' => 'C',
+            'ñ' => 'n',
+            'Ñ' => 'N',
+            _ => return None,
+        };
+        
+        Some(base)
+    }
+    
+    /// Unidade usada para exibir quantidades de bytes
+    /// 
+    /// A crate inteira (relatórios e pontuação) usa [`ByteUnit::Gib`], ou seja,
+    /// 1 GiB = 1024³ bytes, a mesma convenção do Gerenciador de Tarefas do
+    /// Windows. Um módulo vendido como "8 GB" (8 × 10⁹ bytes) aparece como
+    /// 7.45 GiB e, coerentemente, é pontuado como tendo menos de 8 GiB.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+    pub enum ByteUnit {
+        /// Gigabyte decimal (10⁹ bytes), usado por fabricantes de discos
+        Gb,
+        /// Gibibyte binário (1024³ bytes), usado nos relatórios e na pontuação
+        #[default]
+        Gib,
+    }
+    
+    impl ByteUnit {
+        /// Quantidade de bytes em uma unidade
+        pub fn bytes_per_unit(self) -> f64 {
+            match self {
+                ByteUnit::Gb => 1_000_000_000.0,
+                ByteUnit::Gib => 1_073_741_824.0,
+            }
+        }
+        
+        /// Sufixo exibido após o valor (ex: "GiB")
+        pub fn suffix(self) -> &'static str {
+            match self {
+                ByteUnit::Gb => "GB",
+                ByteUnit::Gib => "GiB",
+            }
+        }
+        
+        /// Converte bytes para esta unidade
+        pub fn convert(self, bytes: u64) -> f64 {
+            bytes as f64 / self.bytes_per_unit()
+        }
+    }
+    
+    /// Base das unidades usadas pelos relatórios gerados pela crate
+    /// 
+    /// Os relatórios escalam a unidade automaticamente com
+    /// [`bytes_to_human_readable`], sempre em base binária como esta.
+    pub const REPORT_UNIT: ByteUnit = ByteUnit::Gib;
+    
+    /// Converte bytes para gigabytes decimais (10⁹) com formatação
+    /// 
+    /// Os relatórios da crate usam [`bytes_to_gib`]; esta função existe para
+    /// quem precisa comparar com valores de fabricantes.
+    /// 
+    /// # Argumentos
+    /// * `bytes` - Quantidade em bytes
+    /// 
+    /// # Retorno
+    /// String formatada em GB com 2 casas decimais
+    pub fn bytes_to_gb(bytes: u64) -> String {
+        format!("{:.2}", bytes_to_gb_f64(bytes))
+    }
+    
+    /// Converte bytes para gigabytes decimais (10⁹) como valor numérico
+    pub fn bytes_to_gb_f64(bytes: u64) -> f64 {
+        ByteUnit::Gb.convert(bytes)
+    }
+    
+    /// Converte bytes para gibibytes binários (1024³) com formatação
+    /// 
+    /// É a conversão usada nos relatórios e na pontuação.
+    /// 
+    /// # Argumentos
+    /// * `bytes` - Quantidade em bytes
+    /// 
+    /// # Retorno
+    /// String formatada em GiB com 2 casas decimais
+    pub fn bytes_to_gib(bytes: u64) -> String {
+        format!("{:.2}", bytes_to_gib_f64(bytes))
+    }
+    
+    /// Converte bytes para gibibytes binários (1024³) como valor numérico
+    pub fn bytes_to_gib_f64(bytes: u64) -> f64 {
+        ByteUnit::Gib.convert(bytes)
+    }
+    
+    /// Formata bytes escolhendo a unidade binária (1024) conforme a magnitude
+    /// 
+    /// # Argumentos
+    /// * `bytes` - Quantidade em bytes
+    /// 
+    /// # Retorno
+    /// String como "512 B", "4.00 KiB", "1.50 GiB" ou "2.00 TiB"
+    pub fn bytes_to_human_readable(bytes: u64) -> String {
+        scale_bytes(bytes, 1024.0, &["B", "KiB", "MiB", "GiB", "TiB", "PiB"])
+    }
+    
+    /// Formata bytes escolhendo a unidade decimal (1000) conforme a magnitude
+    /// 
+    /// Útil para comparar com capacidades anunciadas por fabricantes.
+    /// 
+    /// # Retorno
+    /// String como "512 B", "4.00 KB", "1.50 GB" ou "2.00 TB"
+    pub fn bytes_to_human_readable_si(bytes: u64) -> String {
+        scale_bytes(bytes, 1000.0, &["B", "KB", "MB", "GB", "TB", "PB"])
+    }
+    
+    /// Divide pela base até o valor caber na unidade
+    fn scale_bytes(bytes: u64, base: f64, units: &[&str]) -> String {
+        let mut value = bytes as f64;
+        let mut unit = 0;
+        while value >= base && unit < units.len() - 1 {
+            value /= base;
+            unit += 1;
+        }
+        
+        if unit == 0 {
+            format!("{} {}", bytes, units[0])
+        } else {
+            format!("{:.2} {}", value, units[unit])
+        }
+    }
+    
+    /// Formata bytes na unidade indicada, incluindo o sufixo (ex: "7.45 GiB")
+    pub fn format_bytes(bytes: u64, unit: ByteUnit) -> String {
+        format!("{:.2} {}", unit.convert(bytes), unit.suffix())
+    }
+    
+    /// Formata um instante como timestamp ISO-8601 em UTC
+    /// 
+    /// # Argumentos
+    /// * `time` - Instante a ser formatado
+    /// 
+    /// # Retorno
+    /// String no formato `AAAA-MM-DDTHH:MM:SSZ` (ex: "2025-01-31T13:45:00Z")
+    pub fn format_iso8601(time: std::time::SystemTime) -> String {
+        let secs = time
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        
+        let days = (secs / 86_400) as i64;
+        let secs_of_day = secs % 86_400;
+        
+        // Conversão de dias desde 1970-01-01 para data civil (algoritmo de Howard Hinnant)
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let doe = z.rem_euclid(146_097);
+        let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = doy - (153 * mp + 2) / 5 + 1;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+        
+        format!("{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+            year, month, day,
+            secs_of_day / 3_600, (secs_of_day % 3_600) / 60, secs_of_day % 60
+        )
+    }
+    
+    /// Desliga as cores ANSI em todas as saídas da biblioteca
+    /// 
+    /// Usado pela opção `--no-color` da CLI. A desativação vale para o
+    /// restante do processo.
+    pub fn disable_ansi() {
+        set_ansi_enabled(false);
+    }
+    
+    /// Liga ou desliga as cores ANSI em todas as saídas da biblioteca
+    /// 
+    /// A biblioteca não lê o ambiente por conta própria: a CLI chama
+    /// `set_ansi_enabled(AnsiSupport::detect().is_enabled())` uma vez, no
+    /// início da execução.
+    pub fn set_ansi_enabled(enabled: bool) {
+        ANSI_ENABLED.store(enabled, Ordering::Relaxed);
+    }
+    
+    /// Indica se as funções de formatação podem emitir sequências `ESC [`
+    /// 
+    /// Falso após [`disable_ansi`] ou `set_ansi_enabled(false)`.
+    pub fn ansi_enabled() -> bool {
+        ANSI_ENABLED.load(Ordering::Relaxed)
+    }
+    
+    /// Serializa os testes que dependem da chave global de cores
+    #[cfg(test)]
+    static ANSI_TEST_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+    
+    /// Fixa a chave global de cores durante um teste
+    /// 
+    /// Mantém os demais testes de cores em espera e restaura o valor
+    /// anterior ao sair de escopo.
+    #[cfg(test)]
+    pub(crate) struct AnsiGuard {
+        previous: bool,
+        _lock: std::sync::MutexGuard<'static, ()>,
+    }
+    
+    #[cfg(test)]
+    impl AnsiGuard {
+        pub(crate) fn set(enabled: bool) -> AnsiGuard {
+            let lock = ANSI_TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+            let previous = ANSI_ENABLED.swap(enabled, Ordering::Relaxed);
+            AnsiGuard { previous, _lock: lock }
+        }
+    }
+    
+    #[cfg(test)]
+    impl Drop for AnsiGuard {
+        fn drop(&mut self) {
+            ANSI_ENABLED.store(self.previous, Ordering::Relaxed);
+        }
+    }
+    
+    /// Nome da plataforma em execução (ex: "Windows", "Linux")
+    /// 
+    /// Usado nos cabeçalhos, para não anunciar Windows em outros sistemas.
+    pub fn platform_name() -> &'static str {
+        match super::platform::current_os() {
+            "windows" => "Windows",
+            "linux" => "Linux",
+            "macos" => "macOS",
+            other => other,
+        }
+    }
+    
+    /// Indica se a saída deve usar cores ANSI
+    /// 
+    /// Retorna `true` apenas quando [`ansi_enabled`] permite cores e a
+    /// saída padrão é um terminal.
+    pub fn colors_enabled() -> bool {
+        use std::io::IsTerminal;
+        
+        ansi_enabled() && io::stdout().is_terminal()
+    }
+    
+    /// Formata uma frequência em MHz como GHz
+    /// 
+    /// # Argumentos
+    /// * `mhz` - Frequência em MHz (como em `CpuInfo::frequency`)
+    /// 
+    /// # Retorno
+    /// String com 2 casas decimais (ex: "3.60 GHz")
+    pub fn format_frequency_ghz(mhz: u64) -> String {
+        format!("{:.2} GHz", mhz as f64 / 1000.0)
+    }
+    
+    /// Formata uma frequência em GHz, ou em MHz quando abaixo de 1000 MHz
+    /// 
+    /// # Retorno
+    /// String como "800 MHz" ou "3.60 GHz"
+    pub fn format_frequency_auto(mhz: u64) -> String {
+        if mhz < 1000 {
+            format!("{} MHz", mhz)
+        } else {
+            format_frequency_ghz(mhz)
+        }
+    }
+    
+    /// Formata um tempo de atividade de forma legível
+    /// 
+    /// # Argumentos
+    /// * `seconds` - Duração em segundos
+    /// 
+    /// # Retorno
+    /// String como "3 dias, 4 horas, 12 minutos" ou "45 minutos". Partes
+    /// zeradas são omitidas; menos de um minuto resulta em "0 minutos".
+    pub fn format_uptime(seconds: u64) -> String {
+        let days = seconds / 86_400;
+        let hours = (seconds % 86_400) / 3_600;
+        let minutes = (seconds % 3_600) / 60;
+        
+        let unit = |value: u64, singular: &str, plural: &str| {
+            format!("{} {}", value, if value == 1 { singular } else { plural })
+        };
+        
+        let mut parts = Vec::new();
+        if days > 0 {
+            parts.push(unit(days, "dia", "dias"));
+        }
+        if hours > 0 {
+            parts.push(unit(hours, "hora", "horas"));
+        }
+        if minutes > 0 || parts.is_empty() {
+            parts.push(unit(minutes, "minuto", "minutos"));
+        }
+        
+        parts.join(", ")
+    }
+    
+    /// Formata uma barra de progresso com os caracteres de `style`
+    /// 
+    /// Percentuais fora de 0 a 100 são limitados, então a barra sempre tem
+    /// `width` caracteres entre os colchetes.
+    /// 
+    /// # Exemplo
+    /// ```
+    /// use hardware_diagnostic::engine::utils::{render_bar, BarStyle};
+    /// 
+    /// assert_eq!(render_bar(50.0, 10, BarStyle::Ascii), "[#####-----]");
+    /// ```
+    pub fn render_bar(percent: f64, width: usize, style: BarStyle) -> String {
+        let (filled, empty) = bar_split(percent, width);
+        format!(
+            "[{}{}]",
+            style.fill().to_string().repeat(filled),
+            style.empty().to_string().repeat(empty)
+        )
+    }
+    
+    /// Número de caracteres preenchidos e vazios de uma barra
+    fn bar_split(percent: f64, width: usize) -> (usize, usize) {
+        let filled = ((percent.clamp(0.0, 100.0) / 100.0) * width as f64).round() as usize;
+        (filled, width - filled)
+    }
+    
+    /// Formata uma barra de progresso para representar percentuais
+    /// 
+    /// Usa o estilo global de [`set_bar_style`]; veja [`render_bar`].
+    /// 
+    /// # Argumentos
+    /// * `percent` - Percentual (0.0 a 100.0)
+    /// * `width` - Largura da barra em caracteres
+    /// 
+    /// # Retorno
+    /// String representando a barra de progresso
+    pub fn progress_bar(percent: f64, width: usize) -> String {
+        render_bar(percent, width, bar_style())
+    }
+    
+    /// Formata uma barra de pontuação, proporcional a `score` de `max`
+    /// 
+    /// Diferente de [`progress_bar`], que representa percentuais de uso: a
+    /// parte preenchida usa `▓` (ou `=` com [`BarStyle::Ascii`]), para que
+    /// 8.3/10 não se confunda com 83% de uso, e um marcador `│` (`|` em
+    /// ASCII) ocupa a posição da pontuação. A barra continua com `width`
+    /// caracteres: o marcador fica na célula seguinte à parte preenchida, ou
+    /// na última quando a pontuação é máxima. Pontuações fora de 0 a `max`
+    /// são limitadas; com `max` não positivo, o marcador fica no início.
+    /// 
+    /// # Exemplo
+    /// ```
+    /// use hardware_diagnostic::engine::utils::format_score_bar;
+    /// 
+    /// assert_eq!(format_score_bar(8.3, 10.0, 10), "[▓▓▓▓▓▓▓▓│░]");
+    /// assert_eq!(format_score_bar(12.0, 10.0, 4), "[▓▓▓│]");
+    /// ```
+    pub fn format_score_bar(score: f64, max: f64, width: usize) -> String {
+        let percent = if max > 0.0 { score / max * 100.0 } else { 0.0 };
+        let (fill, marker, empty_char) = match bar_style() {
+            BarStyle::Unicode => ('▓', '│', '░'),
+            BarStyle::Ascii => ('=', '|', '-'),
+            style => (style.fill(), '|', style.empty()),
+        };
+        if width == 0 {
+            return "[]".to_string();
+        }
+        let filled = bar_split(percent, width).0.min(width - 1);
+        format!(
+            "[{}{}{}]",
+            fill.to_string().repeat(filled),
+            marker,
+            empty_char.to_string().repeat(width - filled - 1)
+        )
+    }
+    
+    /// Formata uma barra de pontuação compacta, com rótulo e valor
+    /// 
+    /// Para exibir várias pontuações alinhadas em poucas linhas; veja
+    /// [`format_score_bar`].
+    /// 
+    /// # Exemplo
+    /// ```
+    /// use hardware_diagnostic::engine::utils::format_score_bar_with_label;
+    /// 
+    /// assert_eq!(format_score_bar_with_label(7.5, 10.0, "CPU", 4), "CPU [▓▓▓│] 7.5/10");
+    /// ```
+    pub fn format_score_bar_with_label(score: f64, max: f64, label: &str, width: usize) -> String {
+        format!("{} {} {:.1}/{}", label, format_score_bar(score, max, width), score, max)
+    }
+    
+    /// Formata uma barra de progresso com a parte preenchida colorida
+    /// 
+    /// A cor indica a gravidade: verde abaixo de 70%, amarelo de 70% até
+    /// 85% e vermelho a partir de 85%. Após [`disable_ansi`], o resultado é
+    /// idêntico ao de [`progress_bar`].
+    /// 
+    /// # Argumentos
+    /// * `percent` - Percentual (0.0 a 100.0)
+    /// * `width` - Largura da barra em caracteres
+    pub fn progress_bar_colored(percent: f64, width: usize) -> String {
+        if !ansi_enabled() {
+            return progress_bar(percent, width);
+        }
+        
+        let (filled, empty) = bar_split(percent, width);
+        let style = bar_style();
+        let color = if percent < 70.0 {
+            "\x1b[32m" // Verde
+        } else if percent < 85.0 {
+            "\x1b[33m" // Amarelo
+        } else {
+            "\x1b[31m" // Vermelho
+        };
+        
+        format!(
+            "[{}{}{}{}]",
+            color,
+            style.fill().to_string().repeat(filled),
+            PerformanceCategory::reset_color(),
+            style.empty().to_string().repeat(empty)
+        )
+    }
+    
+    /// Remove as sequências de cor ANSI (`ESC [ ... letra`) de um texto
+    /// 
+    /// Para saídas que não interpretam cores, como Markdown.
+    pub fn strip_ansi(text: &str) -> String {
+        let mut plain = String::with_capacity(text.len());
+        let mut chars = text.chars().peekable();
+        
+        while let Some(c) = chars.next() {
+            if c == '\x1b' && chars.peek() == Some(&'[') {
+                // Parâmetros até o byte final da sequência (de '@' a '~')
+                chars.next();
+                for c in chars.by_ref() {
+                    if ('@'..='~').contains(&c) {
+                        break;
+                    }
+                }
+            } else {
+                plain.push(c);
+            }
+        }
+        plain
+    }
+    
+    /// Compara duas pontuações: `a` é a anterior e `b`, a atual
+    /// 
+    /// O mesmo que `b.compare(a)`: as variações ficam em cada
+    /// [`super::ScoreDelta`] do [`ScoreDiff`] retornado.
+    /// 
+    /// # Exemplo
+    /// ```no_run
+    /// use hardware_diagnostic::calculate_performance_score;
+    /// use hardware_diagnostic::engine::utils::compare_scores;
+    /// 
+    /// let before = calculate_performance_score();
+    /// // ... manutenção ...
+    /// let after = calculate_performance_score();
+    /// let diff = compare_scores(&before, &after);
+    /// println!("{}", diff.to_text());
+    /// if diff.category_degraded {
+    ///     println!("A máquina mudou para uma categoria pior");
+    /// }
+    /// ```
+    pub fn compare_scores(a: &PerformanceScore, b: &PerformanceScore) -> ScoreDiff {
+        b.compare(a)
+    }
+    
+    /// Colunas do CSV de [`report_to_csv`] e [`DiagnosticReport::to_csv`], na mesma ordem
+    /// 
+    /// As 13 primeiras descrevem a máquina e o disco principal; as demais
+    /// trazem as pontuações por componente, os discos agregados e o hostname.
+    const CSV_COLUMNS: [&str; 21] = [
+        "timestamp",
+        "cpu_name",
+        "cpu_cores",
+        "cpu_usage_pct",
+        "cpu_freq_mhz",
+        "ram_total_gb",
+        "ram_used_gb",
+        "ram_usage_pct",
+        "primary_disk_name",
+        "primary_disk_total_gb",
+        "primary_disk_usage_pct",
+        "overall_score",
+        "category",
+        "cpu_score",
+        "ram_score",
+        "disk_score",
+        "gpu_score",
+        "disk_count",
+        "disk_total_gb",
+        "disk_free_gb",
+        "hostname",
+    ];
+    
+    /// Retorna a linha de cabeçalho do CSV gerado por [`report_to_csv`]
+    pub fn csv_header() -> String {
+        CSV_COLUMNS.join(",")
+    }
+    
+    /// Gera uma única linha CSV com o resumo da máquina
+    /// 
+    /// Pensada para consolidar diagnósticos de várias máquinas em uma planilha:
+    /// cada execução produz uma linha, e [`csv_header`] fornece o cabeçalho.
+    /// Usa o instante e o hostname atuais; para os de uma captura, use
+    /// [`DiagnosticReport::to_csv_row`], que gera as mesmas colunas.
+    /// 
+    /// # Exemplo
+    /// ```
+    /// use hardware_diagnostic::{calculate_performance_score, cpu_info, ram_info, disk_info};
+    /// use hardware_diagnostic::engine::utils::{csv_header, report_to_csv};
+    /// 
+    /// let score = calculate_performance_score();
+    /// println!("{}", csv_header());
+    /// println!("{}", report_to_csv(&score, &cpu_info(), &ram_info(), &disk_info()));
+    /// ```
+    pub fn report_to_csv(score: &PerformanceScore, cpu: &CpuInfo, ram: &RamInfo, disks: &[DiskInfo]) -> String {
+        let hostname = System::host_name().unwrap_or_default();
+        csv_row(std::time::SystemTime::now(), &hostname, score, cpu, ram, disks)
+    }
+    
+    /// Monta a linha de [`CSV_COLUMNS`]
+    /// 
+    /// Entre vários discos, o "principal" é o de maior capacidade; sem
+    /// discos, os campos dele ficam vazios. Tamanhos em GiB (1024³), com duas
+    /// casas; pontuações ausentes (ex: GPU) ficam vazias.
+    pub(crate) fn csv_row(
+        captured_at: std::time::SystemTime,
+        hostname: &str,
+        score: &PerformanceScore,
+        cpu: &CpuInfo,
+        ram: &RamInfo,
+        disks: &[DiskInfo],
+    ) -> String {
+        let gib = |bytes: u64| format!("{:.2}", bytes_to_gib_f64(bytes));
+        let primary_disk = disks.iter().max_by_key(|disk| disk.total_space);
+        
+        let fields = [
+            format_iso8601(captured_at),
+            cpu.name.clone(),
+            cpu.number_cpus.to_string(),
+            format!("{:.1}", cpu.cpu_usage),
+            cpu.frequency.to_string(),
+            gib(ram.total_ram),
+            gib(ram.used_ram),
+            format!("{:.1}", ram.ram_usage_percent),
+            primary_disk.map(|disk| disk.name.clone()).unwrap_or_default(),
+            primary_disk.map(|disk| gib(disk.total_space)).unwrap_or_default(),
+            primary_disk.map(|disk| format!("{:.1}", disk.usage_percent)).unwrap_or_default(),
+            format!("{:.2}", score.overall_score),
+            score.category.to_ascii_name().to_string(),
+            optional_score(score.cpu_score),
+            optional_score(score.ram_score),
+            optional_score(score.disk_score),
+            optional_score(score.gpu_score),
+            disks.len().to_string(),
+            gib(disks.iter().map(|d| d.total_space).sum()),
+            gib(disks.iter().map(|d| d.available_space).sum()),
+            hostname.to_string(),
+        ];
+        
+        fields.iter().map(|f| csv_escape(f)).collect::<Vec<_>>().join(",")
+    }
+    
+    /// Formata uma pontuação opcional; ausente vira campo vazio
+    fn optional_score(score: Option<f64>) -> String {
+        score.map(|s| format!("{:.2}", s)).unwrap_or_default()
+    }
+    
+    /// Escapa um campo CSV conforme a RFC 4180
+    pub(crate) fn csv_escape(field: &str) -> String {
+        if field.contains([',', '"', '\n', '\r']) {
+            format!("\"{}\"", field.replace('"', "\"\""))
+        } else {
+            field.to_string()
+        }
+    }
+    
+    /// Gera as métricas no formato de texto do Prometheus
+    /// 
+    /// Cada métrica é um gauge com linhas `# HELP` e `# TYPE` e o prefixo
+    /// `hardware_`. As métricas de disco levam os rótulos `disk` e `mount`;
+    /// pontuações ausentes (ex: GPU) não são emitidas. A categoria sai como
+    /// número em `hardware_performance_category` (1 descarte a 4 bom estado). Pensado para ser servido por um endpoint
+    /// `/metrics` raspado periodicamente.
+    /// 
+    /// # Exemplo
+    /// ```
+    /// use hardware_diagnostic::{calculate_performance_score, cpu_info, ram_info, disk_info};
+    /// use hardware_diagnostic::engine::utils::to_prometheus;
+    /// 
+    /// let score = calculate_performance_score();
+    /// let metrics = to_prometheus(&score, &cpu_info(), &ram_info(), &disk_info());
+    /// assert!(metrics.contains("hardware_performance_score"));
+    /// ```
+    pub fn to_prometheus(score: &PerformanceScore, cpu: &CpuInfo, ram: &RamInfo, disks: &[DiskInfo]) -> String {
+        let mut output = String::new();
+        let mut gauge = |name: &str, help: &str, samples: &[(String, f64)]| {
+            if samples.is_empty() {
+                return;
+            }
+            output.push_str(&format!("# HELP hardware_{} {}\n", name, help));
+            output.push_str(&format!("# TYPE hardware_{} gauge\n", name));
+            for (labels, value) in samples {
+                output.push_str(&format!("hardware_{}{} {}\n", name, labels, value));
+            }
+        };
+        let single = |value: f64| vec![(String::new(), value)];
+        let disk_labels = |disk: &DiskInfo| {
+            format!(
+                "{{disk=\"{}\",mount=\"{}\"}}",
+                prometheus_escape(&disk.name),
+                prometheus_escape(&disk.mount_point)
+            )
+        };
+        let per_disk = |value: fn(&DiskInfo) -> f64| {
+            disks.iter().map(|disk| (disk_labels(disk), value(disk))).collect::<Vec<_>>()
+        };
+        
+        gauge("performance_score", "Pontuação geral de desempenho (0 a 10)", &single(score.overall_score));
+        let components: Vec<(String, f64)> = [
+            ("cpu", score.cpu_score),
+            ("ram", score.ram_score),
+            ("disk", score.disk_score),
+            ("gpu", score.gpu_score),
+            ("battery", score.battery_score),
+        ]
+        .into_iter()
+        .filter_map(|(component, value)| Some((format!("{{component=\"{}\"}}", component), value?)))
+        .collect();
+        gauge("component_score", "Pontuação de cada componente (0 a 10)", &components);
+        gauge(
+            "performance_category_info",
+            "Categoria de desempenho atual (1 na categoria ativa)",
+            &[(format!("{{category=\"{}\"}}", score.category.to_ascii_name()), 1.0)],
+        );
+        let level = PerformanceCategory::all()
+            .iter()
+            .position(|category| *category == score.category)
+            .map_or(0.0, |index| index as f64 + 1.0);
+        gauge(
+            "performance_category",
+            "Categoria como número: 1 descarte, 2 manutenção, 3 precaução, 4 bom estado",
+            &single(level),
+        );
+        
+        gauge("cpu_usage_percent", "Uso total da CPU em percentual", &single(f64::from(cpu.cpu_usage)));
+        gauge("cpu_frequency_mhz", "Frequência atual da CPU em MHz", &single(cpu.frequency as f64));
+        gauge("cpu_logical_cores", "Número de núcleos lógicos", &single(cpu.number_cpus as f64));
+        
+        gauge("ram_total_bytes", "Memória RAM total em bytes", &single(ram.total_ram as f64));
+        gauge("ram_used_bytes", "Memória RAM em uso em bytes", &single(ram.used_ram as f64));
+        gauge("ram_usage_percent", "Uso da RAM em percentual", &single(ram.ram_usage_percent));
+        gauge("swap_usage_percent", "Uso do SWAP em percentual", &single(ram.swap_usage_percent));
+        
+        gauge("disk_total_bytes", "Capacidade total do disco em bytes", &per_disk(|d| d.total_space as f64));
+        gauge("disk_available_bytes", "Espaço livre do disco em bytes", &per_disk(|d| d.available_space as f64));
+        gauge("disk_usage_percent", "Uso do disco em percentual", &per_disk(|d| d.usage_percent));
+        let disk_scores: Vec<(String, f64)> = disks
+            .iter()
+            .filter_map(|disk| {
+                let (_, value) = score.per_disk_scores.iter().find(|(name, _)| *name == disk.name)?;
+                Some((disk_labels(disk), *value))
+            })
+            .collect();
+        gauge("disk_score", "Pontuação de cada disco (0 a 10)", &disk_scores);
+        
+        output
+    }
+    
+    /// Escapa o valor de um rótulo do Prometheus (barra invertida, aspas e quebra de linha)
+    fn prometheus_escape(value: &str) -> String {
+        value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+    }
+    
+    /// Acrescenta a linha CSV de um relatório a um arquivo de histórico
+    /// 
+    /// Cria o arquivo se necessário e grava o cabeçalho de
+    /// [`DiagnosticReport::to_csv`] apenas quando ele está vazio, de modo que
+    /// execuções sucessivas formem um único CSV válido.
+    /// 
+    /// # Erros
+    /// Retorna `DiagnosticError::Io` se o arquivo não puder ser aberto ou gravado.
+    /// 
+    /// # Exemplo
+    /// ```no_run
+    /// use std::path::Path;
+    /// use hardware_diagnostic::DiagnosticReport;
+    /// use hardware_diagnostic::engine::utils::append_csv_row;
+    /// 
+    /// append_csv_row(Path::new("historico.csv"), &DiagnosticReport::capture()).unwrap();
+    /// ```
+    pub fn append_csv_row(path: &std::path::Path, report: &DiagnosticReport) -> Result<(), DiagnosticError> {
+        use std::io::Write;
+        
+        let io_error = |e: io::Error| DiagnosticError::Io {
+            path: path.display().to_string(),
+            source: e.to_string(),
+        };
+        
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(io_error)?;
+        
+        if file.metadata().map_err(io_error)?.len() == 0 {
+            writeln!(file, "{}", DiagnosticReport::csv_header()).map_err(io_error)?;
+        }
+        writeln!(file, "{}", report.to_csv_row()).map_err(io_error)
+    }
+    
+    /// Acrescenta a pontuação, com o instante atual, como uma linha JSON (JSONL)
+    /// 
+    /// O arquivo é criado se não existir. Leia o histórico de volta com
+    /// [`load_snapshots`] ou, para as estatísticas e o gráfico, com
+    /// [`ScoreHistory::load`](history::ScoreHistory::load).
+    /// 
+    /// # Erros
+    /// Retorna `DiagnosticError::Io` se o arquivo não puder ser aberto ou gravado.
+    /// 
+    /// # Exemplo
+    /// ```no_run
+    /// use std::path::Path;
+    /// use hardware_diagnostic::calculate_performance_score;
+    /// use hardware_diagnostic::engine::utils::{append_snapshot, load_snapshots};
+    /// 
+    /// let path = Path::new("historico.jsonl");
+    /// append_snapshot(path, &calculate_performance_score()).unwrap();
+    /// println!("{} execuções registradas", load_snapshots(path).len());
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn append_snapshot(path: &std::path::Path, score: &PerformanceScore) -> Result<(), DiagnosticError> {
+        use std::io::Write;
+        
+        let io_error = |e: io::Error| DiagnosticError::Io {
+            path: path.display().to_string(),
+            source: e.to_string(),
+        };
+        let snapshot = TimestampedScore {
+            timestamp: std::time::SystemTime::now(),
+            score: score.clone(),
+        };
+        // Todos os campos são serializáveis; a falha não é esperada
+        let line = serde_json::to_string(&snapshot).unwrap_or_default();
+        
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(io_error)?;
+        writeln!(file, "{}", line).map_err(io_error)
+    }
+    
+    /// Lê as pontuações gravadas por [`append_snapshot`], da mais antiga para a mais recente
+    /// 
+    /// Um arquivo inexistente resulta em lista vazia. Linhas vazias ou
+    /// inválidas (ex: uma gravação interrompida) são ignoradas.
+    #[cfg(feature = "serde")]
+    pub fn load_snapshots(path: &std::path::Path) -> Vec<TimestampedScore> {
+        fs::read_to_string(path)
+            .unwrap_or_default()
+            .lines()
+            .filter_map(|line| serde_json::from_str(line).ok())
+            .collect()
+    }
+    
+    /// Ajusta a reta de [`history::fit_trend`] às pontuações gerais gravadas
+    /// por [`append_snapshot`]
+    /// 
+    /// # Retorno
+    /// `None` com menos de duas pontuações ou com todas no mesmo instante.
+    pub fn fit_score_trend(snapshots: &[TimestampedScore]) -> Option<history::TrendFit> {
+        let points: Vec<(std::time::SystemTime, f64)> = snapshots
+            .iter()
+            .map(|snapshot| (snapshot.timestamp, snapshot.score.overall_score))
+            .collect();
+        history::fit_trend(&points)
+    }
+    
+    /// Tendência das pontuações gerais pela reta de [`fit_score_trend`]
+    /// 
+    /// Para considerar só as execuções recentes, passe o fim da lista
+    /// (ex: as últimas 30 de [`load_snapshots`]). Com histórico
+    /// insuficiente, a tendência é [`history::ScoreTrend::Stable`].
+    /// 
+    /// # Exemplo
+    /// ```
+    /// use hardware_diagnostic::TimestampedScore;
+    /// use hardware_diagnostic::engine::utils::analyze_trend;
+    /// 
+    /// fn print_trend(snapshots: &[TimestampedScore]) {
+    ///     let recent = &snapshots[snapshots.len().saturating_sub(30)..];
+    ///     println!("{:?}", analyze_trend(recent));
+    /// }
+    /// ```
+    pub fn analyze_trend(snapshots: &[TimestampedScore]) -> history::ScoreTrend {
+        fit_score_trend(snapshots).map_or(history::ScoreTrend::Stable, |fit| fit.trend())
+    }
+    
+    /// Gera um relatório formatado de informações do sistema
+    /// 
+    /// Tamanhos são exibidos com [`bytes_to_human_readable`] (base 1024).
+    pub fn generate_report() -> String {
+        let mut report = format_hardware_sections(&os_info(), &cpu_info(), &ram_info(), &memory::memory_layout(), &disk_info());
+        report.push_str(&format_environment_sections(bios::bios_info().ok().as_ref()));
+        report
+    }
+    
+    /// Formata as seções de sistema, CPU, memória e armazenamento
+    pub(crate) fn format_hardware_sections(
+        os: &OsInfo,
+        cpu: &CpuInfo,
+        ram: &RamInfo,
+        memory: &MemoryLayout,
+        disks: &[DiskInfo],
+    ) -> String {
+        let mut report = format_system_section(os);
+        report.push_str(&format_cpu_section(cpu));
+        report.push_str(&format_ram_section(ram, memory));
+        report.push_str(&format_disk_section(disks));
+        report
+    }
+    
+    /// Formata a seção do sistema operacional
+    pub fn format_system_section(os: &OsInfo) -> String {
+        let mut report = String::new();
+        
+        report.push_str("=== INFORMACOES DO SISTEMA ===\n");
+        report.push_str(&format!("Sistema operacional: {} {}\n", os.os_name, os.os_version));
+        report.push_str(&format!("Kernel: {}\n", os.kernel_version));
+        report.push_str(&format!("Plataforma: {}\n", os.platform));
+        if let Some(kind) = os.virtualization {
+            report.push_str(&format!("Virtualização: {} (valores refletem os recursos alocados)\n", kind));
+        }
+        report.push_str(&format!("Hostname: {}\n", os.hostname));
+        report.push_str(&format!("Tempo ligado: {}\n", format_uptime(os.uptime_seconds)));
+        report.push_str(&format!("Inicializado em: {}\n",
+            format_iso8601(std::time::UNIX_EPOCH + Duration::from_secs(os.boot_time))));
+        report.push('\n');
+        
+        render_text(&report)
+    }
+    
+    /// Formata a seção da CPU, com a tabela de uso por núcleo
+    pub fn format_cpu_section(cpu: &CpuInfo) -> String {
+        let mut report = String::new();
+        
+        report.push_str("=== INFORMACOES DA CPU ===\n");
+        report.push_str(&format!("Modelo: {}\n", cpu.name));
+        report.push_str(&format!("Núcleos lógicos: {}\n", cpu.number_cpus));
+        if let Some(physical) = cpu.physical_cores {
+            report.push_str(&format!("Núcleos físicos: {}\n", physical));
+        }
+        report.push_str(&format!("Frequência: {}\n", format_frequency_auto(cpu.frequency)));
+        report.push_str(&format!("Uso atual: {:.1}%\n", cpu.cpu_usage));
+        report.push_str(&format!("Barra: {}\n", progress_bar(cpu.cpu_usage as f64, 20)));
+        if let Some(vulnerabilities) = &cpu.vulnerabilities {
+            let unmitigated = cpu.unmitigated_vulnerabilities();
+            report.push_str(&format!("Vulnerabilidades: {} verificadas, {} sem mitigação\n",
+                vulnerabilities.len(), unmitigated.len()));
+            for status in unmitigated {
+                report.push_str(&format!("  ⚠️ {}: {}\n", status.name, status.description));
+            }
+        }
+        
+        // Tabela por núcleo
+        if !cpu.per_core_usage.is_empty() {
+            report.push_str("Uso por núcleo:\n");
+            report.push_str("  Núcleo   Uso      Frequência\n");
+            for (i, usage) in cpu.per_core_usage.iter().enumerate() {
+                let frequency = cpu.per_core_frequency.get(i).copied().unwrap_or(0);
+                report.push_str(&format!("  #{:<6} {:>5.1}%   {}\n", i, usage, format_frequency_auto(frequency)));
+            }
+        }
+        report.push('\n');
+        
+        render_text(&report)
+    }
+    
+    /// Formata a seção de memória: RAM, pentes instalados e SWAP
+    pub fn format_ram_section(ram: &RamInfo, memory: &MemoryLayout) -> String {
+        let fmt = bytes_to_human_readable;
+        let mut report = String::new();
+        
+        report.push_str("=== INFORMACOES DE MEMORIA ===\n");
+        report.push_str(&format!("RAM Total: {}\n", fmt(ram.total_ram)));
+        report.push_str(&format!("RAM Usada: {} ({:.1}%)\n", 
+            fmt(ram.used_ram), ram.ram_usage_percent));
+        report.push_str(&format!("RAM Livre: {}\n", fmt(ram.free_ram)));
+        report.push_str(&format!("Barra: {}\n", progress_bar(ram.ram_usage_percent, 20)));
+        
+        // Tipo e velocidade definem quais pentes servem em um upgrade
+        let kind = [ram.memory_type.clone(), ram.speed_mhz.map(|mhz| format!("{} MHz", mhz))];
+        let kind: Vec<String> = kind.into_iter().flatten().collect();
+        if !kind.is_empty() {
+            report.push_str(&format!("Tipo: {}\n", kind.join(", ")));
+        }
+        
+        // Pentes instalados: indica se há slots livres para upgrade
+        if let Some(summary) = memory.slot_summary() {
+            report.push_str(&format!("\nPentes: {}\n", summary));
+            for module in &memory.modules {
+                let speed = if module.speed_mhz > 0 {
+                    format!(", {} MHz", module.speed_mhz)
+                } else {
+                    String::new()
+                };
+                report.push_str(&format!("  {}: {}{}{}\n",
+                    module.slot,
+                    fmt(module.size_bytes),
+                    speed,
+                    module.manufacturer.as_ref().map(|m| format!(", {}", m)).unwrap_or_default()));
+            }
+        }
+        
+        if ram.total_swap > 0 {
+            report.push_str(&format!("\nSWAP Total: {}\n", fmt(ram.total_swap)));
+            report.push_str(&format!("SWAP Usado: {} ({:.1}%)\n", 
+                fmt(ram.used_swap), ram.swap_usage_percent));
+        }
+        report.push('\n');
+        
+        render_text(&report)
+    }
+    
+    /// Formata a seção de armazenamento, um bloco por disco
+    pub fn format_disk_section(disks: &[DiskInfo]) -> String {
+        let fmt = bytes_to_human_readable;
+        let mut report = String::new();
+        
+        report.push_str("=== INFORMACOES DE ARMAZENAMENTO ===\n");
+        if disks.is_empty() {
+            report.push_str("Nenhum disco encontrado.\n");
+        } else {
+            for (i, disk) in disks.iter().enumerate() {
+                report.push_str(&format!("\nDisco {}:\n", i + 1));
+                report.push_str(&format!("  Nome: {}\n", disk.name));
+                report.push_str(&format!("  Ponto de montagem: {}\n", disk.mount_point));
+                report.push_str(&format!("  Sistema de arquivos: {}\n", disk.file_system));
+                report.push_str(&format!("  Tipo: {}\n", disk.kind));
+                report.push_str(&format!("  Capacidade: {}\n", fmt(disk.total_space)));
+                report.push_str(&format!("  Usado: {}\n", fmt(disk.used_space)));
+                report.push_str(&format!("  Livre: {}\n", fmt(disk.available_space)));
+                report.push_str(&format!("  Uso: {:.1}%\n", disk.usage_percent));
+                report.push_str(&format!("  Barra: {}\n", progress_bar(disk.usage_percent, 20)));
+                report.push_str(&format!("  E/S: leitura {}/s ({} IOPS), escrita {}/s ({} IOPS), ocupação {:.1}%\n",
+                    fmt(disk.read_bytes_per_sec), disk.read_iops,
+                    fmt(disk.write_bytes_per_sec), disk.write_iops,
+                    disk.io_utilization_percent));
+                if let Some(smart) = &disk.health {
+                    report.push_str(&format!("  SMART: {}\n", smart.health_status));
+                    if let Some(sectors) = smart.reallocated_sectors {
+                        report.push_str(&format!("  Setores realocados: {}\n", sectors));
+                    }
+                    if let Some(sectors) = smart.pending_sectors {
+                        report.push_str(&format!("  Setores pendentes: {}\n", sectors));
+                    }
+                    if let Some(errors) = smart.uncorrectable_errors {
+                        report.push_str(&format!("  Erros incorrigíveis: {}\n", errors));
+                    }
+                    if let Some(celsius) = smart.temperature_celsius {
+                        report.push_str(&format!("  Temperatura: {}°C\n", celsius));
+                    }
+                    if let Some(hours) = smart.power_on_hours {
+                        report.push_str(&format!("  Horas ligado: {}\n", hours));
+                    }
+                }
+            }
+        }
+        report.push('\n');
+        
+        render_text(&report)
+    }
+    
+    /// Formata a linha da tendência da pontuação, logo após a pontuação
+    /// 
+    /// Retorna uma string vazia sem tendência (histórico insuficiente).
+    pub(crate) fn format_trend(trend: Option<&history::TrendFit>) -> String {
+        trend
+            .map(|fit| render_text(&format!("{}\n", fit.summary(Language::default()))))
+            .unwrap_or_default()
+    }
+    
+    /// Formata a seção dos processos que mais consomem CPU e memória
+    /// 
+    /// Retorna uma string vazia quando as duas listas estão vazias.
+    pub(crate) fn format_top_processes(by_cpu: &[ProcessInfo], by_memory: &[ProcessInfo]) -> String {
+        if by_cpu.is_empty() && by_memory.is_empty() {
+            return String::new();
+        }
+        
+        let mut report = String::from("\n=== TOP PROCESSES ===\n");
+        for (title, list) in [("Por CPU", by_cpu), ("Por memória", by_memory)] {
+            report.push_str(&format!("{}:\n", title));
+            report.push_str("  PID      CPU      Memória     Nome\n");
+            for process in list {
+                report.push_str(&format!("  {:<8} {:>5.1}%   {:>10}  {}\n",
+                    process.pid, process.cpu_usage, bytes_to_human_readable(process.memory_bytes), process.name));
+            }
+        }
+        render_text(&report)
+    }
+    
+    /// Formata a seção do BIOS já coletado e as de bateria, ventoinhas e
+    /// rede, consultadas no momento da chamada
+    pub(crate) fn format_environment_sections(bios: Option<&bios::BiosInfo>) -> String {
+        let fmt = bytes_to_human_readable;
+        let mut report = String::new();
+        
+        // Seção BIOS/UEFI (versão e data para auditorias de microcódigo)
+        if let Some(bios) = bios {
+            report.push_str("=== BIOS/FIRMWARE ===\n");
+            report.push_str(&format!("Fabricante: {}\n", bios.manufacturer));
+            report.push_str(&format!("Versão: {}\n", bios.version));
+            if !bios.release_date.is_empty() {
+                report.push_str(&format!("Data: {}\n", bios.release_date));
+            }
+            report.push_str(&format!("Secure Boot: {}\n", match bios.secure_boot_enabled {
+                Some(true) => "Ativado",
+                Some(false) => "Desativado",
+                None => "Indisponível (BIOS legado ou desconhecido)",
+            }));
+            report.push('\n');
+        }
+        
+        // Seção Bateria (apenas notebooks)
+        if let Some(battery) = battery::battery_info() {
+            report.push_str("=== INFORMACOES DA BATERIA ===\n");
+            report.push_str(&format!("Carga: {:.0}%\n", battery.charge_percent));
+            report.push_str(&format!("Estado: {}\n", battery.state.description()));
+            if let Some(health) = battery.health_percent {
+                report.push_str(&format!("Saúde: {:.1}%\n", health));
+            }
+            if let (Some(full), Some(design)) = (battery.full_capacity_wh, battery.design_capacity_wh) {
+                report.push_str(&format!("Capacidade: {:.1} Wh de {:.1} Wh de projeto\n", full, design));
+            }
+            if let Some(cycles) = battery.cycle_count {
+                report.push_str(&format!("Ciclos de carga: {}\n", cycles));
+            }
+            if let Some(minutes) = battery.estimated_minutes_remaining {
+                report.push_str(&format!("Autonomia estimada: {} min\n", minutes));
+            }
+            report.push('\n');
+        }
+        
+        // Seção Ventoinhas (apenas com sensores expostos)
+        let fans = fans::fan_info();
+        if !fans.is_empty() {
+            report.push_str("=== VENTOINHAS ===\n");
+            for fan in &fans {
+                match fan.rpm {
+                    Some(rpm) => report.push_str(&format!("{}: {} RPM\n", fan.label, rpm)),
+                    None => report.push_str(&format!("{}: rotação não informada\n", fan.label)),
+                }
+            }
+            for fan in fans::stalled_fans(&fans, &temperature_info()) {
+                report.push_str(&format!("⚠️ {}: parada com a CPU quente, possível falha de refrigeração\n", fan.label));
+            }
+            report.push('\n');
+        }
+        
+        // Seção Monitores (vazia em servidores sem monitor)
+        let displays = display::display_info();
+        if !displays.is_empty() {
+            report.push_str("=== MONITORES ===\n");
+            for display in &displays {
+                report.push_str(&format!("{}\n", display));
+            }
+            report.push('\n');
+        }
+        
+        // Seção Rede
+        report.push_str("=== INFORMACOES DE REDE ===\n");
+        let networks = network_info();
+        if networks.is_empty() {
+            report.push_str("Nenhuma interface de rede encontrada.\n");
+        } else {
+            for net in &networks {
+                report.push_str(&format!("\nInterface {}:\n", net.name));
+                report.push_str(&format!("  MAC: {}\n", net.mac_address));
+                if !net.ip_addresses.is_empty() {
+                    report.push_str(&format!("  IPs: {}\n", net.ip_addresses.join(", ")));
+                }
+                report.push_str(&format!("  Status: {}\n", if net.is_up { "Ativa" } else { "Inativa" }));
+                report.push_str(&format!("  Recebido: {}\n", fmt(net.total_received_bytes)));
+                report.push_str(&format!("  Transmitido: {}\n", fmt(net.total_transmitted_bytes)));
+            }
+            report.push_str(&format!("\nConectividade: {}\n",
+                if has_network_connectivity(&networks) { "OK" } else { "Sem conexão" }));
+        }
+        
+        render_text(&report)
+    }
+    
+    /// Gera um relatório completo incluindo a pontuação de desempenho
+    /// 
+    /// Hardware e pontuação vêm de um único [`collect_snapshot`], então os
+    /// valores exibidos são os mesmos usados na pontuação.
+    pub fn generate_complete_report() -> String {
+        format_complete_report(&collect_snapshot())
+    }
+    
+    /// Formata o relatório completo a partir de dados já coletados
+    /// 
+    /// Apenas as seções de bateria e rede são consultadas no momento da chamada.
+    pub fn format_complete_report(snapshot: &SystemSnapshot) -> String {
+        let mut report = format_hardware_sections(&snapshot.os, &snapshot.cpu, &snapshot.ram, &snapshot.memory, &snapshot.disks);
+        report.push_str(&format_environment_sections(snapshot.bios.as_ref()));
+        report.push('\n');
+        // Relatórios vão para arquivo: nunca com cores
+        report.push_str(&display_performance_score_with_boundaries(&snapshot.score, false, Language::default(), &snapshot.categories));
+        report.push_str(&format_trend(snapshot.trend.as_ref()));
+        report.push_str(&format_top_processes(&snapshot.top_cpu_processes, &snapshot.top_memory_processes));
+        report
+    }
+
+    /// Gera o relatório HTML autocontido (CSS embutido, abre sem internet)
+    /// 
+    /// Mesmo conteúdo de [`DiagnosticReport::to_html`] para uma captura nova.
+    pub fn generate_html_report() -> String {
+        DiagnosticReport::capture().to_html()
+    }
+    
+    /// Gera o relatório em Markdown, para issues e chamados de suporte
+    /// 
+    /// Mesmo conteúdo de [`DiagnosticReport::to_markdown`] para uma captura
+    /// nova: seções `##`, tabela de discos, recomendações em lista e nenhuma
+    /// cor ANSI.
+    pub fn generate_markdown_report() -> String {
+        DiagnosticReport::capture().to_markdown()
+    }
+    
+    /// Gera o relatório completo em JSON, para outros programas
+    /// 
+    /// Mesmo conteúdo de [`DiagnosticReport::to_json`] para uma captura nova,
+    /// dentro de um [`report::ReportEnvelope`].
+    /// 
+    /// # Exemplo
+    /// ```
+    /// use hardware_diagnostic::engine::utils::generate_report_json;
+    /// 
+    /// let json: serde_json::Value = serde_json::from_str(&generate_report_json()).unwrap();
+    /// assert_eq!(json["schema_version"], 2);
+    /// assert!(json["data"]["score"]["overall_score"].is_number());
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn generate_report_json() -> String {
+        DiagnosticReport::capture().to_json()
+    }
+    
+    /// Gera o resumo da máquina em CSV, com cabeçalho
+    /// 
+    /// Mesmo conteúdo de [`DiagnosticReport::to_csv`] para uma captura nova.
+    pub fn generate_report_csv() -> String {
+        DiagnosticReport::capture().to_csv()
+    }
+
+    /// Caminho padrão do relatório: `./diagnostico_<timestamp>.txt`
+    /// 
+    /// O timestamp é o instante atual em segundos desde a época Unix.
+    pub fn default_report_path() -> std::path::PathBuf {
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        std::path::PathBuf::from(format!("diagnostico_{}.txt", timestamp))
+    }
+
+    /// Grava o relatório completo em [`default_report_path`]
+    /// 
+    /// # Erros
+    /// Retorna `DiagnosticError::Io` se o arquivo não puder ser gravado.
+    pub fn write_report() -> Result<(), DiagnosticError> {
+        write_report_to(&default_report_path())
+    }
+    
+    /// Grava o relatório completo de [`generate_complete_report`] no caminho indicado
+    /// 
+    /// Diretórios intermediários inexistentes são criados; um arquivo
+    /// existente é sobrescrito.
+    /// 
+    /// # Erros
+    /// Retorna `DiagnosticError::Io` se o diretório ou o arquivo não puderem
+    /// ser criados.
+    /// 
+    /// # Exemplo
+    /// ```no_run
+    /// use std::path::Path;
+    /// use hardware_diagnostic::engine::utils::write_report_to;
+    /// 
+    /// write_report_to(Path::new("relatorios/pc-01.txt")).unwrap();
+    /// ```
+    pub fn write_report_to(path: &std::path::Path) -> Result<(), DiagnosticError> {
+        write_complete_report_to(path, &collect_snapshot())
+    }
+    
+    /// Grava o relatório completo de dados já coletados, formatado por
+    /// [`format_complete_report`], no caminho indicado
+    /// 
+    /// Como [`write_report_to`], mas sem uma nova coleta: o arquivo traz os
+    /// mesmos valores já exibidos a partir de `snapshot`.
+    /// 
+    /// # Erros
+    /// Retorna `DiagnosticError::Io` se o diretório ou o arquivo não puderem
+    /// ser criados.
+    pub fn write_complete_report_to(path: &std::path::Path, snapshot: &SystemSnapshot) -> Result<(), DiagnosticError> {
+        let io_error = |e: io::Error| DiagnosticError::Io {
+            path: path.display().to_string(),
+            source: e.to_string(),
+        };
+        
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent).map_err(io_error)?;
+        }
+        fs::write(path, format_complete_report(snapshot)).map_err(io_error)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cpu_score_calculation() {
+        let cpu_info = CpuInfo {
+            number_cpus: 4,
+            cpu_usage: 25.0,
+            frequency: 3000,
+            name: "Test CPU".to_string(),
+            physical_cores: Some(2),
+            per_core_usage: vec![20.0, 30.0, 25.0, 25.0],
+            per_core_frequency: vec![3000; 4],
+            thermal_throttling_detected: false,
+            throttling: None,
+            vulnerabilities: None,
+        };
+        
+        let score = calculate_cpu_score(&cpu_info, &DiagnosticConfig::default());
+        
+        // Verifica limites
+        assert!(score >= 0.0, "Pontuação não pode ser negativa");
+        assert!(score <= 10.0, "Pontuação não pode exceder 10.0");
+        
+        // Verifica cálculo específico
+        assert!(score > 5.0, "CPU com 4 cores deve ter pontuação > 5.0");
+    }
+
+    #[test]
+    fn test_cpu_score_penalizes_saturated_core() {
+        let balanced = CpuInfo {
+            number_cpus: 4,
+            cpu_usage: 25.0,
+            frequency: 3000,
+            name: "Test CPU".to_string(),
+            physical_cores: Some(4),
+            per_core_usage: vec![25.0, 25.0, 25.0, 25.0],
+            per_core_frequency: vec![3000; 4],
+            thermal_throttling_detected: false,
+            throttling: None,
+            vulnerabilities: None,
+        };
+        let bottleneck = CpuInfo {
+            per_core_usage: vec![100.0, 0.0, 0.0, 0.0],
+            ..balanced.clone()
+        };
+        
+        let config = DiagnosticConfig::default();
+        assert_eq!(bottleneck.max_core_usage(), 100.0);
+        assert_eq!(bottleneck.min_core_usage(), 0.0);
+        assert!(
+            calculate_cpu_score(&bottleneck, &config) < calculate_cpu_score(&balanced, &config),
+            "Núcleo saturado deve reduzir a pontuação mesmo com média baixa"
+        );
+    }
+    
+    #[test]
+    fn test_cpu_throttling_caps_score() {
+        let cpu = CpuInfo {
+            number_cpus: 16,
+            cpu_usage: 10.0,
+            frequency: 4000,
+            per_core_usage: vec![10.0; 16],
+            ..CpuInfo::default()
+        };
+        let throttled = CpuInfo { throttling: Some(true), ..cpu.clone() };
+        let config = DiagnosticConfig::default();
+        
+        assert!(calculate_cpu_score(&cpu, &config) > config.scoring.thresholds.throttling_cpu_max_score);
+        let not_throttled = CpuInfo { throttling: Some(false), ..cpu.clone() };
+        assert_eq!(calculate_cpu_score(&not_throttled, &config), calculate_cpu_score(&cpu, &config));
+        assert_eq!(calculate_cpu_score(&throttled, &config), 3.0);
+        
+        // O teto é configurável e validado
+        let mut lenient = DiagnosticConfig::default();
+        lenient.scoring.thresholds.throttling_cpu_max_score = 6.0;
+        assert_eq!(calculate_cpu_score(&throttled, &lenient), 6.0);
+        lenient.scoring.thresholds.throttling_cpu_max_score = 11.0;
+        assert!(lenient.validate().is_err());
+        
+        // Só um limite de desempenho abaixo de 90% conta como throttling
+        assert!(!performance_limit_throttling(100));
+        assert!(!performance_limit_throttling(95));
+        assert!(!performance_limit_throttling(90));
+        assert!(performance_limit_throttling(89));
+        
+        // O throttling reportado substitui a estimativa térmica na recomendação
+        let recommend = |cpu: &CpuInfo| {
+            generate_recommendations(Some(cpu), None, &[], None, &[], &[], None, None, None, &PerformanceCategory::BomEstado, &ScoringConfig::default())
+        };
+        let texts = recommend(&CpuInfo { thermal_throttling_detected: true, ..throttled });
+        assert!(texts.iter().any(|text| text.contains("limitando o desempenho")));
+        assert!(!texts.iter().any(|text| text.contains("Throttling térmico")));
+        assert!(!recommend(&cpu).iter().any(|text| text.contains("throttling")));
+    }
+
+    /// Configuração em que apenas um subfator conta: a pontuação é a da faixa
+    fn only_factor(factor: usize, weights: fn(&mut ScoringConfig) -> [&mut f64; 3]) -> DiagnosticConfig {
+        let mut config = DiagnosticConfig::default();
+        for (i, weight) in weights(&mut config.scoring).into_iter().enumerate() {
+            *weight = if i == factor { 1.0 } else { 0.0 };
+        }
+        config
+    }
+    
+    fn cpu_weights(config: &mut ScoringConfig) -> [&mut f64; 3] {
+        [&mut config.cpu_cores_weight, &mut config.cpu_usage_weight, &mut config.cpu_frequency_weight]
+    }
+    
+    fn ram_weights(config: &mut ScoringConfig) -> [&mut f64; 3] {
+        [&mut config.ram_usage_weight, &mut config.ram_swap_weight, &mut config.ram_capacity_weight]
+    }
+    
+    fn disk_weights(config: &mut ScoringConfig) -> [&mut f64; 3] {
+        [&mut config.disk_usage_weight, &mut config.disk_type_weight, &mut config.disk_free_space_weight]
+    }
+    
+    #[test]
+    fn test_cpu_score_boundaries() {
+        let cpu = |number_cpus: usize, cpu_usage: f32, frequency: u64| CpuInfo {
+            number_cpus,
+            cpu_usage,
+            frequency,
+            ..CpuInfo::default()
+        };
+        
+        // Núcleos: limites [1, 2, 4, 8] inclusivos
+        let cores = only_factor(0, cpu_weights);
+        for (number_cpus, expected) in [(1, 2.0), (2, 4.0), (3, 6.0), (4, 6.0), (5, 8.0), (8, 8.0), (9, 10.0)] {
+            assert_eq!(calculate_cpu_score(&cpu(number_cpus, 0.0, 0), &cores), expected, "{} núcleos", number_cpus);
+        }
+        
+        // Uso: limites [30, 60, 85] exclusivos
+        let usage = only_factor(1, cpu_weights);
+        for (cpu_usage, expected) in [(29.9, 10.0), (30.0, 7.0), (59.9, 7.0), (60.0, 4.0), (84.9, 4.0), (85.0, 1.0)] {
+            assert_eq!(calculate_cpu_score(&cpu(4, cpu_usage, 0), &usage), expected, "uso {}%", cpu_usage);
+        }
+        
+        // Núcleo acima de 95% limita o uso à faixa regular
+        let saturated = |core: f32| CpuInfo { per_core_usage: vec![core, 0.0], ..cpu(4, 10.0, 0) };
+        assert_eq!(calculate_cpu_score(&saturated(95.0), &usage), 10.0);
+        assert_eq!(calculate_cpu_score(&saturated(95.1), &usage), 4.0);
+        
+        // Frequência: limites [2000, 3000, 4000] MHz exclusivos
+        let frequency = only_factor(2, cpu_weights);
+        for (mhz, expected) in [(1999, 3.0), (2000, 6.0), (2999, 6.0), (3000, 8.0), (3999, 8.0), (4000, 10.0)] {
+            assert_eq!(calculate_cpu_score(&cpu(4, 0.0, mhz), &frequency), expected, "{} MHz", mhz);
+        }
+        
+        // Pesos padrão: 8.0 * 0.4 + 10.0 * 0.4 + 8.0 * 0.2
+        let score = calculate_cpu_score(&cpu(8, 20.0, 3500), &DiagnosticConfig::default());
+        assert!((score - 8.8).abs() < 1e-9, "{}", score);
+    }
+    
+    #[test]
+    fn test_ram_score_boundaries() {
+        const GIB: f64 = 1024.0 * 1024.0 * 1024.0;
+        let ram = |total_gib: f64, ram_usage_percent: f64, total_swap: u64, swap_usage_percent: f64| RamInfo {
+            total_ram: (total_gib * GIB) as u64,
+            total_swap,
+            ram_usage_percent,
+            swap_usage_percent,
+            ..RamInfo::default()
+        };
+        
+        // Uso: limites [60, 75, 90] exclusivos
+        let usage = only_factor(0, ram_weights);
+        for (percent, expected) in [(59.9, 10.0), (60.0, 7.0), (74.9, 7.0), (75.0, 4.0), (89.9, 4.0), (90.0, 1.0)] {
+            assert_eq!(calculate_ram_score(&ram(16.0, percent, 1, 0.0), &usage), expected, "uso {}%", percent);
+        }
+        
+        // SWAP: limites [10, 30, 50] exclusivos; sem SWAP é neutro até 85% de RAM
+        let swap = only_factor(1, ram_weights);
+        for (percent, expected) in [(9.9, 10.0), (10.0, 7.0), (29.9, 7.0), (30.0, 4.0), (49.9, 4.0), (50.0, 1.0)] {
+            assert_eq!(calculate_ram_score(&ram(16.0, 0.0, 1, percent), &swap), expected, "SWAP {}%", percent);
+        }
+        assert_eq!(calculate_ram_score(&ram(16.0, 85.0, 0, 0.0), &swap), 8.0);
+        assert_eq!(calculate_ram_score(&ram(16.0, 85.1, 0, 0.0), &swap), 2.0);
+        
+        // Capacidade: limites [4, 8, 16] GiB exclusivos
+        let capacity = only_factor(2, ram_weights);
+        for (gib, expected) in [(3.9, 3.0), (4.0, 6.0), (7.9, 6.0), (8.0, 8.0), (15.9, 8.0), (16.0, 10.0)] {
+            assert_eq!(calculate_ram_score(&ram(gib, 0.0, 0, 0.0), &capacity), expected, "{} GiB", gib);
+        }
+        
+        // Pesos padrão: 7.0 * 0.5 + 8.0 * 0.3 + 10.0 * 0.2
+        let score = calculate_ram_score(&ram(32.0, 70.0, 0, 0.0), &DiagnosticConfig::default());
+        assert!((score - 7.9).abs() < 1e-9, "{}", score);
+    }
+    
+    #[test]
+    fn test_disk_score_boundaries() {
+        const GIB: f64 = 1024.0 * 1024.0 * 1024.0;
+        let disk = |usage_percent: f64, kind: DiskKind, free_gib: f64| DiskInfo {
+            name: "sda".to_string(),
+            mount_point: "/".to_string(),
+            total_space: (1000.0 * GIB) as u64,
+            available_space: (free_gib * GIB) as u64,
+            used_space: 0,
+            usage_percent,
+            file_system: "ext4".to_string(),
+            kind,
+            read_bytes_per_sec: 0,
+            write_bytes_per_sec: 0,
+            read_iops: 0,
+            write_iops: 0,
+            io_utilization_percent: 0.0,
+            health: None,
+            removable: false,
+            read_only: false,
+        };
+        
+        // Uso: limites [70, 85, 95] exclusivos
+        let usage = only_factor(0, disk_weights);
+        for (percent, expected) in [(69.9, 10.0), (70.0, 7.0), (84.9, 7.0), (85.0, 4.0), (94.9, 4.0), (95.0, 1.0)] {
+            assert_eq!(calculate_disk_score(&[disk(percent, DiskKind::Ssd, 500.0)], &usage), expected, "uso {}%", percent);
+        }
+        
+        // Tipo de mídia
+        let kind = only_factor(1, disk_weights);
+        for (media, expected) in [(DiskKind::Ssd, 10.0), (DiskKind::Unknown, 8.0), (DiskKind::Hdd, 6.0)] {
+            assert_eq!(calculate_disk_score(&[disk(0.0, media, 500.0)], &kind), expected, "{}", media);
+        }
+        
+        // Espaço livre: limites [10, 20, 50, 100] GiB, o valor do limite fica na faixa de baixo
+        let free = only_factor(2, disk_weights);
+        for (gib, expected) in [(100.1, 10.0), (100.0, 8.0), (50.1, 8.0), (50.0, 6.0), (20.1, 6.0), (20.0, 4.0), (10.1, 4.0), (10.0, 1.0)] {
+            assert_eq!(calculate_disk_score(&[disk(0.0, DiskKind::Ssd, gib)], &free), expected, "{} GiB livres", gib);
+        }
+        
+        // HDD com E/S acima de 80% perde 40%; falha SMART fixa em 1.0
+        let busy = |io: f32| DiskInfo { io_utilization_percent: io, ..disk(0.0, DiskKind::Hdd, 500.0) };
+        assert_eq!(calculate_disk_score(&[busy(80.0)], &usage), 10.0);
+        assert_eq!(calculate_disk_score(&[busy(80.1)], &usage), 6.0);
+        let failing = DiskInfo {
+            health: Some(DiskHealth::from(SmartInfo { failure_predicted: true, ..SmartInfo::default() })),
+            ..disk(0.0, DiskKind::Ssd, 500.0)
+        };
+        assert_eq!(calculate_disk_score(&[failing], &usage), 1.0);
+        
+        // Média simples entre os discos; sem discos a pontuação é neutra
+        let disks = [disk(0.0, DiskKind::Ssd, 500.0), disk(95.0, DiskKind::Ssd, 500.0)];
+        assert_eq!(calculate_disk_score(&disks, &usage), 5.5);
+        assert_eq!(calculate_disk_score(&[], &usage), 5.0);
+    }
+    
+    #[test]
+    fn test_ram_score_edge_cases() {
+        // Teste com RAM muito cheia
+        let ram_critical = RamInfo {
+            total_ram: 8 * 1024 * 1024 * 1024, // 8GB
+            used_ram: 7 * 1024 * 1024 * 1024,  // 7GB usado (87.5%)
+            free_ram: 1024 * 1024 * 1024,
+            total_swap: 2 * 1024 * 1024 * 1024,
+            used_swap: 1024 * 1024 * 1024,
+            ram_usage_percent: 87.5,
+            swap_usage_percent: 50.0,
+            memory_type: None,
+            speed_mhz: None,
+            slots_used: None,
+            slots_total: None,
+        };
+        
+        let score = calculate_ram_score(&ram_critical, &DiagnosticConfig::default());
+        assert!(score < 5.0, "RAM com 87.5% uso deve ter pontuação baixa");
+        
+        // Teste com RAM vazia
+        let ram_empty = RamInfo {
+            total_ram: 16 * 1024 * 1024 * 1024,
+            used_ram: 1024 * 1024 * 1024,  // 6.25% usado
+            free_ram: 15 * 1024 * 1024 * 1024,
+            total_swap: 0,
+            used_swap: 0,
+            ram_usage_percent: 6.25,
+            swap_usage_percent: 0.0,
+            memory_type: None,
+            speed_mhz: None,
+            slots_used: None,
+            slots_total: None,
+        };
+        
+        let score = calculate_ram_score(&ram_empty, &DiagnosticConfig::default());
+        assert!(score > 7.0, "RAM com pouco uso deve ter pontuação alta");
+    }
+    
+    /// RAM de 16 GiB com o uso indicado e sem SWAP configurado
+    fn ram_without_swap(ram_usage_percent: f64) -> RamInfo {
+        RamInfo {
+            total_ram: 16 * 1024 * 1024 * 1024,
+            used_ram: (16.0 * 1024.0 * 1024.0 * 1024.0 * ram_usage_percent / 100.0) as u64,
+            free_ram: 0,
+            total_swap: 0,
+            used_swap: 0,
+            ram_usage_percent,
+            swap_usage_percent: 0.0,
+            memory_type: None,
+            speed_mhz: None,
+            slots_used: None,
+            slots_total: None,
+        }
+    }
+    
+    /// Indica se as recomendações para a RAM sugerem ativar o arquivo de paginação
+    fn recommends_paging_file(ram: &RamInfo) -> bool {
+        generate_recommendations(None, Some(ram), &[], None, &[], &[], None, None, None, &PerformanceCategory::Precaução, &ScoringConfig::default())
+            .iter()
+            .any(|r| r.contains("arquivo de paginação"))
+    }
+    
+    #[test]
+    fn test_memory_pressure_levels() {
+        let ram = |ram_usage_percent: f64, total_swap: u64, swap_usage_percent: f64| RamInfo {
+            total_swap,
+            swap_usage_percent,
+            ..ram_without_swap(ram_usage_percent)
+        };
+        
+        for (info, expected) in [
+            (ram(30.0, 0, 0.0), MemoryPressure::Low),
+            (ram(59.9, 1, 9.9), MemoryPressure::Low),
+            (ram(60.0, 0, 0.0), MemoryPressure::Moderate),
+            // RAM folgada, mas o sistema já pagina
+            (ram(30.0, 1, 10.0), MemoryPressure::Moderate),
+            (ram(75.0, 1, 0.0), MemoryPressure::High),
+            (ram(30.0, 1, 30.0), MemoryPressure::High),
+            (ram(90.0, 0, 0.0), MemoryPressure::Critical),
+            (ram(30.0, 1, 50.0), MemoryPressure::Critical),
+            // SWAP não configurado não conta, mesmo com percentual inválido
+            (ram(30.0, 0, 80.0), MemoryPressure::Low),
+        ] {
+            let level = info.pressure_level();
+            assert_eq!(level, expected, "RAM {}%, SWAP {}%", info.ram_usage_percent, info.swap_usage_percent);
+            
+            // Cada nível gera (ou não) a recomendação correspondente
+            let texts = generate_recommendations(None, Some(&info), &[], None, &[], &[], None, None, None, &PerformanceCategory::BomEstado, &ScoringConfig::default());
+            let mentions = |label: &str| texts.iter().any(|r| r.contains(label));
+            match level {
+                MemoryPressure::Low => assert!(!mentions("Pressão de memória")),
+                MemoryPressure::Moderate => assert!(mentions("Pressão de memória moderada")),
+                MemoryPressure::High => assert!(mentions("Pressão de memória alta")),
+                MemoryPressure::Critical => assert!(mentions("Pressão de memória crítica")),
+            }
+        }
+        assert!(MemoryPressure::Low < MemoryPressure::Moderate && MemoryPressure::High < MemoryPressure::Critical);
+        
+        // Limites personalizados valem também para a recomendação
+        let strict = ScoringThresholds { ram_usage: [20.0, 40.0, 60.0], ..ScoringThresholds::default() };
+        assert_eq!(ram(30.0, 0, 0.0).pressure_level_with(&strict), MemoryPressure::Moderate);
+        let config = ScoringConfig { thresholds: strict, ..ScoringConfig::default() };
+        let texts = generate_recommendations(None, Some(&ram(30.0, 0, 0.0)), &[], None, &[], &[], None, None, None, &PerformanceCategory::BomEstado, &config);
+        assert!(texts.iter().any(|r| r.contains("Pressão de memória moderada")));
+    }
+    
+    #[test]
+    fn test_no_swap_with_high_ram_usage() {
+        let config = DiagnosticConfig::default();
+        let no_swap = ram_without_swap(92.0);
+        let with_swap = RamInfo { total_swap: 4 * 1024 * 1024 * 1024, ..ram_without_swap(92.0) };
+        
+        // Subfator do SWAP penalizado e recomendação específica
+        assert!(calculate_ram_score(&no_swap, &config) < calculate_ram_score(&with_swap, &config));
+        assert!(recommends_paging_file(&no_swap));
+        assert!(!recommends_paging_file(&with_swap));
+    }
+    
+    #[test]
+    fn test_no_swap_with_low_ram_usage() {
+        // Sem pressão de memória, a falta de SWAP continua neutra
+        let no_swap = ram_without_swap(30.0);
+        assert!(calculate_ram_score(&no_swap, &DiagnosticConfig::default()) > 9.0);
+        assert!(!recommends_paging_file(&no_swap));
+    }
+    
+    #[test]
+    fn test_memory_upgrade_path_recommendations() {
+        let recommendations = |ram: &RamInfo| {
+            generate_recommendations(None, Some(ram), &[], None, &[], &[], None, None, None, &PerformanceCategory::BomEstado, &ScoringConfig::default())
+        };
+        let full_slots = RamInfo {
+            memory_type: Some("DDR3".to_string()),
+            speed_mhz: Some(1600),
+            slots_used: Some(2),
+            slots_total: Some(2),
+            ..ram_without_swap(30.0)
+        };
+        assert!(full_slots.all_slots_used());
+        let texts = recommendations(&full_slots);
+        assert!(texts.iter().any(|r| r.contains("Todos os slots de memória ocupados")));
+        assert!(texts.iter().any(|r| r.contains("DDR3")));
+        
+        let free_slot = RamInfo { memory_type: Some("DDR4".to_string()), slots_total: Some(4), ..full_slots };
+        assert!(!free_slot.all_slots_used());
+        let texts = recommendations(&free_slot);
+        assert!(!texts.iter().any(|r| r.contains("slots") || r.contains("DDR3")), "{:?}", texts);
+        
+        // Slots desconhecidos não geram recomendação
+        assert!(!ram_without_swap(30.0).all_slots_used());
+        assert!(!RamInfo { slots_used: Some(2), ..ram_without_swap(30.0) }.all_slots_used());
+    }
+    
+    #[test]
+    fn test_secure_boot_disabled_recommendation() {
+        let warns = |secure_boot: Option<bool>| {
+            generate_recommendations(None, None, &[], None, &[], &[], None, None, secure_boot, &PerformanceCategory::BomEstado, &ScoringConfig::default())
+                .iter()
+                .any(|r| r.contains("Secure Boot"))
+        };
+        
+        assert!(warns(Some(false)));
+        assert!(!warns(Some(true)));
+        assert!(!warns(None), "BIOS legado não gera recomendação");
+    }
+    
+    #[test]
+    fn test_recommendation_thresholds_are_configurable() {
+        let disk = DiskInfo {
+            name: "sda".to_string(),
+            mount_point: "/".to_string(),
+            total_space: 100 * 1024 * 1024 * 1024,
+            available_space: 15 * 1024 * 1024 * 1024,
+            used_space: 85 * 1024 * 1024 * 1024,
+            usage_percent: 85.0,
+            file_system: "ext4".to_string(),
+            kind: DiskKind::Ssd,
+            read_bytes_per_sec: 0,
+            write_bytes_per_sec: 0,
+            read_iops: 0,
+            write_iops: 0,
+            io_utilization_percent: 0.0,
+            health: None,
+            removable: false,
+            read_only: false,
+        };
+        let ram = RamInfo { total_ram: 6 * 1024 * 1024 * 1024, ..ram_without_swap(30.0) };
+        let battery = BatteryInfo {
+            charge_percent: 80.0,
+            is_charging: true,
+            health_percent: Some(70.0),
+            estimated_minutes_remaining: None,
+            cycle_count: None,
+            state: battery::BatteryState::Charging,
+            full_capacity_wh: None,
+            design_capacity_wh: None,
+        };
+        let recommendations = |config: &ScoringConfig| {
+            generate_recommendations(None, Some(&ram), std::slice::from_ref(&disk), Some(&battery), &[], &[], None, None, None, &PerformanceCategory::BomEstado, config)
+        };
+        
+        let defaults = recommendations(&ScoringConfig::default());
+        assert!(!defaults.iter().any(|r| r.contains("insuficiente") || r.contains("DISCO") || r.contains("BATERIA")), "{:?}", defaults);
+        
+        let mut strict = ScoringConfig::default();
+        strict.thresholds.low_ram_gib = 8.0;
+        strict.thresholds.disk_almost_full_percent = 80.0;
+        strict.thresholds.low_disk_free_gib = 20.0;
+        strict.thresholds.battery_health_percent = 75.0;
+        let texts = recommendations(&strict);
+        assert!(texts.contains(&"🟡 RAM: Memória insuficiente para sistemas modernos".to_string()));
+        assert!(texts.contains(&"🔴 DISCO sda: Capacidade quase esgotada (85.0%)".to_string()));
+        assert!(texts.contains(&"🔴 DISCO sda: Menos de 20 GiB livres".to_string()));
+        assert!(texts.iter().any(|r| r.starts_with("🔴 BATERIA: Saúde em 70%")), "{:?}", texts);
+        
+        // Limiares percentuais fora de 0-100 são rejeitados
+        for invalid in [
+            ScoringThresholds { hdd_io_saturation: 120.0, ..ScoringThresholds::default() },
+            ScoringThresholds { cpu_saturated_core: -5.0, ..ScoringThresholds::default() },
+            ScoringThresholds { disk_almost_full_percent: 101.0, ..ScoringThresholds::default() },
+            ScoringThresholds { low_disk_free_gib: -1.0, ..ScoringThresholds::default() },
+        ] {
+            assert!(matches!(invalid.validate(), Err(DiagnosticError::InvalidConfig(_))), "{:?}", invalid);
+        }
+        assert!(ScoringThresholds::default().validate().is_ok());
+    }
+    
+    #[test]
+    fn test_disk_usage_never_underflows() {
+        assert_eq!(disk_usage(100, 25), (75, 75.0));
+        assert_eq!(disk_usage(0, 0), (0, 0.0));
+        
+        // Espaço livre otimista (rede/virtual) maior que o total
+        let (used_space, usage_percent) = disk_usage(100_000_000_000, 150_000_000_000);
+        assert_eq!(used_space, 0);
+        assert_eq!(usage_percent, 0.0);
+        
+        let optimistic = DiskInfo {
+            name: "//nas/share".to_string(),
+            mount_point: "/mnt/nas".to_string(),
+            total_space: 100_000_000_000,
+            available_space: 150_000_000_000,
+            used_space,
+            usage_percent,
+            file_system: "cifs".to_string(),
+            kind: DiskKind::Unknown,
+            read_bytes_per_sec: 0,
+            write_bytes_per_sec: 0,
+            read_iops: 0,
+            write_iops: 0,
+            io_utilization_percent: 0.0,
+            health: None,
+            removable: false,
+            read_only: false,
+        };
+        let score = calculate_disk_score(std::slice::from_ref(&optimistic), &DiagnosticConfig::default());
+        assert!((0.0..=10.0).contains(&score));
+        
+        let disks = [optimistic];
+        let score = score_from(None, None, Some(&disks), None, None, &[], &[], None, None, None, &DiagnosticConfig::default());
+        let csv = utils::report_to_csv(&score, &CpuInfo::default(), &RamInfo::default(), &disks);
+        assert!(csv.contains(",//nas/share,93.13,0.0,"), "Uso do disco principal deve ser 0%: {}", csv);
+    }
+    
+    #[test]
+    fn test_critical_subsystem_floor() {
+        let failing = DiskInfo {
+            name: "sda".to_string(),
+            mount_point: "/".to_string(),
+            total_space: 500_000_000_000,
+            available_space: 2_000_000_000,
+            used_space: 498_000_000_000,
+            usage_percent: 99.6,
+            file_system: "ext4".to_string(),
+            kind: DiskKind::Hdd,
+            read_bytes_per_sec: 0,
+            write_bytes_per_sec: 0,
+            read_iops: 0,
+            write_iops: 0,
+            // HDD cheio e saturado de E/S: 2.5 * 0.6 = 1.5, abaixo do piso sem falha SMART
+            io_utilization_percent: 95.0,
+            health: None,
+            removable: false,
+            read_only: false,
+        };
+        let disks = [failing];
+        
+        // GPU perfeita com peso alto: a média fica em Bom Estado, mas o disco está abaixo do piso
+        let config = DiagnosticConfig::from(ScoringConfig { gpu_weight: 10.0, ..ScoringConfig::default() });
+        let score = score_from(None, None, Some(&disks), Some(10.0), None, &[], &[], None, None, None, &config);
+        assert!(score.disk_score.unwrap() < config.scoring.critical_floor);
+        assert!(score.overall_score >= config.scoring.categories.bom_estado, "{}", score.overall_score);
+        assert_eq!(score.category, PerformanceCategory::Manutencao);
+        assert!(score.recommendations[1].starts_with("⚠️ SUBSISTEMA CRÍTICO: sda com pontuação"), "{:?}", score.recommendations);
+        
+        // Desativado, vale apenas a média ponderada
+        let disabled = DiagnosticConfig::from(ScoringConfig { critical_floor_enabled: false, ..config.scoring.clone() });
+        let score = score_from(None, None, Some(&disks), Some(10.0), None, &[], &[], None, None, None, &disabled);
+        assert_eq!(score.category, PerformanceCategory::BomEstado);
+        assert!(!score.recommendations.iter().any(|r| r.contains("SUBSISTEMA CRÍTICO")));
+        
+        // O piso não melhora uma categoria já abaixo de Manutenção
+        let score = score_from(None, None, Some(&disks), None, None, &[], &[], None, None, None, &config);
+        assert_eq!(score.category, PerformanceCategory::Descarte);
+        
+        assert!(ScoringConfig { critical_floor: 11.0, ..ScoringConfig::default() }.validate().is_err());
+    }
+    
+    #[test]
+    fn test_disk_score_uses_disk_kind() {
+        let disk = |kind| DiskInfo {
+            name: "disk0".to_string(),
+            mount_point: "/".to_string(),
+            total_space: 500_000_000_000,
+            available_space: 250_000_000_000,
+            used_space: 250_000_000_000,
+            usage_percent: 50.0,
+            file_system: "ext4".to_string(),
+            kind,
+            read_bytes_per_sec: 0,
+            write_bytes_per_sec: 0,
+            read_iops: 0,
+            write_iops: 0,
+            io_utilization_percent: 0.0,
+            health: None,
+            removable: false,
+            read_only: false,
+        };
+        
+        let config = DiagnosticConfig::default();
+        let ssd = calculate_disk_score(&[disk(DiskKind::Ssd)], &config);
+        let unknown = calculate_disk_score(&[disk(DiskKind::Unknown)], &config);
+        let hdd = calculate_disk_score(&[disk(DiskKind::Hdd)], &config);
+        
+        assert!(ssd > unknown, "SSD deve pontuar acima de tipo desconhecido");
+        assert!(unknown > hdd, "Tipo desconhecido deve pontuar acima de HDD");
+        assert_eq!(DiskKind::from(sysinfo::DiskKind::SSD), DiskKind::Ssd);
+        assert_eq!(DiskKind::from(sysinfo::DiskKind::Unknown(-1)), DiskKind::Unknown);
+        assert_eq!(DiskKind::Hdd.to_string(), "HDD");
+        
+        // Predicados: NVMe é um SSD reconhecido pelo nome do dispositivo
+        let nvme = DiskInfo { name: "/dev/nvme0n1p2".to_string(), ..disk(DiskKind::Ssd) };
+        assert!(nvme.is_ssd() && nvme.is_nvme());
+        assert!(disk(DiskKind::Ssd).is_ssd() && !disk(DiskKind::Ssd).is_nvme());
+        assert!(!disk(DiskKind::Hdd).is_ssd() && !disk(DiskKind::Hdd).is_nvme());
+        assert!(!disk(DiskKind::Unknown).is_ssd() && !disk(DiskKind::Unknown).is_nvme());
+        let unknown_nvme = DiskInfo { name: "nvme0n1".to_string(), ..disk(DiskKind::Unknown) };
+        assert!(!unknown_nvme.is_nvme(), "tipo desconhecido não é SSD");
+        let sata_named_nvme = DiskInfo { name: "nvme-backup".to_string(), ..disk(DiskKind::Ssd) };
+        assert!(sata_named_nvme.is_ssd() && !sata_named_nvme.is_nvme());
+        
+        // A média esconde o HDD quase cheio; a pontuação individual não
+        let system_ssd = DiskInfo { name: "nvme0n1".to_string(), ..disk(DiskKind::Ssd) };
+        let full_hdd = DiskInfo {
+            name: "sda".to_string(),
+            available_space: 5_000_000_000,
+            used_space: 495_000_000_000,
+            usage_percent: 99.0,
+            ..disk(DiskKind::Hdd)
+        };
+        let disks = [system_ssd, full_hdd];
+        let score = score_from(None, None, Some(&disks), None, None, &[], &[], None, None, None, &config);
+        
+        assert_eq!(score.per_disk_scores.len(), 2);
+        let (ssd_name, ssd_score) = &score.per_disk_scores[0];
+        let (hdd_name, hdd_score) = &score.per_disk_scores[1];
+        assert_eq!((ssd_name.as_str(), hdd_name.as_str()), ("nvme0n1", "sda"));
+        assert!(hdd_score < ssd_score);
+        assert!((score.disk_score.unwrap() - (ssd_score + hdd_score) / 2.0).abs() < 1e-9);
+        
+        // Discos não avaliados não geram pontuações individuais
+        let score = score_from(None, None, None, None, None, &[], &[], None, None, None, &config);
+        assert!(score.per_disk_scores.is_empty());
+    }
+    
+    #[cfg(unix)]
+    #[test]
+    fn test_sysfs_disk_kind() {
+        use std::os::unix::fs::symlink;
+        
+        // Estrutura do sysfs: /sys/class/block/<dev> aponta para o dispositivo,
+        // e as partições ficam dentro do diretório do disco
+        let root = std::env::temp_dir().join(format!("hardware_diagnostic_sysfs_{}", std::process::id()));
+        let devices = root.join("devices");
+        let class_block = root.join("class").join("block");
+        for (disk, rotational) in [("sda", "1\n"), ("nvme0n1", "0\n")] {
+            fs::create_dir_all(devices.join(disk).join("queue")).unwrap();
+            fs::write(devices.join(disk).join("queue").join("rotational"), rotational).unwrap();
+        }
+        fs::create_dir_all(devices.join("sda").join("sda1")).unwrap();
+        fs::create_dir_all(devices.join("loop0")).unwrap();
+        fs::create_dir_all(&class_block).unwrap();
+        for (name, target) in [("sda", "sda"), ("sda1", "sda/sda1"), ("nvme0n1", "nvme0n1"), ("loop0", "loop0")] {
+            symlink(devices.join(target), class_block.join(name)).unwrap();
+        }
+        
+        let kinds: Vec<DiskKind> = ["sda", "sda1", "nvme0n1", "loop0", "nao-existe", ""]
+            .iter()
+            .map(|device| sysfs_disk_kind(&class_block, device))
+            .collect();
+        fs::remove_dir_all(&root).ok();
+        
+        assert_eq!(kinds, [
+            DiskKind::Hdd,
+            DiskKind::Hdd,
+            DiskKind::Ssd,
+            DiskKind::Unknown,
+            DiskKind::Unknown,
+            DiskKind::Unknown,
+        ]);
+    }
+    
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_kernel_device_name() {
+        use std::os::unix::fs::symlink;
+        
+        // `/dev/mapper/<volume>` é um link para `/dev/dm-<N>`, o nome do /proc/diskstats
+        let dev = std::env::temp_dir().join(format!("hardware_diagnostic_dev_{}", std::process::id()));
+        fs::create_dir_all(dev.join("mapper")).unwrap();
+        fs::write(dev.join("dm-3"), "").unwrap();
+        symlink(dev.join("dm-3"), dev.join("mapper").join("raiz")).unwrap();
+        let mapped = kernel_device_name(&dev.join("mapper").join("raiz").to_string_lossy());
+        fs::remove_dir_all(&dev).ok();
+        
+        assert_eq!(mapped, "dm-3");
+        assert_eq!(kernel_device_name("/dev/nao-existe/sdz1"), "sdz1");
+        assert_eq!(kernel_device_name("sdz"), "sdz");
+    }
+    
+    #[test]
+    fn test_disk_io_wait_skipped_without_counters() {
+        let interval = Duration::from_secs(5);
+        let unsupported = DiskIoSample { taken_at: Instant::now(), counters: HashMap::new() };
+        let started = Instant::now();
+        unsupported.wait_for_next(interval);
+        assert!(started.elapsed() < interval);
+        
+        // Uma leitura com contadores aguarda só o que falta do intervalo
+        let mut counters = HashMap::new();
+        counters.insert("sda".to_string(), DiskIoCounters::default());
+        let old = DiskIoSample { taken_at: Instant::now() - interval, counters };
+        let started = Instant::now();
+        old.wait_for_next(interval);
+        assert!(started.elapsed() < interval);
+    }
+    
+    #[test]
+    fn test_diskutil_disk_kind() {
+        let info = "   Device Identifier:         disk0s1
+   Device Node:               /dev/disk0s1
+   Solid State:               Yes
+";
+        assert_eq!(diskutil_disk_kind(info), DiskKind::Ssd);
+        assert_eq!(diskutil_disk_kind(&info.replace("Yes", "No")), DiskKind::Hdd);
+        assert_eq!(diskutil_disk_kind("Could not find disk: disk9"), DiskKind::Unknown);
+    }
+    
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_linux_collection() {
+        // O sysinfo lê /proc/cpuinfo: núcleos físicos devem ser detectados
+        let cpu = cpu_info();
+        let physical = cpu.physical_cores.expect("núcleos físicos não detectados no Linux");
+        assert!(physical >= 1 && physical <= cpu.number_cpus);
+        
+        // Rótulos iguais aos do Windows, sem o `Debug` do sysinfo (ex: "Unknown(-1)")
+        let disks = disk_info();
+        let section = utils::format_disk_section(&disks);
+        for disk in &disks {
+            assert!(["SSD", "HDD", "Unknown"].contains(&disk.kind.to_string().as_str()));
+            assert!(section.contains(&format!("  Tipo: {}\n", disk.kind)), "{}", section);
+        }
+        assert_eq!(utils::platform_name(), "Linux");
+    }
+    
+    #[test]
+    fn test_disk_filter_excludes_disks_from_score() {
+        let system_ssd = DiskInfo {
+            name: "nvme0n1".to_string(),
+            mount_point: "/".to_string(),
+            total_space: 500_000_000_000,
+            available_space: 250_000_000_000,
+            used_space: 250_000_000_000,
+            usage_percent: 50.0,
+            file_system: "ext4".to_string(),
+            kind: DiskKind::Ssd,
+            read_bytes_per_sec: 0,
+            write_bytes_per_sec: 0,
+            read_iops: 0,
+            write_iops: 0,
+            io_utilization_percent: 0.0,
+            health: None,
+            removable: false,
+            read_only: false,
+        };
+        // Imagem ISO montada: sempre 100% ocupada
+        let iso = DiskInfo {
+            name: "loop0".to_string(),
+            mount_point: "/media/cdrom".to_string(),
+            total_space: 4_000_000_000,
+            available_space: 0,
+            used_space: 4_000_000_000,
+            usage_percent: 100.0,
+            file_system: "iso9660".to_string(),
+            read_only: true,
+            ..system_ssd.clone()
+        };
+        let usb = DiskInfo { name: "sdb1".to_string(), removable: true, ..system_ssd.clone() };
+        let ram_disk = DiskInfo { name: "zram0".to_string(), total_space: 100_000_000, ..system_ssd.clone() };
+        let disks = [system_ssd, iso, usb, ram_disk];
+        
+        let filter = DiskFilter {
+            exclude_removable: true,
+            exclude_read_only: true,
+            min_total_space: 1_000_000_000,
+        };
+        let kept: Vec<String> = filter.apply(&disks).into_iter().map(|disk| disk.name).collect();
+        assert_eq!(kept, ["nvme0n1"]);
+        assert_eq!(DiskFilter::default().apply(&disks).len(), 4, "Filtro padrão não deve excluir discos");
+        
+        let config = DiagnosticConfig::from(ScoringConfig { disk_filter: filter, ..ScoringConfig::default() });
+        let filtered = score_from(None, None, Some(&disks), None, None, &[], &[], None, None, None, &config);
+        let unfiltered = score_from(None, None, Some(&disks), None, None, &[], &[], None, None, None, &DiagnosticConfig::default());
+        
+        assert_eq!(filtered.per_disk_scores.len(), 1);
+        assert!(filtered.disk_score.unwrap() > unfiltered.disk_score.unwrap());
+        assert!(!filtered.recommendations.iter().any(|r| r.contains("loop0")));
+        assert!(unfiltered.recommendations.iter().any(|r| r.contains("loop0")));
+        
+        // Filtro que exclui todos os discos: sem pontuação neutra, com aviso
+        let strict = DiskFilter { min_total_space: u64::MAX, ..filter };
+        let config = DiagnosticConfig::from(ScoringConfig { disk_filter: strict, ..ScoringConfig::default() });
+        let none_selected = score_from(None, None, Some(&disks), None, None, &[], &[], None, None, None, &config);
+        assert_eq!(none_selected.disk_score, None);
+        assert!(none_selected.per_disk_scores.is_empty());
+        assert_eq!(none_selected.recommendations[1], Message::NoDiskSelected.text(Language::default()));
+    }
+    
+    #[test]
+    fn test_same_mount_point() {
+        assert!(same_mount_point("/", "/"));
+        assert!(same_mount_point("/home/", "/home"));
+        assert!(same_mount_point("C:\\", "C:"));
+        assert!(!same_mount_point("/", "/home"));
+        assert!(!same_mount_point("/home", "/home2"));
+        assert!(!same_mount_point("", "/"));
+        assert_eq!(same_mount_point("c:", "C:\\"), cfg!(target_os = "windows"));
+        
+        assert!(disk_info_for("/ponto/de/montagem/inexistente").is_none());
+    }
+    
+    #[test]
+    fn test_disk_io_statistics() {
+        let idle_hdd = DiskInfo {
+            name: "/dev/sda1".to_string(),
+            mount_point: "/".to_string(),
+            total_space: 500_000_000_000,
+            available_space: 250_000_000_000,
+            used_space: 250_000_000_000,
+            usage_percent: 50.0,
+            file_system: "ext4".to_string(),
+            kind: DiskKind::Hdd,
+            read_bytes_per_sec: 0,
+            write_bytes_per_sec: 0,
+            read_iops: 0,
+            write_iops: 0,
+            io_utilization_percent: 0.0,
+            health: None,
+            removable: false,
+            read_only: false,
+        };
+        let config = DiagnosticConfig::default();
+        
+        // E/S zerada (plataforma sem estatísticas) não pode negativar a pontuação
+        let idle_score = calculate_disk_score(std::slice::from_ref(&idle_hdd), &config);
+        assert!(idle_score >= 0.0);
+        
+        // HDD saturado perde pontuação de forma significativa
+        let busy_hdd = DiskInfo { io_utilization_percent: 95.0, ..idle_hdd.clone() };
+        let busy_score = calculate_disk_score(std::slice::from_ref(&busy_hdd), &config);
+        assert!(busy_score >= 0.0);
+        assert!(busy_score < idle_score * 0.7);
+        
+        // Falha prevista pelo SMART derruba a nota mesmo com disco vazio
+        let failing = DiskInfo {
+            usage_percent: 5.0,
+            kind: DiskKind::Ssd,
+            health: Some(DiskHealth::from(SmartInfo {
+                failure_predicted: true,
+                reallocated_sectors: Some(120),
+                power_on_hours: Some(40_000),
+                ..SmartInfo::default()
+            })),
+            ..idle_hdd.clone()
+        };
+        assert_eq!(calculate_disk_score(std::slice::from_ref(&failing), &config), 1.0);
+        assert_eq!(failing.health_status(), SmartStatus::Failed);
+        assert_eq!(idle_hdd.health_status(), SmartStatus::Unknown);
+        let recommendations = generate_recommendations(None, None, std::slice::from_ref(&failing), None, &[], &[], None, None, None, &PerformanceCategory::Precaução, &ScoringConfig::default());
+        assert!(recommendations.iter().any(|r| r.contains("SMART prevê falha")));
+        
+        // Falha SMART leva ao descarte mesmo com o restante da máquina perfeito
+        let score = score_from(None, None, Some(std::slice::from_ref(&failing)), Some(10.0), None, &[], &[], None, None, None,
+            &DiagnosticConfig::from(ScoringConfig { gpu_weight: 100.0, ..ScoringConfig::default() }));
+        assert!(score.overall_score > 9.0);
+        assert_eq!(score.category, PerformanceCategory::Descarte);
+        
+        // Setores pendentes geram alerta próprio, sem falha prevista
+        let unstable = DiskInfo {
+            health: Some(DiskHealth::from(SmartInfo { pending_sectors: Some(3), uncorrectable_errors: Some(1), ..SmartInfo::default() })),
+            ..idle_hdd
+        };
+        assert_eq!(unstable.health_status(), SmartStatus::Warning);
+        let recommendations = generate_recommendations(None, None, &[unstable], None, &[], &[], None, None, None, &PerformanceCategory::Precaução, &ScoringConfig::default());
+        assert!(recommendations.iter().any(|r| r.contains("4 setores pendentes ou erros incorrigíveis")), "{:?}", recommendations);
+        
+        // E/S saturada só é apontada quando a pontuação está baixa
+        let saturated = |category: PerformanceCategory| {
+            generate_recommendations(None, None, std::slice::from_ref(&busy_hdd), None, &[], &[], None, None, None, &category, &ScoringConfig::default())
+                .iter()
+                .any(|r| r.contains("E/S saturada"))
+        };
+        assert!(saturated(PerformanceCategory::Manutencao));
+        assert!(!saturated(PerformanceCategory::BomEstado));
+        
+        // Recomendação e penalidade usam o mesmo limite: 85% já é saturação
+        let loaded_hdd = DiskInfo { io_utilization_percent: 85.0, ..busy_hdd.clone() };
+        assert!(calculate_disk_score(std::slice::from_ref(&loaded_hdd), &config) < idle_score * 0.7);
+        assert!(generate_recommendations(None, None, &[loaded_hdd], None, &[], &[], None, None, None, &PerformanceCategory::Manutencao, &config.scoring)
+            .iter()
+            .any(|r| r.contains("E/S saturada")));
+        
+        // Taxas calculadas a partir de duas leituras
+        let start = Instant::now();
+        let counters = |ops, bytes, busy_ms| DiskIoCounters {
+            read_ops: ops,
+            write_ops: ops,
+            read_bytes: bytes,
+            write_bytes: bytes,
+            busy_ms,
+        };
+        let before = DiskIoSample {
+            taken_at: start,
+            counters: HashMap::from([("sda1".to_string(), counters(100, 1_000_000, 1_000))]),
+        };
+        let after = DiskIoSample {
+            taken_at: start + Duration::from_secs(2),
+            counters: HashMap::from([("sda1".to_string(), counters(300, 5_000_000, 2_000))]),
+        };
+        let rates = after.rates_since(&before)["sda1"];
+        assert_eq!(rates.read_iops, 100);
+        assert_eq!(rates.write_bytes_per_sec, 2_000_000);
+        assert_eq!(rates.io_utilization_percent, 50.0);
+    }
+
+    #[test]
+    fn test_scoring_config_validation() {
+        assert!(ScoringConfig::default().validate().is_ok());
+        
+        let invalid = ScoringConfig {
+            weights: ScoreWeights { cpu: 0.4, ram: 0.3, disk: 0.5 },
+            ..ScoringConfig::default()
+        };
+        assert!(matches!(invalid.validate(), Err(DiagnosticError::InvalidConfig(_))));
+        
+        let negative = ScoringConfig {
+            ram_usage_weight: 1.5,
+            ram_swap_weight: -0.5,
+            ram_capacity_weight: 0.0,
+            ..ScoringConfig::default()
+        };
+        assert!(negative.validate().is_err());
+        
+        assert!(ScoreWeights::new(0.4, 0.3, 0.3).is_ok());
+        assert!(ScoreWeights::new(0.4, 0.3, 0.5).is_err());
+        assert!(ScoreWeights::new(1.2, -0.1, -0.1).is_err());
+        
+        // NaN e infinito não passam pela soma nem pelo sinal
+        for weight in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            assert!(matches!(ScoreWeights::new(weight, 0.3, 0.3), Err(DiagnosticError::InvalidConfig(_))));
+            let config = ScoringConfig { cpu_usage_weight: weight, ..ScoringConfig::default() };
+            assert!(matches!(config.validate(), Err(DiagnosticError::InvalidConfig(_))));
+            let config = ScoringConfig { battery_weight: weight, ..ScoringConfig::default() };
+            assert!(matches!(config.validate(), Err(DiagnosticError::InvalidConfig(_))));
+        }
+        
+        let mut unordered = ScoringConfig::default();
+        unordered.thresholds.ram_usage = [60.0, 90.0, 75.0];
+        assert!(matches!(unordered.validate(), Err(DiagnosticError::InvalidConfig(_))));
+        
+        // Limiares mais rígidos reduzem a pontuação da mesma máquina
+        let ram_info = RamInfo {
+            total_ram: 16 * 1024 * 1024 * 1024,
+            used_ram: 11 * 1024 * 1024 * 1024,
+            free_ram: 5 * 1024 * 1024 * 1024,
+            total_swap: 0,
+            used_swap: 0,
+            ram_usage_percent: 68.75,
+            swap_usage_percent: 0.0,
+            memory_type: None,
+            speed_mhz: None,
+            slots_used: None,
+            slots_total: None,
+        };
+        let mut strict = DiagnosticConfig::default();
+        strict.scoring.thresholds.ram_usage = [40.0, 50.0, 60.0];
+        assert!(strict.validate().is_ok());
+        assert!(calculate_ram_score(&ram_info, &strict) < calculate_ram_score(&ram_info, &DiagnosticConfig::default()));
+    }
+    
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_scoring_config_from_file() {
+        let dir = std::env::temp_dir();
+        
+        // TOML parcial: campos ausentes mantêm os padrões
+        let toml_path = dir.join(format!("diagnostic-{}.toml", std::process::id()));
+        fs::write(&toml_path, "[thresholds]\nram_usage = [50.0, 65.0, 80.0]\n\n[categories]\nbom_estado = 8.0\n").unwrap();
+        let config = ScoringConfig::from_file(&toml_path).unwrap();
+        fs::remove_file(&toml_path).unwrap();
+        assert_eq!(config.thresholds.ram_usage, [50.0, 65.0, 80.0]);
+        assert_eq!(config.thresholds.disk_usage, ScoringThresholds::default().disk_usage);
+        assert_eq!(config.categories.bom_estado, 8.0);
+        assert_eq!(config.weights, ScoreWeights::default());
+        
+        // A configuração padrão sobrevive a uma ida e volta em TOML e JSON
+        let defaults = ScoringConfig::default();
+        let dumped_path = dir.join(format!("diagnostic-dump-{}.toml", std::process::id()));
+        fs::write(&dumped_path, defaults.to_toml().unwrap()).unwrap();
+        let restored = ScoringConfig::from_file(&dumped_path);
+        fs::remove_file(&dumped_path).unwrap();
+        assert_eq!(restored.unwrap(), defaults);
+        
+        let json_path = dir.join(format!("diagnostic-{}.json", std::process::id()));
+        fs::write(&json_path, serde_json::to_string(&defaults).unwrap()).unwrap();
+        let restored = ScoringConfig::from_file(&json_path);
+        fs::remove_file(&json_path).unwrap();
+        assert_eq!(restored.unwrap(), defaults);
+        
+        // Limiares fora de ordem e arquivos ausentes são rejeitados
+        let invalid_path = dir.join(format!("diagnostic-invalid-{}.json", std::process::id()));
+        fs::write(&invalid_path, r#"{"categories": {"manutencao": 6.0, "precaucao": 5.0}}"#).unwrap();
+        let invalid = ScoringConfig::from_file(&invalid_path);
+        fs::remove_file(&invalid_path).unwrap();
+        assert!(matches!(invalid, Err(DiagnosticError::InvalidConfig(_))));
+        
+        assert!(matches!(
+            ScoringConfig::from_file(dir.join("nao-existe-diagnostic.toml")),
+            Err(DiagnosticError::Io { .. })
+        ));
+    }
+    
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_diagnostic_config_from_toml_file() {
+        let dir = std::env::temp_dir();
+        
+        // Pontuação no nível principal e alertas na tabela [alerts]
+        let path = dir.join(format!("diagnostic-full-{}.toml", std::process::id()));
+        fs::write(&path, "[thresholds]\ncpu_usage = [20.0, 50.0, 80.0]\n\n[alerts]\nmin_score = 6.0\n").unwrap();
+        let config = DiagnosticConfig::from_toml_file(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(config.scoring.thresholds.cpu_usage, [20.0, 50.0, 80.0]);
+        assert_eq!(config.scoring.thresholds.ram_usage, ScoringThresholds::default().ram_usage);
+        assert_eq!(config.alert_thresholds.min_score, 6.0);
+        assert_eq!(config.alert_thresholds.cpu_usage_warn, alerts::AlertConfig::default().cpu_usage_warn);
+        
+        // Campos explícitos substituem o valor correspondente de [thresholds]
+        let path = dir.join(format!("diagnostic-full-explicit-{}.toml", std::process::id()));
+        fs::write(&path, "cpu_usage_excellent_threshold = 20.0\ncpu_cores_high = 16\n\n[thresholds]\ncpu_usage = [40.0, 60.0, 85.0]\n").unwrap();
+        let explicit = DiagnosticConfig::from_toml_file(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(explicit.cpu_usage_excellent_threshold, Some(20.0));
+        assert_eq!(explicit.thresholds().cpu_usage, [20.0, 60.0, 85.0]);
+        assert_eq!(explicit.thresholds().cpu_cores, [1, 2, 4, 16]);
+        assert_eq!(explicit.thresholds().ram_usage, ScoringThresholds::default().ram_usage);
+        let cpu = CpuInfo { number_cpus: 4, cpu_usage: 25.0, frequency: 3000, ..CpuInfo::default() };
+        assert!(calculate_cpu_score(&cpu, &explicit) < calculate_cpu_score(&cpu, &DiagnosticConfig::default()));
+        
+        let dumped = explicit.to_toml().unwrap();
+        assert!(dumped.contains("cpu_usage_excellent_threshold = 20.0"), "{}", dumped);
+        assert!(!dumped.contains("disk_free_excellent_gb"), "{}", dumped);
+        let dumped_path = dir.join(format!("diagnostic-full-explicit-dump-{}.toml", std::process::id()));
+        fs::write(&dumped_path, dumped).unwrap();
+        let restored = DiagnosticConfig::from_toml_file(&dumped_path);
+        fs::remove_file(&dumped_path).unwrap();
+        assert_eq!(restored.unwrap(), explicit);
+        
+        // Um limiar explícito fora de ordem é rejeitado como os de [thresholds]
+        let invalid = DiagnosticConfig { ram_usage_excellent_threshold: Some(95.0), ..DiagnosticConfig::default() };
+        assert!(matches!(invalid.validate(), Err(DiagnosticError::InvalidConfig(_))));
+        
+        // A configuração padrão sobrevive a uma ida e volta em TOML
+        let defaults = DiagnosticConfig::default();
+        let dumped_path = dir.join(format!("diagnostic-full-dump-{}.toml", std::process::id()));
+        fs::write(&dumped_path, defaults.to_toml().unwrap()).unwrap();
+        let restored = DiagnosticConfig::from_toml_file(&dumped_path);
+        fs::remove_file(&dumped_path).unwrap();
+        assert_eq!(restored.unwrap(), defaults);
+        
+        // Sobre um perfil: o arquivo só substitui o que define
+        let mut base = DiagnosticConfig::default();
+        ScoreProfile::Laptop.apply(&mut base.scoring);
+        let path = dir.join(format!("diagnostic-full-over-{}.toml", std::process::id()));
+        fs::write(&path, "[thresholds]\ncpu_usage = [20.0, 50.0, 80.0]\n").unwrap();
+        let config = DiagnosticConfig::from_toml_file_over(&path, &base);
+        fs::write(&path, "battery_weight = 0.1\n\n[weights]\ncpu = 0.5\nram = 0.25\ndisk = 0.25\n").unwrap();
+        let reweighted = DiagnosticConfig::from_toml_file_over(&path, &base);
+        fs::remove_file(&path).unwrap();
+        let config = config.unwrap();
+        assert_eq!(config.scoring.weights, ScoreWeights::laptop());
+        assert_eq!(config.scoring.battery_weight, 0.3);
+        assert_eq!(config.scoring.thresholds.cpu_usage, [20.0, 50.0, 80.0]);
+        let reweighted = reweighted.unwrap();
+        assert_eq!(reweighted.scoring.weights, ScoreWeights::new(0.5, 0.25, 0.25).unwrap());
+        assert_eq!(reweighted.scoring.battery_weight, 0.1);
+        
+        // Limiares de alerta inválidos são rejeitados
+        let invalid_path = dir.join(format!("diagnostic-full-invalid-{}.toml", std::process::id()));
+        fs::write(&invalid_path, "[alerts]\ncpu_usage_warn = 99.0\ncpu_usage_crit = 90.0\n").unwrap();
+        let invalid = DiagnosticConfig::from_toml_file(&invalid_path);
+        fs::remove_file(&invalid_path).unwrap();
+        assert!(matches!(invalid, Err(DiagnosticError::InvalidConfig(_))));
     }
     
-    output
-}
-
-/// Funções utilitárias para formatação de dados
-pub mod utils {
-    use super::*;
-    
-    /// Converte bytes para gigabytes com formatação
-    /// 
-    /// # Argumentos
-    /// * `bytes` - Quantidade em bytes
-    /// 
-    /// # Retorno
-    /// String formatada em GB com 2 casas decimais
-    pub fn bytes_to_gb(bytes: u64) -> String {
-        format!("{:.2}", bytes as f64 / 1_000_000_000.0)
+    #[test]
+    fn test_score_builder_excludes_components() {
+        assert!(matches!(
+            PerformanceScoreBuilder::new().build(),
+            Err(DiagnosticError::InvalidConfig(_))
+        ));
+        
+        let score = PerformanceScoreBuilder::new()
+            .include_ram()
+            .with_weights(ScoreWeights::new(0.5, 0.5, 0.0).unwrap())
+            .build()
+            .unwrap();
+        assert!(score.cpu_score.is_none());
+        assert!(score.disk_score.is_none());
+        
+        // Só a RAM foi avaliada: a média é a própria nota da RAM
+        let ram_score = score.ram_score.unwrap();
+        assert!((score.overall_score - ram_score).abs() < 1e-9);
     }
     
-    /// Converte bytes para gigabytes como valor numérico
-    pub fn bytes_to_gb_f64(bytes: u64) -> f64 {
-        bytes as f64 / 1_000_000_000.0
+    #[test]
+    fn test_diagnostic_builder_skips_subsystems() {
+        assert!(matches!(DiagnosticBuilder::new().collect(), Err(DiagnosticError::InvalidConfig(_))));
+        
+        let diagnostic = DiagnosticBuilder::new().with_ram().with_disks().collect().unwrap();
+        assert!(diagnostic.cpu.is_none());
+        assert!(diagnostic.ram.is_some());
+        assert!(diagnostic.disks.is_some());
+        
+        // CPU não avaliada: sem nota, e não puxa a média para baixo
+        assert!(diagnostic.score.cpu_score.is_none());
+        assert!(diagnostic.score.ram_score.is_some());
+        let ram = diagnostic.score.ram_score.unwrap();
+        let disk = diagnostic.score.disk_score.unwrap_or(ram);
+        let overall = diagnostic.score.overall_score;
+        assert!(overall >= ram.min(disk) - 1e-9 && overall <= ram.max(disk) + 1e-9);
     }
     
-    /// Formata uma barra de progresso ASCII para representar percentuais
-    /// 
-    /// # Argumentos
-    /// * `percent` - Percentual (0.0 a 100.0)
-    /// * `width` - Largura da barra em caracteres
-    /// 
-    /// # Retorno
-    /// String representando a barra de progresso
-    pub fn progress_bar(percent: f64, width: usize) -> String {
-        let filled = ((percent / 100.0) * width as f64).round() as usize;
-        let empty = width.saturating_sub(filled);
+    #[test]
+    fn test_context_names_top_consumers_on_high_cpu() {
+        let mut ctx = SystemContext::with_sample_interval(Duration::from_millis(100));
+        let idle = CpuInfo { number_cpus: 4, cpu_usage: 20.0, ..CpuInfo::default() };
+        let busy = CpuInfo { cpu_usage: 95.0, ..idle.clone() };
+        let high_cpu = |score: &PerformanceScore| {
+            score.recommendations.iter().find(|r| r.starts_with("🔴 CPU: Uso muito alto")).cloned()
+        };
         
-        format!("[{}{}]", "█".repeat(filled), " ".repeat(empty))
+        // Uso normal: nenhuma amostra de processos
+        ctx.refresh_processes_if_busy(&idle);
+        assert!(ctx.processes_from().is_empty());
+        assert_eq!(high_cpu(&ctx.score_with_cpu(&idle, &ScoringConfig::default())), None);
+        
+        // Uso alto: a mesma pontuação usada por refresh_all cita o maior consumidor
+        ctx.refresh_processes_if_busy(&busy);
+        let top = processes::top_by_cpu(ctx.processes_from(), processes::RECOMMENDATION_TOP_PROCESSES);
+        assert!(!top.is_empty(), "processos não foram amostrados");
+        let text = high_cpu(&ctx.score_with_cpu(&busy, &ScoringConfig::default())).expect("recomendação de CPU alta");
+        assert!(text.contains("Maior consumo:") && text.contains(&top[0].name), "{}", text);
     }
     
-    /// Gera um relatório formatado de informações do sistema
-    pub fn generate_report() -> String {
-        let cpu = cpu_info();
-        let ram = ram_info();
-        let disks = disk_info();
+    #[test]
+    fn test_custom_weights_change_overall_score() {
+        let cpu_info = CpuInfo {
+            number_cpus: 8,
+            cpu_usage: 10.0,
+            frequency: 3500,
+            name: "Fast CPU".to_string(),
+            physical_cores: Some(8),
+            per_core_usage: vec![10.0; 8],
+            per_core_frequency: vec![3500; 8],
+            thermal_throttling_detected: false,
+            throttling: None,
+            vulnerabilities: None,
+        };
+        // RAM fraca: pouca memória e quase toda em uso
+        let ram_info = RamInfo {
+            total_ram: 2 * 1024 * 1024 * 1024,
+            used_ram: 1900 * 1024 * 1024,
+            free_ram: 148 * 1024 * 1024,
+            total_swap: 0,
+            used_swap: 0,
+            ram_usage_percent: 92.8,
+            swap_usage_percent: 0.0,
+            memory_type: None,
+            speed_mhz: None,
+            slots_used: None,
+            slots_total: None,
+        };
         
-        let mut report = String::new();
+        let score_with = |weights| {
+            let config = DiagnosticConfig::from(ScoringConfig { weights, ..ScoringConfig::default() });
+            score_from(Some(&cpu_info), Some(&ram_info), Some(&[]), None, None, &[], &[], None, None, None, &config).overall_score
+        };
         
-        // Seção CPU
-        report.push_str("=== INFORMACOES DA CPU ===\n");
-        report.push_str(&format!("Modelo: {}\n", cpu.name));
-        report.push_str(&format!("Núcleos lógicos: {}\n", cpu.number_cpus));
-        if let Some(physical) = cpu.physical_cores {
-            report.push_str(&format!("Núcleos físicos: {}\n", physical));
-        }
-        report.push_str(&format!("Frequência: {} MHz\n", cpu.frequency));
-        report.push_str(&format!("Uso atual: {:.1}%\n", cpu.cpu_usage));
-        report.push_str(&format!("Barra: {}\n\n", progress_bar(cpu.cpu_usage as f64, 20)));
+        let default_score = score_with(ScoreWeights::default());
+        let cpu_heavy = score_with(ScoreWeights::new(0.8, 0.1, 0.1).unwrap());
+        let ram_heavy = score_with(ScoreWeights::new(0.1, 0.8, 0.1).unwrap());
         
-        // Seção Memória
-        report.push_str("=== INFORMACOES DE MEMORIA ===\n");
-        report.push_str(&format!("RAM Total: {} GB\n", bytes_to_gb(ram.total_ram)));
-        report.push_str(&format!("RAM Usada: {} GB ({:.1}%)\n", 
-            bytes_to_gb(ram.used_ram), ram.ram_usage_percent));
-        report.push_str(&format!("RAM Livre: {} GB\n", bytes_to_gb(ram.free_ram)));
-        report.push_str(&format!("Barra: {}\n", progress_bar(ram.ram_usage_percent, 20)));
+        assert!(cpu_heavy > default_score, "CPU forte deve elevar a nota quando pesa mais");
+        assert!(ram_heavy < default_score, "RAM fraca deve reduzir a nota quando pesa mais");
         
-        if ram.total_swap > 0 {
-            report.push_str(&format!("\nSWAP Total: {} GB\n", bytes_to_gb(ram.total_swap)));
-            report.push_str(&format!("SWAP Usado: {} GB ({:.1}%)\n", 
-                bytes_to_gb(ram.used_swap), ram.swap_usage_percent));
+        // Perfis predefinidos: pesos válidos e na direção esperada
+        for profile in [ScoreProfile::Workstation, ScoreProfile::Server, ScoreProfile::Laptop] {
+            assert!(profile.weights().validate().is_ok(), "{:?}", profile);
         }
-        report.push_str("\n");
+        assert!(score_with(ScoreProfile::Workstation.weights()) > score_with(ScoreProfile::Server.weights()));
+        assert_eq!(ScoreProfile::from_name("LAPTOP"), Some(ScoreProfile::Laptop));
+        assert_eq!(ScoreProfile::from_name("desktop"), None);
+    }
+
+    #[test]
+    fn test_network_connectivity() {
+        let loopback = NetworkInfo {
+            name: "lo".to_string(),
+            mac_address: "00:00:00:00:00:00".to_string(),
+            ip_addresses: vec!["127.0.0.1".to_string(), "::1".to_string()],
+            total_received_bytes: 0,
+            total_transmitted_bytes: 0,
+            is_up: true,
+        };
+        assert!(!has_network_connectivity(std::slice::from_ref(&loopback)));
         
-        // Seção Discos
-        report.push_str("=== INFORMACOES DE ARMAZENAMENTO ===\n");
-        if disks.is_empty() {
-            report.push_str("Nenhum disco encontrado.\n");
-        } else {
-            for (i, disk) in disks.iter().enumerate() {
-                report.push_str(&format!("\nDisco {}:\n", i + 1));
-                report.push_str(&format!("  Nome: {}\n", disk.name));
-                report.push_str(&format!("  Ponto de montagem: {}\n", disk.mount_point));
-                report.push_str(&format!("  Sistema de arquivos: {}\n", disk.file_system));
-                report.push_str(&format!("  Tipo: {}\n", disk.disk_type));
-                report.push_str(&format!("  Capacidade: {} GB\n", bytes_to_gb(disk.total_space)));
-                report.push_str(&format!("  Usado: {} GB\n", bytes_to_gb(disk.used_space)));
-                report.push_str(&format!("  Livre: {} GB\n", bytes_to_gb(disk.available_space)));
-                report.push_str(&format!("  Uso: {:.1}%\n", disk.usage_percent));
-                report.push_str(&format!("  Barra: {}\n", progress_bar(disk.usage_percent, 20)));
-            }
-        }
+        let ethernet = NetworkInfo {
+            name: "eth0".to_string(),
+            ip_addresses: vec!["192.168.0.10".to_string()],
+            ..loopback.clone()
+        };
+        assert!(has_network_connectivity(&[loopback.clone(), ethernet.clone()]));
         
-        report
-    }
-    
-    /// Gera um relatório completo incluindo a pontuação de desempenho
-    pub fn generate_complete_report() -> String {
-        let mut report = generate_report(); // Relatório original
-        report.push_str("\n");
-        report.push_str(&display_performance_score(&calculate_performance_score()));
-        report
+        let down = NetworkInfo { is_up: false, ..ethernet };
+        assert!(!has_network_connectivity(&[loopback, down]));
     }
 
-    ///Grava o relatorio gerado no arquivo complete_report.txt
-    pub fn write_report() -> io::Result<()> {
-        let data = generate_complete_report();
-        let file_path = "../../complete_report.txt";
-
-        // fs::write tenta criar o arquivo (ou sobrescreve se já existir)
-        fs::write(file_path, data)?;
+    #[test]
+    fn test_display_color_is_optional() {
+        let score = PerformanceScore {
+            overall_score: 2.0,
+            cpu_score: Some(2.0),
+            ram_score: Some(2.0),
+            disk_score: Some(2.0),
+            per_disk_scores: Vec::new(),
+            gpu_score: None,
+            battery_score: None,
+            category: PerformanceCategory::Descarte,
+            recommendations: Vec::new(),
+        };
         
-        println!("Dados gravados com sucesso em {}", file_path);
-
-        Ok(())
+        let ansi = utils::AnsiGuard::set(true);
+        let colored = display_performance_score_colored(&score, true);
+        drop(ansi);
+        let plain = display_performance_score_colored(&score, false);
+        assert!(colored.contains("\x1b[31m"));
+        assert!(!plain.contains('\x1b'));
+        assert!(plain.contains(PerformanceCategory::Descarte.description()));
+        
+        // Português continua o padrão; inglês troca títulos e categoria
+        assert_eq!(plain, display_performance_score_localized(&score, false, Language::PtBr));
+        let english = display_performance_score_localized(&score, false, Language::EnUs);
+        assert!(english.contains("OVERALL SCORE: 2.0/10.0"));
+        assert!(english.contains("  • Disks:    2.0/10.0"));
+        assert!(english.contains(PerformanceCategory::Descarte.description_in(Language::EnUs)));
+        assert!(!english.contains("PONTUAÇÃO"));
     }
     
-}
-
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::sync::Mutex;
-
-    // Mock do sistema para testes
-    struct MockSystem {
-        cpu_count: usize,
-        cpu_usage: f32,
-        total_ram: u64,
-        used_ram: u64,
+    #[test]
+    fn test_compare_scores() {
+        let before = PerformanceScore {
+            overall_score: 4.0,
+            cpu_score: Some(5.0),
+            ram_score: Some(3.0),
+            disk_score: Some(4.0),
+            per_disk_scores: Vec::new(),
+            gpu_score: None,
+            battery_score: Some(6.0),
+            category: PerformanceCategory::Manutencao,
+            recommendations: Vec::new(),
+        };
+        let after = PerformanceScore {
+            overall_score: 7.5,
+            ram_score: Some(3.0),
+            disk_score: Some(9.0),
+            gpu_score: Some(8.0),
+            battery_score: None,
+            category: PerformanceCategory::BomEstado,
+            ..before.clone()
+        };
+        
+        let diff = after.compare(&before);
+        assert_eq!(diff.overall.delta(), 3.5);
+        assert_eq!(diff.disk.unwrap().delta(), 5.0);
+        assert_eq!(diff.ram.unwrap().delta(), 0.0);
+        assert!(diff.gpu.is_none() && diff.battery.is_none(), "Componentes ausentes em um dos lados");
+        assert!(diff.category_changed());
+        assert!(!before.compare(&before).category_changed());
+        
+        let text = display_diff(&diff);
+        assert!(text.contains("  • Geral:    4.0 → 7.5  ▲ +3.5"));
+        assert!(text.contains("  • RAM:      3.0 → 3.0  → +0.0"));
+        assert!(!text.contains("GPU"));
+        assert!(text.contains("MANUTENÇÃO URGENTE - Requer ações corretivas → BOM ESTADO"));
+        assert!(display_diff(&before.compare(&after)).contains("▼ -5.0"));
+        
+        let english = display_diff_localized(&diff, false, Language::EnUs);
+        assert!(english.contains("SCORE COMPARISON"));
+        assert!(english.contains("  • Overall:  4.0 → 7.5  ▲ +3.5"));
+        assert!(english.contains("CATEGORY: URGENT MAINTENANCE - Corrective action required → GOOD CONDITION"));
+        assert!(utils::strip_ansi(&diff.to_text_localized(Language::EnUs)).starts_with("Overall: ▲ +3.5"));
+        
+        // Pontuações idênticas: todas as variações zeradas
+        let same = utils::compare_scores(&before, &before);
+        assert_eq!(same.overall.delta(), 0.0);
+        assert!(!same.category_improved && !same.category_degraded);
+        
+        let delta = utils::compare_scores(&before, &after);
+        assert_eq!(delta, diff);
+        assert!(delta.category_improved && !delta.category_degraded);
+        assert_eq!(
+            utils::strip_ansi(&delta.to_text()),
+            "Geral: ▲ +3.5 | CPU: → +0.0 | RAM: → +0.0 | Discos: ▲ +5.0"
+        );
+        
+        let regression = utils::compare_scores(&after, &before);
+        assert!(regression.category_degraded && !regression.category_improved);
+        assert!(utils::strip_ansi(&regression.to_text()).contains("Geral: ▼ -3.5"));
     }
 
     #[test]
-    fn test_cpu_score_calculation() {
-        let cpu_info = CpuInfo {
+    fn test_report_to_csv() {
+        let score = PerformanceScore {
+            overall_score: 5.5,
+            cpu_score: Some(6.0),
+            ram_score: Some(5.0),
+            disk_score: Some(5.3),
+            per_disk_scores: Vec::new(),
+            gpu_score: None,
+            battery_score: None,
+            category: PerformanceCategory::Manutencao,
+            recommendations: Vec::new(),
+        };
+        let cpu = CpuInfo {
+            name: "Test, CPU".to_string(),
             number_cpus: 4,
-            cpu_usage: 25.0,
-            frequency: 3000,
-            name: "Test CPU".to_string(),
-            physical_cores: Some(2),
+            ..CpuInfo::default()
         };
         
-        let score = calculate_cpu_score(&cpu_info);
+        let header = utils::csv_header();
+        let row = utils::report_to_csv(&score, &cpu, &RamInfo::default(), &[]);
         
-        // Verifica limites
-        assert!(score >= 0.0, "Pontuação não pode ser negativa");
-        assert!(score <= 10.0, "Pontuação não pode exceder 10.0");
+        // Campos com vírgula são escapados entre aspas
+        assert!(row.contains(",\"Test, CPU\",4,"));
+        assert!(row.contains(",5.50,Manutencao,6.00,5.00,5.30,,0,0.00,0.00,"));
         
-        // Verifica cálculo específico
-        assert!(score > 5.0, "CPU com 4 cores deve ter pontuação > 5.0");
+        // Mesmo número de colunas no cabeçalho e na linha
+        let unquoted = row.replace("\"Test, CPU\"", "cpu");
+        assert_eq!(unquoted.split(',').count(), header.split(',').count());
+        assert!(!row.contains('\n'));
     }
 
     #[test]
-    fn test_ram_score_edge_cases() {
-        // Teste com RAM muito cheia
-        let ram_critical = RamInfo {
-            total_ram: 8 * 1024 * 1024 * 1024, // 8GB
-            used_ram: 7 * 1024 * 1024 * 1024,  // 7GB usado (87.5%)
-            free_ram: 1 * 1024 * 1024 * 1024,
-            total_swap: 2 * 1024 * 1024 * 1024,
-            used_swap: 1 * 1024 * 1024 * 1024,
-            ram_usage_percent: 87.5,
-            swap_usage_percent: 50.0,
+    fn test_to_prometheus() {
+        let disk = DiskInfo {
+            name: "C:".to_string(),
+            mount_point: "C:\\".to_string(),
+            total_space: 500_000_000_000,
+            available_space: 200_000_000_000,
+            used_space: 300_000_000_000,
+            usage_percent: 60.0,
+            file_system: "NTFS".to_string(),
+            kind: DiskKind::Ssd,
+            read_bytes_per_sec: 0,
+            write_bytes_per_sec: 0,
+            read_iops: 0,
+            write_iops: 0,
+            io_utilization_percent: 0.0,
+            health: None,
+            removable: false,
+            read_only: false,
         };
+        let cpu = CpuInfo { cpu_usage: 12.5, ..CpuInfo::default() };
+        let disks = [disk];
+        let score = score_from(Some(&cpu), None, Some(&disks), None, None, &[], &[], None, None, None, &DiagnosticConfig::default());
         
-        let score = calculate_ram_score(&ram_critical);
-        assert!(score < 5.0, "RAM com 87.5% uso deve ter pontuação baixa");
-        
-        // Teste com RAM vazia
-        let ram_empty = RamInfo {
-            total_ram: 16 * 1024 * 1024 * 1024,
-            used_ram: 1 * 1024 * 1024 * 1024,  // 6.25% usado
-            free_ram: 15 * 1024 * 1024 * 1024,
-            total_swap: 0,
-            used_swap: 0,
-            ram_usage_percent: 6.25,
-            swap_usage_percent: 0.0,
+        let metrics = utils::to_prometheus(&score, &cpu, &RamInfo::default(), &disks);
+        assert!(metrics.contains("# HELP hardware_performance_score "));
+        assert!(metrics.contains("# TYPE hardware_performance_score gauge\n"));
+        assert!(metrics.contains(&format!("hardware_performance_score {}\n", score.overall_score)));
+        assert!(metrics.contains("hardware_cpu_usage_percent 12.5\n"));
+        let level = match score.category {
+            PerformanceCategory::Descarte => 1,
+            PerformanceCategory::Manutencao => 2,
+            PerformanceCategory::Precaução => 3,
+            PerformanceCategory::BomEstado => 4,
         };
+        assert!(metrics.contains(&format!("hardware_performance_category {}\n", level)));
+        assert!(metrics.contains(&format!(
+            "hardware_performance_category_info{{category=\"{}\"}} 1\n",
+            score.category.to_ascii_name()
+        )));
+        assert!(metrics.contains("hardware_component_score{component=\"cpu\"}"));
+        assert!(!metrics.contains("component=\"ram\""), "Pontuações ausentes não são emitidas");
         
-        let score = calculate_ram_score(&ram_empty);
-        assert!(score > 7.0, "RAM com pouco uso deve ter pontuação alta");
+        // Rótulos escapados: a barra invertida do ponto de montagem é duplicada
+        assert!(metrics.contains("hardware_disk_usage_percent{disk=\"C:\",mount=\"C:\\\\\"} 60\n"));
+        assert!(metrics.contains("hardware_disk_score{disk=\"C:\""));
+        
+        // Toda linha de amostra pertence a uma métrica declarada
+        for line in metrics.lines().filter(|l| !l.starts_with('#')) {
+            let name = line.split(['{', ' ']).next().unwrap();
+            assert!(metrics.contains(&format!("# TYPE {} gauge", name)), "{}", line);
+        }
     }
-
+    
     #[test]
     fn test_determine_category() {
-        assert_eq!(determine_category(1.5), PerformanceCategory::Descarte);
-        assert_eq!(determine_category(3.5), PerformanceCategory::Manutencao);
-        assert_eq!(determine_category(5.5), PerformanceCategory::Precaução);
-        assert_eq!(determine_category(8.5), PerformanceCategory::BomEstado);
+        let boundaries = CategoryBoundaries::default();
+        assert_eq!(determine_category(1.5, &boundaries), PerformanceCategory::Descarte);
+        assert_eq!(determine_category(3.5, &boundaries), PerformanceCategory::Manutencao);
+        assert_eq!(determine_category(5.5, &boundaries), PerformanceCategory::Precaução);
+        assert_eq!(determine_category(8.5, &boundaries), PerformanceCategory::BomEstado);
         
         // Teste de limites
-        assert_eq!(determine_category(2.9), PerformanceCategory::Descarte);
-        assert_eq!(determine_category(3.0), PerformanceCategory::Manutencao);
-        assert_eq!(determine_category(6.9), PerformanceCategory::Precaução);
-        assert_eq!(determine_category(7.0), PerformanceCategory::BomEstado);
+        assert_eq!(determine_category(2.9, &boundaries), PerformanceCategory::Descarte);
+        assert_eq!(determine_category(3.0, &boundaries), PerformanceCategory::Manutencao);
+        assert_eq!(determine_category(6.9, &boundaries), PerformanceCategory::Precaução);
+        assert_eq!(determine_category(7.0, &boundaries), PerformanceCategory::BomEstado);
+        
+        // Limites personalizados deslocam as categorias
+        let strict = CategoryBoundaries { manutencao: 4.0, precaucao: 6.0, bom_estado: 8.0 };
+        assert!(strict.validate().is_ok());
+        assert_eq!(determine_category(7.5, &strict), PerformanceCategory::Precaução);
+        assert_eq!(determine_category(3.5, &strict), PerformanceCategory::Descarte);
+        
+        let unordered = CategoryBoundaries { manutencao: 5.0, precaucao: 3.0, bom_estado: 7.0 };
+        assert!(unordered.validate().is_err());
+    }
+    
+    #[test]
+    fn test_category_ascii_names() {
+        for category in PerformanceCategory::all() {
+            assert!(category.to_ascii_name().is_ascii());
+        }
+        // A exibição mantém os acentos
+        assert_eq!(PerformanceCategory::Precaução.description(), "USO COM PRECAUÇÃO - Monitorar constantemente");
+        assert_eq!(PerformanceCategory::Precaução.to_ascii_name(), "Precaucao");
+    }
+    
+    #[test]
+    fn test_category_legend_follows_boundaries() {
+        let boundaries = CategoryBoundaries::default();
+        assert_eq!(boundaries.manutencao, MANUTENCAO_MIN_SCORE);
+        assert_eq!(determine_category(BOM_ESTADO_MIN_SCORE, &boundaries), PerformanceCategory::BomEstado);
+        assert_eq!(determine_category(PRECAUCAO_MIN_SCORE - 0.01, &boundaries), PerformanceCategory::Manutencao);
+        
+        assert_eq!(boundaries.legend_range(&PerformanceCategory::Descarte), "0-2.9");
+        assert_eq!(boundaries.legend_range(&PerformanceCategory::Manutencao), "3-4.9");
+        assert_eq!(boundaries.legend_range(&PerformanceCategory::BomEstado), "7-10");
+        
+        let custom = CategoryBoundaries { bom_estado: 6.5, ..boundaries };
+        assert_eq!(custom.legend_range(&PerformanceCategory::Precaução), "5-6.4");
+        assert_eq!(custom.legend_range(&PerformanceCategory::BomEstado), "6.5-10");
+        
+        let score = PerformanceScore {
+            overall_score: 8.0,
+            cpu_score: Some(8.0),
+            ram_score: Some(8.0),
+            disk_score: Some(8.0),
+            per_disk_scores: Vec::new(),
+            gpu_score: None,
+            battery_score: None,
+            category: PerformanceCategory::BomEstado,
+            recommendations: Vec::new(),
+        };
+        let display = display_performance_score_colored(&score, false);
+        assert!(display.contains("7-10  → BOM ESTADO DE USO"));
+        assert!(display.contains("0-2.9 → DESCARTE/UPGRADE COMPLETO"));
+        
+        // A legenda segue os limites ativos da configuração
+        let config = ScoringConfig { categories: custom, ..ScoringConfig::default() };
+        let display = display_performance_score_with_config(&score, false, &config);
+        assert!(display.contains("6.5-10→ BOM ESTADO DE USO"), "{}", display);
+        assert!(display.contains("5-6.4 → "), "{}", display);
+        assert!(!display.contains("7-10"));
+    }
+
+    #[test]
+    fn test_info_from_caller_system() {
+        let mut sys = System::new();
+        sys.refresh_cpu();
+        sys.refresh_memory();
+        let disks = Disks::new_with_refreshed_list();
+        
+        // Sem a espera de amostragem da CPU nem da E/S
+        let start = Instant::now();
+        let cpu = cpu_info_from(&sys, &[]);
+        let ram = ram_info_from(&sys);
+        let disk_list = disk_info_from(&disks);
+        assert!(start.elapsed() < DEFAULT_CPU_SAMPLE_INTERVAL);
+        
+        assert_eq!(cpu.number_cpus, sys.cpus().len());
+        assert_eq!(ram.total_ram, sys.total_memory());
+        assert_eq!(disk_list.len(), disks.list().len());
+        assert!(disk_list.iter().all(|disk| disk.health.is_none()));
+        
+        // `System` sem atualização: valores padrão, como nas versões sem `_checked`
+        assert_eq!(cpu_info_from(&System::new(), &[]).number_cpus, 0);
+        assert_eq!(ram_info_from(&System::new()).total_ram, 0);
+        assert!(disk_info_from(&Disks::new()).is_empty());
+    }
+    
+    #[test]
+    fn test_checked_defaults_and_errors() {
+        // Valores padrão usados quando a consulta falha
+        let cpu = CpuInfo::default();
+        assert_eq!(cpu.number_cpus, 0);
+        assert_eq!(cpu.name, "Desconhecido");
+        assert_eq!(RamInfo::default().total_ram, 0);
+        
+        let err = DiagnosticError::CpuQueryFailed("nenhuma CPU detectada".to_string());
+        assert!(err.to_string().contains("nenhuma CPU detectada"));
+        
+        let err = DiagnosticError::DiskQueryFailed {
+            mount_point: "C:\\".to_string(),
+            source: "acesso negado".to_string(),
+        };
+        assert!(err.to_string().contains("C:\\"));
+        assert!(err.to_string().contains("acesso negado"));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let score = PerformanceScore {
+            overall_score: 5.5,
+            cpu_score: Some(6.0),
+            ram_score: Some(5.0),
+            disk_score: Some(5.3),
+            per_disk_scores: Vec::new(),
+            gpu_score: None,
+            battery_score: None,
+            category: PerformanceCategory::Precaução,
+            recommendations: vec!["🔶 USO COM PRECAUÇÃO".to_string()],
+        };
+        
+        let json = serde_json::to_string(&score).unwrap();
+        assert!(json.contains("\"Precaucao\""), "Categoria deve ser serializada em ASCII");
+        
+        let restored: PerformanceScore = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, score);
+    }
+    
+    #[test]
+    fn test_analyze_trend() {
+        let day = Duration::from_secs(86_400);
+        let start = std::time::UNIX_EPOCH + day * 20_000;
+        let snapshots = |scores: &[f64]| -> Vec<TimestampedScore> {
+            scores
+                .iter()
+                .enumerate()
+                .map(|(i, &overall_score)| TimestampedScore {
+                    timestamp: start + day * 10 * i as u32,
+                    score: PerformanceScore {
+                        overall_score,
+                        cpu_score: None,
+                        ram_score: None,
+                        disk_score: None,
+                        per_disk_scores: Vec::new(),
+                        gpu_score: None,
+                        battery_score: None,
+                        category: PerformanceCategory::from_score(overall_score),
+                        recommendations: Vec::new(),
+                    },
+                })
+                .collect()
+        };
+        
+        // 0.4 ponto a menos a cada 10 dias: 1.2 em 30 dias
+        let degrading = snapshots(&[8.0, 7.6, 7.2, 6.8]);
+        let fit = utils::fit_score_trend(&degrading).unwrap();
+        assert!((fit.points_per_day + 0.04).abs() < 1e-9);
+        assert!((fit.days - 30.0).abs() < 1e-9);
+        assert_eq!(utils::analyze_trend(&degrading), history::ScoreTrend::Degrading);
+        assert_eq!(fit.summary(Language::PtBr), "📉 A máquina piorou 1.2 pontos em 30 dias");
+        assert_eq!(fit.summary(Language::EnUs), "📉 The machine has degraded 1.2 points over 30 days");
+        
+        // A reta ignora um pico isolado
+        assert_eq!(utils::analyze_trend(&snapshots(&[6.0, 6.2, 9.0, 6.1, 6.0])), history::ScoreTrend::Stable);
+        assert_eq!(utils::analyze_trend(&snapshots(&[5.0, 6.0, 7.0])), history::ScoreTrend::Improving);
+        
+        // Histórico insuficiente
+        assert_eq!(utils::analyze_trend(&snapshots(&[5.0])), history::ScoreTrend::Stable);
+        assert!(utils::fit_score_trend(&[]).is_none());
+        let same_instant = vec![snapshots(&[5.0])[0].clone(), snapshots(&[9.0])[0].clone()];
+        assert!(utils::fit_score_trend(&same_instant).is_none());
+    }
+    
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_snapshots_round_trip() {
+        let path = std::env::temp_dir().join(format!("hardware_diagnostic_snapshots_{}.jsonl", std::process::id()));
+        let score = |overall_score| PerformanceScore {
+            overall_score,
+            cpu_score: Some(6.0),
+            ram_score: Some(5.0),
+            disk_score: None,
+            per_disk_scores: Vec::new(),
+            gpu_score: None,
+            battery_score: None,
+            category: PerformanceCategory::Precaução,
+            recommendations: Vec::new(),
+        };
+        
+        assert!(utils::load_snapshots(&path).is_empty(), "Arquivo inexistente deve resultar em lista vazia");
+        utils::append_snapshot(&path, &score(5.0)).unwrap();
+        utils::append_snapshot(&path, &score(6.5)).unwrap();
+        // Gravação interrompida no meio da linha
+        let mut file = fs::OpenOptions::new().append(true).open(&path).unwrap();
+        std::io::Write::write_all(&mut file, b"{\"timestamp\":").unwrap();
+        
+        let snapshots = utils::load_snapshots(&path);
+        fs::remove_file(&path).ok();
+        
+        assert_eq!(snapshots.len(), 2);
+        assert_eq!(snapshots[0].score, score(5.0));
+        assert_eq!(snapshots[1].score.overall_score, 6.5);
+        assert!(snapshots[0].timestamp <= snapshots[1].timestamp);
     }
 
     #[test]
@@ -838,12 +7037,248 @@ mod tests {
         assert_eq!(utils::bytes_to_gb(5_000_000_000), "5.00");
         assert_eq!(utils::bytes_to_gb_f64(5_000_000_000), 5.0);
         
+        // Teste bytes_to_gib e ByteUnit
+        assert_eq!(utils::bytes_to_gib(8 * 1024 * 1024 * 1024), "8.00");
+        assert_eq!(utils::bytes_to_gib(8_000_000_000), "7.45");
+        assert_eq!(utils::format_bytes(8_000_000_000, utils::ByteUnit::Gb), "8.00 GB");
+        assert_eq!(utils::format_bytes(8_000_000_000, utils::REPORT_UNIT), "7.45 GiB");
+        
+        // Teste bytes_to_human_readable nas fronteiras de cada unidade
+        const KIB: u64 = 1024;
+        assert_eq!(utils::bytes_to_human_readable(0), "0 B");
+        assert_eq!(utils::bytes_to_human_readable(KIB - 1), "1023 B");
+        assert_eq!(utils::bytes_to_human_readable(KIB), "1.00 KiB");
+        assert_eq!(utils::bytes_to_human_readable(KIB.pow(2) - 1), "1024.00 KiB");
+        assert_eq!(utils::bytes_to_human_readable(KIB.pow(2)), "1.00 MiB");
+        assert_eq!(utils::bytes_to_human_readable(512 * KIB.pow(2)), "512.00 MiB");
+        assert_eq!(utils::bytes_to_human_readable(KIB.pow(3)), "1.00 GiB");
+        assert_eq!(utils::bytes_to_human_readable(3 * KIB.pow(3) / 2), "1.50 GiB");
+        assert_eq!(utils::bytes_to_human_readable(KIB.pow(4)), "1.00 TiB");
+        assert_eq!(utils::bytes_to_human_readable(2 * KIB.pow(4)), "2.00 TiB");
+        assert_eq!(utils::bytes_to_human_readable_si(999), "999 B");
+        assert_eq!(utils::bytes_to_human_readable_si(4_000), "4.00 KB");
+        assert_eq!(utils::bytes_to_human_readable_si(1_500_000_000), "1.50 GB");
+        assert_eq!(utils::bytes_to_human_readable_si(2_000_000_000_000), "2.00 TB");
+        
+        // Teste format_frequency_ghz e format_frequency_auto
+        assert_eq!(utils::format_frequency_ghz(0), "0.00 GHz");
+        assert_eq!(utils::format_frequency_ghz(999), "1.00 GHz");
+        assert_eq!(utils::format_frequency_ghz(1000), "1.00 GHz");
+        assert_eq!(utils::format_frequency_ghz(3600), "3.60 GHz");
+        assert_eq!(utils::format_frequency_ghz(5000), "5.00 GHz");
+        assert_eq!(utils::format_frequency_auto(0), "0 MHz");
+        assert_eq!(utils::format_frequency_auto(999), "999 MHz");
+        assert_eq!(utils::format_frequency_auto(1000), "1.00 GHz");
+        assert_eq!(utils::format_frequency_auto(3600), "3.60 GHz");
+        assert_eq!(utils::format_frequency_auto(5000), "5.00 GHz");
+        
+        // Teste format_uptime
+        assert_eq!(utils::format_uptime(0), "0 minutos");
+        assert_eq!(utils::format_uptime(59), "0 minutos");
+        assert_eq!(utils::format_uptime(60), "1 minuto");
+        assert_eq!(utils::format_uptime(3_599), "59 minutos");
+        assert_eq!(utils::format_uptime(3_600), "1 hora");
+        assert_eq!(utils::format_uptime(86_399), "23 horas, 59 minutos");
+        assert_eq!(utils::format_uptime(86_400), "1 dia");
+        assert_eq!(utils::format_uptime(2_592_000), "30 dias");
+        assert_eq!(utils::format_uptime(2 * 86_400 + 5 * 3_600 + 30 * 60 + 59), "2 dias, 5 horas, 30 minutos");
+        
         // Teste progress_bar
         let bar = utils::progress_bar(75.0, 10);
-        assert_eq!(bar.len(), 12); // [ + 10 chars + ]
-        assert!(bar.contains("██████████")); // 75% de 10 = 7.5 ≈ 8 caracteres
+        assert_eq!(bar.chars().count(), 12); // [ + 10 chars + ]
+        assert!(bar.contains("████████")); // 75% de 10 = 7.5 ≈ 8 caracteres
+        
+        // Estilos e limites da barra
+        assert_eq!(utils::render_bar(75.0, 10, utils::BarStyle::Unicode), "[████████░░]");
+        assert_eq!(utils::render_bar(30.0, 10, utils::BarStyle::Ascii), "[###-------]");
+        assert_eq!(utils::render_bar(150.0, 4, utils::BarStyle::Ascii), "[####]");
+        assert_eq!(utils::render_bar(-5.0, 4, utils::BarStyle::Custom { fill: '=', empty: '.' }), "[....]");
+        
+        // Barra de pontuação: proporção exata de `max`, sem confundir com percentuais
+        assert_eq!(utils::format_score_bar(8.3, 10.0, 20), "[▓▓▓▓▓▓▓▓▓▓▓▓▓▓▓▓▓│░░]");
+        assert_eq!(utils::format_score_bar(2.5, 5.0, 4), "[▓▓│░]");
+        assert_eq!(utils::format_score_bar(-1.0, 10.0, 3), "[│░░]");
+        assert_eq!(utils::format_score_bar(5.0, 0.0, 3), "[│░░]");
+        assert_eq!(utils::format_score_bar(5.0, 10.0, 0), "[]");
+        assert_eq!(utils::format_score_bar_with_label(10.0, 10.0, "RAM", 2), "RAM [▓│] 10.0/10");
+        assert_eq!(utils::to_ascii("[▓▓│░]"), "[==|-]");
+        
+        // O marcador fica na posição da pontuação, com a largura preservada
+        for (score, position) in [(0.0, 0), (3.0, 3), (6.6, 7), (9.9, 9), (10.0, 9)] {
+            let bar = utils::format_score_bar(score, 10.0, 10);
+            let cells: Vec<char> = bar.trim_matches(|c| c == '[' || c == ']').chars().collect();
+            assert_eq!(cells.len(), 10, "{}", bar);
+            assert_eq!(cells.iter().position(|&c| c == '│'), Some(position), "{}", bar);
+            assert!(cells[..position].iter().all(|&c| c == '▓'), "{}", bar);
+        }
+    }
+    
+    #[test]
+    fn test_progress_bar_colored() {
+        let ansi = utils::AnsiGuard::set(true);
+        assert!(utils::ansi_enabled());
+        assert!(utils::progress_bar_colored(50.0, 10).contains("\x1b[32m"));
+        assert!(utils::progress_bar_colored(70.0, 10).contains("\x1b[33m"));
+        assert!(utils::progress_bar_colored(84.9, 10).contains("\x1b[33m"));
+        assert!(utils::progress_bar_colored(85.0, 10).contains("\x1b[31m"));
+        assert!(utils::progress_bar_colored(85.0, 10).contains("\x1b[0m"));
+        let score = PerformanceScore {
+            overall_score: 2.0,
+            cpu_score: None,
+            ram_score: None,
+            disk_score: None,
+            per_disk_scores: Vec::new(),
+            gpu_score: None,
+            battery_score: None,
+            category: PerformanceCategory::Descarte,
+            recommendations: Vec::new(),
+        };
+        assert!(display_performance_score_colored(&score, true).contains("\x1b[31m"));
+        let better = PerformanceScore { overall_score: 8.0, category: PerformanceCategory::BomEstado, ..score.clone() };
+        assert!(display_diff_colored(&better.compare(&score), true).contains("\x1b[32m▲ +6.0"));
+        drop(ansi);
+        
+        let _ansi = utils::AnsiGuard::set(false);
+        assert!(!utils::ansi_enabled());
+        assert!(!utils::colors_enabled());
+        let bar = utils::progress_bar_colored(90.0, 10);
+        assert!(!bar.contains('\x1b'));
+        assert_eq!(bar, utils::progress_bar(90.0, 10));
+        assert_eq!(PerformanceCategory::Descarte.color_code(), "");
+        assert!(!display_performance_score_colored(&score, true).contains('\x1b'));
+        assert!(!display_diff_colored(&better.compare(&score), true).contains('\x1b'));
+    }
+
+    #[test]
+    fn test_to_ascii() {
+        assert_eq!(utils::to_ascii("⚠️ AÇÃO RECOMENDADA"), "[!] ACAO RECOMENDADA");
+        assert_eq!(utils::to_ascii("🖥️  DIAGNÓSTICO"), "DIAGNOSTICO");
+        assert_eq!(utils::to_ascii("Memória: 1º módulo Ñandú"), "Memoria: 1? modulo Nandu");
+        assert_eq!(utils::to_ascii("  • RAM total:  8 GiB → 16 GiB"), "  * RAM total:  8 GiB -> 16 GiB");
+        assert_eq!(utils::to_ascii("Geral: ▲ +1.2 | CPU: ▼ -0.8"), "Geral: ^ +1.2 | CPU: v -0.8");
+        assert_eq!(utils::to_ascii("▁▄█ ℹ️ ok"), "_-# [i] ok");
+        assert_eq!(utils::to_ascii("╔══╗"), "+--+");
+        
+        // Texto já em ASCII não muda; a chave global começa desligada
+        assert_eq!(utils::to_ascii("[###---] 5.0/10"), "[###---] 5.0/10");
+        assert!(!utils::ascii_output());
+        assert_eq!(utils::render_text("✅ OK"), "✅ OK");
+    }
+    
+    #[test]
+    fn test_strip_ansi() {
+        assert_eq!(utils::strip_ansi("\x1b[32m[███]\x1b[0m 8.0"), "[███] 8.0");
+        assert_eq!(utils::strip_ansi("\x1b[1;93mPRECAUÇÃO\x1b[0m"), "PRECAUÇÃO");
+        assert_eq!(utils::strip_ansi("sem cores"), "sem cores");
+    }
+    
+    #[test]
+    fn test_ansi_support_from_env() {
+        use std::ffi::OsStr;
+        use utils::AnsiSupport;
+        
+        assert_eq!(AnsiSupport::from_env(None, None), AnsiSupport::Enabled);
+        assert_eq!(AnsiSupport::from_env(None, Some(OsStr::new("xterm-256color"))), AnsiSupport::Enabled);
+        assert_eq!(AnsiSupport::from_env(Some(OsStr::new("1")), Some(OsStr::new("xterm"))), AnsiSupport::NoColor);
+        assert_eq!(AnsiSupport::from_env(Some(OsStr::new("")), None), AnsiSupport::Enabled);
+        assert_eq!(AnsiSupport::from_env(Some(OsStr::new("")), Some(OsStr::new("dumb"))), AnsiSupport::DumbTerminal);
+        assert!(AnsiSupport::Enabled.is_enabled());
+        assert!(!AnsiSupport::NoColor.is_enabled());
+        assert!(!AnsiSupport::DumbTerminal.is_enabled());
+    }
+    
+    #[test]
+    fn test_format_iso8601() {
+        use std::time::{Duration, UNIX_EPOCH};
+        
+        assert_eq!(utils::format_iso8601(UNIX_EPOCH), "1970-01-01T00:00:00Z");
+        assert_eq!(
+            utils::format_iso8601(UNIX_EPOCH + Duration::from_secs(951_782_400)),
+            "2000-02-29T00:00:00Z"
+        );
+        assert_eq!(
+            utils::format_iso8601(UNIX_EPOCH + Duration::from_secs(1_735_689_599)),
+            "2024-12-31T23:59:59Z"
+        );
     }
 
+    #[test]
+    fn test_category_from_score() {
+        let cases = [
+            (2.99, PerformanceCategory::Descarte),
+            (3.0, PerformanceCategory::Manutencao),
+            (4.99, PerformanceCategory::Manutencao),
+            (5.0, PerformanceCategory::Precaução),
+            (6.99, PerformanceCategory::Precaução),
+            (7.0, PerformanceCategory::BomEstado),
+            (10.0, PerformanceCategory::BomEstado),
+        ];
+        for (score, expected) in cases {
+            assert_eq!(PerformanceCategory::from_score(score), expected, "pontuação {}", score);
+            assert!(expected.score_range().contains(&score), "pontuação {}", score);
+        }
+        
+        // Faixas contíguas, na mesma ordem de `all()`
+        let all = PerformanceCategory::all();
+        assert_eq!(all.len(), 4);
+        assert_eq!(all[0].score_range(), 0.0..3.0);
+        for pair in all.windows(2) {
+            assert_eq!(pair[0].score_range().end, pair[1].score_range().start);
+        }
+    }
+    
+    #[test]
+    fn test_display_summaries() {
+        const GIB: u64 = 1024 * 1024 * 1024;
+        let cpu = CpuInfo {
+            number_cpus: 20,
+            cpu_usage: 45.2,
+            frequency: 3600,
+            name: "Intel Core i7-12700K".to_string(),
+            physical_cores: Some(12),
+            ..CpuInfo::default()
+        };
+        assert_eq!(cpu.to_string(), "Intel Core i7-12700K | 20 cores (12 physical) | 3.60 GHz | 45.2% usage");
+        
+        let ram = RamInfo {
+            total_ram: 32 * GIB,
+            used_ram: 16 * GIB,
+            free_ram: 16 * GIB,
+            total_swap: 8 * GIB,
+            used_swap: GIB / 2,
+            ram_usage_percent: 50.0,
+            swap_usage_percent: 6.25,
+            memory_type: None,
+            speed_mhz: None,
+            slots_used: None,
+            slots_total: None,
+        };
+        assert_eq!(ram.to_string(), "16.0 GiB / 32.0 GiB (50.0% used) | SWAP: 0.5 GiB / 8.0 GiB");
+        
+        let disk = DiskInfo {
+            name: "C:".to_string(),
+            mount_point: "C:\\".to_string(),
+            total_space: 512 * GIB,
+            available_space: 256 * GIB,
+            used_space: 256 * GIB,
+            usage_percent: 50.0,
+            file_system: "NTFS".to_string(),
+            kind: DiskKind::Ssd,
+            read_bytes_per_sec: 0,
+            write_bytes_per_sec: 0,
+            read_iops: 0,
+            write_iops: 0,
+            io_utilization_percent: 0.0,
+            health: None,
+            removable: false,
+            read_only: false,
+        };
+        assert_eq!(disk.to_string(), "C: [NTFS/SSD] 256.0 GiB / 512.0 GiB (50.0% used)");
+        
+        let score = score_from(None, None, None, None, None, &[], &[], None, None, None, &DiagnosticConfig::default());
+        assert_eq!(PerformanceScore { overall_score: 8.3, category: PerformanceCategory::BomEstado, ..score }.to_string(), "8.3/10 — BomEstado");
+    }
+    
     #[test]
     fn test_recommendations_generation() {
         let cpu_info = CpuInfo {
@@ -852,6 +7287,11 @@ mod tests {
             frequency: 2000,
             name: "Single Core".to_string(),
             physical_cores: Some(1),
+            per_core_usage: vec![90.0],
+            per_core_frequency: vec![2000],
+            thermal_throttling_detected: false,
+            throttling: None,
+            vulnerabilities: None,
         };
         
         let ram_info = RamInfo {
@@ -862,6 +7302,10 @@ mod tests {
             used_swap: 0,
             ram_usage_percent: 90.0,
             swap_usage_percent: 0.0,
+            memory_type: None,
+            speed_mhz: None,
+            slots_used: None,
+            slots_total: None,
         };
         
         let disks = vec![DiskInfo {
@@ -872,14 +7316,151 @@ mod tests {
             used_space: 95_000_000_000,
             usage_percent: 95.0,
             file_system: "NTFS".to_string(),
-            disk_type: "HDD".to_string(),
+            kind: DiskKind::Hdd,
+            read_bytes_per_sec: 0,
+            write_bytes_per_sec: 0,
+            read_iops: 0,
+            write_iops: 0,
+            io_utilization_percent: 0.0,
+            health: None,
+            removable: false,
+            read_only: false,
         }];
         
-        let recommendations = generate_recommendations(&cpu_info, &ram_info, &disks, 2.5);
+        let recommendations = generate_recommendations(Some(&cpu_info), Some(&ram_info), &disks, None, &[], &[], None, None, None, &PerformanceCategory::Descarte, &ScoringConfig::default());
         
         assert!(!recommendations.is_empty());
         assert!(recommendations.iter().any(|r| r.contains("CPU")));
         assert!(recommendations.iter().any(|r| r.contains("RAM")));
         assert!(recommendations.iter().any(|r| r.contains("DISCO")));
+        
+        // Uso alto: cita apenas os processos recebidos, sem amostrar o sistema
+        assert!(recommendations.contains(&"🔴 CPU: Uso muito alto. Verifique processos desnecessários".to_string()));
+        let top = [ProcessInfo {
+            pid: 4242,
+            name: "compilador".to_string(),
+            cpu_usage: 97.5,
+            memory_bytes: 0,
+            status: "Runnable".to_string(),
+        }];
+        let recommendations = generate_recommendations(Some(&cpu_info), None, &[], None, &[], &top, None, None, None, &PerformanceCategory::Descarte, &ScoringConfig::default());
+        assert!(recommendations.iter().any(|r| r.contains("Maior consumo: compilador (PID 4242, 97.5%)")));
+        
+        // Bateria degradada gera recomendação própria
+        let battery = BatteryInfo {
+            charge_percent: 50.0,
+            is_charging: false,
+            health_percent: Some(45.0),
+            estimated_minutes_remaining: Some(40),
+            cycle_count: None,
+            state: battery::BatteryState::Discharging,
+            full_capacity_wh: Some(22.5),
+            design_capacity_wh: Some(50.0),
+        };
+        let recommendations = generate_recommendations(Some(&cpu_info), Some(&ram_info), &disks, Some(&battery), &[], &[], None, None, None, &PerformanceCategory::Descarte, &ScoringConfig::default());
+        assert!(recommendations.iter().any(|r| r.contains("BATERIA")));
+        
+        // Mais de 30 dias ligada sugere reinicialização
+        let recommendations = generate_recommendations(None, None, &[], None, &[], &[], Some(45 * 86_400), None, None, &PerformanceCategory::BomEstado, &ScoringConfig::default());
+        assert!(recommendations.iter().any(|r| r.contains("Ligado há 45 dias")));
+        let recommendations = generate_recommendations(None, None, &[], None, &[], &[], Some(30 * 86_400), None, None, &PerformanceCategory::BomEstado, &ScoringConfig::default());
+        assert!(!recommendations.iter().any(|r| r.contains("SISTEMA")));
+        
+        // Em VMs e contêineres o descarte do hardware não é sugerido
+        let physical = generate_recommendations(Some(&cpu_info), Some(&ram_info), &disks, None, &[], &[], None, None, None, &PerformanceCategory::Descarte, &ScoringConfig::default());
+        let virtualized = generate_recommendations(Some(&cpu_info), Some(&ram_info), &disks, None, &[], &[], None, Some(VirtKind::HyperV), None, &PerformanceCategory::Descarte, &ScoringConfig::default());
+        assert!(physical.iter().any(|r| r.contains("substituição do equipamento")));
+        assert!(!virtualized.iter().any(|r| r.contains("substituição do equipamento")));
+        assert!(physical.iter().any(|r| r.contains("Substituir equipamento")));
+        assert!(!virtualized.iter().any(|r| r.contains("Substituir equipamento")));
+        assert!(virtualized.last().unwrap().contains("alocados ao ambiente virtual"));
+        assert!(virtualized.iter().any(|r| r.contains("AMBIENTE VIRTUAL: Executando em Hyper-V")));
+        
+        // CPU acima de 90°C gera alerta crítico
+        let hot_cpu = [TemperatureReading {
+            label: "coretemp Package id 0".to_string(),
+            temperature_celsius: 95.0,
+            critical_threshold: Some(100.0),
+            component: ThermalComponent::Cpu,
+        }];
+        let recommendations = generate_recommendations(Some(&cpu_info), Some(&ram_info), &disks, None, &hot_cpu, &[], None, None, None, &PerformanceCategory::Descarte, &ScoringConfig::default());
+        assert!(recommendations.iter().any(|r| r.contains("Temperatura crítica")));
+        
+        // Mesmas condições em inglês, na mesma ordem
+        let english_config = ScoringConfig { language: Language::EnUs, ..ScoringConfig::default() };
+        let english = generate_recommendations(Some(&cpu_info), Some(&ram_info), &disks, None, &hot_cpu, &[], None, None, None, &PerformanceCategory::Descarte, &english_config);
+        assert_eq!(english.len(), recommendations.len());
+        assert!(english.iter().any(|r| r.contains("Critical temperature (95°C)")));
+        assert!(english.iter().any(|r| r.contains("DISK C:")));
+        assert!(english.last().unwrap().contains("Recommended action"));
+        
+        assert_eq!(Language::from_code("EN-us"), Some(Language::EnUs));
+        assert_eq!(Language::default(), Language::PtBr);
+    }
+    
+    #[test]
+    fn test_cpu_vulnerabilities() {
+        let dir = std::env::temp_dir().join(format!("vulnerabilities-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("meltdown"), "Mitigation: PTI\n").unwrap();
+        fs::write(dir.join("mds"), "Vulnerable: Clear CPU buffers attempted, no microcode\n").unwrap();
+        fs::write(dir.join("l1tf"), "Not affected\n").unwrap();
+        
+        let vulnerabilities = read_sysfs_vulnerabilities(&dir).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+        let names: Vec<&str> = vulnerabilities.iter().map(|v| v.name.as_str()).collect();
+        assert_eq!(names, ["l1tf", "mds", "meltdown"]);
+        assert_eq!(vulnerabilities[2].description, "Mitigation: PTI");
+        assert!(vulnerabilities[0].mitigated && vulnerabilities[2].mitigated);
+        assert!(!vulnerabilities[1].mitigated);
+        assert_eq!(read_sysfs_vulnerabilities(&dir), None, "Diretório ausente: informação indisponível");
+        
+        // Saída do Get-SpeculationControlSettings; módulo ausente resulta em None
+        let output = "BTIHardwarePresent             : True\nBTIWindowsSupportEnabled       : True\n\
+                      KVAShadowRequired              : True\nKVAShadowWindowsSupportEnabled : False\n\
+                      L1TFRequired                   : False\n";
+        let windows = parse_speculation_control(output).unwrap();
+        let status = |name: &str| windows.iter().find(|v| v.name == name).map(|v| v.mitigated);
+        assert_eq!(status("spectre_v2"), Some(true));
+        assert_eq!(status("meltdown"), Some(false));
+        assert_eq!(status("l1tf"), Some(true));
+        assert_eq!(status("mds"), None);
+        assert_eq!(parse_speculation_control("Get-SpeculationControlSettings : termo não reconhecido"), None);
+        
+        // Vulnerabilidade sem mitigação gera recomendação de segurança
+        let cpu = CpuInfo { vulnerabilities: Some(vulnerabilities), ..CpuInfo::default() };
+        let recommendations = generate_recommendations(Some(&cpu), None, &[], None, &[], &[], None, None, None, &PerformanceCategory::BomEstado, &ScoringConfig::default());
+        assert!(recommendations.iter().any(|r| r.contains("SEGURANÇA: CPU sem mitigação para mds")));
+    }
+    
+    #[test]
+    fn test_thermal_detection() {
+        assert_eq!(ThermalComponent::from_label("coretemp Package id 0"), ThermalComponent::Cpu);
+        assert_eq!(ThermalComponent::from_label("k10temp Tctl"), ThermalComponent::Cpu);
+        assert_eq!(ThermalComponent::from_label("amdgpu edge"), ThermalComponent::Gpu);
+        assert_eq!(ThermalComponent::from_label("nvme Composite"), ThermalComponent::Disk);
+        assert_eq!(ThermalComponent::from_label("acpitz temp1"), ThermalComponent::Motherboard);
+        
+        let hot = [TemperatureReading {
+            label: "CPU".to_string(),
+            temperature_celsius: 92.0,
+            critical_threshold: None,
+            component: ThermalComponent::Cpu,
+        }];
+        assert!(detect_thermal_throttling(&hot, 1800, Some(3000)));
+        assert!(!detect_thermal_throttling(&hot, 3000, Some(3000)));
+        assert!(detect_thermal_throttling(&hot, 3000, None));
+        assert!(!detect_thermal_throttling(&[], 1800, Some(3000)));
+        
+        // Exatamente no limite não conta, como na recomendação de temperatura crítica
+        let at_limit = [TemperatureReading { temperature_celsius: 90.0, ..hot[0].clone() }];
+        assert!(!detect_thermal_throttling(&at_limit, 1800, Some(3000)));
+        let near_critical = [TemperatureReading { temperature_celsius: 81.0, critical_threshold: Some(85.0), ..hot[0].clone() }];
+        assert!(detect_thermal_throttling(&near_critical, 1800, Some(3000)));
+        let recommendations = |readings: &[TemperatureReading]| {
+            generate_recommendations(None, None, &[], None, readings, &[], None, None, None, &PerformanceCategory::BomEstado, &ScoringConfig::default())
+        };
+        assert!(!recommendations(&at_limit).iter().any(|r| r.contains("Temperatura crítica")));
+        assert!(recommendations(&hot).iter().any(|r| r.contains("Temperatura crítica (92°C)")));
     }
 }
\ No newline at end of file
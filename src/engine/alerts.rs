@@ -0,0 +1,433 @@
+// hardware-diagnostic - Ferramenta de diagnóstico de hardware
+// Copyright (C) 2025  Seu Nome
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Módulo `alerts` - Alertas por limiar para monitoramento automatizado
+//! 
+//! Enquanto as recomendações são texto para pessoas, os alertas são
+//! condições detectáveis por máquina: cada [`Alert`] traz a severidade, o
+//! valor medido e o limiar ultrapassado, prontos para scripts e agendadores.
+
+use super::smart::SmartStatus;
+use super::{utils, DiagnosticError, DiagnosticReport};
+use std::fmt;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::Path;
+
+/// Severidade de um alerta, da menos para a mais grave
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AlertSeverity {
+    /// Informativo: merece atenção, mas não exige ação
+    Info,
+    /// Aviso: limiar de alerta ultrapassado
+    Warning,
+    /// Crítico: limiar crítico ultrapassado ou falha prevista
+    Critical,
+}
+
+impl AlertSeverity {
+    /// Rótulo em português usado nos logs e na CLI
+    pub fn label(&self) -> &'static str {
+        match self {
+            AlertSeverity::Info => "INFO",
+            AlertSeverity::Warning => "AVISO",
+            AlertSeverity::Critical => "CRÍTICO",
+        }
+    }
+}
+
+/// Condição detectada por [`AlertManager::check`]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Alert {
+    /// Severidade do alerta
+    pub severity: AlertSeverity,
+    /// Componente afetado (ex: "CPU", "RAM", "Disco /dev/sda1")
+    pub component: String,
+    /// Descrição do problema
+    pub message: String,
+    /// Valor medido
+    pub current_value: f64,
+    /// Limiar ultrapassado
+    pub threshold: f64,
+}
+
+impl fmt::Display for Alert {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "[{}] {}: {} (atual {:.1}, limite {:.1})",
+            self.severity.label(),
+            self.component,
+            self.message,
+            self.current_value,
+            self.threshold
+        )
+    }
+}
+
+/// Limiares de alerta
+/// 
+/// Com a feature `serde`, pode ser carregada de um arquivo via
+/// [`AlertConfig::from_file`]; campos ausentes mantêm os valores padrão.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct AlertConfig {
+    /// Uso da CPU (%) a partir do qual emitir aviso
+    pub cpu_usage_warn: f32,
+    /// Uso da CPU (%) a partir do qual o alerta é crítico
+    pub cpu_usage_crit: f32,
+    /// Uso da RAM (%) a partir do qual emitir aviso
+    pub ram_usage_warn: f64,
+    /// Uso da RAM (%) a partir do qual o alerta é crítico
+    pub ram_usage_crit: f64,
+    /// Uso de cada disco (%) a partir do qual emitir aviso
+    pub disk_usage_warn: f64,
+    /// Uso de cada disco (%) a partir do qual o alerta é crítico
+    pub disk_usage_crit: f64,
+    /// Espaço livre mínimo em cada disco, em GiB
+    #[cfg_attr(feature = "serde", serde(alias = "min_free_disk_gb"))]
+    pub min_free_disk_gib: f64,
+    /// Pontuação geral mínima aceitável (0.0 a 10.0)
+    pub min_score: f64,
+}
+
+impl Default for AlertConfig {
+    fn default() -> Self {
+        AlertConfig {
+            cpu_usage_warn: 80.0,
+            cpu_usage_crit: 95.0,
+            ram_usage_warn: 85.0,
+            ram_usage_crit: 95.0,
+            disk_usage_warn: 85.0,
+            disk_usage_crit: 95.0,
+            min_free_disk_gib: 10.0,
+            min_score: 5.0,
+        }
+    }
+}
+
+impl AlertConfig {
+    /// Carrega os limiares de um arquivo TOML ou JSON
+    /// 
+    /// O formato é escolhido pela extensão, como em
+    /// [`ScoringConfig::from_file`](super::ScoringConfig::from_file), e a
+    /// configuração resultante é validada.
+    /// 
+    /// # Erros
+    /// - `DiagnosticError::Io` se o arquivo não puder ser lido
+    /// - `DiagnosticError::InvalidConfig` se o conteúdo for inválido
+    #[cfg(feature = "serde")]
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, DiagnosticError> {
+        let config: AlertConfig = super::read_config_file(path.as_ref())?;
+        config.validate()?;
+        Ok(config)
+    }
+    
+    /// Verifica se cada limiar de aviso não passa do crítico e se os
+    /// mínimos estão em faixas válidas
+    /// 
+    /// # Erros
+    /// Retorna `DiagnosticError::InvalidConfig` indicando o limiar inválido.
+    pub fn validate(&self) -> Result<(), DiagnosticError> {
+        let pairs = [
+            ("CPU", self.cpu_usage_warn as f64, self.cpu_usage_crit as f64),
+            ("RAM", self.ram_usage_warn, self.ram_usage_crit),
+            ("discos", self.disk_usage_warn, self.disk_usage_crit),
+        ];
+        for (name, warn, crit) in pairs {
+            if !(0.0..=100.0).contains(&warn) || !(0.0..=100.0).contains(&crit) || warn > crit {
+                return Err(DiagnosticError::InvalidConfig(format!(
+                    "limiares de alerta de {} devem estar entre 0 e 100, com aviso ≤ crítico",
+                    name
+                )));
+            }
+        }
+        
+        if self.min_free_disk_gib < 0.0 {
+            return Err(DiagnosticError::InvalidConfig(
+                "espaço livre mínimo não pode ser negativo".to_string(),
+            ));
+        }
+        if !(0.0..=10.0).contains(&self.min_score) {
+            return Err(DiagnosticError::InvalidConfig(
+                "pontuação mínima deve estar entre 0 e 10".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Avaliação e registro de alertas
+pub struct AlertManager;
+
+impl AlertManager {
+    /// Compara o relatório com os limiares e retorna os alertas disparados
+    /// 
+    /// Além dos limiares, a previsão de falha SMART gera um alerta crítico e
+    /// setores realocados geram um alerta informativo. Discos excluídos pelo
+    /// [`disk_filter`](DiagnosticReport::disk_filter) do relatório são ignorados.
+    /// 
+    /// # Exemplo
+    /// ```
+    /// use hardware_diagnostic::DiagnosticReport;
+    /// use hardware_diagnostic::engine::alerts::{AlertConfig, AlertManager};
+    /// 
+    /// let report = DiagnosticReport::capture();
+    /// for alert in AlertManager::check(&report, &AlertConfig::default()) {
+    ///     println!("{}", alert);
+    /// }
+    /// ```
+    pub fn check(report: &DiagnosticReport, config: &AlertConfig) -> Vec<Alert> {
+        let mut alerts = Vec::new();
+        
+        if let Some(alert) = usage_alert(
+            "CPU",
+            report.cpu.cpu_usage as f64,
+            config.cpu_usage_warn as f64,
+            config.cpu_usage_crit as f64,
+        ) {
+            alerts.push(alert);
+        }
+        if let Some(alert) = usage_alert(
+            "RAM",
+            report.ram.ram_usage_percent,
+            config.ram_usage_warn,
+            config.ram_usage_crit,
+        ) {
+            alerts.push(alert);
+        }
+        
+        // Os mesmos discos que entram na pontuação
+        for disk in report.disks.iter().filter(|disk| report.disk_filter.matches(disk)) {
+            let component = format!("Disco {}", disk.name);
+            if let Some(alert) = usage_alert(&component, disk.usage_percent, config.disk_usage_warn, config.disk_usage_crit) {
+                alerts.push(alert);
+            }
+            
+            let free_gib = utils::bytes_to_gib_f64(disk.available_space);
+            if free_gib < config.min_free_disk_gib {
+                alerts.push(Alert {
+                    severity: AlertSeverity::Warning,
+                    component: component.clone(),
+                    message: "Espaço livre abaixo do mínimo (GiB)".to_string(),
+                    current_value: free_gib,
+                    threshold: config.min_free_disk_gib,
+                });
+            }
+            
+            if let Some(health) = &disk.health {
+                if health.health_status == SmartStatus::Failed {
+                    alerts.push(Alert {
+                        severity: AlertSeverity::Critical,
+                        component: component.clone(),
+                        message: "SMART prevê falha iminente".to_string(),
+                        current_value: 1.0,
+                        threshold: 0.0,
+                    });
+                }
+                if let Some(sectors) = health.reallocated_sectors.filter(|&sectors| sectors > 0) {
+                    alerts.push(Alert {
+                        severity: AlertSeverity::Info,
+                        component,
+                        message: "Setores realocados".to_string(),
+                        current_value: sectors as f64,
+                        threshold: 0.0,
+                    });
+                }
+            }
+        }
+        
+        if report.score.overall_score < config.min_score {
+            alerts.push(Alert {
+                severity: AlertSeverity::Warning,
+                component: "Pontuação".to_string(),
+                message: "Pontuação geral abaixo do mínimo".to_string(),
+                current_value: report.score.overall_score,
+                threshold: config.min_score,
+            });
+        }
+        
+        alerts
+    }
+    
+    /// Retorna a severidade mais grave entre os alertas (`None` se não houver)
+    pub fn highest_severity(alerts: &[Alert]) -> Option<AlertSeverity> {
+        alerts.iter().map(|alert| alert.severity).max()
+    }
+    
+    /// Acrescenta os alertas ao log, uma linha por alerta com data e hora
+    /// 
+    /// O arquivo é criado se não existir. Sem alertas, nada é gravado.
+    /// 
+    /// # Erros
+    /// Retorna `DiagnosticError::Io` se o arquivo não puder ser gravado.
+    pub fn write_alerts(alerts: &[Alert], path: &Path) -> Result<(), DiagnosticError> {
+        if alerts.is_empty() {
+            return Ok(());
+        }
+        
+        let io_error = |e: std::io::Error| DiagnosticError::Io {
+            path: path.display().to_string(),
+            source: e.to_string(),
+        };
+        let timestamp = utils::format_iso8601(std::time::SystemTime::now());
+        let lines: String = alerts
+            .iter()
+            .map(|alert| format!("{} {}\n", timestamp, alert))
+            .collect();
+        
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(io_error)?;
+        file.write_all(lines.as_bytes()).map_err(io_error)
+    }
+}
+
+/// Gera o alerta de uso de um componente, se algum limiar foi atingido
+fn usage_alert(component: &str, usage: f64, warn: f64, crit: f64) -> Option<Alert> {
+    let (severity, threshold) = if usage >= crit {
+        (AlertSeverity::Critical, crit)
+    } else if usage >= warn {
+        (AlertSeverity::Warning, warn)
+    } else {
+        return None;
+    };
+    
+    Some(Alert {
+        severity,
+        component: component.to_string(),
+        message: "Uso acima do limite (%)".to_string(),
+        current_value: usage,
+        threshold,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::{DiskHealth, DiskInfo, DiskKind, PerformanceCategory, SmartInfo};
+    
+    #[test]
+    fn test_alert_check_and_severity() {
+        let mut report = DiagnosticReport::capture();
+        report.cpu.cpu_usage = 97.0;
+        report.ram.ram_usage_percent = 50.0;
+        report.score.overall_score = 4.0;
+        report.score.category = PerformanceCategory::Manutencao;
+        report.disks = vec![DiskInfo {
+            name: "sda1".to_string(),
+            mount_point: "/".to_string(),
+            total_space: 100_000_000_000,
+            available_space: 12_000_000_000,
+            used_space: 88_000_000_000,
+            usage_percent: 88.0,
+            file_system: "ext4".to_string(),
+            kind: DiskKind::Hdd,
+            read_bytes_per_sec: 0,
+            write_bytes_per_sec: 0,
+            read_iops: 0,
+            write_iops: 0,
+            io_utilization_percent: 0.0,
+            health: Some(DiskHealth::from(SmartInfo {
+                failure_predicted: false,
+                reallocated_sectors: Some(8),
+                power_on_hours: None,
+                ..SmartInfo::default()
+            })),
+            removable: false,
+            read_only: false,
+        }];
+        
+        let alerts = AlertManager::check(&report, &AlertConfig::default());
+        let find = |component: &str| alerts.iter().find(|alert| alert.component == component);
+        
+        assert_eq!(find("CPU").unwrap().severity, AlertSeverity::Critical);
+        assert_eq!(find("CPU").unwrap().threshold, 95.0);
+        assert!(find("RAM").is_none());
+        assert_eq!(find("Disco sda1").unwrap().severity, AlertSeverity::Warning);
+        assert!(alerts.iter().any(|alert| alert.severity == AlertSeverity::Info && alert.current_value == 8.0));
+        assert!(find("Pontuação").is_some());
+        // 12 GB (11,2 GiB) livres não disparam o mínimo padrão de 10 GiB
+        assert!(!alerts.iter().any(|alert| alert.message.contains("Espaço livre")));
+        assert_eq!(AlertManager::highest_severity(&alerts), Some(AlertSeverity::Critical));
+        assert_eq!(AlertManager::highest_severity(&[]), None);
+        
+        let path = std::env::temp_dir().join(format!("hardware_diagnostic_alerts_{}.log", std::process::id()));
+        AlertManager::write_alerts(&alerts, &path).unwrap();
+        AlertManager::write_alerts(&alerts[..1], &path).unwrap();
+        let log = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+        assert_eq!(log.lines().count(), alerts.len() + 1, "Log deve acumular as linhas");
+        assert!(log.contains("[CRÍTICO] CPU: Uso acima do limite (%) (atual 97.0, limite 95.0)"));
+        
+        // Disco excluído pelo filtro da pontuação não gera alertas
+        report.disk_filter.min_total_space = u64::MAX;
+        let alerts = AlertManager::check(&report, &AlertConfig::default());
+        assert!(!alerts.iter().any(|alert| alert.component == "Disco sda1"));
+    }
+    
+    #[test]
+    fn test_alert_config_validation() {
+        assert!(AlertConfig::default().validate().is_ok());
+        
+        let inverted = AlertConfig { ram_usage_warn: 95.0, ram_usage_crit: 85.0, ..AlertConfig::default() };
+        assert!(matches!(inverted.validate(), Err(DiagnosticError::InvalidConfig(msg)) if msg.contains("RAM")));
+        assert!(AlertConfig { min_score: 11.0, ..AlertConfig::default() }.validate().is_err());
+    }
+    
+    #[test]
+    fn test_min_free_disk_uses_gib() {
+        let mut report = DiagnosticReport::capture();
+        // 10,5 GB decimais são apenas 9,8 GiB
+        report.disks = vec![DiskInfo {
+            name: "sda1".to_string(),
+            mount_point: "/".to_string(),
+            total_space: 100_000_000_000,
+            available_space: 10_500_000_000,
+            used_space: 89_500_000_000,
+            usage_percent: 50.0,
+            file_system: "ext4".to_string(),
+            kind: DiskKind::Ssd,
+            read_bytes_per_sec: 0,
+            write_bytes_per_sec: 0,
+            read_iops: 0,
+            write_iops: 0,
+            io_utilization_percent: 0.0,
+            health: None,
+            removable: false,
+            read_only: false,
+        }];
+        
+        let alerts = AlertManager::check(&report, &AlertConfig::default());
+        let alert = alerts.iter().find(|alert| alert.message.contains("Espaço livre")).unwrap();
+        assert!((alert.current_value - 9.78).abs() < 0.01, "{}", alert.current_value);
+        assert_eq!(alert.threshold, 10.0);
+    }
+    
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_alert_config_accepts_old_disk_key() {
+        let config: AlertConfig = toml::from_str("min_free_disk_gb = 25.0").unwrap();
+        assert_eq!(config.min_free_disk_gib, 25.0);
+        let config: AlertConfig = toml::from_str("min_free_disk_gib = 30.0").unwrap();
+        assert_eq!(config.min_free_disk_gib, 30.0);
+    }
+}
@@ -0,0 +1,167 @@
+// hardware-diagnostic - Ferramenta de diagnóstico de hardware
+// Copyright (C) 2025  Seu Nome
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Módulo `async_api` - Variantes assíncronas das funções de coleta
+//! 
+//! Disponível com a feature `async`. As esperas de amostragem (uso da CPU e
+//! taxas de E/S dos discos) são feitas com `tokio::time::sleep`, e as consultas
+//! bloqueantes ao `sysinfo` rodam em `spawn_blocking`, sem travar o runtime.
+//! A API síncrona permanece inalterada.
+
+use super::*;
+
+/// Executa uma consulta bloqueante no pool de threads do tokio
+/// 
+/// Pânicos dentro da consulta são repassados para quem aguarda.
+async fn blocking<T, F>(query: F) -> T
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    match tokio::task::spawn_blocking(query).await {
+        Ok(value) => value,
+        Err(e) => std::panic::resume_unwind(e.into_panic()),
+    }
+}
+
+/// Coleta informações da CPU sem bloquear o runtime
+/// 
+/// Equivalente assíncrono de [`cpu_info()`](super::cpu_info).
+/// 
+/// # Exemplo
+/// ```
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// use hardware_diagnostic::engine::async_api::cpu_info_async;
+/// 
+/// let cpu = cpu_info_async().await;
+/// println!("CPU: {}", cpu.name);
+/// # }
+/// ```
+pub async fn cpu_info_async() -> CpuInfo {
+    cpu_and_temperatures().await.0
+}
+
+/// Amostra a CPU e lê os sensores de temperatura uma única vez
+/// 
+/// A mesma leitura dos sensores detecta o throttling térmico e entra na pontuação.
+async fn cpu_and_temperatures() -> (CpuInfo, Vec<TemperatureReading>) {
+    // Duas leituras separadas pelo intervalo de amostragem
+    let sys = blocking(|| {
+        let mut sys = System::new();
+        sys.refresh_cpu();
+        sys
+    })
+    .await;
+    
+    tokio::time::sleep(DEFAULT_CPU_SAMPLE_INTERVAL).await;
+    
+    blocking(move || {
+        let mut sys = sys;
+        sys.refresh_cpu();
+        let temperatures = temperature_info();
+        (read_cpu(&sys, cpu_throttling(), &temperatures).unwrap_or_default(), temperatures)
+    })
+    .await
+}
+
+/// Coleta informações de RAM e SWAP sem bloquear o runtime
+/// 
+/// Equivalente assíncrono de [`ram_info()`](super::ram_info).
+pub async fn ram_info_async() -> RamInfo {
+    blocking(ram_info).await
+}
+
+/// Coleta informações de todos os discos sem bloquear o runtime
+/// 
+/// Equivalente assíncrono de [`disk_info()`](super::disk_info).
+pub async fn disk_info_async() -> Vec<DiskInfo> {
+    // Duas leituras de E/S separadas pelo intervalo de amostragem (se a plataforma tiver contadores)
+    let (disks, previous) = blocking(|| (Disks::new_with_refreshed_list(), DiskIoSample::now())).await;
+    
+    if previous.has_counters() {
+        tokio::time::sleep(DEFAULT_CPU_SAMPLE_INTERVAL).await;
+    }
+    
+    blocking(move || {
+        let rates = DiskIoSample::now().rates_since(&previous);
+        read_disks(&disks, &rates, &smart::query_smart()).unwrap_or_default()
+    })
+    .await
+}
+
+/// Calcula a pontuação de desempenho executando as coletas em paralelo
+/// 
+/// Equivalente assíncrono de [`calculate_performance_score()`](super::calculate_performance_score).
+pub async fn calculate_performance_score_async() -> PerformanceScore {
+    let ((cpu_info, temperatures), ram_info, disks_info, (gpu_score, battery_info, bios)) = tokio::join!(
+        cpu_and_temperatures(),
+        ram_info_async(),
+        disk_info_async(),
+        blocking(|| (collect_gpu_score(), battery::battery_info(), bios::bios_info()))
+    );
+    
+    // Uso alto da CPU: amostra os processos fora do executor para nomear o responsável
+    let top_processes = if cpu_info.cpu_usage > HIGH_CPU_USAGE_PERCENT {
+        blocking(|| processes::top_processes(processes::RECOMMENDATION_TOP_PROCESSES)).await
+    } else {
+        Vec::new()
+    };
+    
+    score_from(
+        Some(&cpu_info),
+        Some(&ram_info),
+        Some(&disks_info),
+        gpu_score,
+        battery_info.as_ref(),
+        &temperatures,
+        &top_processes,
+        Some(System::uptime()),
+        detect_virtualization(),
+        bios.ok().and_then(|bios| bios.secure_boot_enabled),
+        &DiagnosticConfig::default(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_async_score_within_bounds() {
+        let score = calculate_performance_score_async().await;
+        
+        assert!(score.overall_score >= 0.0);
+        assert!(score.overall_score <= 10.0);
+        assert!(!score.recommendations.is_empty());
+    }
+    
+    #[tokio::test]
+    async fn test_sampling_does_not_block_runtime() {
+        // Runtime de thread única: se a amostragem bloqueasse a thread,
+        // a tarefa abaixo só terminaria depois da coleta da CPU
+        let ticker = tokio::spawn(async {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            Instant::now()
+        });
+        
+        let cpu = cpu_info_async().await;
+        let cpu_done = Instant::now();
+        
+        assert!(cpu.number_cpus > 0);
+        assert!(ticker.await.unwrap() < cpu_done);
+    }
+}
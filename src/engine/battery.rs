@@ -0,0 +1,450 @@
+// hardware-diagnostic - Ferramenta de diagnóstico de hardware
+// Copyright (C) 2025  Seu Nome
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Módulo `battery` - Coleta de informações da bateria
+//! 
+//! No Windows os dados vêm do WMI (`Win32_Battery` e `BatteryStaticData`),
+//! o que exige a feature `wmi`. No Linux, de `/sys/class/power_supply`;
+//! no macOS, do registro do IOKit (`ioreg -rn AppleSmartBattery`).
+//! Máquinas sem bateria (desktops) retornam `None`.
+
+/// Representa o estado da bateria de um notebook
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BatteryInfo {
+    /// Carga atual (0.0 a 100.0)
+    pub charge_percent: f32,
+    /// Indica se a bateria está carregando
+    pub is_charging: bool,
+    /// Saúde: capacidade máxima atual em relação à de projeto (0.0 a 100.0)
+    pub health_percent: Option<f32>,
+    /// Tempo estimado de autonomia em minutos (apenas descarregando)
+    pub estimated_minutes_remaining: Option<u32>,
+    /// Número de ciclos de carga
+    pub cycle_count: Option<u32>,
+    /// Estado de carga informado pelo sistema
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub state: BatteryState,
+    /// Capacidade máxima atual em Wh
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub full_capacity_wh: Option<f32>,
+    /// Capacidade de projeto (de fábrica) em Wh
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub design_capacity_wh: Option<f32>,
+}
+
+/// Estado de carga da bateria
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BatteryState {
+    /// Carregando
+    Charging,
+    /// Descarregando (máquina na bateria)
+    Discharging,
+    /// Totalmente carregada
+    Full,
+    /// Estado não informado (ex: conectada à energia sem carregar)
+    #[default]
+    Unknown,
+}
+
+impl BatteryState {
+    /// Descrição do estado para exibição em relatórios
+    pub fn description(&self) -> &'static str {
+        match self {
+            BatteryState::Charging => "Carregando",
+            BatteryState::Discharging => "Descarregando",
+            BatteryState::Full => "Carga completa",
+            BatteryState::Unknown => "Desconhecido",
+        }
+    }
+}
+
+/// Coleta informações da bateria principal
+/// 
+/// # Retorno
+/// Retorna `None` em máquinas sem bateria ou quando os dados
+/// não podem ser consultados.
+/// 
+/// # Exemplo
+/// ```
+/// use hardware_diagnostic::engine::battery::battery_info;
+/// 
+/// match battery_info() {
+///     Some(battery) => println!("Bateria: {:.0}%", battery.charge_percent),
+///     None => println!("Nenhuma bateria detectada"),
+/// }
+/// ```
+pub fn battery_info() -> Option<BatteryInfo> {
+    log::debug!("coletando informações da bateria");
+    query_battery()
+}
+
+/// Calcula a pontuação da bateria (0.0 a 10.0)
+/// 
+/// Combina a saúde (60%) e a autonomia restante (40%). Saúde abaixo
+/// de 40% ou menos de 30 minutos de autonomia resultam em pontuação baixa.
+pub fn calculate_battery_score(battery: &BatteryInfo) -> f64 {
+    // Fator 1: Saúde da bateria (quanto maior, melhor)
+    let health_score = match battery.health_percent {
+        None => 8.0,                // Desconhecida (neutro)
+        Some(h) if h >= 80.0 => 10.0, // Excelente
+        Some(h) if h >= 60.0 => 7.0,  // Bom
+        Some(h) if h >= 40.0 => 4.0,  // Regular
+        Some(_) => 1.0,               // Crítico
+    };
+    
+    // Fator 2: Autonomia (irrelevante enquanto carrega)
+    let runtime_score = if battery.is_charging {
+        10.0
+    } else {
+        match battery.estimated_minutes_remaining {
+            None => 8.0,                 // Desconhecida (neutro)
+            Some(m) if m >= 120 => 10.0, // Excelente
+            Some(m) if m >= 60 => 7.0,   // Bom
+            Some(m) if m >= 30 => 4.0,   // Regular
+            Some(_) => 1.0,              // Crítico
+        }
+    };
+    
+    let score: f64 = health_score * 0.6 + runtime_score * 0.4;
+    score.clamp(0.0, 10.0)
+}
+
+/// Consulta a bateria via WMI
+#[cfg(all(target_os = "windows", feature = "wmi"))]
+fn query_battery() -> Option<BatteryInfo> {
+    use serde::Deserialize;
+    use wmi::{COMLibrary, WMIConnection};
+    
+    #[derive(Deserialize)]
+    #[serde(rename = "Win32_Battery")]
+    #[serde(rename_all = "PascalCase")]
+    struct Win32Battery {
+        estimated_charge_remaining: Option<u16>,
+        battery_status: Option<u16>,
+        estimated_run_time: Option<u32>,
+    }
+    
+    #[derive(Deserialize)]
+    #[serde(rename = "BatteryStaticData")]
+    #[serde(rename_all = "PascalCase")]
+    struct BatteryStaticData {
+        designed_capacity: Option<u32>,
+    }
+    
+    #[derive(Deserialize)]
+    #[serde(rename = "BatteryFullChargedCapacity")]
+    #[serde(rename_all = "PascalCase")]
+    struct BatteryFullChargedCapacity {
+        full_charged_capacity: Option<u32>,
+    }
+    
+    #[derive(Deserialize)]
+    #[serde(rename = "BatteryCycleCount")]
+    #[serde(rename_all = "PascalCase")]
+    struct BatteryCycleCount {
+        cycle_count: Option<u32>,
+    }
+    
+    let com = COMLibrary::new().ok()?;
+    let cimv2 = match WMIConnection::new(com) {
+        Ok(cimv2) => cimv2,
+        Err(e) => {
+            log::error!("conexão WMI falhou ({}); bateria indisponível", e);
+            return None;
+        }
+    };
+    let battery = cimv2.query::<Win32Battery>().ok()?.into_iter().next()?;
+    
+    // BatteryStatus: 1 = descarregando, 2 = conectado à energia,
+    // 3 = carga completa, 6-9 = carregando
+    let is_charging = matches!(battery.battery_status, Some(2) | Some(6..=9));
+    let state = match battery.battery_status {
+        Some(1) => BatteryState::Discharging,
+        Some(3) => BatteryState::Full,
+        Some(6..=9) => BatteryState::Charging,
+        _ => BatteryState::Unknown,
+    };
+    
+    // Valor especial 71582788 indica autonomia desconhecida
+    let estimated_minutes_remaining = battery
+        .estimated_run_time
+        .filter(|m| !is_charging && *m != 71_582_788);
+    
+    // Dados estáticos ficam no namespace ROOT\WMI
+    let root_wmi = WMIConnection::with_namespace_path("ROOT\\WMI", com).ok();
+    let (designed, full, cycle_count) = match root_wmi {
+        Some(con) => {
+            let designed = con
+                .query::<BatteryStaticData>()
+                .ok()
+                .and_then(|d| d.into_iter().next())
+                .and_then(|d| d.designed_capacity);
+            let full = con
+                .query::<BatteryFullChargedCapacity>()
+                .ok()
+                .and_then(|d| d.into_iter().next())
+                .and_then(|d| d.full_charged_capacity);
+            let cycles = con
+                .query::<BatteryCycleCount>()
+                .ok()
+                .and_then(|d| d.into_iter().next())
+                .and_then(|d| d.cycle_count)
+                .filter(|c| *c > 0);
+            (designed, full, cycles)
+        }
+        None => (None, None, None),
+    };
+    
+    // Capacidades do WMI são informadas em mWh
+    let to_wh = |mwh: u32| mwh as f32 / 1000.0;
+    
+    Some(BatteryInfo {
+        charge_percent: f32::from(battery.estimated_charge_remaining.unwrap_or(0)),
+        is_charging,
+        health_percent: health_from(full.map(u64::from), designed.map(u64::from)),
+        estimated_minutes_remaining,
+        cycle_count,
+        state,
+        full_capacity_wh: full.map(to_wh),
+        design_capacity_wh: designed.map(to_wh),
+    })
+}
+
+/// Sem a feature `wmi` não há como consultar a bateria no Windows
+#[cfg(all(target_os = "windows", not(feature = "wmi")))]
+fn query_battery() -> Option<BatteryInfo> {
+    None
+}
+
+/// Consulta a bateria via `/sys/class/power_supply`
+#[cfg(target_os = "linux")]
+fn query_battery() -> Option<BatteryInfo> {
+    use std::fs;
+    
+    // Primeira fonte de energia do tipo "Battery" (ex: BAT0)
+    let battery_dir = fs::read_dir("/sys/class/power_supply")
+        .ok()?
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .find(|path| {
+            fs::read_to_string(path.join("type"))
+                .map(|t| t.trim() == "Battery")
+                .unwrap_or(false)
+        })?;
+    
+    let read = |name: &str| {
+        fs::read_to_string(battery_dir.join(name))
+            .ok()
+            .map(|v| v.trim().to_string())
+    };
+    let read_u64 = |name: &str| read(name).and_then(|v| v.parse::<u64>().ok());
+    
+    let status = read("status").unwrap_or_default();
+    let is_charging = status == "Charging" || status == "Full";
+    let state = match status.as_str() {
+        "Charging" => BatteryState::Charging,
+        "Discharging" => BatteryState::Discharging,
+        "Full" => BatteryState::Full,
+        _ => BatteryState::Unknown,
+    };
+    
+    // Alguns drivers usam energy_* (µWh), outros charge_* (µAh)
+    let full = read_u64("energy_full").or_else(|| read_u64("charge_full"));
+    let design = read_u64("energy_full_design").or_else(|| read_u64("charge_full_design"));
+    let now = read_u64("energy_now").or_else(|| read_u64("charge_now"));
+    let rate = read_u64("power_now").or_else(|| read_u64("current_now"));
+    
+    // Converte para Wh: energy_* já está em µWh; charge_* (µAh) exige a tensão (µV)
+    let voltage = read_u64("voltage_min_design");
+    let to_wh = |value: u64| {
+        if read_u64("energy_full").is_some() {
+            Some(value as f32 / 1_000_000.0)
+        } else {
+            voltage.map(|uv| (value as f64 * uv as f64 / 1e12) as f32)
+        }
+    };
+    
+    let estimated_minutes_remaining = match (is_charging, now, rate) {
+        (false, Some(now), Some(rate)) if rate > 0 => Some((now * 60 / rate) as u32),
+        _ => None,
+    };
+    
+    Some(BatteryInfo {
+        charge_percent: read("capacity").and_then(|v| v.parse().ok()).unwrap_or(0.0),
+        is_charging,
+        health_percent: health_from(full, design),
+        estimated_minutes_remaining,
+        cycle_count: read("cycle_count")
+            .and_then(|v| v.parse().ok())
+            .filter(|c| *c > 0),
+        state,
+        full_capacity_wh: full.and_then(to_wh),
+        design_capacity_wh: design.and_then(to_wh),
+    })
+}
+
+/// Consulta a bateria no registro do IOKit (`AppleSmartBattery`)
+#[cfg(target_os = "macos")]
+fn query_battery() -> Option<BatteryInfo> {
+    let output = std::process::Command::new("ioreg")
+        .args(["-rn", "AppleSmartBattery"])
+        .output()
+        .ok()?;
+    
+    parse_ioreg_battery(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Plataformas sem suporte retornam `None`
+#[cfg(not(any(target_os = "windows", target_os = "linux", target_os = "macos")))]
+fn query_battery() -> Option<BatteryInfo> {
+    None
+}
+
+/// Interpreta a saída de `ioreg -rn AppleSmartBattery`
+/// 
+/// Cada propriedade ocupa uma linha no formato `"Chave" = valor`. Nos Macs
+/// com Apple Silicon, `MaxCapacity` é um percentual e a capacidade real em
+/// mAh fica em `AppleRawMaxCapacity`.
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+fn parse_ioreg_battery(output: &str) -> Option<BatteryInfo> {
+    let value = |key: &str| {
+        let prefix = format!("\"{}\" = ", key);
+        output
+            .lines()
+            .find_map(|line| line.trim().strip_prefix(prefix.as_str()))
+            .map(str::trim)
+    };
+    let number = |key: &str| value(key).and_then(|v| v.parse::<u64>().ok());
+    let flag = |key: &str| value(key) == Some("Yes");
+    
+    let current = number("CurrentCapacity")?;
+    let max = number("MaxCapacity").filter(|m| *m > 0)?;
+    let raw_max = number("AppleRawMaxCapacity").or((max > 100).then_some(max));
+    let design = number("DesignCapacity");
+    
+    let fully_charged = flag("FullyCharged");
+    let is_charging = flag("IsCharging") || (fully_charged && flag("ExternalConnected"));
+    let state = if fully_charged {
+        BatteryState::Full
+    } else if flag("IsCharging") {
+        BatteryState::Charging
+    } else if !flag("ExternalConnected") {
+        BatteryState::Discharging
+    } else {
+        BatteryState::Unknown
+    };
+    
+    // 65535 indica autonomia ainda em cálculo
+    let estimated_minutes_remaining = number("AvgTimeToEmpty")
+        .or_else(|| number("TimeRemaining"))
+        .filter(|m| !is_charging && *m < 65_535)
+        .map(|m| m as u32);
+    
+    // Capacidades em mAh; a tensão (mV) converte para Wh
+    let voltage = number("Voltage");
+    let to_wh = |mah: u64| voltage.map(|mv| (mah as f64 * mv as f64 / 1e6) as f32);
+    
+    Some(BatteryInfo {
+        charge_percent: (current as f32 / max as f32 * 100.0).min(100.0),
+        is_charging,
+        health_percent: health_from(raw_max, design),
+        estimated_minutes_remaining,
+        cycle_count: number("CycleCount").map(|c| c as u32).filter(|c| *c > 0),
+        state,
+        full_capacity_wh: raw_max.and_then(to_wh),
+        design_capacity_wh: design.and_then(to_wh),
+    })
+}
+
+/// Calcula a saúde a partir da capacidade máxima atual e da de projeto
+#[cfg_attr(all(target_os = "windows", not(feature = "wmi")), allow(dead_code))]
+fn health_from(full: Option<u64>, design: Option<u64>) -> Option<f32> {
+    match (full, design) {
+        (Some(full), Some(design)) if design > 0 => {
+            Some((full as f32 / design as f32 * 100.0).min(100.0))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_battery_score() {
+        let healthy = BatteryInfo {
+            charge_percent: 80.0,
+            is_charging: false,
+            health_percent: Some(95.0),
+            estimated_minutes_remaining: Some(240),
+            cycle_count: Some(120),
+            state: BatteryState::Discharging,
+            full_capacity_wh: Some(47.5),
+            design_capacity_wh: Some(50.0),
+        };
+        assert_eq!(calculate_battery_score(&healthy), 10.0);
+        
+        let worn = BatteryInfo {
+            health_percent: Some(35.0),
+            estimated_minutes_remaining: Some(20),
+            ..healthy.clone()
+        };
+        assert!(calculate_battery_score(&worn) < 3.0);
+        
+        assert_eq!(health_from(Some(40), Some(50)), Some(80.0));
+        assert_eq!(health_from(Some(40), Some(0)), None);
+        assert_eq!(BatteryState::default().description(), "Desconhecido");
+    }
+    
+    #[test]
+    fn test_parse_ioreg_battery() {
+        let output = r#"+-o AppleSmartBattery  <class AppleSmartBattery, id 0x100000234>
+    {
+      "ExternalConnected" = No
+      "AvgTimeToEmpty" = 312
+      "CurrentCapacity" = 76
+      "MaxCapacity" = 100
+      "AppleRawMaxCapacity" = 4382
+      "DesignCapacity" = 4790
+      "CycleCount" = 212
+      "IsCharging" = No
+      "FullyCharged" = No
+      "Voltage" = 12512
+    }"#;
+        
+        let battery = parse_ioreg_battery(output).unwrap();
+        assert_eq!(battery.charge_percent, 76.0);
+        assert!(!battery.is_charging);
+        assert_eq!(battery.state, BatteryState::Discharging);
+        assert_eq!(battery.estimated_minutes_remaining, Some(312));
+        assert_eq!(battery.cycle_count, Some(212));
+        assert!((battery.health_percent.unwrap() - 91.48).abs() < 0.01);
+        assert!((battery.design_capacity_wh.unwrap() - 59.93).abs() < 0.01);
+        
+        let charging = output
+            .replace("\"IsCharging\" = No", "\"IsCharging\" = Yes")
+            .replace("\"ExternalConnected\" = No", "\"ExternalConnected\" = Yes");
+        let battery = parse_ioreg_battery(&charging).unwrap();
+        assert_eq!(battery.state, BatteryState::Charging);
+        assert_eq!(battery.estimated_minutes_remaining, None);
+        
+        // Desktops não têm AppleSmartBattery
+        assert_eq!(parse_ioreg_battery(""), None);
+    }
+}
@@ -0,0 +1,435 @@
+// hardware-diagnostic - Ferramenta de diagnóstico de hardware
+// Copyright (C) 2025  Seu Nome
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Módulo `benchmark` - Medição real de desempenho da CPU e do disco
+//! 
+//! Ao contrário da pontuação, que usa apenas dados informados pelo sistema
+//! (núcleos, uso, frequência), o benchmark executa carga real: laços de
+//! cálculo em ponto flutuante e de hash inteiro, uma cópia sequencial em
+//! memória e uma escrita/leitura sequencial de um arquivo temporário. É
+//! intrusivo e demorado, por isso só roda quando chamado explicitamente,
+//! sempre dentro de um tempo máximo.
+//! 
+//! [`BenchmarkResult::to_performance_score`] converte as taxas medidas em
+//! uma pontuação, comparando-as com uma máquina de referência.
+
+use super::locale::Message;
+use super::{determine_category, DiagnosticError, PerformanceScore, ScoringConfig};
+use std::fs::{self, File};
+use std::hint::black_box;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// Tamanho de cada bloco gravado e lido no teste de disco
+const DISK_CHUNK_BYTES: usize = 1024 * 1024;
+
+/// Limite do arquivo temporário, para não encher discos pequenos
+const DISK_MAX_BYTES: u64 = 256 * 1024 * 1024;
+
+/// Operações de ponto flutuante por iteração do laço da CPU
+/// (4 cadeias independentes de multiplicação + soma)
+const FLOPS_PER_ITERATION: u64 = 8;
+
+/// Iterações entre cada verificação do relógio no laço da CPU
+const CPU_BATCH_ITERATIONS: u64 = 1_000_000;
+
+/// Tamanho de cada um dos dois buffers da cópia em memória, maior que os
+/// caches da CPU
+const MEMORY_BUFFER_BYTES: usize = 64 * 1024 * 1024;
+
+/// Duração de cada medição de [`run_quick_benchmark`]
+pub const QUICK_PHASE_DURATION: Duration = Duration::from_millis(500);
+
+/// Hashes por segundo, em milhões, que valem 10.0 na pontuação da CPU
+/// 
+/// Referência: um núcleo de um desktop recente (~4 GHz); cada hash é uma
+/// cadeia dependente de ~13 ciclos.
+pub const REFERENCE_CPU_MHASH_PER_SEC: f64 = 300.0;
+
+/// Banda de cópia em memória, em GB/s, que vale 10.0 na pontuação da RAM
+/// 
+/// Referência: DDR4-3200 em dois canais, cópia em um único núcleo.
+pub const REFERENCE_MEMORY_GB_PER_SEC: f64 = 10.0;
+
+/// Leitura sequencial, em MB/s, que vale 10.0 na pontuação dos discos
+/// 
+/// Referência: SSD NVMe PCIe 3.0.
+pub const REFERENCE_DISK_READ_MB_PER_SEC: f64 = 2_000.0;
+
+/// Fração da referência abaixo da qual a medição vira recomendação
+const BELOW_REFERENCE_RATIO: f64 = 0.5;
+
+/// Resultado medido pelo benchmark
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BenchmarkResult {
+    /// Milhões de operações de ponto flutuante por segundo (um núcleo)
+    pub cpu_mflops: f64,
+    /// Milhões de hashes inteiros de 64 bits por segundo (um núcleo)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub cpu_mhash_per_sec: f64,
+    /// Banda de cópia sequencial em memória em GB/s (10⁹ bytes copiados)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub memory_bandwidth_gb_per_sec: f64,
+    /// Taxa de escrita sequencial em MB/s (10⁶ bytes)
+    pub disk_write_mb_per_sec: f64,
+    /// Taxa de leitura sequencial de um arquivo temporário em MB/s (10⁶ bytes)
+    /// 
+    /// A leitura logo após a escrita pode ser atendida pelo cache do sistema
+    /// operacional; trate o valor como limite superior.
+    #[cfg_attr(feature = "serde", serde(alias = "disk_read_mb_per_sec"))]
+    pub disk_seq_read_mb_per_sec: f64,
+    /// Tempo total gasto pelo benchmark
+    pub elapsed: Duration,
+}
+
+/// Executa o benchmark de CPU, memória e disco dentro do tempo indicado
+/// 
+/// O tempo é dividido em cinco partes: uma para cada laço de CPU, uma para
+/// a cópia em memória e duas para o teste de disco, feito no diretório
+/// temporário do sistema. O arquivo temporário é removido ao final, mesmo
+/// em caso de erro.
+/// 
+/// # Argumentos
+/// * `max_duration` - Tempo máximo aproximado do benchmark
+/// 
+/// # Erros
+/// - `DiagnosticError::InvalidConfig` se `max_duration` for zero
+/// - `DiagnosticError::Io` se o arquivo temporário não puder ser gravado ou lido
+/// 
+/// # Exemplo
+/// ```no_run
+/// use std::time::Duration;
+/// use hardware_diagnostic::engine::benchmark::run_benchmark;
+/// 
+/// let result = run_benchmark(Duration::from_secs(10)).unwrap();
+/// println!("CPU: {:.0} MFLOPS", result.cpu_mflops);
+/// println!("Disco: {:.0} MB/s escrita", result.disk_write_mb_per_sec);
+/// ```
+pub fn run_benchmark(max_duration: Duration) -> Result<BenchmarkResult, DiagnosticError> {
+    if max_duration.is_zero() {
+        return Err(DiagnosticError::InvalidConfig(
+            "duração do benchmark deve ser maior que zero".to_string(),
+        ));
+    }
+    
+    run_phases(max_duration / 5)
+}
+
+/// Executa o benchmark com [`QUICK_PHASE_DURATION`] por medição (~3 s no total)
+/// 
+/// Para pontuar a máquina pelo desempenho medido, em vez das especificações
+/// informadas pelo sistema; veja [`BenchmarkResult::to_performance_score`].
+/// 
+/// # Erros
+/// Retorna `DiagnosticError::Io` se o arquivo temporário não puder ser
+/// gravado ou lido.
+/// 
+/// # Exemplo
+/// ```no_run
+/// use hardware_diagnostic::engine::benchmark::run_quick_benchmark;
+/// 
+/// let result = run_quick_benchmark().unwrap();
+/// println!("CPU: {:.0} Mhash/s", result.cpu_mhash_per_sec);
+/// println!("Pontuação medida: {:.1}", result.to_performance_score().overall_score);
+/// ```
+pub fn run_quick_benchmark() -> Result<BenchmarkResult, DiagnosticError> {
+    run_phases(QUICK_PHASE_DURATION)
+}
+
+/// Executa cada medição por `phase` (o disco, por duas vezes `phase`)
+fn run_phases(phase: Duration) -> Result<BenchmarkResult, DiagnosticError> {
+    let start = Instant::now();
+    let cpu_mflops = measure_cpu_mflops(phase);
+    let cpu_mhash_per_sec = measure_cpu_mhash(phase);
+    let memory_bandwidth_gb_per_sec = measure_memory_bandwidth(phase);
+    
+    let path = temp_file_path();
+    let disk = measure_disk_throughput(&path, phase * 2);
+    fs::remove_file(&path).ok();
+    let (disk_write_mb_per_sec, disk_seq_read_mb_per_sec) = disk?;
+    
+    Ok(BenchmarkResult {
+        cpu_mflops,
+        cpu_mhash_per_sec,
+        memory_bandwidth_gb_per_sec,
+        disk_write_mb_per_sec,
+        disk_seq_read_mb_per_sec,
+        elapsed: start.elapsed(),
+    })
+}
+
+impl BenchmarkResult {
+    /// Calcula a pontuação a partir das taxas medidas, com a configuração padrão
+    /// 
+    /// O mesmo que [`BenchmarkResult::to_performance_score_with_config`] com
+    /// [`ScoringConfig::default`].
+    pub fn to_performance_score(&self) -> PerformanceScore {
+        self.to_performance_score_with_config(&ScoringConfig::default())
+    }
+    
+    /// Calcula a pontuação a partir das taxas medidas
+    /// 
+    /// Cada componente vale 10.0 ao atingir a referência
+    /// ([`REFERENCE_CPU_MHASH_PER_SEC`], [`REFERENCE_MEMORY_GB_PER_SEC`] e
+    /// [`REFERENCE_DISK_READ_MB_PER_SEC`]) e cai proporcionalmente abaixo
+    /// dela. A média usa os pesos gerais e os limites de categoria de
+    /// `config`, e as recomendações saem no idioma de `config`. Medições
+    /// abaixo da metade da referência viram recomendações.
+    /// 
+    /// # Exemplo
+    /// ```
+    /// use std::time::Duration;
+    /// use hardware_diagnostic::engine::benchmark::{BenchmarkResult, REFERENCE_CPU_MHASH_PER_SEC};
+    /// use hardware_diagnostic::{Language, ScoringConfig};
+    /// 
+    /// let result = BenchmarkResult {
+    ///     cpu_mflops: 0.0,
+    ///     cpu_mhash_per_sec: REFERENCE_CPU_MHASH_PER_SEC,
+    ///     memory_bandwidth_gb_per_sec: 1.0,
+    ///     disk_write_mb_per_sec: 0.0,
+    ///     disk_seq_read_mb_per_sec: 100.0,
+    ///     elapsed: Duration::ZERO,
+    /// };
+    /// let config = ScoringConfig { language: Language::EnUs, ..ScoringConfig::default() };
+    /// let score = result.to_performance_score_with_config(&config);
+    /// assert_eq!(score.cpu_score, Some(10.0));
+    /// assert!(score.recommendations.iter().any(|r| r.contains("Disks")));
+    /// ```
+    pub fn to_performance_score_with_config(&self, config: &ScoringConfig) -> PerformanceScore {
+        let weights = &config.weights;
+        let language = config.language;
+        let disks_label = Message::DisksLabel.text(language);
+        let measured = [
+            ("CPU", self.cpu_mhash_per_sec / REFERENCE_CPU_MHASH_PER_SEC),
+            ("RAM", self.memory_bandwidth_gb_per_sec / REFERENCE_MEMORY_GB_PER_SEC),
+            (disks_label.as_str(), self.disk_seq_read_mb_per_sec / REFERENCE_DISK_READ_MB_PER_SEC),
+        ];
+        let score = |ratio: f64| (ratio * 10.0).clamp(0.0, 10.0);
+        let (cpu_score, ram_score, disk_score) = (score(measured[0].1), score(measured[1].1), score(measured[2].1));
+        
+        let overall_score = cpu_score * weights.cpu() + ram_score * weights.ram() + disk_score * weights.disk();
+        let category = determine_category(overall_score, &config.categories);
+        
+        let mut recommendations = vec![Message::CategorySummary(&category).text(language)];
+        for (component, ratio) in measured {
+            if ratio < BELOW_REFERENCE_RATIO {
+                recommendations.push(Message::BelowBenchmarkReference(component, ratio * 100.0).text(language));
+            }
+        }
+        recommendations.push(Message::RecommendedAction(&category).text(language));
+        
+        PerformanceScore {
+            overall_score,
+            cpu_score: Some(cpu_score),
+            ram_score: Some(ram_score),
+            disk_score: Some(disk_score),
+            per_disk_scores: Vec::new(),
+            gpu_score: None,
+            battery_score: None,
+            recommendations,
+            category,
+        }
+    }
+}
+
+/// Executa o laço de ponto flutuante até esgotar o tempo e retorna os MFLOPS
+fn measure_cpu_mflops(budget: Duration) -> f64 {
+    let start = Instant::now();
+    let mut accumulators = [1.0f64, 2.0, 3.0, 4.0];
+    let mut iterations: u64 = 0;
+    
+    loop {
+        for _ in 0..CPU_BATCH_ITERATIONS {
+            // Cadeias independentes permitem paralelismo no pipeline
+            for acc in accumulators.iter_mut() {
+                *acc = *acc * 0.999_999 + 0.000_001;
+            }
+            accumulators = black_box(accumulators);
+        }
+        iterations += CPU_BATCH_ITERATIONS;
+        
+        if start.elapsed() >= budget {
+            break;
+        }
+    }
+    
+    let seconds = start.elapsed().as_secs_f64();
+    (iterations * FLOPS_PER_ITERATION) as f64 / seconds / 1_000_000.0
+}
+
+/// Calcula hashes de 64 bits (finalizador do SplitMix64) até esgotar o
+/// tempo e retorna os milhões de hashes por segundo
+fn measure_cpu_mhash(budget: Duration) -> f64 {
+    let start = Instant::now();
+    let mut state: u64 = 0;
+    let mut hashes: u64 = 0;
+    
+    loop {
+        for _ in 0..CPU_BATCH_ITERATIONS {
+            // Cada hash depende do anterior: mede a latência das operações inteiras
+            let mut z = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+            z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+            state = z ^ (z >> 31);
+        }
+        state = black_box(state);
+        hashes += CPU_BATCH_ITERATIONS;
+        
+        if start.elapsed() >= budget {
+            break;
+        }
+    }
+    
+    hashes as f64 / start.elapsed().as_secs_f64() / 1_000_000.0
+}
+
+/// Copia um buffer para outro repetidamente até esgotar o tempo e retorna
+/// os GB/s copiados
+fn measure_memory_bandwidth(budget: Duration) -> f64 {
+    let source: Vec<u8> = (0..MEMORY_BUFFER_BYTES).map(|i| (i % 251) as u8).collect();
+    let mut destination = vec![0u8; MEMORY_BUFFER_BYTES];
+    // Primeira cópia fora da medição: as páginas do destino já estão mapeadas
+    destination.copy_from_slice(&source);
+    
+    let start = Instant::now();
+    let mut copied: u64 = 0;
+    while copied == 0 || start.elapsed() < budget {
+        destination.copy_from_slice(black_box(&source));
+        black_box(&mut destination);
+        copied += MEMORY_BUFFER_BYTES as u64;
+    }
+    
+    copied as f64 / start.elapsed().as_secs_f64() / 1_000_000_000.0
+}
+
+/// Grava e relê o arquivo em blocos sequenciais, retornando (escrita, leitura) em MB/s
+/// 
+/// A escrita para quando metade do tempo se esgota ou o arquivo atinge
+/// [`DISK_MAX_BYTES`]; a leitura percorre o arquivo inteiro.
+fn measure_disk_throughput(path: &Path, budget: Duration) -> Result<(f64, f64), DiagnosticError> {
+    let io_error = |e: std::io::Error| DiagnosticError::Io {
+        path: path.display().to_string(),
+        source: e.to_string(),
+    };
+    
+    // Conteúdo não trivial, para evitar atalhos de compressão
+    let chunk: Vec<u8> = (0..DISK_CHUNK_BYTES).map(|i| (i % 251) as u8).collect();
+    let write_budget = budget / 2;
+    
+    let write_start = Instant::now();
+    let mut file = File::create(path).map_err(io_error)?;
+    let mut written: u64 = 0;
+    while written < DISK_MAX_BYTES && (written == 0 || write_start.elapsed() < write_budget) {
+        file.write_all(&chunk).map_err(io_error)?;
+        written += chunk.len() as u64;
+    }
+    // Conta o tempo até os dados chegarem ao disco
+    file.sync_all().map_err(io_error)?;
+    let write_seconds = write_start.elapsed().as_secs_f64();
+    drop(file);
+    
+    let read_start = Instant::now();
+    let mut file = File::open(path).map_err(io_error)?;
+    let mut buffer = vec![0u8; DISK_CHUNK_BYTES];
+    let mut read: u64 = 0;
+    loop {
+        let n = file.read(&mut buffer).map_err(io_error)?;
+        if n == 0 {
+            break;
+        }
+        read += n as u64;
+    }
+    let read_seconds = read_start.elapsed().as_secs_f64();
+    
+    Ok((
+        mb_per_sec(written, write_seconds),
+        mb_per_sec(read, read_seconds),
+    ))
+}
+
+/// Converte bytes em um intervalo para MB/s
+fn mb_per_sec(bytes: u64, seconds: f64) -> f64 {
+    if seconds > 0.0 {
+        bytes as f64 / seconds / 1_000_000.0
+    } else {
+        0.0
+    }
+}
+
+/// Caminho do arquivo temporário, único por processo
+fn temp_file_path() -> PathBuf {
+    std::env::temp_dir().join(format!("hardware_diagnostic_benchmark_{}.tmp", std::process::id()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::{CategoryBoundaries, Language, PerformanceCategory};
+    
+    #[test]
+    fn test_benchmark_respects_duration() {
+        let result = run_benchmark(Duration::from_millis(400)).unwrap();
+        
+        assert!(result.cpu_mflops > 0.0);
+        assert!(result.cpu_mhash_per_sec > 0.0);
+        assert!(result.memory_bandwidth_gb_per_sec > 0.0);
+        assert!(result.disk_write_mb_per_sec > 0.0);
+        assert!(result.disk_seq_read_mb_per_sec > 0.0);
+        // Margem para o sync do disco e a leitura final
+        assert!(result.elapsed < Duration::from_secs(5), "Benchmark levou {:?}", result.elapsed);
+        assert!(!temp_file_path().exists(), "Arquivo temporário deve ser removido");
+        
+        assert!(matches!(run_benchmark(Duration::ZERO), Err(DiagnosticError::InvalidConfig(_))));
+    }
+    
+    #[test]
+    fn test_to_performance_score() {
+        let reference = BenchmarkResult {
+            cpu_mflops: 0.0,
+            cpu_mhash_per_sec: REFERENCE_CPU_MHASH_PER_SEC * 2.0,
+            memory_bandwidth_gb_per_sec: REFERENCE_MEMORY_GB_PER_SEC,
+            disk_seq_read_mb_per_sec: REFERENCE_DISK_READ_MB_PER_SEC,
+            disk_write_mb_per_sec: 0.0,
+            elapsed: Duration::ZERO,
+        };
+        let score = reference.to_performance_score();
+        // Acima da referência não passa de 10.0
+        assert_eq!(score.cpu_score, Some(10.0));
+        assert!((score.overall_score - 10.0).abs() < 1e-9);
+        assert_eq!(score.category, PerformanceCategory::BomEstado);
+        
+        // Disco lento: 25% da referência pesa só na pontuação dos discos
+        let slow_disk = BenchmarkResult { disk_seq_read_mb_per_sec: REFERENCE_DISK_READ_MB_PER_SEC / 4.0, ..reference };
+        let score = slow_disk.to_performance_score();
+        assert!((score.disk_score.unwrap() - 2.5).abs() < 1e-9);
+        assert!((score.overall_score - 7.75).abs() < 1e-9);
+        assert_eq!(score.recommendations.len(), 3);
+        assert!(score.recommendations[1].contains("Discos"));
+        assert!(score.recommendations[1].contains("25%"));
+        
+        // Limites de categoria e idioma da configuração
+        let config = ScoringConfig {
+            categories: CategoryBoundaries { bom_estado: 8.0, ..CategoryBoundaries::default() },
+            language: Language::EnUs,
+            ..ScoringConfig::default()
+        };
+        let score = slow_disk.to_performance_score_with_config(&config);
+        assert_eq!(score.category, PerformanceCategory::Precaução);
+        assert!(score.recommendations[1].contains("Disks"));
+    }
+}
@@ -0,0 +1,227 @@
+// hardware-diagnostic - Ferramenta de diagnóstico de hardware
+// Copyright (C) 2025  Seu Nome
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Módulo `bios` - Informações do BIOS/UEFI
+//! 
+//! A versão e a data do firmware indicam se a máquina recebeu as
+//! atualizações de microcódigo (ex: mitigações de Spectre/Meltdown). No
+//! Windows os dados vêm do WMI (`Win32_BIOS`), o que exige a feature `wmi`,
+//! e o Secure Boot do registro. No Linux, de `/sys/class/dmi/id` e das
+//! variáveis EFI em `/sys/firmware/efi/efivars`.
+
+use super::DiagnosticError;
+
+/// GUID das variáveis globais do UEFI, onde fica `SecureBoot`
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+const EFI_GLOBAL_VARIABLE_GUID: &str = "8be4df61-93ca-11d2-aa0d-00e098032b8c";
+
+/// Fabricante, versão e data do firmware da placa-mãe
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BiosInfo {
+    /// Fabricante do firmware (ex: "American Megatrends Inc.")
+    pub manufacturer: String,
+    /// Versão do firmware (ex: "F.42")
+    pub version: String,
+    /// Data de lançamento no formato AAAA-MM-DD (vazia se desconhecida)
+    pub release_date: String,
+    /// Estado do Secure Boot; `None` em BIOS legado ou se desconhecido
+    pub secure_boot_enabled: Option<bool>,
+}
+
+/// Coleta as informações do BIOS/UEFI
+/// 
+/// # Erros
+/// Retorna `DiagnosticError::BiosQueryFailed` quando o firmware não pode
+/// ser consultado (plataforma sem suporte ou Windows sem a feature `wmi`).
+/// 
+/// # Exemplo
+/// ```
+/// use hardware_diagnostic::engine::bios::bios_info;
+/// 
+/// if let Ok(bios) = bios_info() {
+///     println!("BIOS {} de {}", bios.version, bios.release_date);
+/// }
+/// ```
+pub fn bios_info() -> Result<BiosInfo, DiagnosticError> {
+    log::debug!("coletando informações da BIOS");
+    query_bios()
+}
+
+/// Consulta o `Win32_BIOS` via WMI e o Secure Boot no registro
+#[cfg(all(target_os = "windows", feature = "wmi"))]
+fn query_bios() -> Result<BiosInfo, DiagnosticError> {
+    use serde::Deserialize;
+    use wmi::{COMLibrary, WMIConnection};
+    
+    #[derive(Deserialize)]
+    #[serde(rename = "Win32_BIOS")]
+    #[serde(rename_all = "PascalCase")]
+    struct Win32Bios {
+        manufacturer: Option<String>,
+        #[serde(rename = "SMBIOSBIOSVersion")]
+        smbios_bios_version: Option<String>,
+        release_date: Option<String>,
+    }
+    
+    let failed = |e: wmi::WMIError| {
+        log::error!("consulta WMI de Win32_BIOS falhou: {}", e);
+        DiagnosticError::BiosQueryFailed(e.to_string())
+    };
+    let connection = COMLibrary::new().and_then(WMIConnection::new).map_err(failed)?;
+    let results: Vec<Win32Bios> = connection.query().map_err(failed)?;
+    let bios = results
+        .into_iter()
+        .next()
+        .ok_or_else(|| DiagnosticError::BiosQueryFailed("Win32_BIOS vazio".to_string()))?;
+    
+    let secure_boot = std::process::Command::new("reg")
+        .args(["query", r"HKLM\SYSTEM\CurrentControlSet\Control\SecureBoot\State", "/v", "UEFISecureBootEnabled"])
+        .output()
+        .ok()
+        .and_then(|output| parse_reg_secure_boot(&String::from_utf8_lossy(&output.stdout)));
+    
+    Ok(BiosInfo {
+        manufacturer: bios.manufacturer.unwrap_or_default().trim().to_string(),
+        version: bios.smbios_bios_version.unwrap_or_default().trim().to_string(),
+        release_date: bios.release_date.as_deref().map(normalize_release_date).unwrap_or_default(),
+        secure_boot_enabled: secure_boot,
+    })
+}
+
+/// Lê o firmware do sysfs
+/// 
+/// Máquinas sem DMI (ex: placas ARM e alguns contêineres) não expõem
+/// `/sys/class/dmi/id`; a falha é esperada e fica no nível debug.
+#[cfg(target_os = "linux")]
+fn query_bios() -> Result<BiosInfo, DiagnosticError> {
+    read_linux(std::path::Path::new("/")).inspect_err(|e| log::debug!("BIOS indisponível: {}", e))
+}
+
+/// Sem suporte na plataforma (ou Windows sem a feature `wmi`)
+#[cfg(not(any(all(target_os = "windows", feature = "wmi"), target_os = "linux")))]
+fn query_bios() -> Result<BiosInfo, DiagnosticError> {
+    log::debug!("BIOS não suportado nesta plataforma");
+    Err(DiagnosticError::BiosQueryFailed("não suportado nesta plataforma".to_string()))
+}
+
+/// Lê o firmware de `sys/class/dmi/id` e o Secure Boot das variáveis EFI sob `root`
+/// 
+/// A variável `SecureBoot` tem 4 bytes de atributos seguidos do valor
+/// (1 = ativo). Sem o diretório `efivars` a máquina usa BIOS legado.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn read_linux(root: &std::path::Path) -> Result<BiosInfo, DiagnosticError> {
+    let dmi = root.join("sys/class/dmi/id");
+    let read = |file: &str| {
+        std::fs::read_to_string(dmi.join(file))
+            .map(|value| value.trim().to_string())
+            .map_err(|e| DiagnosticError::BiosQueryFailed(format!("{}: {}", file, e)))
+    };
+    
+    let secure_boot = std::fs::read(
+        root.join("sys/firmware/efi/efivars")
+            .join(format!("SecureBoot-{}", EFI_GLOBAL_VARIABLE_GUID)),
+    )
+    .ok()
+    .and_then(|bytes| bytes.get(4).map(|value| *value == 1));
+    
+    Ok(BiosInfo {
+        manufacturer: read("bios_vendor")?,
+        version: read("bios_version")?,
+        release_date: normalize_release_date(&read("bios_date").unwrap_or_default()),
+        secure_boot_enabled: secure_boot,
+    })
+}
+
+/// Converte a data do firmware para AAAA-MM-DD
+/// 
+/// Aceita o formato do DMI (`MM/DD/AAAA`) e o datetime CIM do WMI
+/// (`AAAAMMDDhhmmss.ffffff+000`); outros formatos são mantidos como estão.
+#[cfg_attr(not(any(target_os = "linux", all(target_os = "windows", feature = "wmi"))), allow(dead_code))]
+fn normalize_release_date(date: &str) -> String {
+    let date = date.trim();
+    let parts: Vec<&str> = date.split('/').collect();
+    
+    if let [month, day, year] = parts[..] {
+        if year.len() == 4 {
+            return format!("{}-{:0>2}-{:0>2}", year, month, day);
+        }
+    }
+    if date.len() >= 8 && date.as_bytes()[..8].iter().all(u8::is_ascii_digit) {
+        return format!("{}-{}-{}", &date[..4], &date[4..6], &date[6..8]);
+    }
+    
+    date.to_string()
+}
+
+/// Lê o valor `UEFISecureBootEnabled` da saída de `reg query`
+#[cfg_attr(not(all(target_os = "windows", feature = "wmi")), allow(dead_code))]
+fn parse_reg_secure_boot(output: &str) -> Option<bool> {
+    output
+        .lines()
+        .find(|line| line.trim_start().starts_with("UEFISecureBootEnabled"))
+        .and_then(|line| line.split_whitespace().last())
+        .and_then(|value| u32::from_str_radix(value.trim_start_matches("0x"), 16).ok())
+        .map(|value| value == 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_normalize_release_date() {
+        assert_eq!(normalize_release_date("03/15/2023\n"), "2023-03-15");
+        assert_eq!(normalize_release_date("1/5/2019"), "2019-01-05");
+        assert_eq!(normalize_release_date("20230115000000.000000+000"), "2023-01-15");
+        assert_eq!(normalize_release_date(""), "");
+        assert_eq!(normalize_release_date("desconhecida"), "desconhecida");
+    }
+    
+    #[test]
+    fn test_parse_reg_secure_boot() {
+        let output = "\nHKEY_LOCAL_MACHINE\\SYSTEM\\CurrentControlSet\\Control\\SecureBoot\\State\n    UEFISecureBootEnabled    REG_DWORD    0x1\n";
+        assert_eq!(parse_reg_secure_boot(output), Some(true));
+        assert_eq!(parse_reg_secure_boot(&output.replace("0x1", "0x0")), Some(false));
+        assert_eq!(parse_reg_secure_boot("ERRO: chave não encontrada"), None);
+    }
+    
+    #[test]
+    fn test_read_linux() {
+        let root = std::env::temp_dir().join(format!("bios-test-{}", std::process::id()));
+        let dmi = root.join("sys/class/dmi/id");
+        std::fs::create_dir_all(&dmi).unwrap();
+        std::fs::write(dmi.join("bios_vendor"), "LENOVO\n").unwrap();
+        std::fs::write(dmi.join("bios_version"), "N2HET77W (1.60 )\n").unwrap();
+        std::fs::write(dmi.join("bios_date"), "03/15/2023\n").unwrap();
+        
+        // BIOS legado: sem variáveis EFI
+        let bios = read_linux(&root).unwrap();
+        assert_eq!(bios.manufacturer, "LENOVO");
+        assert_eq!(bios.version, "N2HET77W (1.60 )");
+        assert_eq!(bios.release_date, "2023-03-15");
+        assert_eq!(bios.secure_boot_enabled, None);
+        
+        // UEFI com Secure Boot desativado: atributos + valor 0
+        let efivars = root.join("sys/firmware/efi/efivars");
+        std::fs::create_dir_all(&efivars).unwrap();
+        std::fs::write(efivars.join(format!("SecureBoot-{}", EFI_GLOBAL_VARIABLE_GUID)), [6, 0, 0, 0, 0]).unwrap();
+        assert_eq!(read_linux(&root).unwrap().secure_boot_enabled, Some(false));
+        
+        std::fs::remove_dir_all(&root).unwrap();
+        assert!(matches!(read_linux(&root), Err(DiagnosticError::BiosQueryFailed(_))));
+    }
+}
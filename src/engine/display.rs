@@ -0,0 +1,218 @@
+// hardware-diagnostic - Ferramenta de diagnóstico de hardware
+// Copyright (C) 2025  Seu Nome
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Módulo `display` - Monitores conectados e suas resoluções
+//! 
+//! No Windows, com a feature `display`, os dados vêm da API do Windows
+//! (`EnumDisplayDevicesW` e `EnumDisplaySettingsW`). No Linux, dos conectores
+//! em `/sys/class/drm`, que não informam a taxa de atualização nem o monitor
+//! principal. Em servidores sem monitor, ou em outras plataformas, a lista
+//! fica vazia.
+
+use std::path::Path;
+
+/// Um monitor conectado
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DisplayInfo {
+    /// Posição do monitor na lista, a partir de 0
+    pub index: usize,
+    /// Nome do dispositivo (ex: "\\\\.\\DISPLAY1", "HDMI-A-1")
+    pub name: String,
+    /// Largura em pixels
+    pub width: u32,
+    /// Altura em pixels
+    pub height: u32,
+    /// Taxa de atualização em Hz, se conhecida
+    pub refresh_rate_hz: Option<u32>,
+    /// Indica o monitor principal (sempre `false` quando desconhecido)
+    pub primary: bool,
+}
+
+/// Resumo em uma linha: `\\.\DISPLAY1: 1920x1080 @ 60 Hz (principal)`
+impl std::fmt::Display for DisplayInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}x{}", self.name, self.width, self.height)?;
+        if let Some(hz) = self.refresh_rate_hz {
+            write!(f, " @ {} Hz", hz)?;
+        }
+        if self.primary {
+            write!(f, " (principal)")?;
+        }
+        Ok(())
+    }
+}
+
+/// Lista os monitores conectados
+/// 
+/// # Retorno
+/// Um `DisplayInfo` por monitor ativo; vazio em servidores sem monitor ou
+/// quando a plataforma não pode ser consultada.
+/// 
+/// # Exemplo
+/// ```
+/// use hardware_diagnostic::engine::display::display_info;
+/// 
+/// for display in display_info() {
+///     println!("Monitor {}: {}x{}", display.index, display.width, display.height);
+/// }
+/// ```
+pub fn display_info() -> Vec<DisplayInfo> {
+    log::debug!("coletando monitores");
+    query_platform()
+}
+
+/// Consulta os monitores ligados à área de trabalho pela API do Windows
+#[cfg(all(target_os = "windows", feature = "display"))]
+fn query_platform() -> Vec<DisplayInfo> {
+    use std::mem::{size_of, zeroed};
+    use winapi::um::wingdi::{DEVMODEW, DISPLAY_DEVICEW, DISPLAY_DEVICE_ATTACHED_TO_DESKTOP, DISPLAY_DEVICE_PRIMARY_DEVICE};
+    use winapi::um::winuser::{EnumDisplayDevicesW, EnumDisplaySettingsW, ENUM_CURRENT_SETTINGS};
+    
+    let mut displays = Vec::new();
+    
+    for device_index in 0.. {
+        // SAFETY: estruturas POD zeradas, com o tamanho informado em `cb`/`dmSize`
+        let mut device: DISPLAY_DEVICEW = unsafe { zeroed() };
+        device.cb = size_of::<DISPLAY_DEVICEW>() as u32;
+        if unsafe { EnumDisplayDevicesW(std::ptr::null(), device_index, &mut device, 0) } == 0 {
+            break;
+        }
+        // Adaptadores sem monitor também são listados
+        if device.StateFlags & DISPLAY_DEVICE_ATTACHED_TO_DESKTOP == 0 {
+            continue;
+        }
+        
+        let mut mode: DEVMODEW = unsafe { zeroed() };
+        mode.dmSize = size_of::<DEVMODEW>() as u16;
+        if unsafe { EnumDisplaySettingsW(device.DeviceName.as_ptr(), ENUM_CURRENT_SETTINGS, &mut mode) } == 0 {
+            continue;
+        }
+        
+        let name_len = device.DeviceName.iter().position(|&c| c == 0).unwrap_or(device.DeviceName.len());
+        displays.push(DisplayInfo {
+            index: displays.len(),
+            name: String::from_utf16_lossy(&device.DeviceName[..name_len]),
+            width: mode.dmPelsWidth,
+            height: mode.dmPelsHeight,
+            // 0 e 1 significam "padrão do hardware"
+            refresh_rate_hz: (mode.dmDisplayFrequency > 1).then_some(mode.dmDisplayFrequency),
+            primary: device.StateFlags & DISPLAY_DEVICE_PRIMARY_DEVICE != 0,
+        });
+    }
+    
+    displays
+}
+
+/// Consulta os conectores com monitor em `/sys/class/drm`
+#[cfg(target_os = "linux")]
+fn query_platform() -> Vec<DisplayInfo> {
+    read_drm_connectors(Path::new("/sys/class/drm"))
+}
+
+/// Sem suporte na plataforma (ou sem a feature `display`): nenhum monitor
+#[cfg(not(any(all(target_os = "windows", feature = "display"), target_os = "linux")))]
+fn query_platform() -> Vec<DisplayInfo> {
+    Vec::new()
+}
+
+/// Lê os conectores DRM (ex: "card0-HDMI-A-1") com um monitor conectado
+/// 
+/// A resolução é o primeiro modo de `modes`, o preferido pelo monitor.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn read_drm_connectors(drm: &Path) -> Vec<DisplayInfo> {
+    let mut connectors: Vec<(String, std::path::PathBuf)> = match std::fs::read_dir(drm) {
+        Ok(entries) => entries
+            .filter_map(Result::ok)
+            .filter_map(|entry| {
+                let file_name = entry.file_name().to_string_lossy().to_string();
+                // "cardN-CONECTOR"; "cardN" é a própria GPU
+                let (_, connector) = file_name.strip_prefix("card")?.split_once('-')?;
+                Some((connector.to_string(), entry.path()))
+            })
+            .collect(),
+        Err(_) => return Vec::new(),
+    };
+    connectors.sort();
+    
+    let read = |path: &Path, name: &str| std::fs::read_to_string(path.join(name)).unwrap_or_default();
+    let mut displays = Vec::new();
+    
+    for (name, path) in connectors {
+        if read(&path, "status").trim() != "connected" {
+            continue;
+        }
+        let resolution = read(&path, "modes")
+            .lines()
+            .next()
+            .and_then(|mode| mode.trim().split_once('x'))
+            .and_then(|(width, height)| {
+                // Modos entrelaçados terminam em "i" (ex: "1920x1080i")
+                let height = height.trim_end_matches(|c: char| !c.is_ascii_digit());
+                Some((width.parse().ok()?, height.parse().ok()?))
+            });
+        let Some((width, height)) = resolution else {
+            continue;
+        };
+        
+        displays.push(DisplayInfo {
+            index: displays.len(),
+            name,
+            width,
+            height,
+            refresh_rate_hz: None,
+            primary: false,
+        });
+    }
+    
+    displays
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_read_drm_connectors() {
+        let drm = std::env::temp_dir().join(format!("display-test-{}", std::process::id()));
+        let connector = |name: &str, status: &str, modes: &str| {
+            let path = drm.join(name);
+            std::fs::create_dir_all(&path).unwrap();
+            std::fs::write(path.join("status"), status).unwrap();
+            std::fs::write(path.join("modes"), modes).unwrap();
+        };
+        connector("card0-HDMI-A-1", "connected\n", "2560x1440\n1920x1080\n");
+        connector("card0-DP-1", "disconnected\n", "");
+        connector("card0-eDP-1", "connected\n", "1920x1080i\n");
+        std::fs::create_dir_all(drm.join("card0")).unwrap();
+        
+        let displays = read_drm_connectors(&drm);
+        std::fs::remove_dir_all(&drm).ok();
+        
+        assert_eq!(displays.len(), 2);
+        assert_eq!((displays[0].index, displays[0].name.as_str()), (0, "HDMI-A-1"));
+        assert_eq!((displays[0].width, displays[0].height), (2560, 1440));
+        assert_eq!((displays[1].index, displays[1].name.as_str()), (1, "eDP-1"));
+        assert_eq!((displays[1].width, displays[1].height), (1920, 1080));
+        assert_eq!(displays[1].to_string(), "eDP-1: 1920x1080");
+        
+        // Servidor sem monitor: nada a listar
+        assert!(read_drm_connectors(Path::new("/caminho/inexistente")).is_empty());
+        
+        let primary = DisplayInfo { refresh_rate_hz: Some(60), primary: true, ..displays[0].clone() };
+        assert_eq!(primary.to_string(), "HDMI-A-1: 2560x1440 @ 60 Hz (principal)");
+    }
+}
@@ -0,0 +1,203 @@
+// hardware-diagnostic - Ferramenta de diagnóstico de hardware
+// Copyright (C) 2025  Seu Nome
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Módulo `fans` - Rotação das ventoinhas
+//! 
+//! No Linux os dados vêm de `/sys/class/hwmon` (`fan*_input`). No Windows,
+//! com a feature `wmi`, de `Win32_Fan`, que lista as ventoinhas mas informa
+//! só a rotação desejada, não a medida.
+//! Sem sensores expostos (comum em máquinas virtuais e notebooks), a lista
+//! fica vazia.
+
+use super::{TemperatureReading, ThermalComponent};
+
+/// Temperatura da CPU (°C) a partir da qual uma ventoinha parada indica
+/// possível falha de refrigeração
+pub const HIGH_CPU_TEMPERATURE: f32 = 80.0;
+
+/// Leitura de uma ventoinha
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FanInfo {
+    /// Rótulo do sensor (ex: "CPU Fan", "nct6775 fan2")
+    pub label: String,
+    /// Rotação medida em RPM; `None` quando o sistema não expõe a leitura
+    /// (ex: `Win32_Fan` informa apenas a rotação desejada)
+    pub rpm: Option<u32>,
+}
+
+/// Coleta a rotação de todas as ventoinhas expostas pelo sistema
+/// 
+/// # Exemplo
+/// ```
+/// use hardware_diagnostic::engine::fans::fan_info;
+/// 
+/// for fan in fan_info() {
+///     if let Some(rpm) = fan.rpm {
+///         println!("{}: {} RPM", fan.label, rpm);
+///     }
+/// }
+/// ```
+pub fn fan_info() -> Vec<FanInfo> {
+    log::debug!("coletando ventoinhas");
+    query_fans()
+}
+
+/// Ventoinhas paradas (0 RPM) enquanto a CPU está quente
+/// 
+/// Retorna vazio se nenhum sensor da CPU passar de [`HIGH_CPU_TEMPERATURE`]:
+/// ventoinhas paradas com a máquina fria são normais em modos silenciosos.
+/// Ventoinhas sem leitura de rotação nunca são consideradas paradas.
+pub fn stalled_fans<'a>(fans: &'a [FanInfo], temperatures: &[TemperatureReading]) -> Vec<&'a FanInfo> {
+    let cpu_is_hot = temperatures
+        .iter()
+        .any(|reading| reading.component == ThermalComponent::Cpu
+            && reading.temperature_celsius >= HIGH_CPU_TEMPERATURE);
+    
+    if !cpu_is_hot {
+        return Vec::new();
+    }
+    fans.iter().filter(|fan| fan.rpm == Some(0)).collect()
+}
+
+/// Consulta as ventoinhas via `/sys/class/hwmon`
+#[cfg(target_os = "linux")]
+fn query_fans() -> Vec<FanInfo> {
+    read_hwmon_fans(std::path::Path::new("/sys/class/hwmon"))
+}
+
+/// Consulta as ventoinhas via WMI
+#[cfg(all(target_os = "windows", feature = "wmi"))]
+fn query_fans() -> Vec<FanInfo> {
+    use serde::Deserialize;
+    use wmi::{COMLibrary, WMIConnection};
+    
+    #[derive(Deserialize)]
+    #[serde(rename = "Win32_Fan")]
+    #[serde(rename_all = "PascalCase")]
+    struct Win32Fan {
+        name: Option<String>,
+        device_id: Option<String>,
+    }
+    
+    let connection = match COMLibrary::new().and_then(WMIConnection::new) {
+        Ok(connection) => connection,
+        Err(e) => {
+            log::error!("conexão WMI falhou ({}); ventoinhas indisponíveis", e);
+            return Vec::new();
+        }
+    };
+    
+    connection
+        .query::<Win32Fan>()
+        .unwrap_or_default()
+        .into_iter()
+        .enumerate()
+        // `DesiredSpeed` é a rotação pedida, não a medida; a classe não tem a leitura atual
+        .map(|(i, fan)| FanInfo {
+            label: fan.name.or(fan.device_id).unwrap_or_else(|| format!("Ventoinha {}", i)),
+            rpm: None,
+        })
+        .collect()
+}
+
+/// Plataformas sem suporte (ou Windows sem a feature `wmi`): nenhuma ventoinha
+#[cfg(not(any(target_os = "linux", all(target_os = "windows", feature = "wmi"))))]
+fn query_fans() -> Vec<FanInfo> {
+    Vec::new()
+}
+
+/// Lê os arquivos `fan*_input` de cada dispositivo em `root`
+/// 
+/// O rótulo vem de `fan*_label` ou, na falta dele, do nome do chip seguido
+/// do nome do sensor (ex: "nct6775 fan2").
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn read_hwmon_fans(root: &std::path::Path) -> Vec<FanInfo> {
+    use std::fs;
+    
+    let read = |path: std::path::PathBuf| fs::read_to_string(path).ok().map(|v| v.trim().to_string());
+    let mut fans = Vec::new();
+    
+    let Ok(devices) = fs::read_dir(root) else {
+        return fans;
+    };
+    for device in devices.filter_map(Result::ok).map(|entry| entry.path()) {
+        let chip = read(device.join("name")).unwrap_or_else(|| "hwmon".to_string());
+        let Ok(entries) = fs::read_dir(&device) else {
+            continue;
+        };
+        
+        for entry in entries.filter_map(Result::ok) {
+            let file_name = entry.file_name().to_string_lossy().to_string();
+            let Some(sensor) = file_name.strip_suffix("_input").filter(|s| s.starts_with("fan")) else {
+                continue;
+            };
+            let Some(rpm) = read(entry.path()).and_then(|v| v.parse::<u32>().ok()) else {
+                continue;
+            };
+            let label = read(device.join(format!("{}_label", sensor)))
+                .unwrap_or_else(|| format!("{} {}", chip, sensor));
+            fans.push(FanInfo { label, rpm: Some(rpm) });
+        }
+    }
+    
+    // A ordem de `read_dir` não é definida
+    fans.sort_by(|a, b| a.label.cmp(&b.label));
+    fans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    
+    #[test]
+    fn test_read_hwmon_fans() {
+        let root = std::env::temp_dir().join(format!("hardware_diagnostic_hwmon_{}", std::process::id()));
+        let chip = root.join("hwmon0");
+        fs::create_dir_all(&chip).unwrap();
+        fs::write(chip.join("name"), "nct6775\n").unwrap();
+        fs::write(chip.join("fan1_input"), "1200\n").unwrap();
+        fs::write(chip.join("fan1_label"), "CPU Fan\n").unwrap();
+        fs::write(chip.join("fan2_input"), "0\n").unwrap();
+        fs::write(chip.join("temp1_input"), "45000\n").unwrap();
+        
+        let fans = read_hwmon_fans(&root);
+        fs::remove_dir_all(&root).ok();
+        
+        assert_eq!(fans, vec![
+            FanInfo { label: "CPU Fan".to_string(), rpm: Some(1200) },
+            FanInfo { label: "nct6775 fan2".to_string(), rpm: Some(0) },
+        ]);
+        assert!(read_hwmon_fans(&root).is_empty());
+        
+        // Ventoinha parada só preocupa com a CPU quente
+        let reading = |celsius| TemperatureReading {
+            label: "coretemp Package id 0".to_string(),
+            temperature_celsius: celsius,
+            critical_threshold: None,
+            component: ThermalComponent::Cpu,
+        };
+        assert!(stalled_fans(&fans, &[reading(50.0)]).is_empty());
+        let stalled = stalled_fans(&fans, &[reading(88.0)]);
+        assert_eq!(stalled.len(), 1);
+        assert_eq!(stalled[0].label, "nct6775 fan2");
+        
+        // Sem leitura de rotação (só a desejada no WMI) não há como saber se parou
+        let unknown = [FanInfo { label: "Ventoinha 0".to_string(), rpm: None }];
+        assert!(stalled_fans(&unknown, &[reading(88.0)]).is_empty());
+    }
+}
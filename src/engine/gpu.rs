@@ -0,0 +1,393 @@
+// hardware-diagnostic - Ferramenta de diagnóstico de hardware
+// Copyright (C) 2025  Seu Nome
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Módulo `gpu` - Coleta de informações das placas de vídeo
+//! 
+//! Disponível com a feature `gpu`. No Windows os dados vêm do WMI
+//! (`Win32_VideoController`); no Linux, de `/sys/class/drm`; no macOS, do
+//! `system_profiler SPDisplaysDataType` (IOKit/Metal).
+
+/// Representa as informações de uma placa de vídeo
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GpuInfo {
+    /// Nome/modelo da GPU
+    pub name: String,
+    /// Fabricante (ex: "NVIDIA", "AMD", "Intel")
+    pub vendor: String,
+    /// Memória de vídeo total em bytes (0 se não disponível)
+    pub vram_total: u64,
+    /// Memória de vídeo em uso em bytes (0 se não disponível)
+    pub vram_used: u64,
+    /// Percentual de utilização da GPU (0.0 a 100.0; 0.0 se não disponível)
+    pub utilization_percent: f32,
+    /// Versão do driver (se disponível)
+    pub driver_version: Option<String>,
+}
+
+/// Coleta informações de todas as placas de vídeo do sistema
+/// 
+/// # Retorno
+/// Retorna um vetor com um `GpuInfo` por adaptador encontrado. O vetor
+/// fica vazio quando nenhuma GPU pode ser consultada.
+/// 
+/// # Exemplo
+/// ```
+/// use hardware_diagnostic::engine::gpu::gpu_info;
+/// 
+/// for gpu in gpu_info() {
+///     println!("GPU: {} ({})", gpu.name, gpu.vendor);
+/// }
+/// ```
+pub fn gpu_info() -> Vec<GpuInfo> {
+    log::debug!("coletando informações das GPUs");
+    query_gpus()
+}
+
+/// Calcula a pontuação média das GPUs (0.0 a 10.0)
+/// 
+/// Combina a capacidade de VRAM (60%) e a utilização atual (40%).
+/// Retorna `None` se a lista estiver vazia.
+pub fn calculate_gpu_score(gpus: &[GpuInfo]) -> Option<f64> {
+    if gpus.is_empty() {
+        return None;
+    }
+    
+    let total: f64 = gpus
+        .iter()
+        .map(|gpu| {
+            // Fator 1: Capacidade de VRAM (quanto maior, melhor)
+            let vram_gb = super::utils::bytes_to_gib_f64(gpu.vram_total);
+            let vram_score = if gpu.vram_total == 0 {
+                5.0  // Desconhecida (neutro)
+            } else if vram_gb < 2.0 {
+                3.0  // Muito baixa
+            } else if vram_gb < 4.0 {
+                6.0  // Baixa
+            } else if vram_gb < 8.0 {
+                8.0  // Boa
+            } else {
+                10.0 // Excelente
+            };
+            
+            // Fator 2: Utilização atual (quanto menor, melhor)
+            let usage_score = if gpu.utilization_percent < 60.0 {
+                10.0 // Excelente
+            } else if gpu.utilization_percent < 85.0 {
+                7.0  // Bom
+            } else if gpu.utilization_percent < 95.0 {
+                4.0  // Regular
+            } else {
+                1.0  // Crítico
+            };
+            
+            let score: f64 = vram_score * 0.6 + usage_score * 0.4;
+            score.clamp(0.0, 10.0)
+        })
+        .sum();
+    
+    Some(total / gpus.len() as f64)
+}
+
+/// Consulta os adaptadores de vídeo via WMI
+#[cfg(target_os = "windows")]
+fn query_gpus() -> Vec<GpuInfo> {
+    use serde::Deserialize;
+    use wmi::{COMLibrary, WMIConnection};
+    
+    #[derive(Deserialize)]
+    #[serde(rename = "Win32_VideoController")]
+    #[serde(rename_all = "PascalCase")]
+    struct VideoController {
+        name: Option<String>,
+        adapter_compatibility: Option<String>,
+        adapter_ram: Option<u32>,
+        driver_version: Option<String>,
+    }
+    
+    // Contadores de desempenho (Windows 10 1709+)
+    #[derive(Deserialize)]
+    #[serde(rename = "Win32_PerfFormattedData_GPUPerformanceCounters_GPUAdapterMemory")]
+    #[serde(rename_all = "PascalCase")]
+    struct AdapterMemory {
+        dedicated_usage: Option<u64>,
+    }
+    
+    #[derive(Deserialize)]
+    #[serde(rename = "Win32_PerfFormattedData_GPUPerformanceCounters_GPUEngine")]
+    #[serde(rename_all = "PascalCase")]
+    struct GpuEngine {
+        name: Option<String>,
+        utilization_percentage: Option<u64>,
+    }
+    
+    let con = match COMLibrary::new().and_then(WMIConnection::new) {
+        Ok(con) => con,
+        Err(e) => {
+            log::error!("conexão WMI falhou ({}); GPUs indisponíveis", e);
+            return Vec::new();
+        }
+    };
+    
+    let controllers: Vec<VideoController> = match con.query() {
+        Ok(controllers) => controllers,
+        Err(e) => {
+            log::error!("consulta WMI Win32_VideoController falhou: {}", e);
+            return Vec::new();
+        }
+    };
+    
+    // Os contadores não identificam o adaptador de forma simples;
+    // só são atribuídos quando há uma única GPU
+    let (vram_used, utilization_percent) = if controllers.len() == 1 {
+        let vram_used = con
+            .query::<AdapterMemory>()
+            .map(|m| m.iter().filter_map(|a| a.dedicated_usage).sum())
+            .unwrap_or(0);
+        let utilization = con
+            .query::<GpuEngine>()
+            .map(|engines| {
+                engines
+                    .iter()
+                    .filter(|e| e.name.as_deref().is_some_and(|n| n.contains("engtype_3D")))
+                    .filter_map(|e| e.utilization_percentage)
+                    .sum::<u64>()
+                    .min(100) as f32
+            })
+            .unwrap_or(0.0);
+        (vram_used, utilization)
+    } else {
+        (0, 0.0)
+    };
+    
+    controllers
+        .into_iter()
+        .map(|c| GpuInfo {
+            name: c.name.unwrap_or_else(|| "Desconhecido".to_string()),
+            vendor: c.adapter_compatibility.unwrap_or_else(|| "Desconhecido".to_string()),
+            vram_total: c.adapter_ram.map(u64::from).unwrap_or(0),
+            vram_used,
+            utilization_percent,
+            driver_version: c.driver_version,
+        })
+        .collect()
+}
+
+/// Consulta os adaptadores de vídeo via `/sys/class/drm`
+#[cfg(target_os = "linux")]
+fn query_gpus() -> Vec<GpuInfo> {
+    use std::fs;
+    
+    let entries = match fs::read_dir("/sys/class/drm") {
+        Ok(entries) => entries,
+        Err(_) => return Vec::new(),
+    };
+    
+    let mut gpus = Vec::new();
+    
+    for entry in entries.filter_map(Result::ok) {
+        // Apenas "cardN"; ignora conectores como "card0-HDMI-A-1"
+        let file_name = entry.file_name().to_string_lossy().to_string();
+        let is_card = file_name
+            .strip_prefix("card")
+            .is_some_and(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()));
+        if !is_card {
+            continue;
+        }
+        
+        let device = entry.path().join("device");
+        let read = |name: &str| {
+            fs::read_to_string(device.join(name))
+                .ok()
+                .map(|v| v.trim().to_string())
+        };
+        
+        let vendor = match read("vendor").as_deref() {
+            Some("0x10de") => "NVIDIA".to_string(),
+            Some("0x1002") => "AMD".to_string(),
+            Some("0x8086") => "Intel".to_string(),
+            Some(other) => other.to_string(),
+            None => "Desconhecido".to_string(),
+        };
+        let device_id = read("device").unwrap_or_default();
+        
+        // O nome do driver vem do link simbólico device/driver
+        let driver = fs::read_link(device.join("driver"))
+            .ok()
+            .and_then(|p| p.file_name().map(|n| n.to_string_lossy().to_string()));
+        let driver_version = driver.and_then(|d| {
+            fs::read_to_string(format!("/sys/module/{}/version", d))
+                .ok()
+                .map(|v| v.trim().to_string())
+        });
+        
+        gpus.push(GpuInfo {
+            name: format!("{} GPU [{}]", vendor, device_id),
+            vendor,
+            vram_total: read("mem_info_vram_total")
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(0),
+            // Expostos apenas por alguns drivers (ex: amdgpu)
+            vram_used: read("mem_info_vram_used")
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(0),
+            utilization_percent: read("gpu_busy_percent")
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(0.0),
+            driver_version,
+        });
+    }
+    
+    gpus
+}
+
+/// Consulta os adaptadores de vídeo via `system_profiler`
+#[cfg(target_os = "macos")]
+fn query_gpus() -> Vec<GpuInfo> {
+    match std::process::Command::new("system_profiler")
+        .arg("SPDisplaysDataType")
+        .output()
+    {
+        Ok(output) => parse_system_profiler_gpus(&String::from_utf8_lossy(&output.stdout)),
+        Err(_) => Vec::new(),
+    }
+}
+
+/// Plataformas sem suporte retornam uma lista vazia
+#[cfg(not(any(target_os = "windows", target_os = "linux", target_os = "macos")))]
+fn query_gpus() -> Vec<GpuInfo> {
+    Vec::new()
+}
+
+/// Interpreta a saída de `system_profiler SPDisplaysDataType`
+/// 
+/// Cada GPU começa em `Chipset Model:`. A VRAM só é informada em Macs com
+/// placa dedicada ou Intel; no Apple Silicon a memória é unificada e fica 0.
+/// A família Metal suportada é registrada como versão do driver.
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+fn parse_system_profiler_gpus(output: &str) -> Vec<GpuInfo> {
+    let mut gpus: Vec<GpuInfo> = Vec::new();
+    
+    for line in output.lines() {
+        let Some((key, value)) = line.trim().split_once(": ") else {
+            continue;
+        };
+        let value = value.trim();
+        
+        if key == "Chipset Model" {
+            gpus.push(GpuInfo {
+                name: value.to_string(),
+                vendor: "Desconhecido".to_string(),
+                vram_total: 0,
+                vram_used: 0,
+                utilization_percent: 0.0,
+                driver_version: None,
+            });
+            continue;
+        }
+        
+        let Some(gpu) = gpus.last_mut() else {
+            continue;
+        };
+        
+        if key == "Vendor" {
+            // Ex: "Apple (0x106b)" ou "sppci_vendor_Apple"
+            let vendor = value.split(" (").next().unwrap_or(value);
+            gpu.vendor = vendor.trim_start_matches("sppci_vendor_").to_string();
+        } else if key.starts_with("VRAM") {
+            // Ex: "1536 MB" ou "8 GB"
+            let mut parts = value.split_whitespace();
+            let amount = parts.next().and_then(|v| v.parse::<u64>().ok());
+            let unit = match parts.next() {
+                Some("GB") => 1024 * 1024 * 1024,
+                _ => 1024 * 1024,
+            };
+            gpu.vram_total = amount.map(|v| v * unit).unwrap_or(0);
+        } else if key.starts_with("Metal") {
+            // Ex: "Metal Support: Metal 3" ou "Metal Family: Supported, Metal GPUFamily Apple 7"
+            gpu.driver_version = Some(value.trim_start_matches("Supported, ").to_string());
+        }
+    }
+    
+    gpus
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gpu_score() {
+        assert_eq!(calculate_gpu_score(&[]), None);
+        
+        let gpu = GpuInfo {
+            name: "Test GPU".to_string(),
+            vendor: "NVIDIA".to_string(),
+            vram_total: 8 * 1024 * 1024 * 1024,
+            vram_used: 1024 * 1024 * 1024,
+            utilization_percent: 10.0,
+            driver_version: None,
+        };
+        let busy = GpuInfo {
+            utilization_percent: 99.0,
+            ..gpu.clone()
+        };
+        
+        let idle_score = calculate_gpu_score(&[gpu]).unwrap();
+        let busy_score = calculate_gpu_score(&[busy]).unwrap();
+        assert_eq!(idle_score, 10.0);
+        assert!(busy_score < idle_score);
+    }
+    
+    #[test]
+    fn test_parse_system_profiler_gpus() {
+        let output = "Graphics/Displays:
+
+    Intel Iris Plus Graphics 655:
+
+      Chipset Model: Intel Iris Plus Graphics 655
+      Type: GPU
+      Bus: Built-In
+      VRAM (Dynamic, Max): 1536 MB
+      Vendor: Intel
+      Device ID: 0x3ea5
+      Metal Support: Metal 3
+      Displays:
+        Color LCD:
+          Resolution: 2560 x 1600 Retina
+
+    Apple M1:
+
+      Chipset Model: Apple M1
+      Type: GPU
+      Total Number of Cores: 8
+      Vendor: Apple (0x106b)
+      Metal Family: Supported, Metal GPUFamily Apple 7
+";
+        
+        let gpus = parse_system_profiler_gpus(output);
+        assert_eq!(gpus.len(), 2);
+        assert_eq!(gpus[0].name, "Intel Iris Plus Graphics 655");
+        assert_eq!(gpus[0].vendor, "Intel");
+        assert_eq!(gpus[0].vram_total, 1536 * 1024 * 1024);
+        assert_eq!(gpus[0].driver_version.as_deref(), Some("Metal 3"));
+        assert_eq!(gpus[1].vendor, "Apple");
+        assert_eq!(gpus[1].vram_total, 0);
+        assert_eq!(gpus[1].driver_version.as_deref(), Some("Metal GPUFamily Apple 7"));
+        
+        assert!(parse_system_profiler_gpus("").is_empty());
+    }
+}
@@ -0,0 +1,416 @@
+// hardware-diagnostic - Ferramenta de diagnóstico de hardware
+// Copyright (C) 2025  Seu Nome
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Módulo `history` - Histórico de pontuações ao longo do tempo
+//! 
+//! Quem executa o diagnóstico periodicamente pode acumular as pontuações
+//! em um [`ScoreHistory`], gravá-lo em JSON (feature `serde`) e acompanhar
+//! a tendência da máquina entre execuções.
+//! 
+//! O histórico em disco é um só: o arquivo JSONL de `utils::append_snapshot`
+//! (uma [`TimestampedScore`] por linha), que [`ScoreHistory::load`] e
+//! [`ScoreHistory::save`] também leem e gravam.
+//! 
+//! A tendência vem sempre da mesma reta de mínimos quadrados ([`fit_trend`]),
+//! usada também por `utils::analyze_trend` e pelos relatórios.
+
+use super::locale::{Language, Message};
+use super::{PerformanceCategory, PerformanceScore, TimestampedScore};
+#[cfg(feature = "serde")]
+use super::DiagnosticError;
+#[cfg(feature = "serde")]
+use std::path::Path;
+use std::time::SystemTime;
+
+/// Variação mínima, ao longo da reta de [`fit_trend`], para considerar que
+/// a pontuação mudou
+const TREND_TOLERANCE: f64 = 0.5;
+
+/// Níveis do gráfico de [`ScoreHistory::plot_ascii`], do menor ao maior
+const SPARK_LEVELS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Uma pontuação registrada no histórico
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HistoryEntry {
+    /// Instante do diagnóstico
+    pub timestamp: SystemTime,
+    /// Pontuação geral (0.0 a 10.0)
+    pub score: f64,
+    /// Pontuação da CPU (0.0 a 10.0), `None` se não avaliada
+    pub cpu_score: Option<f64>,
+    /// Pontuação da RAM (0.0 a 10.0), `None` se não avaliada
+    pub ram_score: Option<f64>,
+    /// Pontuação dos discos (0.0 a 10.0), `None` se não avaliados
+    pub disk_score: Option<f64>,
+    /// Categoria de desempenho
+    pub category: PerformanceCategory,
+}
+
+impl HistoryEntry {
+    /// Cria uma entrada com o instante atual a partir de uma pontuação
+    /// 
+    /// Componentes não avaliados continuam `None`.
+    pub fn from_score(score: &PerformanceScore) -> Self {
+        HistoryEntry {
+            timestamp: SystemTime::now(),
+            score: score.overall_score,
+            cpu_score: score.cpu_score,
+            ram_score: score.ram_score,
+            disk_score: score.disk_score,
+            category: score.category.clone(),
+        }
+    }
+    
+    /// Linha do arquivo de histórico correspondente à entrada
+    #[cfg(feature = "serde")]
+    fn to_snapshot(&self) -> TimestampedScore {
+        TimestampedScore {
+            timestamp: self.timestamp,
+            score: PerformanceScore {
+                overall_score: self.score,
+                cpu_score: self.cpu_score,
+                ram_score: self.ram_score,
+                disk_score: self.disk_score,
+                per_disk_scores: Vec::new(),
+                gpu_score: None,
+                battery_score: None,
+                category: self.category.clone(),
+                recommendations: Vec::new(),
+            },
+        }
+    }
+}
+
+impl From<&TimestampedScore> for HistoryEntry {
+    /// Entrada com o instante da execução registrada
+    fn from(snapshot: &TimestampedScore) -> Self {
+        HistoryEntry {
+            timestamp: snapshot.timestamp,
+            ..HistoryEntry::from_score(&snapshot.score)
+        }
+    }
+}
+
+/// Tendência das pontuações registradas
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ScoreTrend {
+    /// Pontuações recentes acima das antigas
+    Improving,
+    /// Sem variação significativa (ou histórico insuficiente)
+    Stable,
+    /// Pontuações recentes abaixo das antigas
+    Degrading,
+}
+
+/// Reta ajustada às pontuações gerais de um histórico, gerada por [`fit_trend`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TrendFit {
+    /// Inclinação da reta, em pontos por dia
+    pub points_per_day: f64,
+    /// Dias entre a primeira e a última pontuação
+    pub days: f64,
+}
+
+impl TrendFit {
+    /// Variação da pontuação ao longo de todo o histórico (`points_per_day * days`)
+    pub fn change(&self) -> f64 {
+        self.points_per_day * self.days
+    }
+    
+    /// Tendência correspondente à variação
+    /// 
+    /// Variações de até 0.5 ponto no período são consideradas estáveis.
+    pub fn trend(&self) -> ScoreTrend {
+        let change = self.change();
+        if change > TREND_TOLERANCE {
+            ScoreTrend::Improving
+        } else if change < -TREND_TOLERANCE {
+            ScoreTrend::Degrading
+        } else {
+            ScoreTrend::Stable
+        }
+    }
+    
+    /// Frase com a variação no período (ex: "📉 A máquina piorou 1.2
+    /// pontos em 30 dias")
+    pub fn summary(&self, language: Language) -> String {
+        Message::ScoreTrendSummary(self.trend(), self.change(), self.days).text(language)
+    }
+}
+
+/// Ajusta uma reta (mínimos quadrados) às pontuações gerais no tempo
+/// 
+/// Recebe pares (instante, pontuação geral), em qualquer ordem.
+/// 
+/// # Retorno
+/// `None` com menos de duas pontuações ou com todas no mesmo instante.
+/// 
+/// # Exemplo
+/// ```
+/// use std::time::{Duration, SystemTime};
+/// use hardware_diagnostic::engine::history::{fit_trend, ScoreTrend};
+/// 
+/// let day = |n: u64| SystemTime::UNIX_EPOCH + Duration::from_secs(n * 86_400);
+/// let fit = fit_trend(&[(day(0), 8.0), (day(15), 7.4), (day(30), 6.8)]).unwrap();
+/// assert_eq!(fit.trend(), ScoreTrend::Degrading);
+/// assert!((fit.change() + 1.2).abs() < 1e-9);
+/// ```
+pub fn fit_trend(points: &[(SystemTime, f64)]) -> Option<TrendFit> {
+    let first = points.iter().map(|(timestamp, _)| *timestamp).min()?;
+    let days = |timestamp: SystemTime| {
+        timestamp.duration_since(first).unwrap_or_default().as_secs_f64() / 86_400.0
+    };
+    
+    let n = points.len() as f64;
+    let mean_x = points.iter().map(|(timestamp, _)| days(*timestamp)).sum::<f64>() / n;
+    let mean_y = points.iter().map(|(_, score)| score).sum::<f64>() / n;
+    let (mut covariance, mut variance) = (0.0, 0.0);
+    for (timestamp, score) in points {
+        let dx = days(*timestamp) - mean_x;
+        covariance += dx * (score - mean_y);
+        variance += dx * dx;
+    }
+    if variance == 0.0 {
+        return None;
+    }
+    
+    Some(TrendFit {
+        points_per_day: covariance / variance,
+        days: points.iter().map(|(timestamp, _)| days(*timestamp)).fold(0.0, f64::max),
+    })
+}
+
+/// Histórico de pontuações, na ordem em que foram registradas
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScoreHistory {
+    /// Entradas registradas, da mais antiga para a mais recente
+    pub entries: Vec<HistoryEntry>,
+}
+
+impl ScoreHistory {
+    /// Cria um histórico vazio
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Cria um histórico a partir das execuções lidas por `utils::load_snapshots`
+    pub fn from_snapshots(snapshots: &[TimestampedScore]) -> Self {
+        ScoreHistory {
+            entries: snapshots.iter().map(HistoryEntry::from).collect(),
+        }
+    }
+    
+    /// Registra uma nova entrada no fim do histórico
+    pub fn push(&mut self, entry: HistoryEntry) {
+        self.entries.push(entry);
+    }
+    
+    /// Média das pontuações gerais (0.0 se o histórico estiver vazio)
+    pub fn average_score(&self) -> f64 {
+        average(&self.entries)
+    }
+    
+    /// Reta de [`fit_trend`] ajustada às pontuações gerais do histórico
+    pub fn fit(&self) -> Option<TrendFit> {
+        let points: Vec<(SystemTime, f64)> = self.entries.iter().map(|entry| (entry.timestamp, entry.score)).collect();
+        fit_trend(&points)
+    }
+    
+    /// Tendência das pontuações gerais pela reta de [`ScoreHistory::fit`]
+    /// 
+    /// Variações de até 0.5 ponto no período são consideradas estáveis. Com
+    /// menos de duas entradas (ou todas no mesmo instante), a tendência é
+    /// sempre [`ScoreTrend::Stable`].
+    pub fn trend(&self) -> ScoreTrend {
+        self.fit().map_or(ScoreTrend::Stable, |fit| fit.trend())
+    }
+    
+    /// Desenha as pontuações gerais mais recentes como um gráfico de uma linha
+    /// 
+    /// Cada caractere representa uma entrada, na escala fixa de 0 a 10, para
+    /// que gráficos de históricos diferentes sejam comparáveis. Mostra no
+    /// máximo `width` entradas, descartando as mais antigas.
+    pub fn plot_ascii(&self, width: usize) -> String {
+        let skip = self.entries.len().saturating_sub(width);
+        let top = (SPARK_LEVELS.len() - 1) as f64;
+        
+        self.entries[skip..]
+            .iter()
+            .map(|entry| {
+                let level = (entry.score.clamp(0.0, 10.0) / 10.0 * top).round() as usize;
+                SPARK_LEVELS[level]
+            })
+            .collect()
+    }
+    
+    /// Grava o histórico no caminho indicado, substituindo o conteúdo
+    /// 
+    /// Cada entrada vira uma linha JSON no formato de `utils::append_snapshot`,
+    /// para que as próximas execuções possam acrescentar pontuações ao mesmo
+    /// arquivo.
+    /// 
+    /// # Erros
+    /// Retorna `DiagnosticError::Io` se o arquivo não puder ser gravado.
+    #[cfg(feature = "serde")]
+    pub fn save(&self, path: &Path) -> Result<(), DiagnosticError> {
+        // Todos os campos são serializáveis; a falha não é esperada
+        let lines: String = self
+            .entries
+            .iter()
+            .map(|entry| serde_json::to_string(&entry.to_snapshot()).unwrap_or_default() + "\n")
+            .collect();
+        std::fs::write(path, lines).map_err(|e| DiagnosticError::Io {
+            path: path.display().to_string(),
+            source: e.to_string(),
+        })
+    }
+    
+    /// Carrega o histórico gravado por [`ScoreHistory::save`] ou por
+    /// `utils::append_snapshot`
+    /// 
+    /// Ao contrário de `utils::load_snapshots`, que ignora linhas inválidas,
+    /// qualquer linha fora do formato é um erro. Linhas vazias são ignoradas.
+    /// 
+    /// # Erros
+    /// - `DiagnosticError::Io` se o arquivo não puder ser lido
+    /// - `DiagnosticError::Parse` com a primeira linha que não é uma pontuação registrada
+    /// 
+    /// # Exemplo
+    /// ```no_run
+    /// use std::path::Path;
+    /// use hardware_diagnostic::calculate_performance_score;
+    /// use hardware_diagnostic::engine::history::{HistoryEntry, ScoreHistory};
+    /// 
+    /// let path = Path::new("historico.jsonl");
+    /// let mut history = ScoreHistory::load(path).unwrap_or_default();
+    /// history.push(HistoryEntry::from_score(&calculate_performance_score()));
+    /// history.save(path).unwrap();
+    /// 
+    /// println!("{} {:?}", history.plot_ascii(30), history.trend());
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn load(path: &Path) -> Result<Self, DiagnosticError> {
+        let contents = std::fs::read_to_string(path).map_err(|e| DiagnosticError::Io {
+            path: path.display().to_string(),
+            source: e.to_string(),
+        })?;
+        
+        let mut entries = Vec::new();
+        for (index, line) in contents.lines().enumerate().filter(|(_, line)| !line.trim().is_empty()) {
+            let snapshot: TimestampedScore = serde_json::from_str(line).map_err(|e| DiagnosticError::Parse {
+                path: path.display().to_string(),
+                line: index + 1,
+                source: e.to_string(),
+            })?;
+            entries.push(HistoryEntry::from(&snapshot));
+        }
+        Ok(ScoreHistory { entries })
+    }
+}
+
+/// Média das pontuações gerais do histórico
+fn average(entries: &[HistoryEntry]) -> f64 {
+    if entries.is_empty() {
+        return 0.0;
+    }
+    entries.iter().map(|entry| entry.score).sum::<f64>() / entries.len() as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    
+    fn history(scores: &[f64]) -> ScoreHistory {
+        let mut history = ScoreHistory::new();
+        for (day, &score) in scores.iter().enumerate() {
+            history.push(HistoryEntry {
+                timestamp: SystemTime::UNIX_EPOCH + Duration::from_secs(day as u64 * 86_400),
+                score,
+                cpu_score: Some(score),
+                ram_score: Some(score),
+                disk_score: Some(score),
+                category: PerformanceCategory::Precaução,
+            });
+        }
+        history
+    }
+    
+    #[test]
+    fn test_history_statistics() {
+        assert_eq!(ScoreHistory::new().average_score(), 0.0);
+        assert_eq!(ScoreHistory::new().trend(), ScoreTrend::Stable);
+        assert_eq!(ScoreHistory::new().plot_ascii(10), "");
+        
+        let improving = history(&[4.0, 5.0, 7.0, 8.0]);
+        assert_eq!(improving.average_score(), 6.0);
+        assert_eq!(improving.trend(), ScoreTrend::Improving);
+        assert_eq!(history(&[8.0, 7.9, 8.1, 8.0]).trend(), ScoreTrend::Stable);
+        assert_eq!(history(&[9.0, 8.0, 5.0, 3.0]).trend(), ScoreTrend::Degrading);
+        // Um pico isolado não inverte a tendência da reta
+        assert_eq!(history(&[6.0, 6.2, 9.0, 6.1, 6.0]).trend(), ScoreTrend::Stable);
+        let fit = history(&[8.0, 7.0, 6.0]).fit().unwrap();
+        assert!((fit.points_per_day + 1.0).abs() < 1e-9);
+        assert_eq!(fit.days, 2.0);
+        
+        // Escala fixa de 0 a 10; só as entradas mais recentes cabem na largura
+        assert_eq!(history(&[0.0, 5.0, 10.0]).plot_ascii(10), "▁▅█");
+        assert_eq!(history(&[0.0, 5.0, 10.0]).plot_ascii(2), "▅█");
+    }
+    
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_history_save_and_load() {
+        let path = std::env::temp_dir().join(format!("hardware_diagnostic_history_{}.json", std::process::id()));
+        let original = history(&[6.5, 7.0, 7.5]);
+        
+        original.save(&path).unwrap();
+        let loaded = ScoreHistory::load(&path);
+        std::fs::remove_file(&path).ok();
+        
+        assert_eq!(loaded.unwrap(), original);
+        assert!(matches!(ScoreHistory::load(&path), Err(DiagnosticError::Io { .. })));
+        
+        // O mesmo arquivo das execuções acrescentadas por `utils::append_snapshot`
+        original.save(&path).unwrap();
+        crate::engine::utils::append_snapshot(&path, &history(&[8.0]).entries[0].to_snapshot().score).unwrap();
+        let appended = ScoreHistory::load(&path).unwrap();
+        assert_eq!(appended.entries.len(), 4);
+        assert_eq!(appended.entries[3].score, 8.0);
+        assert_eq!(ScoreHistory::from_snapshots(&crate::engine::utils::load_snapshots(&path)), appended);
+        
+        // Linha corrompida: erro de formato com o número da linha
+        std::fs::write(&path, "\n{\"timestamp\":").unwrap();
+        let corrupted = ScoreHistory::load(&path);
+        std::fs::remove_file(&path).ok();
+        assert!(matches!(corrupted, Err(DiagnosticError::Parse { line: 2, .. })));
+        
+        // Componente não avaliado continua sem nota, e não vira 0.0
+        let mut partial = history(&[7.0]);
+        partial.entries[0].disk_score = None;
+        partial.save(&path).unwrap();
+        let loaded = ScoreHistory::load(&path);
+        let snapshots = crate::engine::utils::load_snapshots(&path);
+        std::fs::remove_file(&path).ok();
+        assert_eq!(loaded.unwrap(), partial);
+        assert_eq!(snapshots[0].score.disk_score, None);
+        assert_eq!(snapshots[0].score.cpu_score, Some(7.0));
+    }
+}
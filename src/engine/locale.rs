@@ -0,0 +1,354 @@
+// hardware-diagnostic - Ferramenta de diagnóstico de hardware
+// Copyright (C) 2025  Seu Nome
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Módulo `locale` - Idiomas das saídas em texto
+//! 
+//! Cada texto exibido ao usuário é uma variante de [`Message`], traduzida
+//! por um método por idioma. Para adicionar um idioma basta uma nova
+//! variante em [`Language`] e um novo braço em [`Message::text`] (o
+//! compilador aponta cada texto ainda não traduzido).
+
+use super::history::ScoreTrend;
+use super::processes::ProcessInfo;
+use super::{MemoryPressure, PerformanceCategory, VirtKind};
+
+/// Idiomas suportados nas saídas em texto
+/// 
+/// O padrão é o português do Brasil, preservando as saídas históricas.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Language {
+    /// Português do Brasil
+    #[default]
+    #[cfg_attr(feature = "serde", serde(rename = "pt-BR"))]
+    PtBr,
+    /// Inglês dos Estados Unidos
+    #[cfg_attr(feature = "serde", serde(rename = "en-US"))]
+    EnUs,
+}
+
+impl Language {
+    /// Interpreta um código de idioma como "pt", "pt-BR", "en" ou "en_US"
+    /// 
+    /// A comparação ignora maiúsculas e aceita `-` ou `_` como separador.
+    /// 
+    /// # Exemplo
+    /// ```
+    /// use hardware_diagnostic::Language;
+    /// 
+    /// assert_eq!(Language::from_code("en"), Some(Language::EnUs));
+    /// assert_eq!(Language::from_code("pt_BR"), Some(Language::PtBr));
+    /// assert_eq!(Language::from_code("xx"), None);
+    /// ```
+    pub fn from_code(code: &str) -> Option<Language> {
+        match code.to_ascii_lowercase().replace('_', "-").as_str() {
+            "pt" | "pt-br" => Some(Language::PtBr),
+            "en" | "en-us" => Some(Language::EnUs),
+            _ => None,
+        }
+    }
+}
+
+/// Textos traduzíveis da pontuação e das recomendações
+pub(crate) enum Message<'a> {
+    // Exibição da pontuação
+    ScoreTitle,
+    OverallScore(f64),
+    CategoryLabel,
+    DetailedScores,
+    DisksLabel,
+    BatteryLabel,
+    LegendTitle,
+    Legend(&'a PerformanceCategory),
+    RecommendationsTitle,
+    
+    // Comparação entre pontuações
+    DiffTitle,
+    OverallLabel,
+    CategoryUnchanged,
+    
+    // Recomendações
+    CategorySummary(&'a PerformanceCategory),
+    DiscardSuggestion,
+    VirtualEnvironment(VirtKind),
+    HighCpuUsage(&'a [ProcessInfo]),
+    SingleCore,
+    ThermalThrottling,
+    ActiveThrottling,
+    CpuTemperatureCritical(f32),
+    // Pressão moderada ou maior; a baixa não gera recomendação
+    MemoryPressure(MemoryPressure, f64, f64),
+    LowRam,
+    NoSwapHighRam,
+    AllSlotsUsed,
+    Ddr3EndOfLife,
+    NoDiskSelected,
+    DiskAlmostFull(&'a str, f64),
+    HddBottleneck(&'a str),
+    IoSaturated(&'a str, f32),
+    LowDiskSpace(&'a str, f64),
+    SmartFailure(&'a str),
+    ReallocatedSectors(&'a str, u64),
+    UnstableSectors(&'a str, u64),
+    BatteryDegraded(f32),
+    LongUptime(u64),
+    CpuVulnerable(String),
+    SecureBootDisabled,
+    CriticalSubsystem(&'a str, f64, f64),
+    BelowBenchmarkReference(&'a str, f64),
+    RecommendedAction(&'a PerformanceCategory),
+    ReviewVirtualResources,
+    
+    // Histórico
+    ScoreTrendSummary(ScoreTrend, f64, f64),
+}
+
+impl Message<'_> {
+    /// Retorna o texto no idioma indicado
+    pub(crate) fn text(&self, language: Language) -> String {
+        match language {
+            Language::PtBr => self.pt_br(),
+            Language::EnUs => self.en_us(),
+        }
+    }
+    
+    fn pt_br(&self) -> String {
+        use PerformanceCategory::*;
+        
+        match self {
+            Message::ScoreTitle => "📊 PONTUAÇÃO DE DESEMPENHO DA MÁQUINA".to_string(),
+            Message::OverallScore(score) => format!("PONTUAÇÃO GERAL: {:.1}/10.0", score),
+            Message::CategoryLabel => "CATEGORIA".to_string(),
+            Message::DetailedScores => "PONTUAÇÕES DETALHADAS".to_string(),
+            Message::DisksLabel => "Discos".to_string(),
+            Message::BatteryLabel => "Bateria".to_string(),
+            Message::DiffTitle => "📈 COMPARAÇÃO DE PONTUAÇÕES".to_string(),
+            Message::OverallLabel => "Geral".to_string(),
+            Message::CategoryUnchanged => "(sem mudança)".to_string(),
+            Message::LegendTitle => "LEGENDA DAS CATEGORIAS".to_string(),
+            Message::Legend(category) => match category {
+                Descarte => "DESCARTE/UPGRADE COMPLETO",
+                Manutencao => "MANUTENÇÃO URGENTE",
+                Precaução => "USO COM PRECAUÇÃO",
+                BomEstado => "BOM ESTADO DE USO",
+            }.to_string(),
+            Message::RecommendationsTitle => "RECOMENDAÇÕES".to_string(),
+            Message::CategorySummary(category) => match category {
+                Descarte => "🛑 CONSIDERE DESCARTE: A máquina está em estado crítico",
+                Manutencao => "⚠️ MANUTENÇÃO URGENTE: A máquina requer intervenção imediata",
+                Precaução => "🔶 USO COM PRECAUÇÃO: Monitore o desempenho regularmente",
+                BomEstado => "✅ BOM ESTADO: A máquina está adequada para uso normal",
+            }.to_string(),
+            Message::DiscardSuggestion => "💡 Sugestão: Upgrade completo ou substituição do equipamento".to_string(),
+            Message::VirtualEnvironment(kind) => format!(
+                "ℹ️ AMBIENTE VIRTUAL: Executando em {}. Os valores refletem os recursos alocados, não o hardware físico", kind),
+            Message::HighCpuUsage([]) => "🔴 CPU: Uso muito alto. Verifique processos desnecessários".to_string(),
+            Message::HighCpuUsage(top) => format!(
+                "🔴 CPU: Uso muito alto. Maior consumo: {}. Verifique processos desnecessários", top_consumers(top)),
+            Message::SingleCore => "🟡 CPU: Apenas 1 núcleo detectado. Limitação para multitarefa".to_string(),
+            Message::ThermalThrottling => "🔴 CPU: Throttling térmico detectado. Desempenho reduzido pelo calor".to_string(),
+            Message::ActiveThrottling => "🔴 CPU: O sistema está limitando o desempenho (throttling). Verifique a refrigeração e o plano de energia".to_string(),
+            Message::CpuTemperatureCritical(celsius) => format!(
+                "🛑 CPU: Temperatura crítica ({:.0}°C). Verifique ventilação e pasta térmica", celsius),
+            Message::MemoryPressure(pressure, ram, swap) => {
+                let (label, advice) = match pressure {
+                    MemoryPressure::Low | MemoryPressure::Moderate =>
+                        ("🟡 RAM: Pressão de memória moderada", "Feche os programas que não estão em uso"),
+                    MemoryPressure::High => ("🔴 RAM: Pressão de memória alta", "Considere adicionar mais memória"),
+                    MemoryPressure::Critical =>
+                        ("🛑 RAM: Pressão de memória crítica", "O sistema está paginando: adicione mais memória"),
+                };
+                format!("{} (RAM {:.0}%, SWAP {:.0}%). {}", label, ram, swap, advice)
+            }
+            Message::LowRam => "🟡 RAM: Memória insuficiente para sistemas modernos".to_string(),
+            Message::NoSwapHighRam => "🔴 SWAP: Memória virtual desativada com a RAM acima de 85%. Ative o arquivo de paginação (swap)".to_string(),
+            Message::AllSlotsUsed =>
+                "🟡 RAM: Todos os slots de memória ocupados. Um upgrade exige substituir os pentes atuais".to_string(),
+            Message::Ddr3EndOfLife =>
+                "🟡 RAM: Memória DDR3 indica plataforma em fim de vida. Prefira substituir a máquina a investir em upgrades".to_string(),
+            Message::NoDiskSelected =>
+                "⚠️ DISCOS: Nenhum disco passou pelo filtro configurado. Discos não avaliados".to_string(),
+            Message::DiskAlmostFull(disk, percent) => format!(
+                "🔴 DISCO {}: Capacidade quase esgotada ({:.1}%)", disk, percent),
+            Message::HddBottleneck(disk) => format!("🟡 DISCO {}: HDD pode estar limitando performance", disk),
+            Message::IoSaturated(disk, utilization) => format!(
+                "🔴 DISCO {}: E/S saturada ({:.0}% do tempo ocupado). O disco é o gargalo", disk, utilization),
+            Message::LowDiskSpace(disk, limit) => format!("🔴 DISCO {}: Menos de {} GiB livres", disk, limit),
+            Message::SmartFailure(disk) => format!(
+                "🛑 DISCO {}: SMART prevê falha iminente. Faça backup e substitua o disco", disk),
+            Message::UnstableSectors(disk, count) => format!(
+                "🔴 DISCO {}: {} setores pendentes ou erros incorrigíveis. Faça backup e planeje a troca do disco", disk, count),
+            Message::ReallocatedSectors(disk, sectors) => format!(
+                "🟡 DISCO {}: {} setores realocados. Monitore a saúde do disco", disk, sectors),
+            Message::BatteryDegraded(health) => format!(
+                "🔴 BATERIA: Saúde em {:.0}%. Considere substituir a bateria", health),
+            Message::LongUptime(days) => format!(
+                "🟡 SISTEMA: Ligado há {} dias. Reinicie para aplicar atualizações e liberar recursos", days),
+            Message::SecureBootDisabled =>
+                "🔴 SEGURANÇA: Secure Boot desativado. Ative-o na configuração do UEFI".to_string(),
+            Message::CriticalSubsystem(name, score, floor) => format!(
+                "⚠️ SUBSISTEMA CRÍTICO: {} com pontuação {:.1}, abaixo do piso de {:.1}. Categoria limitada a Manutenção", name, score, floor),
+            Message::BelowBenchmarkReference(name, percent) => format!(
+                "🔴 BENCHMARK: {} mediu {:.0}% do desempenho da máquina de referência", name, percent),
+            Message::CpuVulnerable(names) => format!(
+                "🔴 SEGURANÇA: CPU sem mitigação para {}. Atualize o sistema e o microcódigo (BIOS)", names),
+            Message::RecommendedAction(category) => match category {
+                Descarte => "📋 Ação recomendada: Substituir equipamento",
+                Manutencao => "📋 Ação recomendada: Manutenção técnica urgente",
+                Precaução => "📋 Ação recomendada: Monitoramento contínuo",
+                BomEstado => "📋 Ação recomendada: Manutenção preventiva regular",
+            }.to_string(),
+            Message::ReviewVirtualResources => {
+                "📋 Ação recomendada: Revisar a CPU, a memória e o disco alocados ao ambiente virtual".to_string()
+            }
+            Message::ScoreTrendSummary(trend, change, days) => match trend {
+                ScoreTrend::Degrading => format!("📉 A máquina piorou {:.1} pontos em {:.0} dias", -change, days),
+                ScoreTrend::Improving => format!("📈 A máquina melhorou {:.1} pontos em {:.0} dias", change, days),
+                ScoreTrend::Stable => format!("➡️ Pontuação estável ({:+.1}) em {:.0} dias", change, days),
+            },
+        }
+    }
+    
+    fn en_us(&self) -> String {
+        use PerformanceCategory::*;
+        
+        match self {
+            Message::ScoreTitle => "📊 MACHINE PERFORMANCE SCORE".to_string(),
+            Message::OverallScore(score) => format!("OVERALL SCORE: {:.1}/10.0", score),
+            Message::CategoryLabel => "CATEGORY".to_string(),
+            Message::DetailedScores => "DETAILED SCORES".to_string(),
+            Message::DisksLabel => "Disks".to_string(),
+            Message::BatteryLabel => "Battery".to_string(),
+            Message::DiffTitle => "📈 SCORE COMPARISON".to_string(),
+            Message::OverallLabel => "Overall".to_string(),
+            Message::CategoryUnchanged => "(unchanged)".to_string(),
+            Message::LegendTitle => "CATEGORY LEGEND".to_string(),
+            Message::Legend(category) => match category {
+                Descarte => "DISCARD/FULL UPGRADE",
+                Manutencao => "URGENT MAINTENANCE",
+                Precaução => "USE WITH CAUTION",
+                BomEstado => "GOOD CONDITION",
+            }.to_string(),
+            Message::RecommendationsTitle => "RECOMMENDATIONS".to_string(),
+            Message::CategorySummary(category) => match category {
+                Descarte => "🛑 CONSIDER DISCARDING: The machine is in critical condition",
+                Manutencao => "⚠️ URGENT MAINTENANCE: The machine needs immediate attention",
+                Precaução => "🔶 USE WITH CAUTION: Monitor performance regularly",
+                BomEstado => "✅ GOOD CONDITION: The machine is fit for normal use",
+            }.to_string(),
+            Message::DiscardSuggestion => "💡 Suggestion: Full upgrade or equipment replacement".to_string(),
+            Message::VirtualEnvironment(kind) => format!(
+                "ℹ️ VIRTUAL ENVIRONMENT: Running on {}. Values reflect the allocated resources, not the physical hardware", kind),
+            Message::HighCpuUsage([]) => "🔴 CPU: Very high usage. Check for unnecessary processes".to_string(),
+            Message::HighCpuUsage(top) => format!(
+                "🔴 CPU: Very high usage. Top consumers: {}. Check for unnecessary processes", top_consumers(top)),
+            Message::SingleCore => "🟡 CPU: Only 1 core detected. Limited multitasking".to_string(),
+            Message::ThermalThrottling => "🔴 CPU: Thermal throttling detected. Performance reduced by heat".to_string(),
+            Message::ActiveThrottling => "🔴 CPU: The system is limiting performance (throttling). Check cooling and the power plan".to_string(),
+            Message::CpuTemperatureCritical(celsius) => format!(
+                "🛑 CPU: Critical temperature ({:.0}°C). Check airflow and thermal paste", celsius),
+            Message::MemoryPressure(pressure, ram, swap) => {
+                let (label, advice) = match pressure {
+                    MemoryPressure::Low | MemoryPressure::Moderate =>
+                        ("🟡 RAM: Moderate memory pressure", "Close programs that are not in use"),
+                    MemoryPressure::High => ("🔴 RAM: High memory pressure", "Consider adding more memory"),
+                    MemoryPressure::Critical =>
+                        ("🛑 RAM: Critical memory pressure", "The system is paging: add more memory"),
+                };
+                format!("{} (RAM {:.0}%, SWAP {:.0}%). {}", label, ram, swap, advice)
+            }
+            Message::LowRam => "🟡 RAM: Not enough memory for modern systems".to_string(),
+            Message::NoSwapHighRam => "🔴 SWAP: Virtual memory disabled with RAM above 85%. Enable the paging file (swap)".to_string(),
+            Message::AllSlotsUsed =>
+                "🟡 RAM: All memory slots are occupied. An upgrade requires replacing the current modules".to_string(),
+            Message::Ddr3EndOfLife =>
+                "🟡 RAM: DDR3 memory indicates an end-of-life platform. Prefer replacing the machine over upgrading it".to_string(),
+            Message::NoDiskSelected =>
+                "⚠️ DISKS: No disk matched the configured filter. Disks not scored".to_string(),
+            Message::DiskAlmostFull(disk, percent) => format!(
+                "🔴 DISK {}: Almost out of capacity ({:.1}%)", disk, percent),
+            Message::HddBottleneck(disk) => format!("🟡 DISK {}: HDD may be limiting performance", disk),
+            Message::IoSaturated(disk, utilization) => format!(
+                "🔴 DISK {}: I/O saturated ({:.0}% of the time busy). The disk is the bottleneck", disk, utilization),
+            Message::LowDiskSpace(disk, limit) => format!("🔴 DISK {}: Less than {} GiB free", disk, limit),
+            Message::SmartFailure(disk) => format!(
+                "🛑 DISK {}: SMART predicts imminent failure. Back up and replace the disk", disk),
+            Message::UnstableSectors(disk, count) => format!(
+                "🔴 DISK {}: {} pending sectors or uncorrectable errors. Back up and plan to replace the disk", disk, count),
+            Message::ReallocatedSectors(disk, sectors) => format!(
+                "🟡 DISK {}: {} reallocated sectors. Monitor disk health", disk, sectors),
+            Message::BatteryDegraded(health) => format!(
+                "🔴 BATTERY: Health at {:.0}%. Consider replacing the battery", health),
+            Message::LongUptime(days) => format!(
+                "🟡 SYSTEM: Up for {} days. Restart to apply updates and free resources", days),
+            Message::SecureBootDisabled =>
+                "🔴 SECURITY: Secure Boot disabled. Enable it in the UEFI settings".to_string(),
+            Message::CriticalSubsystem(name, score, floor) => format!(
+                "⚠️ CRITICAL SUBSYSTEM: {} scored {:.1}, below the {:.1} floor. Category capped at Maintenance", name, score, floor),
+            Message::BelowBenchmarkReference(name, percent) => format!(
+                "🔴 BENCHMARK: {} measured {:.0}% of the reference machine's performance", name, percent),
+            Message::CpuVulnerable(names) => format!(
+                "🔴 SECURITY: CPU without mitigation for {}. Update the system and the microcode (BIOS)", names),
+            Message::RecommendedAction(category) => match category {
+                Descarte => "📋 Recommended action: Replace equipment",
+                Manutencao => "📋 Recommended action: Urgent technical maintenance",
+                Precaução => "📋 Recommended action: Continuous monitoring",
+                BomEstado => "📋 Recommended action: Regular preventive maintenance",
+            }.to_string(),
+            Message::ReviewVirtualResources => {
+                "📋 Recommended action: Review the CPU, memory and disk allocated to the virtual environment".to_string()
+            }
+            Message::ScoreTrendSummary(trend, change, days) => match trend {
+                ScoreTrend::Degrading => format!("📉 The machine has degraded {:.1} points over {:.0} days", -change, days),
+                ScoreTrend::Improving => format!("📈 The machine has improved {:.1} points over {:.0} days", change, days),
+                ScoreTrend::Stable => format!("➡️ Stable score ({:+.1}) over {:.0} days", change, days),
+            },
+        }
+    }
+}
+
+/// Lista os processos como "nome (PID n, x%)", separados por vírgula
+fn top_consumers(processes: &[ProcessInfo]) -> String {
+    processes
+        .iter()
+        .map(|process| format!("{} (PID {}, {:.1}%)", process.name, process.pid, process.cpu_usage))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_high_cpu_names_top_consumer() {
+        let process = |pid: u32, name: &str, cpu_usage: f32| ProcessInfo {
+            pid,
+            name: name.to_string(),
+            cpu_usage,
+            memory_bytes: 0,
+            status: "Runnable".to_string(),
+        };
+        let top = [process(4242, "compilador", 97.5), process(7, "navegador", 12.0)];
+        
+        assert_eq!(
+            Message::HighCpuUsage(&top).text(Language::PtBr),
+            "🔴 CPU: Uso muito alto. Maior consumo: compilador (PID 4242, 97.5%), navegador (PID 7, 12.0%). Verifique processos desnecessários"
+        );
+        assert!(Message::HighCpuUsage(&top[..1]).text(Language::EnUs).contains("Top consumers: compilador (PID 4242, 97.5%)."));
+        assert_eq!(Message::HighCpuUsage(&[]).text(Language::PtBr), "🔴 CPU: Uso muito alto. Verifique processos desnecessários");
+    }
+}
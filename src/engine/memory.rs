@@ -0,0 +1,272 @@
+// hardware-diagnostic - Ferramenta de diagnóstico de hardware
+// Copyright (C) 2025  Seu Nome
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Módulo `memory` - Pentes de memória (DIMMs) instalados
+//! 
+//! No Windows, com a feature `memory`, os dados vêm do WMI
+//! (`Win32_PhysicalMemory` e `Win32_PhysicalMemoryArray`). Em outras
+//! plataformas a leitura exige privilégios de administrador (SMBIOS), e a
+//! lista de pentes fica vazia.
+
+use std::sync::OnceLock;
+
+/// Um pente de memória instalado
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MemoryModule {
+    /// Identificação do slot na placa-mãe (ex: "DIMM 0", "ChannelA-DIMM0")
+    pub slot: String,
+    /// Capacidade do pente em bytes
+    pub size_bytes: u64,
+    /// Velocidade em MHz (0 se não disponível)
+    pub speed_mhz: u32,
+    /// Fabricante (se disponível)
+    pub manufacturer: Option<String>,
+    /// Tipo da memória (ex: "DDR4"), se informado pelo sistema
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub memory_type: Option<String>,
+}
+
+/// Pentes instalados e número de slots da placa-mãe
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MemoryLayout {
+    /// Pentes instalados, na ordem informada pelo sistema
+    pub modules: Vec<MemoryModule>,
+    /// Total de slots da placa-mãe, `None` se desconhecido
+    pub total_slots: Option<usize>,
+}
+
+impl MemoryLayout {
+    /// Resume a ocupação dos slots (ex: "2 de 4 slots usados")
+    /// 
+    /// Indica ao técnico se é possível adicionar memória sem trocar pentes.
+    /// Retorna `None` quando nenhum pente foi detectado.
+    pub fn slot_summary(&self) -> Option<String> {
+        if self.modules.is_empty() {
+            return None;
+        }
+        
+        Some(match self.total_slots {
+            Some(total) => format!("{} de {} slots usados", self.modules.len(), total),
+            None => format!("{} slots usados (total desconhecido)", self.modules.len()),
+        })
+    }
+    
+    /// Indica se há slots livres (`None` se o total de slots for desconhecido)
+    pub fn has_free_slots(&self) -> Option<bool> {
+        self.total_slots.map(|total| self.modules.len() < total)
+    }
+    
+    /// Tipo da memória, quando todos os pentes que o informam concordam
+    pub fn memory_type(&self) -> Option<&str> {
+        let mut types = self.modules.iter().filter_map(|module| module.memory_type.as_deref());
+        let first = types.next()?;
+        types.all(|other| other == first).then_some(first)
+    }
+    
+    /// Velocidade efetiva em MHz: pentes diferentes operam na velocidade do mais lento
+    pub fn speed_mhz(&self) -> Option<u32> {
+        self.modules.iter().map(|module| module.speed_mhz).filter(|&speed| speed > 0).min()
+    }
+}
+
+/// Coleta os pentes instalados e o total de slots
+/// 
+/// # Exemplo
+/// ```
+/// use hardware_diagnostic::engine::memory::memory_layout;
+/// 
+/// let layout = memory_layout();
+/// if let Some(summary) = layout.slot_summary() {
+///     println!("Memória: {}", summary);
+/// }
+/// ```
+pub fn memory_layout() -> MemoryLayout {
+    log::debug!("coletando pentes de memória");
+    query_platform()
+}
+
+/// Pentes instalados, consultados uma única vez por processo
+/// 
+/// Usado no preenchimento de [`RamInfo`](super::RamInfo), que é lido a cada
+/// atualização; os pentes não mudam com a máquina ligada.
+pub(crate) fn cached_memory_layout() -> &'static MemoryLayout {
+    static LAYOUT: OnceLock<MemoryLayout> = OnceLock::new();
+    LAYOUT.get_or_init(memory_layout)
+}
+
+/// Coleta os pentes de memória instalados
+/// 
+/// Vazio quando os pentes não podem ser consultados.
+pub fn memory_modules() -> Vec<MemoryModule> {
+    memory_layout().modules
+}
+
+/// Consulta os pentes e os slots via WMI
+#[cfg(all(target_os = "windows", feature = "memory"))]
+fn query_platform() -> MemoryLayout {
+    use serde::Deserialize;
+    use wmi::{COMLibrary, WMIConnection};
+    
+    #[derive(Deserialize)]
+    #[serde(rename = "Win32_PhysicalMemory")]
+    #[serde(rename_all = "PascalCase")]
+    struct PhysicalMemory {
+        device_locator: Option<String>,
+        bank_label: Option<String>,
+        capacity: Option<u64>,
+        configured_clock_speed: Option<u32>,
+        speed: Option<u32>,
+        manufacturer: Option<String>,
+        #[serde(rename = "SMBIOSMemoryType")]
+        smbios_memory_type: Option<u32>,
+        memory_type: Option<u16>,
+    }
+    
+    #[derive(Deserialize)]
+    #[serde(rename = "Win32_PhysicalMemoryArray")]
+    #[serde(rename_all = "PascalCase")]
+    struct PhysicalMemoryArray {
+        memory_devices: Option<u16>,
+    }
+    
+    let connection = match COMLibrary::new().and_then(WMIConnection::new) {
+        Ok(connection) => connection,
+        Err(e) => {
+            log::error!("conexão WMI falhou ({}); pentes de memória indisponíveis", e);
+            return MemoryLayout::default();
+        }
+    };
+    
+    let modules: Vec<PhysicalMemory> = connection.query().unwrap_or_default();
+    let arrays: Vec<PhysicalMemoryArray> = connection.query().unwrap_or_default();
+    
+    let total_slots: usize = arrays
+        .iter()
+        .filter_map(|array| array.memory_devices)
+        .map(usize::from)
+        .sum();
+    
+    MemoryLayout {
+        modules: modules
+            .into_iter()
+            .enumerate()
+            .map(|(i, module)| MemoryModule {
+                slot: module
+                    .device_locator
+                    .or(module.bank_label)
+                    .unwrap_or_else(|| format!("Slot {}", i)),
+                size_bytes: module.capacity.unwrap_or(0),
+                // Velocidade efetiva configurada pelo BIOS, se informada
+                speed_mhz: module.configured_clock_speed.or(module.speed).unwrap_or(0),
+                // Alguns fabricantes preenchem com espaços ou códigos genéricos
+                manufacturer: module
+                    .manufacturer
+                    .map(|m| m.trim().to_string())
+                    .filter(|m| !m.is_empty() && m != "Unknown"),
+                // `MemoryType` é o campo antigo, zerado a partir do DDR4
+                memory_type: module
+                    .smbios_memory_type
+                    .and_then(memory_type_name)
+                    .or_else(|| module.memory_type.map(u32::from).and_then(memory_type_name))
+                    .map(str::to_string),
+            })
+            .collect(),
+        total_slots: (total_slots > 0).then_some(total_slots),
+    }
+}
+
+/// Nome do tipo de memória a partir do código SMBIOS (tabela 17, "Memory Type")
+#[cfg_attr(not(all(target_os = "windows", feature = "memory")), allow(dead_code))]
+fn memory_type_name(code: u32) -> Option<&'static str> {
+    Some(match code {
+        20 => "DDR",
+        21 => "DDR2",
+        24 => "DDR3",
+        26 => "DDR4",
+        27 => "LPDDR",
+        28 => "LPDDR2",
+        29 => "LPDDR3",
+        30 => "LPDDR4",
+        34 => "DDR5",
+        35 => "LPDDR5",
+        _ => return None,
+    })
+}
+
+/// Sem suporte na plataforma (ou sem a feature `memory`): nenhum pente
+#[cfg(not(all(target_os = "windows", feature = "memory")))]
+fn query_platform() -> MemoryLayout {
+    MemoryLayout::default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_slot_summary() {
+        let module = |slot: &str| MemoryModule {
+            slot: slot.to_string(),
+            size_bytes: 8 * 1024 * 1024 * 1024,
+            speed_mhz: 3200,
+            manufacturer: Some("Samsung".to_string()),
+            memory_type: Some("DDR4".to_string()),
+        };
+        
+        assert_eq!(MemoryLayout::default().slot_summary(), None);
+        
+        let layout = MemoryLayout {
+            modules: vec![module("DIMM 0"), module("DIMM 2")],
+            total_slots: Some(4),
+        };
+        assert_eq!(layout.slot_summary().as_deref(), Some("2 de 4 slots usados"));
+        assert_eq!(layout.has_free_slots(), Some(true));
+        
+        let unknown = MemoryLayout { total_slots: None, ..layout };
+        assert_eq!(unknown.slot_summary().as_deref(), Some("2 slots usados (total desconhecido)"));
+        assert_eq!(unknown.has_free_slots(), None);
+    }
+    
+    #[test]
+    fn test_memory_type_and_speed() {
+        assert_eq!(memory_type_name(24), Some("DDR3"));
+        assert_eq!(memory_type_name(26), Some("DDR4"));
+        assert_eq!(memory_type_name(34), Some("DDR5"));
+        assert_eq!(memory_type_name(0), None);
+        
+        let module = |speed_mhz: u32, memory_type: Option<&str>| MemoryModule {
+            slot: "DIMM".to_string(),
+            size_bytes: 16 * 1024 * 1024 * 1024,
+            speed_mhz,
+            manufacturer: None,
+            memory_type: memory_type.map(str::to_string),
+        };
+        
+        let layout = MemoryLayout {
+            modules: vec![module(3200, Some("DDR4")), module(2666, None), module(0, Some("DDR4"))],
+            total_slots: Some(4),
+        };
+        assert_eq!(layout.memory_type(), Some("DDR4"));
+        assert_eq!(layout.speed_mhz(), Some(2666));
+        
+        let mixed = MemoryLayout { modules: vec![module(0, Some("DDR4")), module(0, Some("DDR5"))], total_slots: None };
+        assert_eq!(mixed.memory_type(), None);
+        assert_eq!(mixed.speed_mhz(), None);
+        assert_eq!(MemoryLayout::default().memory_type(), None);
+    }
+}
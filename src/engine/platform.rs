@@ -0,0 +1,55 @@
+// hardware-diagnostic - Ferramenta de diagnóstico de hardware
+// Copyright (C) 2025  Seu Nome
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Módulo `platform` - Identificação do sistema operacional
+//! 
+//! As coletas específicas de cada sistema ficam atrás de
+//! `#[cfg(target_os = ...)]` nos próprios módulos; aqui fica apenas o
+//! identificador da plataforma incluído nos relatórios.
+
+/// Identificador da plataforma em execução: "windows", "linux" ou "macos"
+/// 
+/// Outros sistemas retornam o valor de `std::env::consts::OS`.
+/// 
+/// # Exemplo
+/// ```
+/// use hardware_diagnostic::engine::platform::current_os;
+/// 
+/// println!("Plataforma: {}", current_os());
+/// ```
+pub fn current_os() -> &'static str {
+    #[cfg(target_os = "windows")]
+    return "windows";
+    
+    #[cfg(target_os = "linux")]
+    return "linux";
+    
+    #[cfg(target_os = "macos")]
+    return "macos";
+    
+    #[cfg(not(any(target_os = "windows", target_os = "linux", target_os = "macos")))]
+    return std::env::consts::OS;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_current_os() {
+        assert_eq!(current_os(), std::env::consts::OS);
+    }
+}
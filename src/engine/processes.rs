@@ -0,0 +1,152 @@
+// hardware-diagnostic - Ferramenta de diagnóstico de hardware
+// Copyright (C) 2025  Seu Nome
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Módulo `processes` - Processos que mais consomem CPU e memória
+//! 
+//! Quando a pontuação da CPU ou da RAM é baixa, a lista de processos
+//! indica o que está consumindo os recursos. Assim como o uso total da CPU,
+//! o uso por processo exige duas leituras separadas pelo intervalo de
+//! amostragem (veja [`SystemContext::refresh_processes`]).
+
+use super::{PerformanceCategory, SystemContext};
+use sysinfo::System;
+
+/// Número de processos listados no relatório quando a pontuação é baixa
+pub const REPORT_TOP_PROCESSES: usize = 5;
+
+/// Número de processos citados na recomendação de uso alto da CPU
+pub const RECOMMENDATION_TOP_PROCESSES: usize = 3;
+
+/// Um processo em execução
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProcessInfo {
+    /// Identificador do processo
+    pub pid: u32,
+    /// Nome do executável
+    pub name: String,
+    /// Percentual de uso da CPU
+    /// 
+    /// Relativo a um núcleo: processos com várias threads podem passar de 100%.
+    pub cpu_usage: f32,
+    /// Memória residente em bytes
+    pub memory_bytes: u64,
+    /// Estado informado pelo sistema (ex: "Runnable", "Sleeping")
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub status: String,
+}
+
+/// Lista os `n` processos com maior uso de CPU, do maior para o menor
+/// 
+/// Aguarda o intervalo de amostragem padrão entre as duas leituras.
+/// 
+/// # Exemplo
+/// ```
+/// use hardware_diagnostic::engine::processes::top_processes;
+/// 
+/// for process in top_processes(5) {
+///     println!("{} ({}): {:.1}%", process.name, process.pid, process.cpu_usage);
+/// }
+/// ```
+pub fn top_processes(n: usize) -> Vec<ProcessInfo> {
+    log::debug!("coletando os {} processos com maior uso de CPU", n);
+    let mut ctx = SystemContext::new();
+    ctx.refresh_processes();
+    top_by_cpu(ctx.processes_from(), n)
+}
+
+/// Lista os `n` processos com maior uso de memória, do maior para o menor
+pub fn top_memory_processes(n: usize) -> Vec<ProcessInfo> {
+    log::debug!("coletando os {} processos com maior uso de memória", n);
+    let mut ctx = SystemContext::new();
+    ctx.refresh_processes();
+    top_by_memory(ctx.processes_from(), n)
+}
+
+/// Indica se a categoria justifica listar os processos no relatório
+/// (Manutenção ou Descarte)
+pub(crate) fn lists_processes(category: &PerformanceCategory) -> bool {
+    matches!(category, PerformanceCategory::Manutencao | PerformanceCategory::Descarte)
+}
+
+/// Seleciona os [`REPORT_TOP_PROCESSES`] maiores consumidores de CPU e de memória
+pub(crate) fn report_top(running: Vec<ProcessInfo>) -> (Vec<ProcessInfo>, Vec<ProcessInfo>) {
+    (
+        top_by_cpu(running.clone(), REPORT_TOP_PROCESSES),
+        top_by_memory(running, REPORT_TOP_PROCESSES),
+    )
+}
+
+/// Lê os processos do último refresh de `sys`
+pub(crate) fn read_processes(sys: &System) -> Vec<ProcessInfo> {
+    sys.processes()
+        .values()
+        .map(|process| ProcessInfo {
+            pid: process.pid().as_u32(),
+            name: process.name().to_string(),
+            cpu_usage: process.cpu_usage(),
+            memory_bytes: process.memory(),
+            status: process.status().to_string(),
+        })
+        .collect()
+}
+
+/// Mantém os `n` processos de maior uso de CPU (empates pelo PID)
+pub(crate) fn top_by_cpu(mut processes: Vec<ProcessInfo>, n: usize) -> Vec<ProcessInfo> {
+    processes.sort_by(|a, b| b.cpu_usage.total_cmp(&a.cpu_usage).then(a.pid.cmp(&b.pid)));
+    processes.truncate(n);
+    processes
+}
+
+/// Mantém os `n` processos de maior uso de memória (empates pelo PID)
+pub(crate) fn top_by_memory(mut processes: Vec<ProcessInfo>, n: usize) -> Vec<ProcessInfo> {
+    processes.sort_by(|a, b| b.memory_bytes.cmp(&a.memory_bytes).then(a.pid.cmp(&b.pid)));
+    processes.truncate(n);
+    processes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_top_processes_ordering() {
+        let process = |pid: u32, cpu_usage: f32, memory_bytes: u64| ProcessInfo {
+            pid,
+            name: format!("proc{}", pid),
+            cpu_usage,
+            memory_bytes,
+            status: "Runnable".to_string(),
+        };
+        let processes = vec![
+            process(1, 5.0, 300),
+            process(2, 80.0, 100),
+            process(3, 40.0, 900),
+            process(4, 80.0, 50),
+        ];
+        
+        let pids = |list: Vec<ProcessInfo>| list.iter().map(|p| p.pid).collect::<Vec<_>>();
+        assert_eq!(pids(top_by_cpu(processes.clone(), 3)), vec![2, 4, 3]);
+        assert_eq!(pids(top_by_memory(processes.clone(), 2)), vec![3, 1]);
+        assert!(top_by_cpu(processes, 0).is_empty());
+        
+        // Leitura real: o próprio processo de teste está na lista
+        let current = std::process::id();
+        let running = top_memory_processes(usize::MAX);
+        let current = running.iter().find(|p| p.pid == current).expect("processo de teste ausente");
+        assert!(!current.status.is_empty());
+    }
+}
@@ -0,0 +1,251 @@
+// hardware-diagnostic - Ferramenta de diagnóstico de hardware
+// Copyright (C) 2025  Seu Nome
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Módulo `reference` - Comparação com a especificação de fábrica
+//! 
+//! Um [`ReferenceProfile`] descreve o hardware com que um modelo de máquina
+//! foi entregue. [`DiagnosticReport::check_against`] aponta cada
+//! [`Deviation`]: memória a menos (pente com defeito ou retirado), menos
+//! núcleos que o esperado, disco ausente ou de outro tipo.
+
+use super::{same_mount_point, utils, DiagnosticReport, DiskKind};
+use std::fmt;
+
+/// Fração da RAM esperada que pode faltar sem ser considerada desvio
+/// 
+/// O sistema reserva parte da memória (vídeo integrado, firmware), então
+/// uma máquina com 16 GiB costuma informar um pouco menos.
+const RAM_TOLERANCE: f64 = 0.1;
+
+/// Disco esperado em um [`ReferenceProfile`]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReferenceDisk {
+    /// Ponto de montagem (ex: "C:\\" ou "/")
+    pub mount_point: String,
+    /// Tipo de mídia entregue
+    pub kind: DiskKind,
+}
+
+/// Especificação de referência de um modelo de máquina
+/// 
+/// Campos ausentes não são verificados. Com a feature `serde`, pode ser
+/// carregada de um arquivo JSON (ou TOML) via [`ReferenceProfile::from_file`]:
+/// 
+/// ```json
+/// {
+///   "model": "OptiPlex 7090",
+///   "cpu_cores": 8,
+///   "ram_gib": 16,
+///   "disks": [{ "mount_point": "C:\\", "kind": "Ssd" }]
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct ReferenceProfile {
+    /// Nome do modelo, apenas para identificação
+    pub model: String,
+    /// Núcleos lógicos esperados
+    pub cpu_cores: Option<usize>,
+    /// RAM instalada esperada, em GiB
+    pub ram_gib: Option<f64>,
+    /// Discos esperados
+    pub disks: Vec<ReferenceDisk>,
+}
+
+impl ReferenceProfile {
+    /// Carrega o perfil de um arquivo JSON ou TOML
+    /// 
+    /// O formato é escolhido pela extensão, como em
+    /// [`ScoringConfig::from_file`](super::ScoringConfig::from_file).
+    /// 
+    /// # Erros
+    /// - `DiagnosticError::Io` se o arquivo não puder ser lido
+    /// - `DiagnosticError::InvalidConfig` se o conteúdo for inválido
+    #[cfg(feature = "serde")]
+    pub fn from_file(path: impl AsRef<std::path::Path>) -> Result<Self, super::DiagnosticError> {
+        super::read_config_file(path.as_ref())
+    }
+}
+
+/// Diferença entre a máquina e o [`ReferenceProfile`]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Deviation {
+    /// RAM abaixo da esperada, em GiB
+    MissingRam {
+        /// RAM da especificação
+        expected_gib: f64,
+        /// RAM informada pelo sistema
+        actual_gib: f64,
+    },
+    /// Menos núcleos lógicos que o esperado
+    FewerCores {
+        /// Núcleos da especificação
+        expected: usize,
+        /// Núcleos detectados
+        actual: usize,
+    },
+    /// Disco esperado que não foi encontrado
+    MissingDisk {
+        /// Ponto de montagem da especificação
+        mount_point: String,
+    },
+    /// Disco com tipo de mídia diferente do entregue
+    DiskKindChanged {
+        /// Ponto de montagem do disco
+        mount_point: String,
+        /// Tipo da especificação
+        expected: DiskKind,
+        /// Tipo detectado
+        actual: DiskKind,
+    },
+}
+
+impl fmt::Display for Deviation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Deviation::MissingRam { expected_gib, actual_gib } => write!(
+                f,
+                "RAM: {:.1} GiB instalados, esperado {:.1} GiB (pente com defeito ou retirado?)",
+                actual_gib, expected_gib
+            ),
+            Deviation::FewerCores { expected, actual } => {
+                write!(f, "CPU: {} núcleos lógicos, esperado {}", actual, expected)
+            }
+            Deviation::MissingDisk { mount_point } => write!(f, "Disco {}: não encontrado", mount_point),
+            Deviation::DiskKindChanged { mount_point, expected, actual } => {
+                write!(f, "Disco {}: tipo {}, esperado {}", mount_point, actual, expected)
+            }
+        }
+    }
+}
+
+impl DiagnosticReport {
+    /// Compara o hardware do relatório com a especificação de referência
+    /// 
+    /// A RAM só é apontada quando faltam mais de 10% do esperado, pois o
+    /// sistema reserva parte da memória. Discos de tipo não identificado
+    /// não são comparados. Retorna vazio quando a máquina confere.
+    /// 
+    /// O método fica em `DiagnosticReport`, e não em [`PerformanceScore`](super::PerformanceScore),
+    /// porque a pontuação guarda só as notas: núcleos, RAM instalada e tipo
+    /// dos discos estão nas seções de hardware do relatório.
+    /// 
+    /// # Exemplo
+    /// ```
+    /// use hardware_diagnostic::{DiagnosticReport, ReferenceProfile};
+    /// 
+    /// let profile = ReferenceProfile { cpu_cores: Some(1), ..ReferenceProfile::default() };
+    /// for deviation in DiagnosticReport::capture().check_against(&profile) {
+    ///     println!("⚠️ {}", deviation);
+    /// }
+    /// ```
+    pub fn check_against(&self, profile: &ReferenceProfile) -> Vec<Deviation> {
+        let mut deviations = Vec::new();
+        
+        if let Some(expected) = profile.cpu_cores.filter(|&expected| self.cpu.number_cpus < expected) {
+            deviations.push(Deviation::FewerCores { expected, actual: self.cpu.number_cpus });
+        }
+        
+        let actual_gib = utils::bytes_to_gib_f64(self.ram.total_ram);
+        if let Some(expected_gib) = profile.ram_gib.filter(|&expected| actual_gib < expected * (1.0 - RAM_TOLERANCE)) {
+            deviations.push(Deviation::MissingRam { expected_gib, actual_gib });
+        }
+        
+        for expected in &profile.disks {
+            let disk = self
+                .disks
+                .iter()
+                .find(|disk| same_mount_point(&disk.mount_point, &expected.mount_point));
+            match disk {
+                None => deviations.push(Deviation::MissingDisk { mount_point: expected.mount_point.clone() }),
+                Some(disk) if disk.kind != DiskKind::Unknown && disk.kind != expected.kind => {
+                    deviations.push(Deviation::DiskKindChanged {
+                        mount_point: expected.mount_point.clone(),
+                        expected: expected.kind,
+                        actual: disk.kind,
+                    })
+                }
+                Some(_) => {}
+            }
+        }
+        
+        deviations
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::report::tests::sample_report;
+    
+    const GIB: u64 = 1024 * 1024 * 1024;
+    
+    #[test]
+    fn test_check_against_reference() {
+        let mut report = sample_report();
+        report.ram.total_ram = 15 * GIB + GIB / 2;
+        let mut data = report.disks[0].clone();
+        data.mount_point = "D:\\".to_string();
+        data.kind = DiskKind::Unknown;
+        report.disks.push(data);
+        
+        let profile = ReferenceProfile {
+            model: "Estação padrão".to_string(),
+            cpu_cores: Some(8),
+            ram_gib: Some(16.0),
+            disks: vec![
+                ReferenceDisk { mount_point: "C:".to_string(), kind: DiskKind::Ssd },
+                ReferenceDisk { mount_point: "D:\\".to_string(), kind: DiskKind::Hdd },
+            ],
+        };
+        // 15.5 GiB de 16 está dentro da tolerância; tipo desconhecido não conta
+        assert!(report.check_against(&profile).is_empty());
+        assert!(report.check_against(&ReferenceProfile::default()).is_empty());
+        
+        // Um pente a menos, núcleos desativados e o SSD trocado por um HDD
+        report.cpu.number_cpus = 4;
+        report.ram.total_ram = 8 * GIB;
+        report.disks.truncate(1);
+        report.disks[0].kind = DiskKind::Hdd;
+        let deviations = report.check_against(&profile);
+        assert_eq!(deviations, vec![
+            Deviation::FewerCores { expected: 8, actual: 4 },
+            Deviation::MissingRam { expected_gib: 16.0, actual_gib: 8.0 },
+            Deviation::DiskKindChanged { mount_point: "C:".to_string(), expected: DiskKind::Ssd, actual: DiskKind::Hdd },
+            Deviation::MissingDisk { mount_point: "D:\\".to_string() },
+        ]);
+        assert_eq!(deviations[0].to_string(), "CPU: 4 núcleos lógicos, esperado 8");
+        assert_eq!(deviations[2].to_string(), "Disco C:: tipo HDD, esperado SSD");
+    }
+    
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_reference_profile_from_json() {
+        let path = std::env::temp_dir().join(format!("hardware_diagnostic_reference_{}.json", std::process::id()));
+        std::fs::write(&path, r#"{"model": "T14", "ram_gib": 32, "disks": [{"mount_point": "C:\\", "kind": "Ssd"}]}"#).unwrap();
+        let profile = ReferenceProfile::from_file(&path);
+        std::fs::remove_file(&path).ok();
+        
+        let profile = profile.unwrap();
+        assert_eq!(profile.model, "T14");
+        assert_eq!(profile.cpu_cores, None);
+        assert_eq!(profile.ram_gib, Some(32.0));
+        assert_eq!(profile.disks[0].kind, DiskKind::Ssd);
+    }
+}
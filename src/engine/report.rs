@@ -0,0 +1,1299 @@
+// hardware-diagnostic - Ferramenta de diagnóstico de hardware
+// Copyright (C) 2025  Seu Nome
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Módulo `report` - Relatório completo capturado de uma só vez
+//! 
+//! O [`DiagnosticReport`] reúne CPU, RAM, discos e pontuação a partir de
+//! uma única atualização de um [`SystemContext`], garantindo que todos os
+//! valores do relatório correspondam ao mesmo instante.
+
+use super::*;
+use std::path::Path;
+use std::time::SystemTime;
+
+/// Versão do formato JSON de [`ReportEnvelope`], no campo `schema_version`
+/// 
+/// Incrementada apenas quando um campo é removido ou muda de significado,
+/// para que consumidores possam rejeitar ou migrar arquivos antigos. A
+/// versão 1 era o relatório sem envelope, com `schema_version` em texto.
+pub const REPORT_SCHEMA_VERSION: u32 = 2;
+
+/// Esqueleto do relatório HTML; os marcadores `{{...}}` são substituídos por
+/// [`DiagnosticReport::to_html`]
+const HTML_TEMPLATE: &str = r#"<!DOCTYPE html>
+<html lang="pt-BR">
+<head>
+<meta charset="utf-8">
+<title>Diagnóstico de Hardware - {{hostname}}</title>
+<style>
+  body { font-family: "Segoe UI", Arial, sans-serif; background: #f4f5f7; color: #222; margin: 0; padding: 24px; }
+  .container { max-width: 760px; margin: 0 auto; background: #fff; border-radius: 8px; padding: 24px 32px; box-shadow: 0 1px 4px rgba(0,0,0,.15); }
+  h1 { font-size: 22px; margin: 0 0 4px; }
+  h2 { font-size: 17px; margin: 28px 0 8px; border-bottom: 1px solid #e2e4e8; padding-bottom: 4px; }
+  .meta { color: #666; font-size: 13px; }
+  .score { font-size: 40px; font-weight: bold; margin: 16px 0 8px; }
+  .gauge { background: #e2e4e8; border-radius: 6px; height: 18px; overflow: hidden; }
+  .gauge-fill { height: 100%; border-radius: 6px; }
+  .gauge.small { height: 10px; }
+  td.bar { width: 50%; }
+  .trend { color: #444; margin: 12px 0 0; }
+  .badge { display: inline-block; margin-top: 12px; padding: 4px 12px; border-radius: 12px; color: #fff; font-weight: bold; font-size: 13px; }
+  .descarte { background: #c62828; }
+  .manutencao { background: #ef6c00; }
+  .precaucao { background: #f9a825; }
+  .bom-estado { background: #2e7d32; }
+  table { width: 100%; border-collapse: collapse; }
+  td, th { text-align: left; padding: 6px 8px; border-bottom: 1px solid #eef0f2; }
+  td.value { text-align: right; font-variant-numeric: tabular-nums; }
+  ul { padding-left: 20px; }
+  li { margin: 4px 0; }
+</style>
+</head>
+<body>
+<div class="container">
+  <h1>🖥️ Diagnóstico de Hardware</h1>
+  <div class="meta">{{hostname}} · {{os}} · {{platform}} · Capturado em {{captured_at}}</div>
+  <div class="score">{{overall_score}}/10</div>
+  <div class="gauge"><div class="gauge-fill {{category_class}}" style="width: {{gauge_percent}}%"></div></div>
+  <span class="badge {{category_class}}">{{category}}</span>
+{{trend}}  <h2>📊 Pontuações por componente</h2>
+  <table>
+{{score_rows}}  </table>
+  <h2>🔧 Hardware</h2>
+  <table>
+{{hardware_rows}}  </table>
+  <h2>💡 Recomendações</h2>
+  <ul>
+{{recommendations}}  </ul>
+</div>
+</body>
+</html>
+"#;
+
+/// Fotografia consistente do sistema e da sua pontuação
+/// 
+/// Também disponível como [`SystemSnapshot`], via [`collect_snapshot`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DiagnosticReport {
+    /// Informações da CPU
+    pub cpu: CpuInfo,
+    /// Informações de memória RAM e SWAP
+    pub ram: RamInfo,
+    /// Pentes de memória instalados (vazio sem a feature `memory`)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub memory: MemoryLayout,
+    /// Informações dos discos
+    pub disks: Vec<DiskInfo>,
+    /// Pontuação calculada a partir dos mesmos dados
+    pub score: PerformanceScore,
+    /// Informações do sistema operacional
+    pub os: OsInfo,
+    /// Instante da captura
+    pub captured_at: SystemTime,
+    /// Processos com maior uso de CPU
+    /// 
+    /// Preenchido quando a categoria é Manutenção ou Descarte, ou por
+    /// [`DiagnosticReport::include_processes`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub top_cpu_processes: Vec<ProcessInfo>,
+    /// Processos com maior uso de memória
+    /// 
+    /// Preenchido quando a categoria é Manutenção ou Descarte, ou por
+    /// [`DiagnosticReport::include_processes`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub top_memory_processes: Vec<ProcessInfo>,
+    /// Tendência da pontuação nas execuções anteriores
+    /// 
+    /// Preenchida por [`DiagnosticReport::include_trend`] quando há histórico;
+    /// exibida no texto e no HTML.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub trend: Option<history::TrendFit>,
+    /// Limites de categoria usados na pontuação, seguidos pela legenda do texto
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub categories: CategoryBoundaries,
+    /// Filtro de discos usado na pontuação, aplicado também pelos alertas
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub disk_filter: DiskFilter,
+    /// Firmware da placa-mãe, consultado uma vez na captura; `None` se indisponível
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub bios: Option<bios::BiosInfo>,
+}
+
+/// Nome alternativo de [`DiagnosticReport`] para quem só precisa dos dados coletados
+pub type SystemSnapshot = DiagnosticReport;
+
+/// Envelope versionado de toda saída JSON
+/// 
+/// Os dados ficam em `data`; os demais campos permitem que um consumidor
+/// identifique o formato antes de interpretá-los.
+/// 
+/// # Exemplo
+/// ```
+/// use hardware_diagnostic::engine::report::{ReportEnvelope, REPORT_SCHEMA_VERSION};
+/// 
+/// let envelope = ReportEnvelope::new(42);
+/// assert_eq!(envelope.schema_version, REPORT_SCHEMA_VERSION);
+/// assert_eq!(envelope.crate_version, hardware_diagnostic::VERSION);
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReportEnvelope<T> {
+    /// Versão do formato ([`REPORT_SCHEMA_VERSION`])
+    pub schema_version: u32,
+    /// Instante da geração, em segundos desde a época Unix
+    pub generated_at: u64,
+    /// Versão da crate que gerou a saída ([`crate::VERSION`])
+    pub crate_version: String,
+    /// Conteúdo: relatório, componente ou pontuação
+    pub data: T,
+}
+
+impl<T> ReportEnvelope<T> {
+    /// Envolve `data` com a versão atual do formato e o instante atual
+    pub fn new(data: T) -> Self {
+        ReportEnvelope {
+            schema_version: REPORT_SCHEMA_VERSION,
+            generated_at: SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs(),
+            crate_version: crate::VERSION.to_string(),
+            data,
+        }
+    }
+}
+
+/// Formatos de saída do relatório, gerados por [`render`]
+/// 
+/// O JSON exige a feature `serde`; sem ela, [`render`] retorna erro.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Texto sem cores ANSI ([`DiagnosticReport::to_text`])
+    Text,
+    /// JSON dentro de um [`ReportEnvelope`] (`DiagnosticReport::to_json`)
+    Json,
+    /// Cabeçalho e linha de dados ([`DiagnosticReport::to_csv`])
+    Csv,
+    /// Documento autocontido ([`DiagnosticReport::to_html`])
+    Html,
+    /// Relatório para issues e wikis ([`DiagnosticReport::to_markdown`])
+    Markdown,
+    /// Formato de texto do Prometheus ([`utils::to_prometheus`])
+    Prometheus,
+}
+
+impl OutputFormat {
+    /// Interpreta o nome de um formato ("text", "json", "csv", "html",
+    /// "markdown" ou "prometheus")
+    /// 
+    /// A comparação ignora maiúsculas; "txt", "md" e "prom" também são aceitos.
+    /// 
+    /// # Exemplo
+    /// ```
+    /// use hardware_diagnostic::OutputFormat;
+    /// 
+    /// assert_eq!(OutputFormat::from_name("MD"), Some(OutputFormat::Markdown));
+    /// assert_eq!(OutputFormat::from_name("xml"), None);
+    /// ```
+    pub fn from_name(name: &str) -> Option<OutputFormat> {
+        match name.to_ascii_lowercase().as_str() {
+            "text" | "txt" => Some(OutputFormat::Text),
+            "json" => Some(OutputFormat::Json),
+            "csv" => Some(OutputFormat::Csv),
+            "html" => Some(OutputFormat::Html),
+            "markdown" | "md" => Some(OutputFormat::Markdown),
+            "prometheus" | "prom" => Some(OutputFormat::Prometheus),
+            _ => None,
+        }
+    }
+    
+    /// Extensão de arquivo do formato, sem o ponto
+    pub fn extension(&self) -> &'static str {
+        match self {
+            OutputFormat::Text => "txt",
+            OutputFormat::Json => "json",
+            OutputFormat::Csv => "csv",
+            OutputFormat::Html => "html",
+            OutputFormat::Markdown => "md",
+            OutputFormat::Prometheus => "prom",
+        }
+    }
+}
+
+/// Gera o relatório no formato indicado
+/// 
+/// Ponto único de formatação: a CLI usa esta função para `--format`, para
+/// os atalhos `--json`, `--csv`, `--html`, `--markdown` e `--prometheus` e
+/// para gravar o arquivo de `--save`.
+/// 
+/// # Erros
+/// - `DiagnosticError::InvalidConfig` se o formato for JSON e a crate tiver
+///   sido compilada sem a feature `serde`
+/// 
+/// # Exemplo
+/// ```
+/// use hardware_diagnostic::{collect_snapshot, render, OutputFormat};
+/// 
+/// let snapshot = collect_snapshot();
+/// assert!(render(OutputFormat::Csv, &snapshot).unwrap().starts_with("timestamp,"));
+/// assert!(render(OutputFormat::Html, &snapshot).unwrap().starts_with("<!DOCTYPE html>"));
+/// ```
+pub fn render(format: OutputFormat, snapshot: &SystemSnapshot) -> Result<String, DiagnosticError> {
+    let output = match format {
+        OutputFormat::Text => snapshot.to_text(),
+        #[cfg(feature = "serde")]
+        OutputFormat::Json => snapshot.to_json(),
+        #[cfg(not(feature = "serde"))]
+        OutputFormat::Json => {
+            return Err(DiagnosticError::InvalidConfig(
+                "o formato json exige a feature `serde` (recompile com `--features serde`)".to_string(),
+            ));
+        }
+        OutputFormat::Csv => snapshot.to_csv(),
+        OutputFormat::Html => snapshot.to_html(),
+        OutputFormat::Markdown => snapshot.to_markdown(),
+        OutputFormat::Prometheus => {
+            utils::to_prometheus(&snapshot.score, &snapshot.cpu, &snapshot.ram, &snapshot.disks)
+        }
+    };
+    
+    Ok(output)
+}
+
+/// Coleta CPU, RAM, discos e pontuação uma única vez
+/// 
+/// Equivalente a [`DiagnosticReport::capture`]: a amostragem da CPU e dos
+/// discos acontece uma só vez, e a pontuação é derivada dos mesmos dados.
+/// 
+/// # Exemplo
+/// ```
+/// use hardware_diagnostic::engine::collect_snapshot;
+/// use hardware_diagnostic::engine::utils::format_complete_report;
+/// 
+/// let snapshot = collect_snapshot();
+/// println!("{}", format_complete_report(&snapshot));
+/// println!("Pontuação: {}", snapshot.score);
+/// ```
+pub fn collect_snapshot() -> SystemSnapshot {
+    DiagnosticReport::capture()
+}
+
+impl DiagnosticReport {
+    /// Captura o estado atual do sistema com uma única atualização
+    /// 
+    /// # Exemplo
+    /// ```
+    /// use hardware_diagnostic::DiagnosticReport;
+    /// 
+    /// let report = DiagnosticReport::capture();
+    /// println!("{}", report.to_text());
+    /// ```
+    pub fn capture() -> DiagnosticReport {
+        Self::capture_with_config(&ScoringConfig::default())
+    }
+    
+    /// Captura o estado atual do sistema, pontuando com a configuração indicada
+    /// 
+    /// Quando a categoria é Manutenção ou Descarte, também amostra os
+    /// processos e lista os que mais consomem CPU e memória. Com o uso da
+    /// CPU acima de 80%, [`SystemContext::refresh_all`] já os amostrou antes
+    /// da pontuação, para que a recomendação cite os maiores consumidores.
+    /// 
+    /// A configuração não é validada aqui; use [`ScoringConfig::validate`] antes.
+    pub fn capture_with_config(config: &ScoringConfig) -> DiagnosticReport {
+        let mut ctx = SystemContext::new();
+        ctx.refresh_all();
+        let mut report = Self::from_context(&ctx, config);
+        
+        if processes::lists_processes(&report.score.category) && report.top_cpu_processes.is_empty() {
+            ctx.refresh_processes();
+            (report.top_cpu_processes, report.top_memory_processes) = processes::report_top(ctx.processes_from());
+        }
+        report
+    }
+    
+    /// Monta o relatório a partir de um contexto já atualizado
+    /// 
+    /// Permite reaproveitar o mesmo [`SystemContext`] em coletas repetidas
+    /// (ex: monitoramento contínuo); chame [`SystemContext::refresh_all`]
+    /// antes de cada relatório. Os processos só são listados se o contexto
+    /// também passou por [`SystemContext::refresh_processes`]. A
+    /// configuração não é validada aqui.
+    pub fn from_context(ctx: &SystemContext, config: &ScoringConfig) -> DiagnosticReport {
+        let score = ctx.performance_score_with_config(config);
+        let (top_cpu_processes, top_memory_processes) = if processes::lists_processes(&score.category) {
+            processes::report_top(ctx.processes_from())
+        } else {
+            Default::default()
+        };
+        
+        DiagnosticReport {
+            cpu: ctx.cpu_info_from(),
+            ram: ctx.ram_info_from(),
+            memory: memory::memory_layout(),
+            disks: ctx.disk_info_from(),
+            score,
+            os: os_info(),
+            captured_at: SystemTime::now(),
+            top_cpu_processes,
+            top_memory_processes,
+            trend: None,
+            categories: config.categories,
+            disk_filter: config.disk_filter,
+            bios: ctx.bios_info_from(),
+        }
+    }
+    
+    /// Lista os processos que mais consomem CPU e memória, qualquer que seja a categoria
+    /// 
+    /// Amostra os processos (aguardando o intervalo padrão) apenas se a
+    /// captura ainda não os listou.
+    pub fn include_processes(&mut self) {
+        if self.top_cpu_processes.is_empty() && self.top_memory_processes.is_empty() {
+            let mut ctx = SystemContext::new();
+            ctx.refresh_processes();
+            (self.top_cpu_processes, self.top_memory_processes) = processes::report_top(ctx.processes_from());
+        }
+    }
+    
+    /// Calcula a tendência da pontuação a partir de um histórico
+    /// 
+    /// Recebe as execuções anteriores (ex: as últimas 30 de
+    /// `utils::load_snapshots`); com histórico insuficiente, não há tendência.
+    pub fn include_trend(&mut self, snapshots: &[TimestampedScore]) {
+        self.trend = utils::fit_score_trend(snapshots);
+    }
+    
+    /// Formata o relatório como texto, sem cores ANSI
+    pub fn to_text(&self) -> String {
+        let mut text = format!("Capturado em: {}\n\n", utils::format_iso8601(self.captured_at));
+        text.push_str(&utils::format_hardware_sections(&self.os, &self.cpu, &self.ram, &self.memory, &self.disks));
+        text.push_str(&display_performance_score_with_boundaries(&self.score, false, Language::default(), &self.categories));
+        text.push_str(&utils::format_trend(self.trend.as_ref()));
+        text.push_str(&utils::format_top_processes(&self.top_cpu_processes, &self.top_memory_processes));
+        text
+    }
+    
+    /// Retorna a linha de cabeçalho do CSV gerado por [`DiagnosticReport::to_csv`]
+    /// 
+    /// O mesmo de [`utils::csv_header`].
+    pub fn csv_header() -> String {
+        utils::csv_header()
+    }
+    
+    /// Gera a linha CSV do relatório, sem cabeçalho
+    /// 
+    /// Mesmas colunas de [`utils::report_to_csv`], com o instante da captura
+    /// e o hostname do relatório. Entre vários discos, apenas o de maior
+    /// capacidade (o "principal") tem colunas próprias; os demais entram nos
+    /// totais. Tamanhos em GiB.
+    pub fn to_csv_row(&self) -> String {
+        utils::csv_row(self.captured_at, &self.os.hostname, &self.score, &self.cpu, &self.ram, &self.disks)
+    }
+    
+    /// Gera o CSV do relatório: linha de cabeçalho seguida da linha de dados
+    /// 
+    /// Para acumular execuções em um arquivo de histórico, use
+    /// [`utils::append_csv_row`].
+    /// 
+    /// # Exemplo
+    /// ```
+    /// use hardware_diagnostic::DiagnosticReport;
+    /// 
+    /// let csv = DiagnosticReport::capture().to_csv();
+    /// assert!(csv.starts_with("timestamp,cpu_name,"));
+    /// ```
+    pub fn to_csv(&self) -> String {
+        format!("{}\n{}", Self::csv_header(), self.to_csv_row())
+    }
+    
+    /// Gera um documento HTML autocontido, com CSS embutido
+    /// 
+    /// Inclui a pontuação geral como barra, a categoria com cor, as
+    /// pontuações por componente (e por disco), um resumo do hardware e as
+    /// recomendações. Pensado para anexar a e-mails e chamados.
+    /// 
+    /// # Exemplo
+    /// ```
+    /// use hardware_diagnostic::DiagnosticReport;
+    /// 
+    /// let html = DiagnosticReport::capture().to_html();
+    /// assert!(html.starts_with("<!DOCTYPE html>"));
+    /// ```
+    pub fn to_html(&self) -> String {
+        let score = &self.score;
+        
+        let mut score_rows = Vec::new();
+        let components = [
+            ("CPU", score.cpu_score),
+            ("RAM", score.ram_score),
+            ("Discos", score.disk_score),
+        ];
+        for (label, component_score) in components {
+            if let Some(component_score) = component_score {
+                score_rows.push((label.to_string(), component_score));
+            }
+        }
+        for (name, disk_score) in &score.per_disk_scores {
+            score_rows.push((format!("&nbsp;&nbsp;↳ {}", html_escape(name)), *disk_score));
+        }
+        for (label, component_score) in [("GPU", score.gpu_score), ("Bateria", score.battery_score)] {
+            if let Some(component_score) = component_score {
+                score_rows.push((label.to_string(), component_score));
+            }
+        }
+        // Cada componente tem a própria barra, colorida pela categoria da sua
+        // pontuação nos mesmos limites da legenda
+        let score_rows: String = score_rows
+            .iter()
+            .map(|(label, value)| format!(
+                "    <tr><td>{}</td><td class=\"bar\"><div class=\"gauge small\"><div class=\"gauge-fill {}\" style=\"width: {:.0}%\"></div></div></td><td class=\"value\">{:.1}/10</td></tr>\n",
+                label,
+                html_category_class(&self.categories.category_for(*value)),
+                (value * 10.0).clamp(0.0, 100.0),
+                value
+            ))
+            .collect();
+        
+        let mut hardware = vec![
+            ("CPU".to_string(), format!("{} ({} núcleos lógicos)", self.cpu.name, self.cpu.number_cpus)),
+            ("Frequência".to_string(), utils::format_frequency_auto(self.cpu.frequency)),
+            ("RAM".to_string(), format!(
+                "{} ({:.1}% em uso)",
+                utils::bytes_to_human_readable(self.ram.total_ram),
+                self.ram.ram_usage_percent
+            )),
+        ];
+        for disk in &self.disks {
+            hardware.push((format!("Disco {}", disk.name), format!(
+                "{} {} ({:.1}% em uso)",
+                utils::bytes_to_human_readable(disk.total_space),
+                disk.kind,
+                disk.usage_percent
+            )));
+        }
+        let hardware_rows: String = hardware
+            .iter()
+            .map(|(label, value)| format!(
+                "    <tr><td>{}</td><td class=\"value\">{}</td></tr>\n",
+                html_escape(label),
+                html_escape(value)
+            ))
+            .collect();
+        
+        let recommendations: String = score
+            .recommendations
+            .iter()
+            .map(|rec| format!("    <li>{}</li>\n", html_escape(rec)))
+            .collect();
+        let trend = self
+            .trend
+            .map(|fit| format!("  <p class=\"trend\">{}</p>\n", html_escape(&fit.summary(Language::default()))))
+            .unwrap_or_default();
+        
+        render_template(HTML_TEMPLATE, |marker| {
+            Some(match marker {
+                "hostname" => html_escape(&self.os.hostname),
+                "os" => html_escape(&format!("{} {}", self.os.os_name, self.os.os_version)),
+                "platform" => html_escape(&self.os.platform),
+                "captured_at" => utils::format_iso8601(self.captured_at),
+                "overall_score" => format!("{:.1}", score.overall_score),
+                "gauge_percent" => format!("{:.0}", (score.overall_score * 10.0).clamp(0.0, 100.0)),
+                "category_class" => html_category_class(&score.category).to_string(),
+                "category" => html_escape(score.category.description()),
+                "score_rows" => score_rows.clone(),
+                "hardware_rows" => hardware_rows.clone(),
+                "recommendations" => recommendations.clone(),
+                "trend" => trend.clone(),
+                _ => return None,
+            })
+        })
+    }
+    
+    /// Gera um documento Markdown com tabelas por componente
+    /// 
+    /// Adequado para issues do GitHub, Confluence ou Notion: seções `##`,
+    /// tabelas, recomendações em lista e nenhuma cor ANSI. A saída depende
+    /// apenas dos dados do relatório (inclusive `captured_at`), portanto é
+    /// determinística para o mesmo relatório.
+    /// 
+    /// # Exemplo
+    /// ```
+    /// use hardware_diagnostic::DiagnosticReport;
+    /// 
+    /// let markdown = DiagnosticReport::capture().to_markdown();
+    /// assert!(markdown.starts_with("# Hardware Diagnostic Report"));
+    /// ```
+    pub fn to_markdown(&self) -> String {
+        let mut md = String::from("# Hardware Diagnostic Report\n\n");
+        md.push_str(&format!("- **Máquina:** {}\n", self.os.hostname));
+        md.push_str(&format!("- **Sistema:** {} {}\n", self.os.os_name, self.os.os_version));
+        md.push_str(&format!("- **Plataforma:** {}\n", self.os.platform));
+        if let Some(kind) = self.os.virtualization {
+            md.push_str(&format!("- **Virtualização:** {}\n", kind));
+        }
+        md.push_str(&format!("- **Capturado em:** {}\n\n", utils::format_iso8601(self.captured_at)));
+        
+        // CPU
+        md.push_str("## CPU\n\n| Campo | Valor |\n|---|---|\n");
+        md.push_str(&format!("| Modelo | {} |\n", markdown_cell(&self.cpu.name)));
+        md.push_str(&format!("| Núcleos lógicos | {} |\n", self.cpu.number_cpus));
+        md.push_str(&format!(
+            "| Núcleos físicos | {} |\n",
+            self.cpu.physical_cores.map(|c| c.to_string()).unwrap_or_else(|| "-".to_string())
+        ));
+        md.push_str(&format!("| Frequência | {} |\n", utils::format_frequency_auto(self.cpu.frequency)));
+        md.push_str(&format!("| Uso | {:.1}% |\n\n", self.cpu.cpu_usage));
+        
+        // Memória
+        md.push_str("## Memory\n\n| Campo | Valor |\n|---|---|\n");
+        md.push_str(&format!("| Total | {} |\n", utils::bytes_to_human_readable(self.ram.total_ram)));
+        md.push_str(&format!(
+            "| Em uso | {} ({:.1}%) |\n",
+            utils::bytes_to_human_readable(self.ram.used_ram),
+            self.ram.ram_usage_percent
+        ));
+        md.push_str(&format!(
+            "| SWAP | {} / {} ({:.1}%) |\n\n",
+            utils::bytes_to_human_readable(self.ram.used_swap),
+            utils::bytes_to_human_readable(self.ram.total_swap),
+            self.ram.swap_usage_percent
+        ));
+        
+        // Discos
+        md.push_str("## Disks\n\n");
+        if self.disks.is_empty() {
+            md.push_str("_Nenhum disco detectado._\n\n");
+        } else {
+            md.push_str("| Disco | Tipo | Sistema de arquivos | Total | Livre | Uso | Pontuação |\n");
+            md.push_str("|---|---|---|---|---|---|---|\n");
+            for disk in &self.disks {
+                let disk_score = self
+                    .score
+                    .per_disk_scores
+                    .iter()
+                    .find(|(name, _)| *name == disk.name)
+                    .map(|(_, score)| format!("{:.1}/10", score))
+                    .unwrap_or_else(|| "-".to_string());
+                md.push_str(&format!(
+                    "| {} | {} | {} | {} | {} | {:.1}% | {} |\n",
+                    markdown_cell(&disk.name),
+                    markdown_cell(&disk.kind.to_string()),
+                    markdown_cell(&disk.file_system),
+                    utils::bytes_to_human_readable(disk.total_space),
+                    utils::bytes_to_human_readable(disk.available_space),
+                    disk.usage_percent,
+                    disk_score
+                ));
+            }
+            md.push('\n');
+        }
+        
+        // Pontuação
+        md.push_str("## Performance Score\n\n");
+        md.push_str(&format!(
+            "`{} {:.1}/10`\n\n",
+            utils::progress_bar(self.score.overall_score * 10.0, 20),
+            self.score.overall_score
+        ));
+        md.push_str(&format!("**Categoria:** {}\n\n", self.score.category.description()));
+        md.push_str("| Componente | Pontuação |\n|---|---|\n");
+        let components = [
+            ("CPU", self.score.cpu_score),
+            ("RAM", self.score.ram_score),
+            ("Discos", self.score.disk_score),
+            ("GPU", self.score.gpu_score),
+            ("Bateria", self.score.battery_score),
+        ];
+        for (label, component_score) in components {
+            if let Some(component_score) = component_score {
+                md.push_str(&format!("| {} | {:.1}/10 |\n", label, component_score));
+            }
+        }
+        md.push('\n');
+        
+        // Recomendações
+        md.push_str("## Recommendations\n\n");
+        if self.score.recommendations.is_empty() {
+            md.push_str("_Nenhuma recomendação._\n");
+        }
+        for rec in &self.score.recommendations {
+            md.push_str(&format!("- {}\n", rec));
+        }
+        
+        // Cores não são renderizadas em Markdown
+        utils::strip_ansi(&md)
+    }
+    
+    /// Serializa o relatório como JSON indentado, dentro de um [`ReportEnvelope`]
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> String {
+        // Todos os campos são serializáveis; a falha não é esperada
+        serde_json::to_string_pretty(&ReportEnvelope::new(self)).unwrap_or_default()
+    }
+    
+    /// Carrega um relatório gravado em JSON por [`DiagnosticReport::to_json`]
+    /// 
+    /// Arquivos da versão 1 do formato, sem envelope, também são aceitos.
+    /// 
+    /// # Erros
+    /// - `DiagnosticError::Io` se o arquivo não puder ser lido
+    /// - `DiagnosticError::InvalidConfig` se o conteúdo não for um relatório
+    ///   válido ou vier de uma versão do formato mais nova que [`REPORT_SCHEMA_VERSION`]
+    #[cfg(feature = "serde")]
+    pub fn load_json(path: &Path) -> Result<DiagnosticReport, DiagnosticError> {
+        let contents = fs::read_to_string(path).map_err(|e| DiagnosticError::Io {
+            path: path.display().to_string(),
+            source: e.to_string(),
+        })?;
+        let invalid = |e: serde_json::Error| DiagnosticError::InvalidConfig(e.to_string());
+        let mut value: serde_json::Value = serde_json::from_str(&contents).map_err(invalid)?;
+        
+        // Na versão 1 o relatório ficava na raiz, com `schema_version: "1"`
+        let Some(version) = value["schema_version"].as_u64() else {
+            return serde_json::from_value(value).map_err(invalid);
+        };
+        if version > u64::from(REPORT_SCHEMA_VERSION) {
+            return Err(DiagnosticError::InvalidConfig(format!(
+                "versão do formato {} não suportada (máxima: {})",
+                version, REPORT_SCHEMA_VERSION
+            )));
+        }
+        serde_json::from_value(value["data"].take()).map_err(invalid)
+    }
+    
+    /// Grava o relatório em texto no caminho indicado
+    /// 
+    /// # Erros
+    /// Retorna `DiagnosticError::Io` se o arquivo não puder ser gravado.
+    pub fn save(&self, path: &Path) -> Result<(), DiagnosticError> {
+        fs::write(path, self.to_text()).map_err(|e| DiagnosticError::Io {
+            path: path.display().to_string(),
+            source: e.to_string(),
+        })
+    }
+    
+    /// Compara este relatório (antes) com um mais recente (depois)
+    /// 
+    /// Componentes não avaliados em algum dos relatórios ficam sem variação
+    /// (`None`), em vez de aparecer como uma queda ou alta até 0.0.
+    /// 
+    /// # Exemplo
+    /// ```no_run
+    /// use hardware_diagnostic::DiagnosticReport;
+    /// 
+    /// let before = DiagnosticReport::capture();
+    /// // ... limpeza das ventoinhas, mais RAM ...
+    /// let after = DiagnosticReport::capture();
+    /// println!("{}", before.diff(&after).to_text());
+    /// ```
+    pub fn diff(&self, other: &DiagnosticReport) -> SnapshotDiff {
+        let delta = |before: Option<f64>, after: Option<f64>| Some(after? - before?);
+        
+        SnapshotDiff {
+            score_delta: other.score.overall_score - self.score.overall_score,
+            cpu_score_delta: delta(self.score.cpu_score, other.score.cpu_score),
+            ram_score_delta: delta(self.score.ram_score, other.score.ram_score),
+            disk_score_delta: delta(self.score.disk_score, other.score.disk_score),
+            category_changed: self.score.category != other.score.category,
+            before: self.clone(),
+            after: other.clone(),
+        }
+    }
+}
+
+/// Comparação entre dois relatórios, gerada por [`DiagnosticReport::diff`]
+/// 
+/// As variações são `depois - antes`: positivas quando a máquina melhorou.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SnapshotDiff {
+    /// Relatório anterior (ex: antes da manutenção)
+    pub before: DiagnosticReport,
+    /// Relatório mais recente
+    pub after: DiagnosticReport,
+    /// Variação da pontuação geral
+    pub score_delta: f64,
+    /// Variação da pontuação da CPU (`None` se não avaliada em algum dos relatórios)
+    pub cpu_score_delta: Option<f64>,
+    /// Variação da pontuação da RAM (`None` se não avaliada em algum dos relatórios)
+    pub ram_score_delta: Option<f64>,
+    /// Variação da pontuação dos discos (`None` se não avaliada em algum dos relatórios)
+    pub disk_score_delta: Option<f64>,
+    /// Indica se a categoria de desempenho mudou
+    pub category_changed: bool,
+}
+
+impl SnapshotDiff {
+    /// Formata a comparação como texto, sem cores ANSI
+    pub fn to_text(&self) -> String {
+        self.to_text_colored(false)
+    }
+    
+    /// Formata a comparação como texto, com melhorias em verde e pioras em
+    /// vermelho quando `use_color` é `true`
+    pub fn to_text_colored(&self, use_color: bool) -> String {
+        let fmt = utils::bytes_to_human_readable;
+        let mut text = format!(
+            "Antes:  {}\nDepois: {}\n\n",
+            utils::format_iso8601(self.before.captured_at),
+            utils::format_iso8601(self.after.captured_at)
+        );
+        text.push_str(&display_diff_colored(&self.after.score.compare(&self.before.score), use_color));
+        
+        // Mudanças de hardware que explicam a variação (ex: RAM adicionada, disco limpo)
+        text.push_str("\nHARDWARE:\n");
+        text.push_str(&format!("  • RAM total:  {} → {}\n", fmt(self.before.ram.total_ram), fmt(self.after.ram.total_ram)));
+        text.push_str(&format!("  • RAM em uso: {:.1}% → {:.1}%\n",
+            self.before.ram.ram_usage_percent, self.after.ram.ram_usage_percent));
+        for after in &self.after.disks {
+            if let Some(before) = self.before.disks.iter().find(|d| d.mount_point == after.mount_point) {
+                text.push_str(&format!("  • Livre em {}: {} → {}\n",
+                    after.mount_point, fmt(before.available_space), fmt(after.available_space)));
+            }
+        }
+        
+        utils::render_text(&text)
+    }
+    
+    /// Serializa a comparação, incluindo os dois relatórios, como JSON
+    /// indentado, dentro de um [`ReportEnvelope`]
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> String {
+        // Todos os campos são serializáveis; a falha não é esperada
+        serde_json::to_string_pretty(&ReportEnvelope::new(self)).unwrap_or_default()
+    }
+}
+
+/// Escapa barras verticais, que encerrariam a célula de uma tabela Markdown
+fn markdown_cell(text: &str) -> String {
+    text.replace('|', "\\|")
+}
+
+/// Classe CSS da cor de cada categoria no relatório HTML
+fn html_category_class(category: &PerformanceCategory) -> &'static str {
+    match category {
+        PerformanceCategory::Descarte => "descarte",
+        PerformanceCategory::Manutencao => "manutencao",
+        PerformanceCategory::Precaução => "precaucao",
+        PerformanceCategory::BomEstado => "bom-estado",
+    }
+}
+
+/// Preenche os marcadores `{{nome}}` do modelo em uma única passagem
+/// 
+/// Os valores inseridos não são examinados de novo, então um nome de máquina
+/// ou uma recomendação contendo `{{...}}` aparece literalmente. Marcadores
+/// para os quais `value` devolve `None` são mantidos como estão.
+fn render_template<F>(template: &str, mut value: F) -> String
+where
+    F: FnMut(&str) -> Option<String>,
+{
+    let mut output = String::with_capacity(template.len() * 2);
+    let mut rest = template;
+    
+    while let Some(start) = rest.find("{{") {
+        output.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        match after.find("}}") {
+            Some(end) => {
+                let marker = &after[..end];
+                match value(marker) {
+                    Some(text) => output.push_str(&text),
+                    None => {
+                        output.push_str("{{");
+                        output.push_str(marker);
+                        output.push_str("}}");
+                    }
+                }
+                rest = &after[end + 2..];
+            }
+            None => {
+                output.push_str(&rest[start..]);
+                rest = "";
+            }
+        }
+    }
+    output.push_str(rest);
+    
+    output
+}
+
+/// Escapa os caracteres especiais de HTML
+fn html_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    #[test]
+    fn test_capture_is_consistent() {
+        let report = DiagnosticReport::capture();
+        
+        // A pontuação deve refletir exatamente a CPU capturada
+        let expected = calculate_cpu_score(&report.cpu, &DiagnosticConfig::default());
+        assert_eq!(report.score.cpu_score, Some(expected));
+        
+        let text = report.to_text();
+        assert!(text.contains("=== INFORMACOES DA CPU ==="));
+        assert!(!text.contains('\x1b'));
+        
+        let path = std::env::temp_dir().join("hardware_diagnostic_report_test.txt");
+        report.save(&path).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), text);
+        fs::remove_file(&path).ok();
+        
+        let invalid = Path::new("/diretorio/inexistente/relatorio.txt");
+        assert!(matches!(report.save(invalid), Err(DiagnosticError::Io { .. })));
+        
+        // O relatório completo usa apenas os dados da fotografia recebida
+        let mut sample = sample_report();
+        let complete = utils::format_complete_report(&sample);
+        assert!(complete.contains("Modelo: Intel Core i7 | 8ª geração"));
+        assert!(complete.contains("PONTUAÇÃO GERAL: 8.3/10.0"));
+        assert!(!complete.contains("BIOS/FIRMWARE"));
+        
+        // Inclusive o BIOS, sem nova consulta ao firmware
+        sample.bios = Some(bios::BiosInfo {
+            manufacturer: "Fabricante de teste".to_string(),
+            version: "F.42".to_string(),
+            release_date: "2023-05-10".to_string(),
+            secure_boot_enabled: Some(false),
+        });
+        let complete = utils::format_complete_report(&sample);
+        assert!(complete.contains("Fabricante: Fabricante de teste\nVersão: F.42\nData: 2023-05-10\nSecure Boot: Desativado"));
+    }
+    
+    #[test]
+    fn test_csv_export() {
+        let mut report = DiagnosticReport::capture();
+        let system_disk = DiskInfo {
+            name: "C:".to_string(),
+            mount_point: "C:\\".to_string(),
+            total_space: 500_000_000_000,
+            available_space: 400_000_000_000,
+            used_space: 100_000_000_000,
+            usage_percent: 20.0,
+            file_system: "NTFS".to_string(),
+            kind: DiskKind::Ssd,
+            read_bytes_per_sec: 0,
+            write_bytes_per_sec: 0,
+            read_iops: 0,
+            write_iops: 0,
+            io_utilization_percent: 0.0,
+            health: None,
+            removable: false,
+            read_only: false,
+        };
+        let data_disk = DiskInfo {
+            name: "D:, dados".to_string(),
+            mount_point: "D:\\".to_string(),
+            total_space: 2_000_000_000_000,
+            available_space: 100_000_000_000,
+            used_space: 1_900_000_000_000,
+            usage_percent: 95.0,
+            kind: DiskKind::Hdd,
+            ..system_disk.clone()
+        };
+        report.disks = vec![system_disk, data_disk];
+        
+        let csv = report.to_csv();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with(
+            "timestamp,cpu_name,cpu_cores,cpu_usage_pct,cpu_freq_mhz,ram_total_gb,ram_used_gb,\
+             ram_usage_pct,primary_disk_name,primary_disk_total_gb,primary_disk_usage_pct,overall_score,category,"
+        ));
+        assert_eq!(lines[0], utils::csv_header());
+        assert_eq!(lines[1], report.to_csv_row());
+        
+        // Apenas o maior disco tem colunas próprias, com o nome escapado; ambos entram nos totais
+        assert!(lines[1].contains(",\"D:, dados\",1862.65,95.0,"), "{}", lines[1]);
+        assert!(!lines[1].contains("C:"));
+        assert!(lines[1].contains(",2,2328.31,465.66,"), "{}", lines[1]);
+        assert!(lines[1].ends_with(&format!(",{}", report.os.hostname)));
+        
+        // Sem discos, os campos do disco principal ficam vazios
+        report.disks.clear();
+        assert!(report.to_csv_row().contains(",,,,"));
+        
+        // Histórico: cabeçalho apenas na primeira gravação
+        let path = std::env::temp_dir().join(format!("hardware_diagnostic_history_{}.csv", std::process::id()));
+        fs::remove_file(&path).ok();
+        utils::append_csv_row(&path, &report).unwrap();
+        utils::append_csv_row(&path, &report).unwrap();
+        let history = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).ok();
+        assert_eq!(history.lines().count(), 3);
+        assert_eq!(history.lines().next(), Some(DiagnosticReport::csv_header().as_str()));
+        
+        let invalid = Path::new("/diretorio/inexistente/historico.csv");
+        assert!(matches!(utils::append_csv_row(invalid, &report), Err(DiagnosticError::Io { .. })));
+    }
+    
+    /// Relatório fixo (8 núcleos, 16 GiB de RAM e um SSD em `C:\`) para os
+    /// testes do engine que não devem depender da máquina
+    pub(crate) fn sample_report() -> DiagnosticReport {
+        let disk = DiskInfo {
+            name: "C:".to_string(),
+            mount_point: "C:\\".to_string(),
+            total_space: 512 * 1024 * 1024 * 1024,
+            available_space: 128 * 1024 * 1024 * 1024,
+            used_space: 384 * 1024 * 1024 * 1024,
+            usage_percent: 75.0,
+            file_system: "NTFS".to_string(),
+            kind: DiskKind::Ssd,
+            read_bytes_per_sec: 0,
+            write_bytes_per_sec: 0,
+            read_iops: 0,
+            write_iops: 0,
+            io_utilization_percent: 0.0,
+            health: None,
+            removable: false,
+            read_only: false,
+        };
+        
+        DiagnosticReport {
+            cpu: CpuInfo {
+                number_cpus: 8,
+                cpu_usage: 12.5,
+                frequency: 3600,
+                name: "Intel Core i7 | 8ª geração".to_string(),
+                physical_cores: Some(4),
+                per_core_usage: vec![12.5; 8],
+                per_core_frequency: vec![3600; 8],
+                thermal_throttling_detected: false,
+                throttling: None,
+                vulnerabilities: None,
+            },
+            ram: RamInfo {
+                total_ram: 16 * 1024 * 1024 * 1024,
+                used_ram: 8 * 1024 * 1024 * 1024,
+                free_ram: 8 * 1024 * 1024 * 1024,
+                total_swap: 2 * 1024 * 1024 * 1024,
+                used_swap: 0,
+                ram_usage_percent: 50.0,
+                swap_usage_percent: 0.0,
+                memory_type: None,
+                speed_mhz: None,
+                slots_used: None,
+                slots_total: None,
+            },
+            memory: MemoryLayout::default(),
+            disks: vec![disk],
+            score: PerformanceScore {
+                overall_score: 8.3,
+                cpu_score: Some(9.0),
+                ram_score: Some(8.0),
+                disk_score: Some(7.5),
+                per_disk_scores: vec![("C:".to_string(), 7.5)],
+                gpu_score: None,
+                battery_score: None,
+                category: PerformanceCategory::BomEstado,
+                recommendations: vec!["✅ BOM ESTADO: A máquina está adequada para uso normal".to_string()],
+            },
+            os: OsInfo {
+                os_name: "Windows".to_string(),
+                os_version: "11".to_string(),
+                kernel_version: "26100".to_string(),
+                hostname: "PC-01".to_string(),
+                uptime_seconds: 3_600,
+                boot_time: 1_700_000_000,
+                platform: "windows".to_string(),
+                virtualization: None,
+            },
+            captured_at: std::time::UNIX_EPOCH + Duration::from_secs(1_700_003_600),
+            top_cpu_processes: Vec::new(),
+            top_memory_processes: Vec::new(),
+            trend: None,
+            categories: CategoryBoundaries::default(),
+            disk_filter: DiskFilter::default(),
+            bios: None,
+        }
+    }
+    
+    #[test]
+    fn test_markdown_export() {
+        let report = sample_report();
+        let expected = r#"# Hardware Diagnostic Report
+
+- **Máquina:** PC-01
+- **Sistema:** Windows 11
+- **Plataforma:** windows
+- **Capturado em:** 2023-11-14T23:13:20Z
+
+## CPU
+
+| Campo | Valor |
+|---|---|
+| Modelo | Intel Core i7 \| 8ª geração |
+| Núcleos lógicos | 8 |
+| Núcleos físicos | 4 |
+| Frequência | 3.60 GHz |
+| Uso | 12.5% |
+
+## Memory
+
+| Campo | Valor |
+|---|---|
+| Total | 16.00 GiB |
+| Em uso | 8.00 GiB (50.0%) |
+| SWAP | 0 B / 2.00 GiB (0.0%) |
+
+## Disks
+
+| Disco | Tipo | Sistema de arquivos | Total | Livre | Uso | Pontuação |
+|---|---|---|---|---|---|---|
+| C: | SSD | NTFS | 512.00 GiB | 128.00 GiB | 75.0% | 7.5/10 |
+
+## Performance Score
+
+`[█████████████████░░░] 8.3/10`
+
+**Categoria:** BOM ESTADO - Adequado para uso normal
+
+| Componente | Pontuação |
+|---|---|
+| CPU | 9.0/10 |
+| RAM | 8.0/10 |
+| Discos | 7.5/10 |
+
+## Recommendations
+
+- ✅ BOM ESTADO: A máquina está adequada para uso normal
+"#;
+        
+        assert_eq!(report.to_markdown(), expected);
+        
+        // Sem discos, a seção continua presente
+        let mut no_disks = report.clone();
+        no_disks.disks.clear();
+        assert!(no_disks.to_markdown().contains("## Disks\n\n_Nenhum disco detectado._\n"));
+        
+        // Cores ANSI são removidas por completo
+        let mut colored = report.clone();
+        colored.score.recommendations = vec!["\x1b[31mRevise o disco\x1b[0m".to_string()];
+        let markdown = colored.to_markdown();
+        assert!(markdown.ends_with("## Recommendations\n\n- Revise o disco\n"));
+        assert!(!markdown.contains('\x1b'));
+    }
+    
+    #[test]
+    fn test_text_lists_top_processes() {
+        let mut report = sample_report();
+        assert!(!report.to_text().contains("PROCESSOS"));
+        
+        report.top_cpu_processes = vec![ProcessInfo {
+            pid: 4242,
+            name: "compilador".to_string(),
+            cpu_usage: 97.5,
+            memory_bytes: 512 * 1024 * 1024,
+            status: "Runnable".to_string(),
+        }];
+        let text = report.to_text();
+        assert!(text.contains("=== TOP PROCESSES ==="));
+        assert!(text.contains("  4242      97.5%   512.00 MiB  compilador\n"), "{}", text);
+    }
+    
+    #[test]
+    fn test_include_processes() {
+        let mut report = sample_report();
+        report.include_processes();
+        assert!(!report.top_cpu_processes.is_empty());
+        assert!(report.top_memory_processes.len() <= processes::REPORT_TOP_PROCESSES);
+        
+        // Listas já preenchidas não são amostradas de novo
+        let listed = report.top_cpu_processes.clone();
+        report.include_processes();
+        assert_eq!(report.top_cpu_processes, listed);
+        assert!(report.to_text().contains("=== TOP PROCESSES ==="));
+    }
+    
+    #[test]
+    fn test_html_export() {
+        let mut report = DiagnosticReport::capture();
+        report.os.hostname = "<pc-01>".to_string();
+        report.score.recommendations.push("🔴 DISCO \"D:\": teste & escape".to_string());
+        
+        let html = report.to_html();
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.trim_end().ends_with("</html>"));
+        assert!(!html.contains("{{"), "Todos os marcadores devem ser substituídos");
+        
+        assert!(html.contains("&lt;pc-01&gt;"));
+        assert!(!html.contains("<pc-01>"));
+        assert!(html.contains("teste &amp; escape"));
+        assert!(html.contains(report.score.category.description()));
+        
+        // Valores inseridos não são substituídos de novo
+        report.os.hostname = "{{category}}".to_string();
+        report.score.recommendations.push("{{hardware_rows}}".to_string());
+        let html = report.to_html();
+        assert!(html.contains("<title>Diagnóstico de Hardware - {{category}}</title>"));
+        assert!(html.contains("<li>{{hardware_rows}}</li>"));
+        assert!(html.contains(&format!("{:.1}/10", report.score.overall_score)));
+        
+        // Uma barra por componente avaliado: CPU, RAM, discos e o disco C:
+        let sample = sample_report().to_html();
+        assert_eq!(sample.matches("<div class=\"gauge small\">").count(), 4);
+        assert!(sample.contains(
+            "<td>CPU</td><td class=\"bar\"><div class=\"gauge small\"><div class=\"gauge-fill bom-estado\" style=\"width: 90%\"></div></div></td><td class=\"value\">9.0/10</td>"
+        ));
+    }
+    
+    #[test]
+    fn test_text_legend_uses_report_boundaries() {
+        let mut report = sample_report();
+        assert!(report.to_text().contains("7-10  → "));
+        
+        report.categories.bom_estado = 8.0;
+        let text = report.to_text();
+        assert!(text.contains("8-10  → "), "{}", text);
+        assert!(utils::format_complete_report(&report).contains("5-7.9 → "));
+        
+        // As barras do HTML seguem os mesmos limites: 7.5 passa a ser Precaução
+        report.score.ram_score = Some(7.5);
+        assert!(report.to_html().contains(
+            "<td>RAM</td><td class=\"bar\"><div class=\"gauge small\"><div class=\"gauge-fill precaucao\""
+        ));
+    }
+    
+    #[test]
+    fn test_trend_in_text_and_html() {
+        let mut report = sample_report();
+        assert!(!report.to_text().contains("pontos em"));
+        assert!(!report.to_html().contains("class=\"trend\""));
+        
+        // Histórico insuficiente: nenhuma tendência
+        let sample = sample_report();
+        let snapshot = |days: u64, overall_score: f64| TimestampedScore {
+            timestamp: sample.captured_at + Duration::from_secs(days * 86_400),
+            score: PerformanceScore { overall_score, ..sample.score.clone() },
+        };
+        report.include_trend(&[snapshot(0, 8.0)]);
+        assert_eq!(report.trend, None);
+        
+        report.include_trend(&[snapshot(0, 8.0), snapshot(15, 7.4), snapshot(30, 6.8)]);
+        let sentence = "📉 A máquina piorou 1.2 pontos em 30 dias";
+        assert!(report.to_text().contains(sentence));
+        assert!(report.to_html().contains(&format!("<p class=\"trend\">{}</p>", sentence)));
+        assert!(utils::format_complete_report(&report).contains(sentence));
+    }
+    
+    #[test]
+    fn test_render_formats() {
+        let report = sample_report();
+        assert_eq!(render(OutputFormat::Text, &report), Ok(report.to_text()));
+        assert_eq!(render(OutputFormat::Csv, &report), Ok(report.to_csv()));
+        assert_eq!(render(OutputFormat::Html, &report), Ok(report.to_html()));
+        assert_eq!(render(OutputFormat::Markdown, &report), Ok(report.to_markdown()));
+        assert!(render(OutputFormat::Prometheus, &report).unwrap().contains("hardware_performance_score 8.3"));
+        
+        assert_eq!(OutputFormat::from_name("Prometheus"), Some(OutputFormat::Prometheus));
+        assert_eq!(OutputFormat::from_name("txt"), Some(OutputFormat::Text));
+        assert_eq!(OutputFormat::from_name("pdf"), None);
+        assert_eq!(OutputFormat::from_name("json"), Some(OutputFormat::Json));
+        assert_eq!(OutputFormat::Markdown.extension(), "md");
+        assert_eq!(OutputFormat::Html.extension(), "html");
+        assert_eq!(OutputFormat::Json.extension(), "json");
+        
+        // Sem a feature `serde`, o formato existe mas não pode ser gerado
+        #[cfg(feature = "serde")]
+        assert_eq!(render(OutputFormat::Json, &report), Ok(report.to_json()));
+        #[cfg(not(feature = "serde"))]
+        assert!(matches!(render(OutputFormat::Json, &report), Err(DiagnosticError::InvalidConfig(_))));
+    }
+
+    #[test]
+    fn test_snapshot_diff() {
+        let mut before = sample_report();
+        before.ram.total_ram = 8 * 1024 * 1024 * 1024;
+        before.score.overall_score = 5.5;
+        before.score.ram_score = Some(4.0);
+        before.score.category = PerformanceCategory::Precaução;
+        before.disks[0].available_space = 16 * 1024 * 1024 * 1024;
+        let after = sample_report();
+        
+        let diff = before.diff(&after);
+        assert!((diff.score_delta - 2.8).abs() < 1e-9);
+        assert_eq!(diff.ram_score_delta, Some(4.0));
+        assert_eq!(diff.cpu_score_delta, Some(0.0));
+        assert!(diff.category_changed);
+        assert!(!after.diff(&after).category_changed);
+        
+        // Componente não avaliado de um dos lados: sem variação, nunca uma queda até 0.0
+        let mut unscored = sample_report();
+        unscored.score.disk_score = None;
+        assert_eq!(after.diff(&unscored).disk_score_delta, None);
+        assert_eq!(unscored.diff(&after).disk_score_delta, None);
+        assert!(!after.diff(&unscored).to_text().contains("Discos:"));
+        
+        let text = diff.to_text();
+        assert!(text.contains("  • Geral:    5.5 → 8.3  ▲ +2.8"));
+        assert!(text.contains("  • RAM total:  8.00 GiB → 16.00 GiB"));
+        assert!(text.contains("  • Livre em C:\\: 16.00 GiB → 128.00 GiB"));
+        assert!(!text.contains('\x1b'));
+        
+        // Melhora em verde, piora em vermelho
+        let _ansi = utils::AnsiGuard::set(true);
+        assert!(diff.to_text_colored(true).contains("\x1b[32m▲ +2.8"));
+        assert!(after.diff(&before).to_text_colored(true).contains("\x1b[31m▼ -2.8"));
+    }
+    
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_snapshot_json_round_trip() {
+        let path = std::env::temp_dir().join(format!("hardware_diagnostic_snapshot_{}.json", std::process::id()));
+        let report = sample_report();
+        let json: serde_json::Value = serde_json::from_str(&report.to_json()).unwrap();
+        assert_eq!(json["schema_version"], REPORT_SCHEMA_VERSION);
+        assert_eq!(json["crate_version"], crate::VERSION);
+        assert!(json["generated_at"].as_u64().unwrap() > 0);
+        assert!(json["data"]["score"]["overall_score"].is_number());
+        fs::write(&path, report.to_json()).unwrap();
+        let loaded = DiagnosticReport::load_json(&path);
+        
+        // Versão 1: relatório na raiz, sem envelope
+        let mut legacy = json["data"].clone();
+        legacy["schema_version"] = "1".into();
+        fs::write(&path, legacy.to_string()).unwrap();
+        let loaded_legacy = DiagnosticReport::load_json(&path);
+        
+        // Versões futuras são rejeitadas
+        let mut future = json.clone();
+        future["schema_version"] = (REPORT_SCHEMA_VERSION + 1).into();
+        fs::write(&path, future.to_string()).unwrap();
+        let loaded_future = DiagnosticReport::load_json(&path);
+        fs::remove_file(&path).ok();
+        
+        let loaded = loaded.unwrap();
+        assert_eq!(loaded.to_text(), report.to_text());
+        assert_eq!(loaded_legacy.unwrap().to_text(), report.to_text());
+        assert!(matches!(loaded_future, Err(DiagnosticError::InvalidConfig(_))));
+        
+        let json: serde_json::Value = serde_json::from_str(&report.diff(&loaded).to_json()).unwrap();
+        assert_eq!(json["schema_version"], REPORT_SCHEMA_VERSION);
+        assert_eq!(json["data"]["score_delta"], 0.0);
+        assert_eq!(json["data"]["category_changed"], false);
+    }
+}
@@ -0,0 +1,529 @@
+// hardware-diagnostic - Ferramenta de diagnóstico de hardware
+// Copyright (C) 2025  Seu Nome
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Módulo `smart` - Atributos SMART dos discos
+//! 
+//! Disponível com a feature `smart`. No Windows, os dados vêm do WMI
+//! (`MSStorageDriver_FailurePredictStatus` e `MSStorageDriver_FailurePredictData`).
+//! Nas demais plataformas, o `smartctl` (smartmontools) é usado se estiver
+//! instalado; em geral ele exige privilégios de administrador. Quando o
+//! SMART não está acessível (ex: gavetas USB) ou a feature está desligada,
+//! os discos ficam sem dados de saúde (`None`).
+
+use std::collections::HashMap;
+use std::fmt;
+
+/// Atributos SMART lidos de um disco físico
+/// 
+/// Leitura bruta da consulta; os discos expõem o resumo em [`DiskHealth`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SmartInfo {
+    /// Indica se o próprio disco prevê uma falha iminente
+    pub failure_predicted: bool,
+    /// Setores realocados (atributo 5), se informado
+    pub reallocated_sectors: Option<u64>,
+    /// Horas ligado (atributo 9), se informado
+    pub power_on_hours: Option<u64>,
+    /// Setores pendentes de realocação (atributo 197), se informado
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub pending_sectors: Option<u64>,
+    /// Erros incorrigíveis (atributo 198 ou erros de mídia do NVMe), se informado
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub uncorrectable_errors: Option<u64>,
+    /// Temperatura do disco em °C (atributo 194), se informada
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub temperature_celsius: Option<u32>,
+}
+
+/// Saúde de um disco segundo o SMART, em [`DiskInfo::health`](super::DiskInfo::health)
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DiskHealth {
+    /// Setores realocados (atributo 5), se informado
+    pub reallocated_sectors: Option<u64>,
+    /// Setores pendentes de realocação (atributo 197), se informado
+    pub pending_sectors: Option<u64>,
+    /// Erros incorrigíveis (atributo 198 ou erros de mídia do NVMe), se informado
+    pub uncorrectable_errors: Option<u64>,
+    /// Temperatura do disco em °C (atributo 194), se informada
+    pub temperature_celsius: Option<u32>,
+    /// Horas ligado (atributo 9), se informado
+    pub power_on_hours: Option<u64>,
+    /// Classificação da saúde (veja [`SmartInfo::status`])
+    pub health_status: SmartStatus,
+}
+
+impl DiskHealth {
+    /// Retorna `true` quando não há previsão de falha nem setores defeituosos
+    pub fn is_healthy(&self) -> bool {
+        self.health_status == SmartStatus::Healthy
+    }
+}
+
+impl From<SmartInfo> for DiskHealth {
+    fn from(smart: SmartInfo) -> Self {
+        DiskHealth {
+            health_status: smart.status(),
+            reallocated_sectors: smart.reallocated_sectors,
+            pending_sectors: smart.pending_sectors,
+            uncorrectable_errors: smart.uncorrectable_errors,
+            temperature_celsius: smart.temperature_celsius,
+            power_on_hours: smart.power_on_hours,
+        }
+    }
+}
+
+/// Estado de saúde de um disco segundo o SMART
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SmartStatus {
+    /// Sem previsão de falha nem setores defeituosos
+    Healthy,
+    /// Setores realocados, pendentes ou erros incorrigíveis: monitore e faça backup
+    Warning,
+    /// O disco prevê falha iminente: substitua
+    Failed,
+    /// SMART indisponível
+    Unknown,
+}
+
+impl fmt::Display for SmartStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            SmartStatus::Healthy => "OK",
+            SmartStatus::Warning => "ATENÇÃO",
+            SmartStatus::Failed => "FALHA PREVISTA",
+            SmartStatus::Unknown => "DESCONHECIDO",
+        })
+    }
+}
+
+impl SmartInfo {
+    /// Retorna `true` quando não há previsão de falha nem setores defeituosos
+    pub fn is_healthy(&self) -> bool {
+        self.status() == SmartStatus::Healthy
+    }
+    
+    /// Classifica a saúde do disco
+    /// 
+    /// A previsão de falha prevalece; qualquer setor realocado ou pendente,
+    /// ou erro incorrigível, rebaixa o disco para [`SmartStatus::Warning`].
+    pub fn status(&self) -> SmartStatus {
+        let defects = [self.reallocated_sectors, self.pending_sectors, self.uncorrectable_errors];
+        if self.failure_predicted {
+            SmartStatus::Failed
+        } else if defects.iter().any(|count| count.unwrap_or(0) > 0) {
+            SmartStatus::Warning
+        } else {
+            SmartStatus::Healthy
+        }
+    }
+}
+
+/// Consulta os dados SMART de todos os discos acessíveis
+/// 
+/// Pode acordar discos e, com o `smartctl`, cria um processo por disco:
+/// o [`SystemContext`](super::SystemContext) consulta uma única vez.
+/// 
+/// # Retorno
+/// Mapa do volume lógico (ex: "C:") ou, com o `smartctl`, do dispositivo
+/// físico (ex: "sda", "nvme0") para os dados SMART. Vazio quando o SMART
+/// não está disponível.
+pub(crate) fn query_smart() -> HashMap<String, SmartInfo> {
+    query_platform(|_| true)
+}
+
+/// Consulta os dados SMART apenas do disco de `volume` ou `device`
+/// 
+/// Usa os mesmos nomes que [`smart_for`]; com o `smartctl`, os demais
+/// discos do `--scan` não são consultados nem acordados.
+pub(crate) fn query_smart_for(volume: &str, device: &str) -> HashMap<String, SmartInfo> {
+    let candidates = device_candidates(device);
+    query_platform(|name| name == volume || candidates.iter().any(|candidate| candidate == name))
+}
+
+/// Consulta o SMART via WMI, associando cada disco físico aos seus volumes
+/// 
+/// Só os volumes aceitos por `wanted` entram no resultado.
+#[cfg(all(target_os = "windows", feature = "smart"))]
+fn query_platform(wanted: impl Fn(&str) -> bool) -> HashMap<String, SmartInfo> {
+    use serde::Deserialize;
+    use wmi::{COMLibrary, WMIConnection};
+    
+    #[derive(Deserialize)]
+    #[serde(rename = "MSStorageDriver_FailurePredictStatus")]
+    #[serde(rename_all = "PascalCase")]
+    struct FailurePredictStatus {
+        instance_name: String,
+        predict_failure: bool,
+    }
+    
+    #[derive(Deserialize)]
+    #[serde(rename = "MSStorageDriver_FailurePredictData")]
+    #[serde(rename_all = "PascalCase")]
+    struct FailurePredictData {
+        instance_name: String,
+        vendor_specific: Vec<u8>,
+    }
+    
+    #[derive(Deserialize)]
+    #[serde(rename = "Win32_DiskDrive")]
+    #[serde(rename_all = "PascalCase")]
+    struct DiskDrive {
+        #[serde(rename = "DeviceID")]
+        device_id: String,
+        #[serde(rename = "PNPDeviceID")]
+        pnp_device_id: Option<String>,
+    }
+    
+    #[derive(Deserialize)]
+    #[serde(rename = "Win32_DiskPartition")]
+    struct DiskPartition {
+        #[serde(rename = "DeviceID")]
+        device_id: String,
+    }
+    
+    #[derive(Deserialize)]
+    #[serde(rename = "Win32_LogicalDisk")]
+    struct LogicalDisk {
+        #[serde(rename = "DeviceID")]
+        device_id: String,
+    }
+    
+    let mut result = HashMap::new();
+    
+    let com = match COMLibrary::new() {
+        Ok(com) => com,
+        Err(e) => {
+            log::error!("inicialização do COM falhou ({}); SMART indisponível", e);
+            return result;
+        }
+    };
+    let (cimv2, root_wmi) = match (
+        WMIConnection::new(com),
+        WMIConnection::with_namespace_path("ROOT\\WMI", com),
+    ) {
+        (Ok(cimv2), Ok(root_wmi)) => (cimv2, root_wmi),
+        (Err(e), _) | (_, Err(e)) => {
+            log::error!("conexão WMI falhou ({}); SMART indisponível", e);
+            return result;
+        }
+    };
+    
+    // Exige privilégios de administrador; sem eles o SMART fica indisponível
+    let statuses: Vec<FailurePredictStatus> = root_wmi.query().unwrap_or_default();
+    let data: Vec<FailurePredictData> = root_wmi.query().unwrap_or_default();
+    let drives: Vec<DiskDrive> = cimv2.query().unwrap_or_default();
+    
+    for status in statuses {
+        // InstanceName é o PNPDeviceID do disco seguido de "_0"
+        let instance = status.instance_name.to_uppercase();
+        let drive = match drives.iter().find(|d| {
+            d.pnp_device_id
+                .as_ref()
+                .is_some_and(|pnp| instance.starts_with(&pnp.to_uppercase()))
+        }) {
+            Some(drive) => drive,
+            None => continue,
+        };
+        
+        let attributes = data
+            .iter()
+            .find(|d| d.instance_name.eq_ignore_ascii_case(&status.instance_name))
+            .map(|d| parse_smart_attributes(&d.vendor_specific))
+            .unwrap_or_default();
+        
+        let smart = SmartInfo {
+            failure_predicted: status.predict_failure,
+            ..attributes
+        };
+        
+        // Disco físico → partições → volumes lógicos
+        let partitions: Vec<DiskPartition> = cimv2
+            .raw_query(format!(
+                "ASSOCIATORS OF {{Win32_DiskDrive.DeviceID='{}'}} WHERE AssocClass = Win32_DiskDriveToDiskPartition",
+                drive.device_id.replace('\\', "\\\\")
+            ))
+            .unwrap_or_default();
+        for partition in partitions {
+            let volumes: Vec<LogicalDisk> = cimv2
+                .raw_query(format!(
+                    "ASSOCIATORS OF {{Win32_DiskPartition.DeviceID='{}'}} WHERE AssocClass = Win32_LogicalDiskToPartition",
+                    partition.device_id
+                ))
+                .unwrap_or_default();
+            for volume in volumes.into_iter().filter(|volume| wanted(&volume.device_id)) {
+                result.insert(volume.device_id, smart.clone());
+            }
+        }
+    }
+    
+    result
+}
+
+/// Consulta o SMART com o `smartctl`, se instalado, indexado pelo dispositivo
+/// 
+/// O Windows fica de fora: os nomes `/dev/sdX` do `smartctl` não podem ser
+/// associados às letras de unidade sem o WMI. Com `-n standby`, discos em
+/// repouso não são acordados e ficam sem dados. Só os dispositivos aceitos
+/// por `wanted` são consultados.
+#[cfg(all(not(target_os = "windows"), feature = "smart"))]
+fn query_platform(wanted: impl Fn(&str) -> bool) -> HashMap<String, SmartInfo> {
+    use std::process::Command;
+    
+    let scan = match Command::new("smartctl").arg("--scan").output() {
+        Ok(output) => String::from_utf8_lossy(&output.stdout).to_string(),
+        Err(_) => return HashMap::new(),
+    };
+    
+    scan.lines()
+        .filter_map(|line| line.split_whitespace().next())
+        .map(|device| (device, device.rsplit('/').next().unwrap_or(device)))
+        .filter(|(_, name)| wanted(name))
+        .filter_map(|(device, name)| {
+            let output = Command::new("smartctl").args(["-n", "standby", "-H", "-A", device]).output().ok()?;
+            let smart = parse_smartctl(&String::from_utf8_lossy(&output.stdout))?;
+            Some((name.to_string(), smart))
+        })
+        .collect()
+}
+
+/// Sem a feature `smart`: nenhum dado SMART
+#[cfg(not(feature = "smart"))]
+fn query_platform(_wanted: impl Fn(&str) -> bool) -> HashMap<String, SmartInfo> {
+    HashMap::new()
+}
+
+/// Extrai os atributos da tabela SMART (setores, horas ligado e temperatura)
+/// 
+/// A tabela começa no byte 2 e tem até 30 entradas de 12 bytes:
+/// id, flags (2), valor atual, pior valor, valor bruto (6) e reservado.
+/// A previsão de falha não faz parte da tabela e fica `false`.
+#[cfg_attr(not(all(target_os = "windows", feature = "smart")), allow(dead_code))]
+fn parse_smart_attributes(vendor_specific: &[u8]) -> SmartInfo {
+    let mut smart = SmartInfo::default();
+    
+    for entry in vendor_specific.get(2..).unwrap_or_default().chunks_exact(12).take(30) {
+        let raw = entry[5..11]
+            .iter()
+            .rev()
+            .fold(0u64, |acc, byte| (acc << 8) | u64::from(*byte));
+        set_attribute(&mut smart, entry[0], raw);
+    }
+    
+    smart
+}
+
+/// Registra um atributo SMART pelo identificador, ignorando os desconhecidos
+#[cfg_attr(not(feature = "smart"), allow(dead_code))]
+fn set_attribute(smart: &mut SmartInfo, id: u8, raw: u64) {
+    match id {
+        5 => smart.reallocated_sectors = Some(raw),
+        // Alguns fabricantes usam os bytes altos para minutos/segundos
+        9 => smart.power_on_hours = Some(raw & 0xFFFF_FFFF),
+        // Os bytes altos guardam mínimo e máximo
+        194 => smart.temperature_celsius = Some((raw & 0xFF) as u32),
+        197 => smart.pending_sectors = Some(raw),
+        198 => smart.uncorrectable_errors = Some(raw),
+        _ => {}
+    }
+}
+
+/// Interpreta a saída de `smartctl -H -A` (discos ATA/SATA e NVMe)
+/// 
+/// Retorna `None` quando a saída não traz nem o resultado da autoavaliação
+/// nem atributos (ex: sem permissão ou dispositivo sem SMART).
+#[cfg_attr(any(target_os = "windows", not(feature = "smart")), allow(dead_code))]
+fn parse_smartctl(output: &str) -> Option<SmartInfo> {
+    let mut smart = SmartInfo::default();
+    let mut found = false;
+    
+    // Valores do NVMe usam separador de milhar ("1,234")
+    let number = |value: &str| value.split_whitespace().next()?.replace([',', '.'], "").parse::<u64>().ok();
+    
+    for line in output.lines() {
+        let line = line.trim();
+        if let Some((key, value)) = line.split_once(':') {
+            let value = value.trim();
+            match key.trim() {
+                // ATA: "PASSED"/"FAILED!"; SCSI: "OK"
+                "SMART overall-health self-assessment test result" | "SMART Health Status" => {
+                    smart.failure_predicted = !(value.starts_with("PASSED") || value.starts_with("OK"));
+                }
+                "Temperature" => smart.temperature_celsius = number(value).map(|c| c as u32),
+                "Power On Hours" => smart.power_on_hours = number(value),
+                "Media and Data Integrity Errors" => smart.uncorrectable_errors = number(value),
+                _ => continue,
+            }
+            found = true;
+            continue;
+        }
+        
+        // Tabela ATA: ID# ATTRIBUTE_NAME FLAG VALUE WORST THRESH TYPE UPDATED WHEN_FAILED RAW_VALUE
+        let columns: Vec<&str> = line.split_whitespace().collect();
+        if let (Some(id), Some(raw)) = (columns.first().and_then(|id| id.parse::<u8>().ok()), columns.get(9)) {
+            if let Ok(raw) = raw.parse::<u64>() {
+                set_attribute(&mut smart, id, raw);
+                found = true;
+            }
+        }
+    }
+    
+    found.then_some(smart)
+}
+
+/// Localiza os dados SMART de um disco
+/// 
+/// Procura primeiro pelo volume (WMI, ex: "C:") e depois, pelo nome exato,
+/// no dispositivo, no disco que contém a partição e no controlador NVMe
+/// (`smartctl`, ex: "sda" para "sda1", "nvme0" para "nvme0n1p2").
+pub(crate) fn smart_for<'a>(smart: &'a HashMap<String, SmartInfo>, volume: &str, device: &str) -> Option<&'a SmartInfo> {
+    smart
+        .get(volume)
+        .or_else(|| device_candidates(device).iter().find_map(|name| smart.get(name)))
+}
+
+/// Nomes sob os quais o `smartctl` pode listar o disco de `device`
+/// 
+/// O próprio dispositivo, o disco sem o número da partição ("sda1" →
+/// "sda", "nvme0n1p2" → "nvme0n1", "mmcblk0p1" → "mmcblk0") e, no NVMe,
+/// o controlador ("nvme0").
+fn device_candidates(device: &str) -> Vec<String> {
+    fn strip_number(name: &str) -> &str {
+        name.trim_end_matches(|c: char| c.is_ascii_digit())
+    }
+    
+    if device.is_empty() {
+        return Vec::new();
+    }
+    let mut candidates = vec![device.to_string()];
+    
+    // Nomes terminados em dígito separam a partição com "p" (nvme0n1p2, mmcblk0p1)
+    let disk = if device.starts_with("nvme") || device.starts_with("mmcblk") {
+        strip_number(device).strip_suffix('p').unwrap_or(device)
+    } else {
+        strip_number(device)
+    };
+    if !disk.is_empty() && disk != device {
+        candidates.push(disk.to_string());
+    }
+    if let Some(controller) = disk.starts_with("nvme").then(|| strip_number(disk).strip_suffix('n')).flatten() {
+        candidates.push(controller.to_string());
+    }
+    candidates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_smart_attributes() {
+        let mut data = vec![0u8; 2 + 12 * 3];
+        // Atributo 5: 8 setores realocados
+        data[2] = 5;
+        data[2 + 5] = 8;
+        // Atributo 9: 0x1234 horas ligado
+        data[14] = 9;
+        data[14 + 5] = 0x34;
+        data[14 + 6] = 0x12;
+        // Atributo 194: 40 °C (bytes altos com mínimo e máximo)
+        data[26] = 194;
+        data[26 + 5] = 40;
+        data[26 + 7] = 20;
+        
+        let smart = parse_smart_attributes(&data);
+        assert_eq!(smart.reallocated_sectors, Some(8));
+        assert_eq!(smart.power_on_hours, Some(0x1234));
+        assert_eq!(smart.temperature_celsius, Some(40));
+        assert_eq!(smart.pending_sectors, None);
+        assert_eq!(parse_smart_attributes(&[]), SmartInfo::default());
+        
+        assert!(!smart.is_healthy());
+        assert_eq!(smart.status(), SmartStatus::Warning);
+        assert_eq!(SmartInfo::default().status(), SmartStatus::Healthy);
+        assert_eq!(SmartInfo { pending_sectors: Some(1), ..SmartInfo::default() }.status(), SmartStatus::Warning);
+        assert_eq!(SmartInfo { failure_predicted: true, ..smart }.status(), SmartStatus::Failed);
+    }
+    
+    #[test]
+    fn test_parse_smartctl() {
+        let ata = "\
+=== START OF READ SMART DATA SECTION ===
+SMART overall-health self-assessment test result: PASSED
+
+ID# ATTRIBUTE_NAME          FLAG     VALUE WORST THRESH TYPE      UPDATED  WHEN_FAILED RAW_VALUE
+  5 Reallocated_Sector_Ct   0x0033   100   100   010    Pre-fail  Always       -       0
+  9 Power_On_Hours          0x0032   095   095   000    Old_age   Always       -       21874
+194 Temperature_Celsius     0x0022   064   052   000    Old_age   Always       -       36 (Min/Max 18/48)
+197 Current_Pending_Sector  0x0012   100   100   000    Old_age   Always       -       2
+198 Offline_Uncorrectable   0x0010   100   100   000    Old_age   Offline      -       0
+";
+        let smart = parse_smartctl(ata).unwrap();
+        assert!(!smart.failure_predicted);
+        assert_eq!(smart.reallocated_sectors, Some(0));
+        assert_eq!(smart.power_on_hours, Some(21874));
+        assert_eq!(smart.temperature_celsius, Some(36));
+        assert_eq!(smart.pending_sectors, Some(2));
+        assert_eq!(smart.uncorrectable_errors, Some(0));
+        assert_eq!(smart.status(), SmartStatus::Warning);
+        
+        let nvme = "\
+SMART overall-health self-assessment test result: FAILED!
+Temperature:                        41 Celsius
+Power On Hours:                     1,234
+Media and Data Integrity Errors:    3
+";
+        let smart = parse_smartctl(nvme).unwrap();
+        assert_eq!(smart.status(), SmartStatus::Failed);
+        assert_eq!(smart.temperature_celsius, Some(41));
+        assert_eq!(smart.power_on_hours, Some(1234));
+        assert_eq!(smart.uncorrectable_errors, Some(3));
+        
+        // Sem permissão o smartctl não imprime dados
+        assert_eq!(parse_smartctl("Smartctl open device: /dev/sda failed: Permission denied"), None);
+    }
+    
+    #[test]
+    fn test_smart_for() {
+        let failing = SmartInfo { failure_predicted: true, ..SmartInfo::default() };
+        let smart = HashMap::from([
+            ("C:".to_string(), SmartInfo::default()),
+            ("sda".to_string(), failing.clone()),
+            ("nvme0".to_string(), SmartInfo::default()),
+        ]);
+        
+        assert_eq!(smart_for(&smart, "C:", "C:"), Some(&SmartInfo::default()));
+        assert_eq!(smart_for(&smart, "/", "sda1"), Some(&failing));
+        assert_eq!(smart_for(&smart, "/home", "nvme0n1p2"), Some(&SmartInfo::default()));
+        assert_eq!(smart_for(&smart, "/mnt", "sdb1"), None);
+        assert_eq!(smart_for(&smart, "/mnt", ""), None);
+        
+        // Nomes exatos: "sda" não é o disco de "sdaa1"
+        assert_eq!(smart_for(&smart, "/mnt", "sdaa1"), None);
+        assert_eq!(smart_for(&smart, "/mnt", "nvme01n1p1"), None);
+        assert_eq!(device_candidates("nvme0n1p2"), ["nvme0n1p2", "nvme0n1", "nvme0"]);
+        assert_eq!(device_candidates("mmcblk0p1"), ["mmcblk0p1", "mmcblk0"]);
+        assert_eq!(device_candidates("sda"), ["sda"]);
+        
+        // DiskHealth resume a leitura com a classificação
+        let health = DiskHealth::from(failing);
+        assert_eq!(health.health_status, SmartStatus::Failed);
+        assert!(!health.is_healthy());
+        assert!(DiskHealth::from(SmartInfo::default()).is_healthy());
+    }
+}
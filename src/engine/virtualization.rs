@@ -0,0 +1,207 @@
+// hardware-diagnostic - Ferramenta de diagnóstico de hardware
+// Copyright (C) 2025  Seu Nome
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Módulo `virtualization` - Detecção de máquinas virtuais e contêineres
+//! 
+//! Dentro de VMs e contêineres o `sysinfo` informa a alocação do ambiente,
+//! não o hardware físico: um único núcleo, nenhum SWAP, sistemas de arquivos
+//! overlay. A detecção permite anotar o relatório e não sugerir o descarte
+//! de um hardware que não é o da máquina.
+//! 
+//! No Linux os sinais vêm de `/proc`, `/.dockerenv` e do DMI em
+//! `/sys/class/dmi/id`; no Windows, com a feature `wmi`, de
+//! `Win32_ComputerSystem`.
+
+use std::fmt;
+
+/// Tipo de ambiente virtualizado
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum VirtKind {
+    /// Máquina virtual Hyper-V
+    HyperV,
+    /// Máquina virtual VMware
+    VMware,
+    /// Máquina virtual KVM/QEMU
+    Kvm,
+    /// Contêiner Docker (ou compatível, como Podman)
+    Docker,
+    /// Windows Subsystem for Linux
+    Wsl,
+}
+
+impl VirtKind {
+    /// Indica se é um contêiner, que compartilha o kernel do hospedeiro
+    pub fn is_container(&self) -> bool {
+        matches!(self, VirtKind::Docker)
+    }
+}
+
+impl fmt::Display for VirtKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            VirtKind::HyperV => "Hyper-V",
+            VirtKind::VMware => "VMware",
+            VirtKind::Kvm => "KVM",
+            VirtKind::Docker => "Docker",
+            VirtKind::Wsl => "WSL",
+        })
+    }
+}
+
+/// Detecta se o diagnóstico está rodando em uma VM ou contêiner
+/// 
+/// # Retorno
+/// `None` em máquinas físicas ou quando não há como identificar o ambiente.
+/// 
+/// # Exemplo
+/// ```
+/// use hardware_diagnostic::engine::virtualization::detect_virtualization;
+/// 
+/// if let Some(kind) = detect_virtualization() {
+///     println!("Ambiente virtualizado: {}", kind);
+/// }
+/// ```
+pub fn detect_virtualization() -> Option<VirtKind> {
+    let kind = query_virtualization();
+    log::debug!("virtualização detectada: {:?}", kind);
+    kind
+}
+
+/// Consulta os sinais de virtualização no sistema de arquivos
+#[cfg(target_os = "linux")]
+fn query_virtualization() -> Option<VirtKind> {
+    detect_linux(std::path::Path::new("/"))
+}
+
+/// Consulta o fabricante e o modelo da máquina via WMI
+#[cfg(all(target_os = "windows", feature = "wmi"))]
+fn query_virtualization() -> Option<VirtKind> {
+    use serde::Deserialize;
+    use wmi::{COMLibrary, WMIConnection};
+    
+    #[derive(Deserialize)]
+    #[serde(rename = "Win32_ComputerSystem")]
+    #[serde(rename_all = "PascalCase")]
+    struct ComputerSystem {
+        manufacturer: Option<String>,
+        model: Option<String>,
+    }
+    
+    let connection = COMLibrary::new().and_then(WMIConnection::new).ok()?;
+    let system = connection.query::<ComputerSystem>().ok()?.into_iter().next()?;
+    
+    classify_hardware(
+        system.manufacturer.as_deref().unwrap_or_default(),
+        system.model.as_deref().unwrap_or_default(),
+    )
+}
+
+/// Plataformas sem suporte (ou Windows sem a feature `wmi`): não detectado
+#[cfg(not(any(target_os = "linux", all(target_os = "windows", feature = "wmi"))))]
+fn query_virtualization() -> Option<VirtKind> {
+    None
+}
+
+/// Procura sinais de contêiner, WSL e hipervisor a partir da raiz `root`
+/// 
+/// Contêineres e WSL são verificados primeiro: dentro deles o DMI, quando
+/// visível, descreve o hospedeiro.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn detect_linux(root: &std::path::Path) -> Option<VirtKind> {
+    use std::fs;
+    
+    let read = |path: &str| fs::read_to_string(root.join(path)).unwrap_or_default();
+    
+    // Docker cria /.dockerenv; Podman, /run/.containerenv
+    let cgroup = read("proc/1/cgroup");
+    if root.join(".dockerenv").exists()
+        || root.join("run/.containerenv").exists()
+        || cgroup.contains("docker")
+        || cgroup.contains("kubepods")
+    {
+        return Some(VirtKind::Docker);
+    }
+    
+    // O kernel do WSL se identifica como "...-microsoft-standard-WSL2"
+    if read("proc/sys/kernel/osrelease").to_lowercase().contains("microsoft") {
+        return Some(VirtKind::Wsl);
+    }
+    
+    classify_hardware(
+        read("sys/class/dmi/id/sys_vendor").trim(),
+        read("sys/class/dmi/id/product_name").trim(),
+    )
+}
+
+/// Identifica o hipervisor pelo fabricante e modelo informados pelo firmware
+#[cfg_attr(not(any(target_os = "linux", all(target_os = "windows", feature = "wmi"))), allow(dead_code))]
+fn classify_hardware(vendor: &str, product: &str) -> Option<VirtKind> {
+    let vendor = vendor.to_lowercase();
+    let product = product.to_lowercase();
+    
+    if vendor.contains("vmware") || product.contains("vmware") {
+        Some(VirtKind::VMware)
+    } else if vendor.contains("microsoft") && product.contains("virtual machine") {
+        Some(VirtKind::HyperV)
+    } else if vendor.contains("qemu") || product.contains("kvm") || product.contains("qemu") {
+        Some(VirtKind::Kvm)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    
+    #[test]
+    fn test_classify_hardware() {
+        assert_eq!(classify_hardware("VMware, Inc.", "VMware Virtual Platform"), Some(VirtKind::VMware));
+        assert_eq!(classify_hardware("Microsoft Corporation", "Virtual Machine"), Some(VirtKind::HyperV));
+        assert_eq!(classify_hardware("QEMU", "Standard PC (Q35 + ICH9, 2009)"), Some(VirtKind::Kvm));
+        // Surface e outros equipamentos físicos da Microsoft não são VMs
+        assert_eq!(classify_hardware("Microsoft Corporation", "Surface Laptop 5"), None);
+        assert_eq!(classify_hardware("Dell Inc.", "OptiPlex 7090"), None);
+    }
+    
+    #[test]
+    fn test_detect_linux() {
+        let root = std::env::temp_dir().join(format!("hardware_diagnostic_virt_{}", std::process::id()));
+        let dmi = root.join("sys/class/dmi/id");
+        fs::create_dir_all(&dmi).unwrap();
+        fs::create_dir_all(root.join("proc/sys/kernel")).unwrap();
+        
+        assert_eq!(detect_linux(&root), None);
+        
+        fs::write(dmi.join("sys_vendor"), "QEMU\n").unwrap();
+        fs::write(dmi.join("product_name"), "Standard PC (i440FX + PIIX, 1996)\n").unwrap();
+        assert_eq!(detect_linux(&root), Some(VirtKind::Kvm));
+        
+        fs::write(root.join("proc/sys/kernel/osrelease"), "5.15.153.1-microsoft-standard-WSL2\n").unwrap();
+        let wsl = detect_linux(&root);
+        
+        fs::write(root.join(".dockerenv"), "").unwrap();
+        let docker = detect_linux(&root);
+        fs::remove_dir_all(&root).ok();
+        
+        assert_eq!(wsl, Some(VirtKind::Wsl));
+        assert_eq!(docker, Some(VirtKind::Docker));
+        assert!(VirtKind::Docker.is_container() && !VirtKind::Wsl.is_container());
+        assert_eq!(VirtKind::HyperV.to_string(), "Hyper-V");
+    }
+}
@@ -0,0 +1,91 @@
+// hardware-diagnostic - Ferramenta de diagnóstico de hardware
+// Copyright (C) 2025  Seu Nome
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Módulo `error` - Tipos de erro do diagnóstico
+//! 
+//! Define o [`DiagnosticError`], retornado pelas funções que propagam
+//! falhas em vez de retornar valores padrão.
+
+use std::fmt;
+use std::time::Duration;
+
+/// Erros que podem ocorrer durante a coleta de informações de hardware
+/// 
+/// As variantes permitem distinguir falhas de consulta (que podem ser
+/// repetidas) de falta de permissão (que exige intervenção).
+#[derive(Debug, Clone, PartialEq)]
+pub enum DiagnosticError {
+    /// A consulta à CPU falhou ou retornou dados inválidos
+    CpuQueryFailed(String),
+    /// A consulta à memória falhou ou retornou dados inválidos
+    MemoryQueryFailed(String),
+    /// A consulta ao firmware (BIOS/UEFI) falhou ou não é suportada
+    BiosQueryFailed(String),
+    /// A consulta a um disco falhou
+    DiskQueryFailed {
+        /// Ponto de montagem do disco (vazio quando a falha afeta todos os discos)
+        mount_point: String,
+        /// Descrição da causa da falha
+        source: String,
+    },
+    /// O processo não tem permissão para consultar o hardware
+    PermissionDenied,
+    /// A consulta excedeu o tempo limite
+    Timeout(Duration),
+    /// A configuração fornecida é inválida
+    InvalidConfig(String),
+    /// Falha ao ler ou gravar um arquivo
+    Io {
+        /// Caminho do arquivo envolvido
+        path: String,
+        /// Descrição da causa da falha
+        source: String,
+    },
+    /// O conteúdo de um arquivo não está no formato esperado
+    Parse {
+        /// Caminho do arquivo envolvido
+        path: String,
+        /// Linha com o conteúdo inválido, a partir de 1
+        line: usize,
+        /// Descrição da causa da falha
+        source: String,
+    },
+}
+
+impl fmt::Display for DiagnosticError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DiagnosticError::CpuQueryFailed(msg) => write!(f, "Falha na consulta à CPU: {}", msg),
+            DiagnosticError::MemoryQueryFailed(msg) => write!(f, "Falha na consulta à memória: {}", msg),
+            DiagnosticError::BiosQueryFailed(msg) => write!(f, "Falha na consulta ao BIOS: {}", msg),
+            DiagnosticError::DiskQueryFailed { mount_point, source } if mount_point.is_empty() => {
+                write!(f, "Falha na consulta aos discos: {}", source)
+            }
+            DiagnosticError::DiskQueryFailed { mount_point, source } => {
+                write!(f, "Falha na consulta ao disco {}: {}", mount_point, source)
+            }
+            DiagnosticError::PermissionDenied => write!(f, "Permissão negada para consultar o hardware"),
+            DiagnosticError::Timeout(duration) => write!(f, "Tempo limite excedido após {:?}", duration),
+            DiagnosticError::InvalidConfig(msg) => write!(f, "Configuração inválida: {}", msg),
+            DiagnosticError::Io { path, source } => write!(f, "Falha de E/S em {}: {}", path, source),
+            DiagnosticError::Parse { path, line, source } => {
+                write!(f, "Conteúdo inválido em {}, linha {}: {}", path, line, source)
+            }
+        }
+    }
+}
+
+impl std::error::Error for DiagnosticError {}
@@ -16,8 +16,8 @@
 
 //! # Hardware Diagnostic Crate
 //! 
-//! Uma biblioteca Rust para diagnóstico de hardware em sistemas Windows
-//! com sistema de pontuação de desempenho inteligente.
+//! Uma biblioteca Rust para diagnóstico de hardware em sistemas Windows e
+//! Linux com sistema de pontuação de desempenho inteligente.
 //! 
 //! ## 📦 Instalação
 //! 
@@ -28,6 +28,36 @@
 //! hardware-diagnostic = "1.0"
 //! ```
 //! 
+//! ### Features opcionais
+//! 
+//! - `serde` - Deriva `Serialize`/`Deserialize` para `CpuInfo`, `RamInfo`,
+//!   `DiskInfo`, `PerformanceScore` e `PerformanceCategory`, habilita a
+//!   saída `--json` da CLI e o carregamento de `ScoringConfig` a partir de
+//!   arquivos TOML/JSON (`--config` e `--dump-config`)
+//! - `async` - Variantes assíncronas das funções de coleta em
+//!   [`engine::async_api`](engine/async_api/index.html), baseadas em `tokio`
+//! - `wmi` - Consultas WMI no Windows, usadas por
+//!   [`engine::battery`](engine/battery/index.html) e
+//!   [`engine::bios`](engine/bios/index.html)
+//! - `smart` - Atributos SMART dos discos em
+//!   [`engine::smart`](engine/smart/index.html) (WMI no Windows; nas demais
+//!   plataformas, o `smartctl`)
+//! - `gpu` - Coleta de informações das placas de vídeo em
+//!   [`engine::gpu`](engine/gpu/index.html) (WMI no Windows, sysfs no Linux)
+//! - `display` - Monitores conectados e resoluções em
+//!   [`engine::display`](engine/display/index.html) (API do Windows; no
+//!   Linux o sysfs é usado sem precisar da feature)
+//! - `cli` (padrão) - Compila o binário `hardware-diagnostic` e sua
+//!   dependência `clap`; sem ela só a biblioteca é compilada
+//! - `ctrlc` (padrão) - Encerramento limpo do `--watch` do binário com
+//!   Ctrl-C; quem usa só a biblioteca pode desativá-la com
+//!   `default-features = false`
+//! 
+//! ```toml
+//! [dependencies]
+//! hardware-diagnostic = { version = "1.0", features = ["serde"] }
+//! ```
+//! 
 //! ## 🚀 Uso Rápido
 //! 
 //! ```rust
@@ -45,23 +75,112 @@
 //! }
 //! ```
 //! 
+//! ## 🪵 Logs
+//! 
+//! A coleta é instrumentada com a fachada [`log`](https://docs.rs/log):
+//! o início de cada coleta e os valores brutos do `sysinfo` em nível
+//! `debug`, a pontuação calculada em `info`, quedas para valores padrão
+//! (nenhuma CPU, memória zerada, espaço livre maior que o total) em `warn`
+//! e falhas nas consultas WMI em `error`. Sem um logger instalado pela
+//! aplicação (`env_logger`, `tracing-subscriber`...), as mensagens são
+//! descartadas sem custo.
+//! 
+//! Com o `env_logger`, por exemplo, o nível é escolhido pela variável
+//! `RUST_LOG`:
+//! 
+//! ```text
+//! RUST_LOG=hardware_diagnostic=debug minha-aplicacao
+//! ```
+//! 
 //! ## 📚 Módulos
 //! 
 //! - [`engine`](engine/index.html) - Funcionalidades principais de diagnóstico
 //!   - [`CpuInfo`](engine/struct.CpuInfo.html) - Informações da CPU
 //!   - [`RamInfo`](engine/struct.RamInfo.html) - Informações de memória
 //!   - [`DiskInfo`](engine/struct.DiskInfo.html) - Informações de discos
+//!   - [`DiskKind`](engine/enum.DiskKind.html) - Tipo de mídia do disco
+//!   - [`DiskFilter`](engine/struct.DiskFilter.html) - Critérios para ignorar discos
+//!   - [`NetworkInfo`](engine/struct.NetworkInfo.html) - Informações de interfaces de rede
 //!   - [`PerformanceScore`](engine/struct.PerformanceScore.html) - Pontuação de desempenho
 //!   - [`PerformanceCategory`](engine/enum.PerformanceCategory.html) - Categorias
+//!   - [`ScoreDiff`](engine/struct.ScoreDiff.html) - Comparação entre duas pontuações
+//!   - [`SystemContext`](engine/struct.SystemContext.html) - Contexto de coleta compartilhado
+//!   - [`ScoringConfig`](engine/struct.ScoringConfig.html) - Pesos da pontuação
+//!   - [`DiagnosticConfig`](engine/struct.DiagnosticConfig.html) - Pontuação e alertas carregados de um arquivo TOML
+//!   - [`ScoreWeights`](engine/struct.ScoreWeights.html) - Pesos gerais de CPU, RAM e discos
+//!   - [`ScoreProfile`](engine/enum.ScoreProfile.html) - Perfis de uso com pesos predefinidos
+//!   - [`ScoringThresholds`](engine/struct.ScoringThresholds.html) - Limiares das faixas de pontuação
+//!   - [`CategoryBoundaries`](engine/struct.CategoryBoundaries.html) - Limites entre as categorias
+//!   - [`PerformanceScoreBuilder`](engine/struct.PerformanceScoreBuilder.html) - Pontuação apenas dos componentes escolhidos
+//!   - [`DiagnosticBuilder`](engine/struct.DiagnosticBuilder.html) - Coleta apenas os subsistemas escolhidos
+//!   - [`DiagnosticReport`](engine/report/struct.DiagnosticReport.html) - Relatório completo capturado de uma só vez
+//!   - [`alerts`](engine/alerts/index.html) - Alertas por limiar para monitoramento automatizado
+//!   - [`reference`](engine/reference/index.html) - Desvios em relação à especificação de cada modelo
+//!   - [`smart`](engine/smart/index.html) - Atributos SMART dos discos
+//!   - [`memory`](engine/memory/index.html) - Pentes de memória instalados
+//!   - [`processes`](engine/processes/index.html) - Processos que mais consomem CPU e memória
+//!   - [`battery`](engine/battery/index.html) - Informações da bateria
+//!   - [`bios`](engine/bios/index.html) - Versão, data e Secure Boot do firmware
+//!   - [`display`](engine/display/index.html) - Monitores conectados e suas resoluções
+//!   - [`fans`](engine/fans/index.html) - Rotação das ventoinhas
+//!   - [`benchmark`](engine/benchmark/index.html) - Medição real de CPU, memória e disco (opcional)
+//!   - [`history`](engine/history/index.html) - Histórico e tendência das pontuações
+//!   - [`locale`](engine/locale/index.html) - Idiomas das saídas em texto
 //!   - [`utils`](engine/utils/index.html) - Funções utilitárias
+//! - [`error`](error/index.html) - Tipos de erro
+//!   - [`DiagnosticError`](error/enum.DiagnosticError.html) - Erros de coleta
 //! 
 //! ## 🔧 Funções Principais
 //! 
 //! - [`cpu_info()`](engine/fn.cpu_info.html) - Coleta informações da CPU
+//! - [`cpu_info_with_interval()`](engine/fn.cpu_info_with_interval.html) - Coleta da CPU com intervalo de amostragem personalizado
 //! - [`ram_info()`](engine/fn.ram_info.html) - Coleta informações de RAM
 //! - [`disk_info()`](engine/fn.disk_info.html) - Coleta informações de discos
+//! - [`disk_info_filtered()`](engine/fn.disk_info_filtered.html) - Coleta apenas os discos que passam por um filtro
+//! - [`disk_info_for()`](engine/fn.disk_info_for.html) - Coleta um único disco pelo ponto de montagem
+//! - [`network_info()`](engine/fn.network_info.html) - Coleta informações de rede
+//! - [`os_info()`](engine/fn.os_info.html) - Coleta informações do sistema operacional
+//! - [`system_info()`](engine/fn.system_info.html) - Coleta sistema, hostname, tempo ligado e inicialização
+//! - [`monitor()`](engine/fn.monitor.html) - Monitora a pontuação continuamente
+//! - [`temperature_info()`](engine/fn.temperature_info.html) - Coleta leituras dos sensores de temperatura
+//! - [`cpu_info_checked()`](engine/fn.cpu_info_checked.html), [`ram_info_checked()`](engine/fn.ram_info_checked.html)
+//!   e [`disk_info_checked()`](engine/fn.disk_info_checked.html) - Variantes que retornam `Result`
 //! - [`calculate_performance_score()`](engine/fn.calculate_performance_score.html) - Calcula pontuação
+//! - [`calculate_performance_score_with_config()`](engine/fn.calculate_performance_score_with_config.html) - Calcula pontuação com pesos personalizados
 //! - [`display_performance_score()`](engine/fn.display_performance_score.html) - Exibe pontuação formatada
+//!   (cores apenas em terminal; [`display_performance_score_colored()`](engine/fn.display_performance_score_colored.html) força o comportamento)
+//! - [`display_performance_score_localized()`](engine/fn.display_performance_score_localized.html) - Exibe pontuação em outro idioma ([`Language`](engine/locale/enum.Language.html))
+//! - [`display_performance_score_with_config()`](engine/fn.display_performance_score_with_config.html) - Exibe pontuação com o idioma e a legenda de categorias da configuração
+//! 
+//! ## 🖥️ Plataformas
+//! 
+//! A coleta usa o `sysinfo` e funciona no Windows, no Linux e no macOS.
+//! Onde as plataformas diferem há implementações por `#[cfg(target_os)]`;
+//! a plataforma da coleta ([`engine::platform::current_os`]) aparece em
+//! todos os relatórios.
+//! 
+//! | Dado | Windows | Linux | macOS |
+//! |------|---------|-------|-------|
+//! | Tipo de mídia (SSD/HDD) | `sysinfo` | `sysinfo`, ou `queue/rotational` do sysfs | `sysinfo`, ou `diskutil info` |
+//! | Bateria | WMI (`wmi`) | `/sys/class/power_supply` | IOKit (`ioreg`) |
+//! | GPU | WMI (`gpu`) | `/sys/class/drm` | IOKit/Metal (`system_profiler`) |
+//! | SMART | WMI (`smart`) | `smartctl` (`smart`), se instalado | `smartctl` (`smart`), se instalado |
+//! | Pentes | WMI (`memory`) | — | — |
+//! | Ventoinhas | WMI (`wmi`) | `/sys/class/hwmon` | — |
+//! | Monitores | `EnumDisplaySettingsW` (`display`) | `/sys/class/drm` | — |
+//! 
+//! Os testes rodam nas três plataformas; a matriz do GitHub Actions é:
+//! 
+//! ```yaml
+//! strategy:
+//!   matrix:
+//!     os: [ubuntu-latest, windows-latest, macos-latest]
+//! runs-on: ${{ matrix.os }}
+//! steps:
+//!   - uses: actions/checkout@v4
+//!   - run: cargo test --verbose
+//!   - run: cargo test --verbose --all-features
+//! ```
 //! 
 //! ## 🎯 Sistema de Pontuação
 //! 
@@ -72,6 +191,11 @@
 //! | 5-6 | 🔶 Precaução | Monitoramento |
 //! | 7-10 | ✅ Bom Estado | Uso normal |
 //! 
+//! **Piso de subsistema crítico:** se CPU, RAM, algum disco, GPU ou bateria
+//! pontuar abaixo de [`ScoringConfig::critical_floor`](engine/struct.ScoringConfig.html#structfield.critical_floor)
+//! (padrão 2.0), a categoria fica limitada a Manutenção, mesmo que a média
+//! seja maior. Desative com `critical_floor_enabled = false`.
+//! 
 //! ## 📖 Documentação
 //! 
 //! Para gerar documentação local:
@@ -96,11 +220,38 @@
 /// Módulo principal contendo todas as funcionalidades de diagnóstico
 pub mod engine;
 
+/// Tipos de erro retornados pelas funções de diagnóstico
+pub mod error;
+
 // Re-exportações para fácil acesso
 pub use engine::{
-    CpuInfo, RamInfo, DiskInfo, PerformanceScore, PerformanceCategory,
-    cpu_info, ram_info, disk_info, calculate_performance_score, display_performance_score
+    CpuInfo, VulnerabilityStatus, RamInfo, MemoryPressure, DiskInfo, DiskKind, DiskFilter, NetworkInfo, OsInfo, SystemInfo, TemperatureReading, ThermalComponent, PerformanceScore, PerformanceScoreBuilder, DiagnosticBuilder, CollectedDiagnostic, ScoreDiff, ScoreDelta, TimestampedScore, DiagnosticReport, SystemSnapshot, PerformanceCategory, SystemContext,
+    cpu_info, ram_info, disk_info, network_info, has_network_connectivity, os_info, system_info, temperature_info, calculate_performance_score, display_performance_score, display_performance_score_colored, display_performance_score_localized, display_performance_score_with_config, display_diff, display_diff_colored, display_diff_localized, monitor, collect_snapshot,
+    cpu_info_checked, ram_info_checked, disk_info_checked, disk_info_filtered, disk_info_for, cpu_info_with_interval,
+    cpu_info_from, ram_info_from, disk_info_from,
+    DiagnosticConfig, ScoringConfig, ScoreWeights, ScoreProfile, ScoringThresholds, CategoryBoundaries, MANUTENCAO_MIN_SCORE, PRECAUCAO_MIN_SCORE, BOM_ESTADO_MIN_SCORE, calculate_performance_score_with_config, calculate_performance_score_with_weights
 };
+pub use engine::Language;
+pub use engine::report::{render, OutputFormat, ReportEnvelope, SnapshotDiff, REPORT_SCHEMA_VERSION};
+pub use error::DiagnosticError;
+pub use engine::alerts::{Alert, AlertConfig, AlertManager, AlertSeverity};
+pub use engine::battery::{BatteryInfo, BatteryState, battery_info};
+pub use engine::benchmark::{BenchmarkResult, run_benchmark, run_quick_benchmark};
+pub use engine::bios::{BiosInfo, bios_info};
+pub use engine::display::{DisplayInfo, display_info};
+pub use engine::fans::{FanInfo, fan_info};
+pub use engine::history::{HistoryEntry, ScoreHistory, ScoreTrend, TrendFit};
+pub use engine::smart::{DiskHealth, SmartInfo, SmartStatus};
+pub use engine::memory::{MemoryLayout, MemoryModule, memory_layout, memory_modules};
+pub use engine::processes::{ProcessInfo, top_processes, top_memory_processes};
+pub use engine::reference::{Deviation, ReferenceDisk, ReferenceProfile};
+pub use engine::virtualization::{VirtKind, detect_virtualization};
+#[cfg(feature = "gpu")]
+pub use engine::gpu::{GpuInfo, gpu_info, calculate_gpu_score};
+
+/// Versão do `sysinfo` usada pela crate, para [`cpu_info_from`],
+/// [`ram_info_from`] e [`disk_info_from`]
+pub use sysinfo;
 
 /// Versão da crate
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
\ No newline at end of file
@@ -1,6 +1,5 @@
 // tests/integration_test.rs
 
-use hardware_diagnostic::*;
 use std::process::Command;
 
 #[test]
@@ -30,6 +29,8 @@ fn test_save_report() {
         .output()
         .expect("Falha ao executar comando");
     
+    assert!(output.status.success());
+    
     // Verifica se algum arquivo foi criado
     let files: Vec<_> = std::fs::read_dir(".")
         .unwrap()
@@ -43,4 +44,405 @@ fn test_save_report() {
     for file in files {
         std::fs::remove_file(file.path()).ok();
     }
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_write_report_creates_directories() {
+    // Testa a gravação em um caminho com diretórios ainda inexistentes
+    let dir = std::env::temp_dir().join(format!("hardware_diagnostic_report_{}", std::process::id()));
+    let path = dir.join("relatorios").join("diagnostico.txt");
+    
+    hardware_diagnostic::engine::utils::write_report_to(&path).expect("Falha ao gravar relatório");
+    let contents = std::fs::read_to_string(&path).expect("Relatório não foi criado");
+    std::fs::remove_dir_all(&dir).ok();
+    
+    assert!(!contents.is_empty());
+    assert!(contents.contains("=== INFORMACOES DA CPU ==="));
+}
+
+#[test]
+fn test_score_only_output() {
+    let output = Command::new("cargo")
+        .args(["run", "--", "--score-only"])
+        .output()
+        .expect("Falha ao executar comando");
+    
+    // Uma única linha com a pontuação; o código de saída é a categoria (0 a 3)
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(stdout.lines().count(), 1, "Saída inesperada: {}", stdout);
+    let score: f64 = stdout.trim().parse().expect("Pontuação deve ser numérica");
+    assert!((0.0..=10.0).contains(&score));
+    assert!(matches!(output.status.code(), Some(0..=3)));
+    
+    let output = Command::new("cargo")
+        .args(["run", "--", "--score-only", "--json"])
+        .output()
+        .expect("Falha ao executar comando");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.starts_with("{\"schema_version\":2,"));
+    assert!(stdout.contains("\"data\":{\"overall_score\":"));
+    assert!(stdout.contains("\"category\":"));
+}
+
+#[test]
+fn test_threshold_fails_below_minimum() {
+    // Pontuação máxima como mínimo: a máquina de teste não deve atingi-la
+    let output = Command::new("cargo")
+        .args(["run", "--", "--threshold", "10", "--exit-code", "3"])
+        .output()
+        .expect("Falha ao executar comando");
+    
+    assert_eq!(output.status.code(), Some(3));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("DECISÃO RECOMENDADA"), "Saída normal deve ser exibida antes de sair");
+    assert!(String::from_utf8_lossy(&output.stderr).contains("abaixo do mínimo"));
+    
+    // Mínimo zero sempre é atingido
+    let output = Command::new("cargo")
+        .args(["run", "--", "--threshold-category", "Descarte"])
+        .output()
+        .expect("Falha ao executar comando");
+    assert!(output.status.success());
+    
+    // Valor fora de 0..=10 é rejeitado como argumento inválido
+    let output = Command::new("cargo")
+        .args(["run", "--", "--threshold", "11"])
+        .output()
+        .expect("Falha ao executar comando");
+    assert_eq!(output.status.code(), Some(2));
+}
+
+#[test]
+fn test_combined_flags_and_invalid_arguments() {
+    // Flags curtas combinadas e caminho de saída em qualquer posição
+    let path = std::env::temp_dir()
+        .join(format!("hardware_diagnostic_cli_{}", std::process::id()))
+        .join("relatorio.txt");
+    let output = Command::new("cargo")
+        .args(["run", "--", "-fs", "--output", path.to_str().unwrap()])
+        .output()
+        .expect("Falha ao executar comando");
+    
+    assert!(output.status.success());
+    let contents = std::fs::read_to_string(&path).expect("Relatório não foi criado");
+    std::fs::remove_dir_all(path.parent().unwrap()).ok();
+    assert!(contents.contains("=== INFORMACOES DA CPU ==="));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("=== INFORMACOES DA CPU ==="), "--full deve exibir o relatório completo");
+    
+    // `--save=<arquivo>` grava a mesma pontuação exibida, sem nova coleta
+    let output = Command::new("cargo")
+        .args(["run", "--", &format!("--save={}", path.display())])
+        .output()
+        .expect("Falha ao executar comando");
+    
+    assert!(output.status.success());
+    let contents = std::fs::read_to_string(&path).expect("Relatório não foi criado");
+    std::fs::remove_dir_all(path.parent().unwrap()).ok();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let score = stdout
+        .lines()
+        .find_map(|line| line.strip_prefix("• Pontuação: "))
+        .expect("Resumo deve exibir a pontuação");
+    assert!(contents.contains(score), "Arquivo deve trazer a pontuação exibida ({})", score);
+    
+    // Argumento desconhecido: erro no stderr e código 2
+    let output = Command::new("cargo")
+        .args(["run", "--", "--nao-existe"])
+        .output()
+        .expect("Falha ao executar comando");
+    assert_eq!(output.status.code(), Some(2));
+    assert!(output.stdout.is_empty());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--nao-existe"));
+}
+
+#[test]
+fn test_html_output_to_file() {
+    // Testa o relatório HTML gravado no caminho indicado
+    let path = std::env::temp_dir().join(format!("hardware_diagnostic_cli_{}.html", std::process::id()));
+    let output = Command::new("cargo")
+        .args(["run", "--", "--html", path.to_str().unwrap()])
+        .output()
+        .expect("Falha ao executar comando");
+    
+    assert!(output.status.success());
+    let html = std::fs::read_to_string(&path).expect("Arquivo HTML não foi criado");
+    std::fs::remove_file(&path).ok();
+    assert!(html.starts_with("<!DOCTYPE html>"));
+    assert!(html.contains("gauge small"));
+}
+
+#[test]
+fn test_format_with_save() {
+    // --format escolhe o conteúdo gravado por --save
+    let path = std::env::temp_dir().join(format!("hardware_diagnostic_cli_{}.md", std::process::id()));
+    let output = Command::new("cargo")
+        .args(["run", "--", "--format", "markdown", &format!("--save={}", path.display())])
+        .output()
+        .expect("Falha ao executar comando");
+    
+    assert!(output.status.success());
+    let markdown = std::fs::read_to_string(&path).expect("Relatório não foi criado");
+    std::fs::remove_file(&path).ok();
+    assert!(markdown.starts_with("# Hardware Diagnostic Report"));
+    
+    // Sem --save, a saída vai para o stdout
+    let output = Command::new("cargo")
+        .args(["run", "--", "--format", "csv"])
+        .output()
+        .expect("Falha ao executar comando");
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).starts_with("timestamp,cpu_name,"));
+    
+    let output = Command::new("cargo")
+        .args(["run", "--", "--format", "pdf"])
+        .output()
+        .expect("Falha ao executar comando");
+    assert_eq!(output.status.code(), Some(2));
+}
+
+#[test]
+fn test_markdown_output() {
+    let output = Command::new("cargo")
+        .args(["run", "--", "--markdown"])
+        .output()
+        .expect("Falha ao executar comando");
+    
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.starts_with("# Hardware Diagnostic Report"));
+    assert!(stdout.contains("## Disks"));
+    assert!(stdout.contains("\n- "), "Recomendações devem estar em lista");
+    assert!(!stdout.contains('\x1b'), "Markdown não deve conter cores ANSI");
+}
+
+#[test]
+fn test_prometheus_output() {
+    let output = Command::new("cargo")
+        .args(["run", "--", "--prometheus"])
+        .output()
+        .expect("Falha ao executar comando");
+    
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.starts_with("# HELP hardware_performance_score"));
+    assert!(stdout.contains("# TYPE hardware_cpu_usage_percent gauge"));
+    assert!(stdout.lines().any(|l| l.starts_with("hardware_performance_score ")));
+}
+
+#[test]
+fn test_ascii_bars() {
+    let output = Command::new("cargo")
+        .args(["run", "--", "ram", "--ascii", "--no-color"])
+        .output()
+        .expect("Falha ao executar comando");
+    
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let bar = stdout.lines().find(|l| l.starts_with("Barra: ")).expect("Barra ausente");
+    assert!(bar.chars().skip("Barra: ".len()).all(|c| "[#-]".contains(c)), "{}", bar);
+}
+
+#[test]
+fn test_ascii_output() {
+    // Relatório completo sem emojis, setas nem blocos; acentos são mantidos
+    let output = Command::new("cargo")
+        .args(["run", "--", "--full", "--ascii", "--no-color"])
+        .output()
+        .expect("Falha ao executar comando");
+    
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let symbol = stdout.chars().find(|c| !c.is_ascii() && !c.is_alphabetic());
+    assert_eq!(symbol, None, "Caractere não ASCII na saída");
+    assert!(stdout.contains("PONTUA"));
+    // Com --full os processos são listados em qualquer categoria
+    assert!(stdout.contains("=== TOP PROCESSES ==="));
+    
+    // As linhas de status da CLI também seguem o --ascii
+    let path = std::env::temp_dir().join(format!("hardware_diagnostic_ascii_{}.txt", std::process::id()));
+    let output = Command::new("cargo")
+        .args(["run", "--", "--ascii", "--no-color", &format!("--save={}", path.display())])
+        .output()
+        .expect("Falha ao executar comando");
+    std::fs::remove_file(&path).ok();
+    
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains(&path.display().to_string()), "Caminho salvo deve ser exibido");
+    let symbol = stdout.chars().find(|c| !c.is_ascii() && !c.is_alphabetic());
+    assert_eq!(symbol, None, "Caractere não ASCII na saída");
+}
+
+#[test]
+fn test_component_subcommands() {
+    // Cada subcomando exibe apenas a seção do seu componente
+    for (command, section, other) in [
+        ("cpu", "=== INFORMACOES DA CPU ===", "=== INFORMACOES DE MEMORIA ==="),
+        ("ram", "=== INFORMACOES DE MEMORIA ===", "=== INFORMACOES DA CPU ==="),
+        ("disk", "=== INFORMACOES DE ARMAZENAMENTO ===", "=== INFORMACOES DA CPU ==="),
+    ] {
+        let output = Command::new("cargo")
+            .args(["run", "--", command, "--no-color"])
+            .output()
+            .expect("Falha ao executar comando");
+        
+        assert!(output.status.success());
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(stdout.contains(section), "{}: seção ausente", command);
+        assert!(!stdout.contains(other), "{}: seção de outro componente", command);
+    }
+    
+    // Pesos personalizados e mínimo máximo: sai com o código de --exit-code
+    // sempre que a pontuação exibida ficar abaixo de 10
+    let output = Command::new("cargo")
+        .args(["run", "--", "score", "--weights", "disk=0.2,cpu=0.5,ram=0.3", "--threshold", "10", "--exit-code", "3"])
+        .output()
+        .expect("Falha ao executar comando");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let shown: f64 = stdout
+        .lines()
+        .find_map(|line| line.split("PONTUAÇÃO GERAL: ").nth(1))
+        .and_then(|rest| rest.split('/').next())
+        .and_then(|score| score.parse().ok())
+        .expect("Pontuação geral deve ser exibida");
+    let below = String::from_utf8_lossy(&output.stderr).contains("abaixo do mínimo");
+    assert_eq!(output.status.code(), Some(if below { 3 } else { 0 }));
+    // Pontuação exibida abaixo de 10.0 (arredondada) está sempre abaixo do mínimo
+    assert!(below || shown >= 10.0);
+    
+    // Mínimo zero sempre é atingido
+    let output = Command::new("cargo")
+        .args(["run", "--", "score", "--threshold", "0", "--exit-code", "3"])
+        .output()
+        .expect("Falha ao executar comando");
+    assert!(output.status.success());
+    
+    // Pesos que não somam 1.0 são um argumento inválido
+    let output = Command::new("cargo")
+        .args(["run", "--", "score", "--weights", "cpu=0.5,ram=0.5,disk=0.5"])
+        .output()
+        .expect("Falha ao executar comando");
+    assert_eq!(output.status.code(), Some(2));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_json_output() {
+    // Testa a saída JSON completa
+    let output = Command::new("cargo")
+        .args(["run", "--features", "serde", "--", "--json"])
+        .output()
+        .expect("Falha ao executar comando");
+    
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let json: serde_json::Value = serde_json::from_str(stdout.trim())
+        .expect("Saída não é um JSON válido");
+    
+    for key in ["schema_version", "generated_at", "crate_version", "data"] {
+        assert!(json.get(key).is_some(), "Chave ausente: {}", key);
+    }
+    for key in ["cpu", "ram", "disks", "score"] {
+        assert!(json["data"].get(key).is_some(), "Chave ausente: data.{}", key);
+    }
+    assert!(json["data"]["disks"].is_array());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_save_json_report() {
+    // --save com --json grava o relatório completo em JSON
+    let path = std::env::temp_dir().join(format!("hardware_diagnostic_cli_{}.json", std::process::id()));
+    let output = Command::new("cargo")
+        .args(["run", "--features", "serde", "--", "--json", &format!("--save={}", path.display())])
+        .output()
+        .expect("Falha ao executar comando");
+    
+    assert!(output.status.success());
+    let contents = std::fs::read_to_string(&path).expect("Relatório não foi criado");
+    std::fs::remove_file(&path).ok();
+    let json: serde_json::Value = serde_json::from_str(&contents).expect("Arquivo não é um JSON válido");
+    assert_eq!(json["schema_version"], 2);
+    assert!(json["data"]["score"]["overall_score"].is_number());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_config_alerts_table() {
+    // Sem arquivo, --alert-config usa a tabela [alerts] de --config
+    let path = std::env::temp_dir().join(format!("hardware_diagnostic_cli_config_{}.toml", std::process::id()));
+    std::fs::write(&path, "[alerts]\nmin_score = 10.0\n").unwrap();
+    let output = Command::new("cargo")
+        .args(["run", "--features", "serde", "--", "--config", &path.display().to_string(), "--alert-config"])
+        .output()
+        .expect("Falha ao executar comando");
+    std::fs::remove_file(&path).ok();
+    
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Pontuação geral abaixo do mínimo"));
+    assert_ne!(output.status.code(), Some(0));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_reference_profile() {
+    // Uma especificação impossível de atender gera desvio e código 1
+    let path = std::env::temp_dir().join(format!("hardware_diagnostic_cli_reference_{}.json", std::process::id()));
+    std::fs::write(&path, r#"{"model": "Modelo teste", "cpu_cores": 100000}"#).unwrap();
+    let output = Command::new("cargo")
+        .args(["run", "--features", "serde", "--", "--reference", &path.display().to_string()])
+        .output()
+        .expect("Falha ao executar comando");
+    
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Modelo teste"), "{}", stdout);
+    assert!(stdout.contains("esperado 100000"), "{}", stdout);
+    assert_eq!(output.status.code(), Some(1));
+    
+    // Sem requisitos, a máquina sempre confere
+    std::fs::write(&path, "{}").unwrap();
+    let output = Command::new("cargo")
+        .args(["run", "--features", "serde", "--", "--reference", &path.display().to_string()])
+        .output()
+        .expect("Falha ao executar comando");
+    std::fs::remove_file(&path).ok();
+    assert!(output.status.success());
+}
+
+#[test]
+fn test_metrics_server() {
+    use std::io::{Read, Write};
+    use std::net::TcpStream;
+    
+    // O binário é executado diretamente para que o servidor seja encerrado com o teste
+    let port = 20_000 + (std::process::id() % 10_000) as u16;
+    let mut server = Command::new(env!("CARGO_BIN_EXE_hardware-diagnostic"))
+        .args(["--metrics-server", &port.to_string()])
+        .stderr(std::process::Stdio::null())
+        .spawn()
+        .expect("Falha ao iniciar o servidor");
+    
+    let scrape = |path: &str| -> Option<String> {
+        let mut stream = TcpStream::connect(("127.0.0.1", port)).ok()?;
+        write!(stream, "GET {} HTTP/1.1\r\nHost: localhost\r\n\r\n", path).ok()?;
+        let mut response = String::new();
+        stream.read_to_string(&mut response).ok()?;
+        Some(response)
+    };
+    let metrics = (0..100).find_map(|_| {
+        scrape("/metrics").or_else(|| {
+            std::thread::sleep(std::time::Duration::from_millis(100));
+            None
+        })
+    });
+    let not_found = scrape("/");
+    server.kill().ok();
+    server.wait().ok();
+    
+    let metrics = metrics.expect("Servidor não respondeu");
+    assert!(metrics.starts_with("HTTP/1.1 200 OK"));
+    assert!(metrics.contains("hardware_performance_score"));
+    assert!(metrics.contains("hardware_performance_category"));
+    assert!(not_found.unwrap_or_default().starts_with("HTTP/1.1 404"));
+}